@@ -0,0 +1,409 @@
+use std::slice::Iter;
+
+use crate::{
+    HasSpan, Parser, Span,
+    attributes::Attrlist,
+    blocks::{
+        Block, CompoundDelimitedBlock, ContentModel, IsBlock, SimpleBlock, metadata::BlockMetadata,
+    },
+    content::Content,
+    document::InterpretedValue,
+    internal::debug::DebugSliceReference,
+    span::MatchedItem,
+    strings::CowStr,
+    warnings::MatchAndWarnings,
+};
+
+/// The type of an admonition, which determines how the admonition is labeled
+/// and styled.
+///
+/// The label is specified either as the block style (e.g., `[NOTE]`) or as a
+/// special paragraph prefix (e.g., `NOTE:`). In both cases, the label must be
+/// uppercase.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum AdmonitionKind {
+    /// `NOTE`: Supplementary information.
+    Note,
+
+    /// `TIP`: A helpful suggestion.
+    Tip,
+
+    /// `IMPORTANT`: Information the reader shouldn't overlook.
+    Important,
+
+    /// `CAUTION`: Advises the reader to act carefully.
+    Caution,
+
+    /// `WARNING`: Informs the reader of danger, harm, or consequences.
+    Warning,
+}
+
+impl AdmonitionKind {
+    /// Interpret an uppercase label (e.g., `NOTE`) as an admonition kind.
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "NOTE" => Some(Self::Note),
+            "TIP" => Some(Self::Tip),
+            "IMPORTANT" => Some(Self::Important),
+            "CAUTION" => Some(Self::Caution),
+            "WARNING" => Some(Self::Warning),
+            _ => None,
+        }
+    }
+
+    /// Parse the label that introduces an admonition paragraph (e.g., `NOTE:
+    /// `).
+    ///
+    /// The label must be uppercase and immediately followed by a colon and at
+    /// least one space or tab. If found, returns the admonition kind and the
+    /// span that follows the label and whitespace.
+    pub(crate) fn parse_paragraph_label<'src>(
+        source: Span<'src>,
+    ) -> Option<MatchedItem<'src, Self>> {
+        let label = source.take_while(|c| c.is_ascii_uppercase());
+        let kind = Self::from_label(label.item.data())?;
+
+        let colon = label.after.take_prefix(":")?;
+        let space = colon.after.take_required_whitespace()?;
+
+        Some(MatchedItem {
+            item: kind,
+            after: space.after,
+        })
+    }
+
+    /// Returns the name of this admonition kind (e.g., `note`).
+    ///
+    /// This is the lowercase form of the label. It's used to look up the
+    /// document attribute (e.g., `note-caption`) that provides the text for
+    /// the label.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Tip => "tip",
+            Self::Important => "important",
+            Self::Caution => "caution",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+impl std::fmt::Debug for AdmonitionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdmonitionKind::Note => write!(f, "AdmonitionKind::Note"),
+            AdmonitionKind::Tip => write!(f, "AdmonitionKind::Tip"),
+            AdmonitionKind::Important => write!(f, "AdmonitionKind::Important"),
+            AdmonitionKind::Caution => write!(f, "AdmonitionKind::Caution"),
+            AdmonitionKind::Warning => write!(f, "AdmonitionKind::Warning"),
+        }
+    }
+}
+
+/// An admonition draws attention to a statement by taking it out of the
+/// content's flow and labeling it with a priority.
+///
+/// An admonition can take one of two forms:
+///
+/// * An **admonition paragraph** is a paragraph that starts with an admonition
+///   label (e.g., `NOTE: ...`) or has an admonition label as its block style
+///   (e.g., `[NOTE]`). Its content is available via [`content()`].
+/// * An **admonition block** is an example (`====`) or open (`--`) delimited
+///   block whose block style is an admonition label (e.g., `[NOTE]`). Its
+///   content is available via [`nested_blocks()`].
+///
+/// [`content()`]: Self::content
+/// [`nested_blocks()`]: IsBlock::nested_blocks
+#[derive(Clone, Eq, PartialEq)]
+pub struct AdmonitionBlock<'src> {
+    kind: AdmonitionKind,
+    textlabel: Option<String>,
+    content: Option<Content<'src>>,
+    blocks: Vec<Block<'src>>,
+    source: Span<'src>,
+    title_source: Option<Span<'src>>,
+    title: Option<String>,
+    anchor: Option<Span<'src>>,
+    anchor_reftext: Option<Span<'src>>,
+    attrlist: Option<Attrlist<'src>>,
+}
+
+impl<'src> AdmonitionBlock<'src> {
+    /// Parse an example or open delimited block whose block style is an
+    /// admonition label.
+    pub(crate) fn parse_delimited(
+        metadata: &BlockMetadata<'src>,
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, Option<MatchedItem<'src, Self>>>> {
+        let kind = Self::declared_kind(metadata)?;
+
+        let delimiter = metadata.block_start.take_normalized_line();
+        if delimiter.item.data() != "--" && !delimiter.item.starts_with("====") {
+            return None;
+        }
+
+        let cdb_maw = CompoundDelimitedBlock::parse(metadata, parser)?;
+        let cdb = cdb_maw.item?;
+
+        let textlabel = Self::resolve_textlabel(kind, metadata, parser);
+        let source = cdb.item.span();
+
+        Some(MatchAndWarnings {
+            item: Some(MatchedItem {
+                item: Self {
+                    kind,
+                    textlabel,
+                    content: None,
+                    blocks: cdb.item.into_blocks(),
+                    source,
+                    title_source: metadata.title_source,
+                    title: metadata.title.clone(),
+                    anchor: metadata.anchor,
+                    anchor_reftext: metadata.anchor_reftext,
+                    attrlist: metadata.attrlist.clone(),
+                },
+                after: cdb.after,
+            }),
+            warnings: cdb_maw.warnings,
+        })
+    }
+
+    /// Parse a paragraph that either starts with an admonition label (e.g.,
+    /// `NOTE:`) or whose block style is an admonition label (e.g., `[NOTE]`).
+    pub(crate) fn parse_paragraph(
+        metadata: &BlockMetadata<'src>,
+        parser: &mut Parser,
+    ) -> Option<MatchedItem<'src, Self>> {
+        let (kind, paragraph) = if let Some(kind) = Self::declared_kind(metadata) {
+            (kind, SimpleBlock::parse(metadata, parser)?)
+        } else {
+            // The label prefix is only recognized when no block style has been
+            // declared.
+            if metadata
+                .attrlist
+                .as_ref()
+                .and_then(|attrlist| attrlist.block_style())
+                .is_some()
+            {
+                return None;
+            }
+
+            let label = AdmonitionKind::parse_paragraph_label(metadata.block_start)?;
+
+            let content_metadata = BlockMetadata {
+                title_source: metadata.title_source,
+                title: metadata.title.clone(),
+                anchor: metadata.anchor,
+                anchor_reftext: metadata.anchor_reftext,
+                attrlist: metadata.attrlist.clone(),
+                source: metadata.source,
+                block_start: label.after,
+            };
+
+            (label.item, SimpleBlock::parse(&content_metadata, parser)?)
+        };
+
+        let textlabel = Self::resolve_textlabel(kind, metadata, parser);
+
+        Some(MatchedItem {
+            item: Self {
+                kind,
+                textlabel,
+                content: Some(paragraph.item.content().clone()),
+                blocks: vec![],
+                source: paragraph.item.span(),
+                title_source: metadata.title_source,
+                title: metadata.title.clone(),
+                anchor: metadata.anchor,
+                anchor_reftext: metadata.anchor_reftext,
+                attrlist: metadata.attrlist.clone(),
+            },
+            after: paragraph.after,
+        })
+    }
+
+    /// Returns the admonition kind named by the block style, if any.
+    fn declared_kind(metadata: &BlockMetadata<'src>) -> Option<AdmonitionKind> {
+        metadata
+            .attrlist
+            .as_ref()
+            .and_then(|attrlist| attrlist.block_style())
+            .and_then(AdmonitionKind::from_label)
+    }
+
+    /// An explicit `caption` attribute on the block takes precedence over the
+    /// `<name>-caption` document attribute (e.g., `note-caption`).
+    fn resolve_textlabel(
+        kind: AdmonitionKind,
+        metadata: &BlockMetadata<'src>,
+        parser: &Parser,
+    ) -> Option<String> {
+        if let Some(caption) = metadata
+            .attrlist
+            .as_ref()
+            .and_then(|attrlist| attrlist.named_attribute("caption"))
+        {
+            return Some(caption.value().to_owned());
+        }
+
+        match parser.attribute_value(format!("{}-caption", kind.name())) {
+            InterpretedValue::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of this admonition.
+    pub fn kind(&self) -> AdmonitionKind {
+        self.kind
+    }
+
+    /// Returns the name of this admonition (e.g., `note`).
+    ///
+    /// This is the lowercase form of the admonition label.
+    pub fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Returns the text used to label this admonition (e.g., `Note`).
+    ///
+    /// This is taken from the block's `caption` attribute, if present, and
+    /// otherwise from the document attribute corresponding to this
+    /// admonition's name (e.g., `note-caption`). Returns `None` if that
+    /// document attribute has been unset.
+    pub fn textlabel(&self) -> Option<&str> {
+        self.textlabel.as_deref()
+    }
+
+    /// Returns the interpreted content of an admonition paragraph.
+    ///
+    /// Returns `None` for an admonition block, whose content is available via
+    /// [`nested_blocks()`] instead.
+    ///
+    /// [`nested_blocks()`]: IsBlock::nested_blocks
+    pub fn content(&self) -> Option<&Content<'src>> {
+        self.content.as_ref()
+    }
+}
+
+impl<'src> IsBlock<'src> for AdmonitionBlock<'src> {
+    fn content_model(&self) -> ContentModel {
+        if self.content.is_some() {
+            ContentModel::Simple
+        } else {
+            ContentModel::Compound
+        }
+    }
+
+    fn rendered_content(&'src self) -> Option<&'src str> {
+        self.content.as_ref().map(|content| content.rendered())
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        "admonition".into()
+    }
+
+    fn nested_blocks(&'src self) -> Iter<'src, Block<'src>> {
+        self.blocks.iter()
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        self.title_source
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        self.anchor
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        self.anchor_reftext
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        self.attrlist.as_ref()
+    }
+}
+
+impl<'src> HasSpan<'src> for AdmonitionBlock<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for AdmonitionBlock<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdmonitionBlock")
+            .field("kind", &self.kind)
+            .field("textlabel", &self.textlabel)
+            .field("content", &self.content)
+            .field("blocks", &DebugSliceReference(&self.blocks))
+            .field("source", &self.source)
+            .field("title_source", &self.title_source)
+            .field("title", &self.title)
+            .field("anchor", &self.anchor)
+            .field("anchor_reftext", &self.anchor_reftext)
+            .field("attrlist", &self.attrlist)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{Span, blocks::AdmonitionKind};
+
+    #[test]
+    fn parse_paragraph_label() {
+        let mi = AdmonitionKind::parse_paragraph_label(Span::new("TIP: Be kind.")).unwrap();
+        assert_eq!(mi.item, AdmonitionKind::Tip);
+        assert_eq!(mi.after.data(), "Be kind.");
+        assert_eq!(mi.after.col(), 6);
+
+        let mi = AdmonitionKind::parse_paragraph_label(Span::new("WARNING:\t\tx")).unwrap();
+        assert_eq!(mi.item, AdmonitionKind::Warning);
+        assert_eq!(mi.after.data(), "x");
+
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new("NOTE:x")).is_none());
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new("NOTE :x")).is_none());
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new("Note: x")).is_none());
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new("NOTES: x")).is_none());
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new("NOTE::x")).is_none());
+        assert!(AdmonitionKind::parse_paragraph_label(Span::new(" NOTE: x")).is_none());
+    }
+
+    #[test]
+    fn kind_name() {
+        assert_eq!(AdmonitionKind::Note.name(), "note");
+        assert_eq!(AdmonitionKind::Tip.name(), "tip");
+        assert_eq!(AdmonitionKind::Important.name(), "important");
+        assert_eq!(AdmonitionKind::Caution.name(), "caution");
+        assert_eq!(AdmonitionKind::Warning.name(), "warning");
+    }
+
+    #[test]
+    fn kind_impl_debug() {
+        assert_eq!(
+            format!("{:?}", AdmonitionKind::Note),
+            "AdmonitionKind::Note"
+        );
+        assert_eq!(format!("{:?}", AdmonitionKind::Tip), "AdmonitionKind::Tip");
+        assert_eq!(
+            format!("{:?}", AdmonitionKind::Important),
+            "AdmonitionKind::Important"
+        );
+        assert_eq!(
+            format!("{:?}", AdmonitionKind::Caution),
+            "AdmonitionKind::Caution"
+        );
+        assert_eq!(
+            format!("{:?}", AdmonitionKind::Warning),
+            "AdmonitionKind::Warning"
+        );
+    }
+}
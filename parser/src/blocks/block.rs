@@ -4,8 +4,9 @@ use crate::{
     HasSpan, Parser, Span,
     attributes::Attrlist,
     blocks::{
-        Break, CompoundDelimitedBlock, ContentModel, IsBlock, MediaBlock, Preamble,
-        RawDelimitedBlock, SectionBlock, SimpleBlock, metadata::BlockMetadata,
+        AdmonitionBlock, AdmonitionKind, Break, CompoundDelimitedBlock, ContentModel,
+        DescriptionListItem, IsBlock, ListBlock, ListItem, MediaBlock, Preamble, RawDelimitedBlock,
+        SectionBlock, SimpleBlock, TableBlock, metadata::BlockMetadata,
     },
     content::SubstitutionGroup,
     document::{Attribute, RefType},
@@ -50,6 +51,22 @@ pub enum Block<'src> {
     /// A delimited block that can contain other blocks.
     CompoundDelimited(CompoundDelimitedBlock<'src>),
 
+    /// An admonition draws attention to a statement by labeling it with a
+    /// priority (e.g., `NOTE` or `WARNING`).
+    Admonition(AdmonitionBlock<'src>),
+
+    /// A table arranges content into rows and columns of cells.
+    Table(TableBlock<'src>),
+
+    /// A list is a sequence of list items, each introduced by a list marker.
+    List(ListBlock<'src>),
+
+    /// A single item within a list.
+    ListItem(ListItem<'src>),
+
+    /// A single item within a description list.
+    DescriptionListItem(DescriptionListItem<'src>),
+
     /// Content between the end of the document header and the first section
     /// title in the document body is called the preamble.
     Preamble(Preamble<'src>),
@@ -78,6 +95,15 @@ impl<'src> std::fmt::Debug for Block<'src> {
                 .field(block)
                 .finish(),
 
+            Block::Admonition(block) => f.debug_tuple("Block::Admonition").field(block).finish(),
+            Block::Table(block) => f.debug_tuple("Block::Table").field(block).finish(),
+            Block::List(block) => f.debug_tuple("Block::List").field(block).finish(),
+            Block::ListItem(block) => f.debug_tuple("Block::ListItem").field(block).finish(),
+
+            Block::DescriptionListItem(block) => f
+                .debug_tuple("Block::DescriptionListItem")
+                .field(block)
+                .finish(),
             Block::Preamble(block) => f.debug_tuple("Block::Preamble").field(block).finish(),
             Block::Break(break_) => f.debug_tuple("Block::Break").field(break_).finish(),
 
@@ -97,6 +123,8 @@ impl<'src> Block<'src> {
         source: Span<'src>,
         parser: &mut Parser,
     ) -> MatchAndWarnings<'src, Option<MatchedItem<'src, Self>>> {
+        let source = source.discard_empty_lines();
+
         // Optimization: If the first line doesn't match any of the early indications
         // for delimited blocks, titles, or attrlists, we can skip directly to treating
         // this as a simple block. That saves quite a bit of parsing time.
@@ -107,9 +135,24 @@ impl<'src> Block<'src> {
         if let Some(first_char) = source.chars().next()
             && !matches!(
                 first_char,
-                '.' | '#' | '=' | '/' | '-' | '+' | '*' | '_' | '[' | ':' | '\'' | '<'
+                '.' | '#'
+                    | '='
+                    | '/'
+                    | '-'
+                    | '+'
+                    | '*'
+                    | '_'
+                    | '['
+                    | ':'
+                    | '\''
+                    | '<'
+                    | '|'
+                    | ','
+                    | '!'
             )
             && !first_line.item.contains("::")
+            && !ListBlock::is_list_item(source)
+            && AdmonitionKind::parse_paragraph_label(source).is_none()
             && let Some(MatchedItem {
                 item: simple_block,
                 after,
@@ -120,7 +163,7 @@ impl<'src> Block<'src> {
 
             Self::register_block_id(
                 block.id(),
-                block.title(),
+                block.reftext(),
                 block.span(),
                 parser,
                 &mut warnings,
@@ -171,7 +214,7 @@ impl<'src> Block<'src> {
 
                 Self::register_block_id(
                     block.id(),
-                    block.title(),
+                    block.reftext(),
                     block.span(),
                     parser,
                     &mut warnings,
@@ -186,6 +229,32 @@ impl<'src> Block<'src> {
                 };
             }
 
+            if let Some(mut admonition_maw) = AdmonitionBlock::parse_delimited(&metadata, parser)
+                && let Some(admonition) = admonition_maw.item
+            {
+                if !admonition_maw.warnings.is_empty() {
+                    warnings.append(&mut admonition_maw.warnings);
+                }
+
+                let block = Self::Admonition(admonition.item);
+
+                Self::register_block_id(
+                    block.id(),
+                    block.reftext(),
+                    block.span(),
+                    parser,
+                    &mut warnings,
+                );
+
+                return MatchAndWarnings {
+                    item: Some(MatchedItem {
+                        item: block,
+                        after: admonition.after,
+                    }),
+                    warnings,
+                };
+            }
+
             if let Some(mut cdb_maw) = CompoundDelimitedBlock::parse(&metadata, parser)
                 && let Some(cdb) = cdb_maw.item
             {
@@ -197,7 +266,7 @@ impl<'src> Block<'src> {
 
                 Self::register_block_id(
                     block.id(),
-                    block.title(),
+                    block.reftext(),
                     block.span(),
                     parser,
                     &mut warnings,
@@ -212,6 +281,32 @@ impl<'src> Block<'src> {
                 };
             }
 
+            if let Some(mut table_maw) = TableBlock::parse(&metadata, parser)
+                && let Some(table) = table_maw.item
+            {
+                if !table_maw.warnings.is_empty() {
+                    warnings.append(&mut table_maw.warnings);
+                }
+
+                let block = Self::Table(table.item);
+
+                Self::register_block_id(
+                    block.id(),
+                    block.reftext(),
+                    block.span(),
+                    parser,
+                    &mut warnings,
+                );
+
+                return MatchAndWarnings {
+                    item: Some(MatchedItem {
+                        item: block,
+                        after: table.after,
+                    }),
+                    warnings,
+                };
+            }
+
             // Try to discern the block type by scanning the first line.
             let line = metadata.block_start.take_normalized_line();
 
@@ -233,7 +328,7 @@ impl<'src> Block<'src> {
 
                     Self::register_block_id(
                         block.id(),
-                        block.title(),
+                        block.reftext(),
                         block.span(),
                         parser,
                         &mut warnings,
@@ -285,6 +380,50 @@ impl<'src> Block<'src> {
                 };
             }
 
+            if let Some(mut list_maw) = ListBlock::parse(&metadata, parser) {
+                if !list_maw.warnings.is_empty() {
+                    warnings.append(&mut list_maw.warnings);
+                }
+
+                let block = Self::List(list_maw.item.item);
+
+                Self::register_block_id(
+                    block.id(),
+                    block.reftext(),
+                    block.span(),
+                    parser,
+                    &mut warnings,
+                );
+
+                return MatchAndWarnings {
+                    item: Some(MatchedItem {
+                        item: block,
+                        after: list_maw.item.after,
+                    }),
+                    warnings,
+                };
+            }
+
+            if let Some(admonition) = AdmonitionBlock::parse_paragraph(&metadata, parser) {
+                let block = Self::Admonition(admonition.item);
+
+                Self::register_block_id(
+                    block.id(),
+                    block.reftext(),
+                    block.span(),
+                    parser,
+                    &mut warnings,
+                );
+
+                return MatchAndWarnings {
+                    item: Some(MatchedItem {
+                        item: block,
+                        after: admonition.after,
+                    }),
+                    warnings,
+                };
+            }
+
             // First, let's look for a fun edge case. Perhaps the text contains block
             // metadata but no block immediately following. If we're not careful, we could
             // spin in a loop (for example, `parse_blocks_until`) thinking there will be
@@ -324,7 +463,7 @@ impl<'src> Block<'src> {
         if let Some(ref matched_item) = result.item {
             Self::register_block_id(
                 matched_item.item.id(),
-                matched_item.item.title(),
+                matched_item.item.reftext(),
                 matched_item.item.span(),
                 parser,
                 &mut result.warnings,
@@ -334,24 +473,37 @@ impl<'src> Block<'src> {
         result
     }
 
+    /// Returns the reference text for this block, which is used as the default
+    /// text for cross references to the block.
+    ///
+    /// This is the reference text from the block's anchor (e.g.,
+    /// `[[id,reftext]]`) or `reftext` attribute, if present; otherwise, the
+    /// block's title.
+    pub(crate) fn reftext(&'src self) -> Option<&'src str> {
+        self.anchor_reftext()
+            .map(|reftext| reftext.data())
+            .or_else(|| {
+                self.attrlist()
+                    .and_then(|attrlist| attrlist.named_attribute("reftext"))
+                    .map(|reftext| reftext.value())
+            })
+            .or_else(|| self.title())
+    }
+
     /// Register a block's ID with the catalog if the block has an ID.
     ///
     /// This should be called for all block types except `SectionBlock`,
     /// which handles its own catalog registration.
-    fn register_block_id(
+    pub(crate) fn register_block_id(
         id: Option<&str>,
-        title: Option<&str>,
+        reftext: Option<&str>,
         span: Span<'src>,
         parser: &mut Parser,
         warnings: &mut Vec<Warning<'src>>,
     ) {
         if let Some(id) = id
             && let Some(catalog) = parser.catalog_mut()
-            && let Err(_duplicate_error) = catalog.register_ref(
-                id,
-                title, // Use block title as reftext if available
-                RefType::Anchor,
-            )
+            && let Err(_duplicate_error) = catalog.register_ref(id, reftext, RefType::Anchor)
         {
             // If registration fails due to duplicate ID, issue a warning.
             warnings.push(Warning {
@@ -370,6 +522,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(_) => ContentModel::Compound,
             Self::RawDelimited(b) => b.content_model(),
             Self::CompoundDelimited(b) => b.content_model(),
+            Self::Admonition(b) => b.content_model(),
+            Self::Table(b) => b.content_model(),
+            Self::List(b) => b.content_model(),
+            Self::ListItem(b) => b.content_model(),
+            Self::DescriptionListItem(b) => b.content_model(),
             Self::Preamble(b) => b.content_model(),
             Self::Break(b) => b.content_model(),
             Self::DocumentAttribute(b) => b.content_model(),
@@ -383,6 +540,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.rendered_content(),
             Self::RawDelimited(b) => b.rendered_content(),
             Self::CompoundDelimited(b) => b.rendered_content(),
+            Self::Admonition(b) => b.rendered_content(),
+            Self::Table(b) => b.rendered_content(),
+            Self::List(b) => b.rendered_content(),
+            Self::ListItem(b) => b.rendered_content(),
+            Self::DescriptionListItem(b) => b.rendered_content(),
             Self::Preamble(b) => b.rendered_content(),
             Self::Break(b) => b.rendered_content(),
             Self::DocumentAttribute(b) => b.rendered_content(),
@@ -396,6 +558,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.raw_context(),
             Self::RawDelimited(b) => b.raw_context(),
             Self::CompoundDelimited(b) => b.raw_context(),
+            Self::Admonition(b) => b.raw_context(),
+            Self::Table(b) => b.raw_context(),
+            Self::List(b) => b.raw_context(),
+            Self::ListItem(b) => b.raw_context(),
+            Self::DescriptionListItem(b) => b.raw_context(),
             Self::Preamble(b) => b.raw_context(),
             Self::Break(b) => b.raw_context(),
             Self::DocumentAttribute(b) => b.raw_context(),
@@ -409,12 +576,53 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.nested_blocks(),
             Self::RawDelimited(b) => b.nested_blocks(),
             Self::CompoundDelimited(b) => b.nested_blocks(),
+            Self::Admonition(b) => b.nested_blocks(),
+            Self::Table(b) => b.nested_blocks(),
+            Self::List(b) => b.nested_blocks(),
+            Self::ListItem(b) => b.nested_blocks(),
+            Self::DescriptionListItem(b) => b.nested_blocks(),
             Self::Preamble(b) => b.nested_blocks(),
             Self::Break(b) => b.nested_blocks(),
             Self::DocumentAttribute(b) => b.nested_blocks(),
         }
     }
 
+    fn options(&'src self) -> Vec<&'src str> {
+        match self {
+            Self::Simple(b) => b.options(),
+            Self::Media(b) => b.options(),
+            Self::Section(b) => b.options(),
+            Self::RawDelimited(b) => b.options(),
+            Self::CompoundDelimited(b) => b.options(),
+            Self::Admonition(b) => b.options(),
+            Self::Table(b) => b.options(),
+            Self::List(b) => b.options(),
+            Self::ListItem(b) => b.options(),
+            Self::DescriptionListItem(b) => b.options(),
+            Self::Preamble(b) => b.options(),
+            Self::Break(b) => b.options(),
+            Self::DocumentAttribute(b) => b.options(),
+        }
+    }
+
+    fn has_option<N: AsRef<str>>(&'src self, name: N) -> bool {
+        match self {
+            Self::Simple(b) => b.has_option(name),
+            Self::Media(b) => b.has_option(name),
+            Self::Section(b) => b.has_option(name),
+            Self::RawDelimited(b) => b.has_option(name),
+            Self::CompoundDelimited(b) => b.has_option(name),
+            Self::Admonition(b) => b.has_option(name),
+            Self::Table(b) => b.has_option(name),
+            Self::List(b) => b.has_option(name),
+            Self::ListItem(b) => b.has_option(name),
+            Self::DescriptionListItem(b) => b.has_option(name),
+            Self::Preamble(b) => b.has_option(name),
+            Self::Break(b) => b.has_option(name),
+            Self::DocumentAttribute(b) => b.has_option(name),
+        }
+    }
+
     fn title_source(&'src self) -> Option<Span<'src>> {
         match self {
             Self::Simple(b) => b.title_source(),
@@ -422,6 +630,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.title_source(),
             Self::RawDelimited(b) => b.title_source(),
             Self::CompoundDelimited(b) => b.title_source(),
+            Self::Admonition(b) => b.title_source(),
+            Self::Table(b) => b.title_source(),
+            Self::List(b) => b.title_source(),
+            Self::ListItem(b) => b.title_source(),
+            Self::DescriptionListItem(b) => b.title_source(),
             Self::Preamble(b) => b.title_source(),
             Self::Break(b) => b.title_source(),
             Self::DocumentAttribute(b) => b.title_source(),
@@ -435,6 +648,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.title(),
             Self::RawDelimited(b) => b.title(),
             Self::CompoundDelimited(b) => b.title(),
+            Self::Admonition(b) => b.title(),
+            Self::Table(b) => b.title(),
+            Self::List(b) => b.title(),
+            Self::ListItem(b) => b.title(),
+            Self::DescriptionListItem(b) => b.title(),
             Self::Preamble(b) => b.title(),
             Self::Break(b) => b.title(),
             Self::DocumentAttribute(b) => b.title(),
@@ -448,6 +666,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.anchor(),
             Self::RawDelimited(b) => b.anchor(),
             Self::CompoundDelimited(b) => b.anchor(),
+            Self::Admonition(b) => b.anchor(),
+            Self::Table(b) => b.anchor(),
+            Self::List(b) => b.anchor(),
+            Self::ListItem(b) => b.anchor(),
+            Self::DescriptionListItem(b) => b.anchor(),
             Self::Preamble(b) => b.anchor(),
             Self::Break(b) => b.anchor(),
             Self::DocumentAttribute(b) => b.anchor(),
@@ -461,6 +684,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.anchor_reftext(),
             Self::RawDelimited(b) => b.anchor_reftext(),
             Self::CompoundDelimited(b) => b.anchor_reftext(),
+            Self::Admonition(b) => b.anchor_reftext(),
+            Self::Table(b) => b.anchor_reftext(),
+            Self::List(b) => b.anchor_reftext(),
+            Self::ListItem(b) => b.anchor_reftext(),
+            Self::DescriptionListItem(b) => b.anchor_reftext(),
             Self::Preamble(b) => b.anchor_reftext(),
             Self::Break(b) => b.anchor_reftext(),
             Self::DocumentAttribute(b) => b.anchor_reftext(),
@@ -474,6 +702,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.attrlist(),
             Self::RawDelimited(b) => b.attrlist(),
             Self::CompoundDelimited(b) => b.attrlist(),
+            Self::Admonition(b) => b.attrlist(),
+            Self::Table(b) => b.attrlist(),
+            Self::List(b) => b.attrlist(),
+            Self::ListItem(b) => b.attrlist(),
+            Self::DescriptionListItem(b) => b.attrlist(),
             Self::Preamble(b) => b.attrlist(),
             Self::Break(b) => b.attrlist(),
             Self::DocumentAttribute(b) => b.attrlist(),
@@ -487,6 +720,11 @@ impl<'src> IsBlock<'src> for Block<'src> {
             Self::Section(b) => b.substitution_group(),
             Self::RawDelimited(b) => b.substitution_group(),
             Self::CompoundDelimited(b) => b.substitution_group(),
+            Self::Admonition(b) => b.substitution_group(),
+            Self::Table(b) => b.substitution_group(),
+            Self::List(b) => b.substitution_group(),
+            Self::ListItem(b) => b.substitution_group(),
+            Self::DescriptionListItem(b) => b.substitution_group(),
             Self::Preamble(b) => b.substitution_group(),
             Self::Break(b) => b.substitution_group(),
             Self::DocumentAttribute(b) => b.substitution_group(),
@@ -502,6 +740,11 @@ impl<'src> HasSpan<'src> for Block<'src> {
             Self::Section(b) => b.span(),
             Self::RawDelimited(b) => b.span(),
             Self::CompoundDelimited(b) => b.span(),
+            Self::Admonition(b) => b.span(),
+            Self::Table(b) => b.span(),
+            Self::List(b) => b.span(),
+            Self::ListItem(b) => b.span(),
+            Self::DescriptionListItem(b) => b.span(),
             Self::Preamble(b) => b.span(),
             Self::Break(b) => b.span(),
             Self::DocumentAttribute(b) => b.span(),
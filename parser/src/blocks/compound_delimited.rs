@@ -101,7 +101,7 @@ impl<'src> CompoundDelimitedBlock<'src> {
 
         let inside_delimiters = delimiter.after.trim_remainder(closing_delimiter);
 
-        let maw_blocks = parse_blocks_until(inside_delimiters, |_| false, parser);
+        let maw_blocks = parse_blocks_until(inside_delimiters, |_, _| false, parser);
 
         let blocks = maw_blocks.item;
         let source = metadata
@@ -137,6 +137,11 @@ impl<'src> CompoundDelimitedBlock<'src> {
             },
         })
     }
+
+    /// Consume this block, returning the blocks nested within it.
+    pub(crate) fn into_blocks(self) -> Vec<Block<'src>> {
+        self.blocks
+    }
 }
 
 impl<'src> IsBlock<'src> for CompoundDelimitedBlock<'src> {
@@ -2276,6 +2281,7 @@ mod tests {
                         offset: 5,
                     },
                     rendered: "block1",
+                    passthroughs: [],
                 },
                 source: Span {
                     data: "block1",
@@ -2301,6 +2307,7 @@ mod tests {
                         offset: 13,
                     },
                     rendered: "block2",
+                    passthroughs: [],
                 },
                 source: Span {
                     data: "block2",
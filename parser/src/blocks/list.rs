@@ -0,0 +1,2265 @@
+use std::slice::Iter;
+
+use crate::{
+    HasSpan, Parser, Span,
+    attributes::Attrlist,
+    blocks::{
+        Block, CompoundDelimitedBlock, ContentModel, IsBlock, RawDelimitedBlock, TableBlock,
+        metadata::BlockMetadata,
+    },
+    content::{Content, SubstitutionGroup},
+    internal::debug::DebugSliceReference,
+    span::MatchedItem,
+    strings::CowStr,
+    warnings::{MatchAndWarnings, Warning, WarningType},
+};
+
+/// The type of a list is determined by the marker used to introduce its
+/// items.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum ListType {
+    /// An unordered list is a list with items prefixed with a symbol, such as
+    /// a disc (aka bullet). Items are marked using an asterisk (`*`) or a
+    /// hyphen (`-`).
+    Unordered,
+
+    /// An ordered list is a list with numbered items. Items are marked using
+    /// one or more periods (`.`) or an explicit number (e.g., `1.`, `a.`, or
+    /// `i)`).
+    Ordered,
+
+    /// A description list is a list of terms, each of which is associated with
+    /// a description. Each term is followed by a term delimiter (`::`, `:::`,
+    /// `::::`, or `;;`).
+    Description,
+
+    /// A callout list describes the callouts (e.g., `<1>`) in the preceding
+    /// verbatim block. Items are marked using the callout number enclosed in
+    /// angle brackets (e.g., `<1>`) or `<.>` to number the items
+    /// automatically.
+    Callout,
+}
+
+impl std::fmt::Debug for ListType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListType::Unordered => write!(f, "ListType::Unordered"),
+            ListType::Ordered => write!(f, "ListType::Ordered"),
+            ListType::Description => write!(f, "ListType::Description"),
+            ListType::Callout => write!(f, "ListType::Callout"),
+        }
+    }
+}
+
+/// The presentation style of a description list, as selected by the list's
+/// block style.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum DescriptionListStyle {
+    /// Each description is displayed below its term(s). This is the default
+    /// style.
+    Default,
+
+    /// Each term and its description are arranged side by side (`[horizontal]`
+    /// block style).
+    Horizontal,
+
+    /// Each item is presented as a numbered question and answer (`[qanda]`
+    /// block style). The terms are the questions and the description is the
+    /// answer.
+    Qanda,
+}
+
+impl std::fmt::Debug for DescriptionListStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DescriptionListStyle::Default => write!(f, "DescriptionListStyle::Default"),
+            DescriptionListStyle::Horizontal => write!(f, "DescriptionListStyle::Horizontal"),
+            DescriptionListStyle::Qanda => write!(f, "DescriptionListStyle::Qanda"),
+        }
+    }
+}
+
+/// The numeration style used for the items of an ordered list.
+///
+/// Unless overridden by the list's block style, the style is inferred from the
+/// marker used for the first item in the list.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum NumberingStyle {
+    /// Decimal numbers (`1`, `2`, `3`, ...). This is the default style.
+    Arabic,
+
+    /// Decimal numbers padded with a leading zero (`01`, `02`, `03`, ...).
+    Decimal,
+
+    /// Lowercase letters (`a`, `b`, `c`, ...).
+    LowerAlpha,
+
+    /// Uppercase letters (`A`, `B`, `C`, ...).
+    UpperAlpha,
+
+    /// Lowercase roman numerals (`i`, `ii`, `iii`, ...).
+    LowerRoman,
+
+    /// Uppercase roman numerals (`I`, `II`, `III`, ...).
+    UpperRoman,
+
+    /// Lowercase greek letters (`α`, `β`, `γ`, ...).
+    LowerGreek,
+}
+
+impl NumberingStyle {
+    /// Default styles for implicitly-numbered lists, indexed by the number of
+    /// periods in the marker less one.
+    const IMPLICIT_STYLES: [NumberingStyle; 5] = [
+        NumberingStyle::Arabic,
+        NumberingStyle::LowerAlpha,
+        NumberingStyle::LowerRoman,
+        NumberingStyle::UpperAlpha,
+        NumberingStyle::UpperRoman,
+    ];
+
+    /// Interpret a block style (e.g., `loweralpha`) as a numbering style.
+    fn from_block_style(block_style: &str) -> Option<Self> {
+        match block_style {
+            "arabic" => Some(Self::Arabic),
+            "decimal" => Some(Self::Decimal),
+            "loweralpha" => Some(Self::LowerAlpha),
+            "upperalpha" => Some(Self::UpperAlpha),
+            "lowerroman" => Some(Self::LowerRoman),
+            "upperroman" => Some(Self::UpperRoman),
+            "lowergreek" => Some(Self::LowerGreek),
+            _ => None,
+        }
+    }
+
+    /// Format `ordinal` the way it would appear in an explicit list marker of
+    /// this style (without the trailing `.` or `)`).
+    fn format_ordinal(self, ordinal: u32) -> String {
+        match self {
+            Self::LowerAlpha | Self::UpperAlpha => {
+                let base = if self == Self::LowerAlpha { b'a' } else { b'A' };
+                match u8::try_from(ordinal) {
+                    Ok(n @ 1..=26) => char::from(base + n - 1).to_string(),
+                    _ => ordinal.to_string(),
+                }
+            }
+
+            Self::LowerRoman => int_to_roman(ordinal).to_lowercase(),
+            Self::UpperRoman => int_to_roman(ordinal),
+            _ => ordinal.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Debug for NumberingStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberingStyle::Arabic => write!(f, "NumberingStyle::Arabic"),
+            NumberingStyle::Decimal => write!(f, "NumberingStyle::Decimal"),
+            NumberingStyle::LowerAlpha => write!(f, "NumberingStyle::LowerAlpha"),
+            NumberingStyle::UpperAlpha => write!(f, "NumberingStyle::UpperAlpha"),
+            NumberingStyle::LowerRoman => write!(f, "NumberingStyle::LowerRoman"),
+            NumberingStyle::UpperRoman => write!(f, "NumberingStyle::UpperRoman"),
+            NumberingStyle::LowerGreek => write!(f, "NumberingStyle::LowerGreek"),
+        }
+    }
+}
+
+/// A list is a sequence of adjacent [`ListItem`]s (or
+/// [`DescriptionListItem`]s) that share the same list marker (or term
+/// delimiter).
+///
+/// A list item whose marker differs from that of the current list (and from
+/// those of any enclosing lists) starts a new, nested list. That nested list is
+/// attached to the preceding list item.
+///
+/// Each item in the list is represented as a [`Block::ListItem`] (or
+/// [`Block::DescriptionListItem`]) and can be accessed via
+/// [`nested_blocks()`].
+///
+/// [`nested_blocks()`]: IsBlock::nested_blocks
+#[derive(Clone, Eq, PartialEq)]
+pub struct ListBlock<'src> {
+    type_: ListType,
+    items: Vec<Block<'src>>,
+    source: Span<'src>,
+    title_source: Option<Span<'src>>,
+    title: Option<String>,
+    anchor: Option<Span<'src>>,
+    anchor_reftext: Option<Span<'src>>,
+    attrlist: Option<Attrlist<'src>>,
+}
+
+impl<'src> ListBlock<'src> {
+    /// Returns `true` if the first line of `source` starts with a list item
+    /// marker.
+    pub(crate) fn is_list_item(source: Span<'src>) -> bool {
+        ListMarker::parse(source).is_some()
+    }
+
+    pub(crate) fn parse(
+        metadata: &BlockMetadata<'src>,
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, MatchedItem<'src, Self>>> {
+        let mut maw = Self::parse_with_parents(metadata, parser, &[])?;
+        maw.item.after = maw.item.after.discard_empty_lines();
+        Some(maw)
+    }
+
+    /// Parse a list whose items may not use any of the markers already in use
+    /// by the enclosing lists.
+    ///
+    /// Unlike [`parse()`], this does not discard empty lines after the list
+    /// so that the caller can tell if the next block was offset by an empty
+    /// line.
+    ///
+    /// [`parse()`]: Self::parse
+    fn parse_with_parents(
+        metadata: &BlockMetadata<'src>,
+        parser: &mut Parser,
+        parent_markers: &[ListMarker<'src>],
+    ) -> Option<MatchAndWarnings<'src, MatchedItem<'src, Self>>> {
+        let first_marker = ListMarker::parse(metadata.block_start)?.item;
+
+        let mut markers = parent_markers.to_vec();
+        markers.push(first_marker);
+
+        let mut items: Vec<Block<'src>> = vec![];
+        let mut warnings: Vec<Warning<'src>> = vec![];
+        let mut next = metadata.block_start;
+
+        // If the first item is explicitly numbered, subsequent items are expected to
+        // be numbered sequentially from there.
+        let first_ordinal = first_marker.ordinal();
+
+        // Callout list items are always expected to be numbered sequentially from 1.
+        let mut autonum = 0;
+
+        while let Some(mut item_maw) = Self::parse_item(next, &markers, parser) {
+            if first_marker.type_ == ListType::Callout
+                && let Block::ListItem(item) = &mut item_maw.item.item
+            {
+                let expected = items.len() + 1;
+
+                let actual = match callout_ordinal(item.marker.data()) {
+                    Some(ordinal) => ordinal,
+                    None => {
+                        autonum += 1;
+                        autonum
+                    }
+                };
+
+                if actual != expected {
+                    warnings.push(Warning {
+                        source: item.marker,
+                        warning: WarningType::ListItemOutOfSequence(
+                            expected.to_string(),
+                            actual.to_string(),
+                        ),
+                    });
+                }
+
+                item.callout_ids = parser.callouts.callout_ids(expected);
+
+                if item.callout_ids.is_empty() {
+                    warnings.push(Warning {
+                        source: item.marker,
+                        warning: WarningType::NoCalloutFound(expected),
+                    });
+                }
+            } else if let Some(first_ordinal) = first_ordinal
+                && let Block::ListItem(item) = &item_maw.item.item
+                && let Some((style, actual)) = explicit_ordinal(item.marker.data())
+                && let Some(expected) = u32::try_from(items.len())
+                    .ok()
+                    .and_then(|count| first_ordinal.checked_add(count))
+            {
+                // NOTE: If the expected ordinal can't be represented, we skip the
+                // sequence check rather than report a bogus ordinal.
+                if actual != expected {
+                    warnings.push(Warning {
+                        source: item.marker,
+                        warning: WarningType::ListItemOutOfSequence(
+                            style.format_ordinal(expected),
+                            style.format_ordinal(actual),
+                        ),
+                    });
+                }
+            }
+
+            if !item_maw.warnings.is_empty() {
+                warnings.append(&mut item_maw.warnings);
+            }
+
+            items.push(item_maw.item.item);
+            next = item_maw.item.after;
+
+            // Adjacent list items with the same marker are joined into the same list,
+            // even if separated by empty lines.
+            let maybe_sibling = next.discard_empty_lines();
+            match ListMarker::parse(maybe_sibling) {
+                Some(marker) if marker.item.is_same_kind_as(&first_marker) => {
+                    next = maybe_sibling;
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+
+        // Callouts registered from here on belong to the next callout list.
+        if first_marker.type_ == ListType::Callout {
+            parser.callouts.next_list();
+        }
+
+        let source = metadata
+            .source
+            .trim_remainder(next)
+            .trim_trailing_whitespace();
+
+        Some(MatchAndWarnings {
+            item: MatchedItem {
+                item: Self {
+                    type_: first_marker.type_,
+                    items,
+                    source,
+                    title_source: metadata.title_source,
+                    title: metadata.title.clone(),
+                    anchor: metadata.anchor,
+                    anchor_reftext: metadata.anchor_reftext,
+                    attrlist: metadata.attrlist.clone(),
+                },
+                after: next,
+            },
+            warnings,
+        })
+    }
+
+    /// Parse a single item of the list whose marker is the last entry in
+    /// `markers`.
+    fn parse_item(
+        source: Span<'src>,
+        markers: &[ListMarker<'src>],
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, MatchedItem<'src, Block<'src>>>> {
+        if markers.last()?.type_ == ListType::Description {
+            let maw = DescriptionListItem::parse(source, markers, parser)?;
+
+            Some(MatchAndWarnings {
+                item: MatchedItem {
+                    item: Block::DescriptionListItem(maw.item.item),
+                    after: maw.item.after,
+                },
+                warnings: maw.warnings,
+            })
+        } else {
+            let maw = ListItem::parse(source, markers, parser)?;
+
+            Some(MatchAndWarnings {
+                item: MatchedItem {
+                    item: Block::ListItem(maw.item.item),
+                    after: maw.item.after,
+                },
+                warnings: maw.warnings,
+            })
+        }
+    }
+
+    /// Return the type of this list.
+    pub fn type_(&self) -> ListType {
+        self.type_
+    }
+
+    /// Returns `true` if any item in this list is a checklist item.
+    ///
+    /// A checklist is given the implicit `checklist` option.
+    fn is_checklist(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(item, Block::ListItem(item) if item.checked.is_some()))
+    }
+
+    /// Return the presentation style for a description list.
+    ///
+    /// This is determined by the declared block style: `[horizontal]` or
+    /// `[qanda]`. Any other block style (or none) results in
+    /// [`DescriptionListStyle::Default`].
+    ///
+    /// Returns `None` if this is not a description list.
+    pub fn description_list_style(&self) -> Option<DescriptionListStyle> {
+        if self.type_ != ListType::Description {
+            return None;
+        }
+
+        Some(
+            match self
+                .attrlist
+                .as_ref()
+                .and_then(|attrlist| attrlist.block_style())
+            {
+                Some("horizontal") => DescriptionListStyle::Horizontal,
+                Some("qanda") => DescriptionListStyle::Qanda,
+                _ => DescriptionListStyle::Default,
+            },
+        )
+    }
+
+    /// Return the numbering style for an ordered list.
+    ///
+    /// A numbering style declared via the block style (e.g., `[loweralpha]`)
+    /// takes precedence. Otherwise, the style is inferred from the marker of
+    /// the first list item: Explicit markers imply their own style (e.g., `a.`
+    /// implies [`NumberingStyle::LowerAlpha`]) and implicit markers are
+    /// assigned a style based on the number of periods.
+    ///
+    /// Returns `None` if this is not an ordered list.
+    pub fn numbering_style(&self) -> Option<NumberingStyle> {
+        if self.type_ != ListType::Ordered {
+            return None;
+        }
+
+        if let Some(style) = self
+            .attrlist
+            .as_ref()
+            .and_then(|attrlist| attrlist.block_style())
+            .and_then(NumberingStyle::from_block_style)
+        {
+            return Some(style);
+        }
+
+        let marker = self.first_marker()?;
+
+        Some(match explicit_ordinal(marker) {
+            Some((style, _)) => style,
+            None => NumberingStyle::IMPLICIT_STYLES
+                .get(marker.len() - 1)
+                .copied()
+                .unwrap_or(NumberingStyle::Arabic),
+        })
+    }
+
+    /// Return the number at which an ordered list starts, if other than the
+    /// default.
+    ///
+    /// This is taken from the `start` attribute if present. Otherwise, if the
+    /// first item is explicitly numbered with an ordinal other than 1, that
+    /// ordinal is used.
+    ///
+    /// Note that the start value is always an integer, even when using a
+    /// different numbering style such as [`NumberingStyle::LowerAlpha`].
+    ///
+    /// Returns `None` if this is not an ordered list.
+    pub fn start(&self) -> Option<u32> {
+        if self.type_ != ListType::Ordered {
+            return None;
+        }
+
+        if let Some(start) = self
+            .attrlist
+            .as_ref()
+            .and_then(|attrlist| attrlist.named_attribute("start"))
+        {
+            return start.value().trim().parse().ok();
+        }
+
+        self.first_marker()
+            .and_then(explicit_ordinal)
+            .map(|(_, ordinal)| ordinal)
+            .filter(|ordinal| *ordinal != 1)
+    }
+
+    /// Returns `true` if an ordered list should be presented in reverse order
+    /// (i.e., has the `reversed` option).
+    pub fn is_reversed(&self) -> bool {
+        self.type_ == ListType::Ordered
+            && self
+                .attrlist
+                .as_ref()
+                .is_some_and(|attrlist| attrlist.has_option("reversed"))
+    }
+
+    fn first_marker(&self) -> Option<&'src str> {
+        match self.items.first() {
+            Some(Block::ListItem(item)) => Some(item.marker.data()),
+            _ => None,
+        }
+    }
+}
+
+impl<'src> IsBlock<'src> for ListBlock<'src> {
+    fn content_model(&self) -> ContentModel {
+        ContentModel::Compound
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        match self.type_ {
+            ListType::Unordered => "ulist",
+            ListType::Ordered => "olist",
+            ListType::Description => "dlist",
+            ListType::Callout => "colist",
+        }
+        .into()
+    }
+
+    fn nested_blocks(&'src self) -> Iter<'src, Block<'src>> {
+        self.items.iter()
+    }
+
+    fn options(&'src self) -> Vec<&'src str> {
+        let mut options = match self.attrlist() {
+            Some(attrlist) => attrlist.options(),
+            None => vec![],
+        };
+
+        if self.is_checklist() && !options.contains(&"checklist") {
+            options.push("checklist");
+        }
+
+        options
+    }
+
+    fn has_option<N: AsRef<str>>(&'src self, name: N) -> bool {
+        (name.as_ref() == "checklist" && self.is_checklist())
+            || self
+                .attrlist()
+                .is_some_and(|attrlist| attrlist.has_option(name))
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        self.title_source
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        self.anchor
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        self.anchor_reftext
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        self.attrlist.as_ref()
+    }
+}
+
+impl<'src> HasSpan<'src> for ListBlock<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for ListBlock<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListBlock")
+            .field("type_", &self.type_)
+            .field("items", &DebugSliceReference(&self.items))
+            .field("source", &self.source)
+            .field("title_source", &self.title_source)
+            .field("title", &self.title)
+            .field("anchor", &self.anchor)
+            .field("anchor_reftext", &self.anchor_reftext)
+            .field("attrlist", &self.attrlist)
+            .finish()
+    }
+}
+
+/// A list item is a single entry in a [`ListBlock`].
+///
+/// It consists of the marker, the principal text that follows the marker, and
+/// any blocks attached to the item (such as a nested list or a block attached
+/// by a list continuation).
+#[derive(Clone, Eq, PartialEq)]
+pub struct ListItem<'src> {
+    marker: Span<'src>,
+    principal_text: Content<'src>,
+    checked: Option<bool>,
+    callout_ids: Vec<String>,
+    blocks: Vec<Block<'src>>,
+    source: Span<'src>,
+}
+
+impl<'src> ListItem<'src> {
+    /// Parse a single list item. `markers` contains the markers in use by the
+    /// current list and all enclosing lists; the last entry is the marker for
+    /// the list that this item belongs to.
+    fn parse(
+        source: Span<'src>,
+        markers: &[ListMarker<'src>],
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, MatchedItem<'src, Self>>> {
+        let MatchedItem {
+            item: marker,
+            after: text,
+        } = ListMarker::parse(source)?;
+
+        if !markers
+            .last()
+            .is_some_and(|list_marker| list_marker.is_same_kind_as(&marker))
+        {
+            return None;
+        }
+
+        let (checked, text) = if marker.type_ == ListType::Unordered {
+            parse_checkbox(text)
+        } else {
+            (None, text)
+        };
+
+        let MatchedItem {
+            item: principal_text,
+            after,
+        } = parse_principal_text(text, parser);
+
+        let mut blocks: Vec<Block<'src>> = vec![];
+        let mut warnings: Vec<Warning<'src>> = vec![];
+        let next = parse_attached_blocks(after, markers, parser, &mut blocks, &mut warnings);
+
+        let source = source
+            .discard_whitespace()
+            .trim_remainder(next)
+            .trim_trailing_whitespace();
+
+        Some(MatchAndWarnings {
+            item: MatchedItem {
+                item: Self {
+                    marker: marker.span,
+                    principal_text,
+                    checked,
+                    callout_ids: vec![],
+                    blocks,
+                    source,
+                },
+                after: next,
+            },
+            warnings,
+        })
+    }
+
+    /// Return the marker that introduced this list item.
+    pub fn marker(&self) -> Span<'src> {
+        self.marker
+    }
+
+    /// Return the principal text of this list item.
+    ///
+    /// The principal text is the text that follows the marker on the first line
+    /// of the list item, along with any lines that are wrapped onto it.
+    pub fn principal_text(&self) -> &Content<'src> {
+        &self.principal_text
+    }
+
+    /// Return the checked state of this list item if it is a checklist item.
+    ///
+    /// An item in an unordered list whose principal text starts with `[*]` or
+    /// `[x]` is a checked item; one that starts with `[ ]` is an unchecked
+    /// item. The checkbox is not included in the principal text.
+    ///
+    /// Returns `None` if this is not a checklist item.
+    pub fn checked(&self) -> Option<bool> {
+        self.checked
+    }
+
+    /// Return the IDs of the callouts described by this item if it is an item
+    /// in a callout list.
+    ///
+    /// Each callout marker (e.g., `<1>`) in the verbatim block(s) preceding the
+    /// callout list is assigned an ID of the form `CO<list>-<callout>`. The
+    /// item is linked to each of the markers that share its number.
+    ///
+    /// Returns an empty slice if this is not a callout list item or if no
+    /// matching callout markers were found.
+    pub fn callout_ids(&self) -> &[String] {
+        &self.callout_ids
+    }
+}
+
+/// A description list item is a single entry in a description list (i.e., a
+/// [`ListBlock`] of type [`ListType::Description`]).
+///
+/// It consists of one or more terms, each followed by a term delimiter, and an
+/// optional description. The description is made up of the principal text that
+/// follows the delimiter of the last term (on the same line or the next line)
+/// and any blocks attached to the item (such as a nested list or a block
+/// attached by a list continuation).
+#[derive(Clone, Eq, PartialEq)]
+pub struct DescriptionListItem<'src> {
+    terms: Vec<Content<'src>>,
+    marker: Span<'src>,
+    principal_text: Option<Content<'src>>,
+    blocks: Vec<Block<'src>>,
+    source: Span<'src>,
+}
+
+impl<'src> DescriptionListItem<'src> {
+    /// Parse a single description list item. `markers` contains the markers in
+    /// use by the current list and all enclosing lists; the last entry is the
+    /// term delimiter for the list that this item belongs to.
+    fn parse(
+        source: Span<'src>,
+        markers: &[ListMarker<'src>],
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, MatchedItem<'src, Self>>> {
+        let MatchedItem {
+            item: marker,
+            after: mut text,
+        } = ListMarker::parse(source)?;
+
+        if !markers
+            .last()
+            .is_some_and(|list_marker| list_marker.is_same_kind_as(&marker))
+        {
+            return None;
+        }
+
+        let mut terms = vec![parse_term(source, &marker, parser)];
+
+        // A term without any text after its delimiter may be followed immediately by
+        // additional terms that share the same description.
+        while text.take_normalized_line().item.is_empty()
+            && let next_line = text.take_line().after
+            && let Some(next_term) = ListMarker::parse(next_line)
+            && next_term.item.is_same_kind_as(&marker)
+        {
+            terms.push(parse_term(next_line, &next_term.item, parser));
+            text = next_term.after;
+        }
+
+        let MatchedItem {
+            item: first_line_text,
+            after: next_line,
+        } = text.take_normalized_line();
+
+        // The description may start on the same line as the (last) term or on the
+        // line immediately after it.
+        let (principal_text, after) = if !first_line_text.is_empty() {
+            let mi = parse_principal_text(text, parser);
+            (Some(mi.item), mi.after)
+        } else if next_line
+            .take_non_empty_line()
+            .is_some_and(|line| !interrupts_principal_text(line.item))
+        {
+            let mi = parse_principal_text(next_line, parser);
+            (Some(mi.item), mi.after)
+        } else {
+            (None, next_line)
+        };
+
+        let mut blocks: Vec<Block<'src>> = vec![];
+        let mut warnings: Vec<Warning<'src>> = vec![];
+        let next = parse_attached_blocks(after, markers, parser, &mut blocks, &mut warnings);
+
+        let source = source
+            .discard_whitespace()
+            .trim_remainder(next)
+            .trim_trailing_whitespace();
+
+        Some(MatchAndWarnings {
+            item: MatchedItem {
+                item: Self {
+                    terms,
+                    marker: marker.span,
+                    principal_text,
+                    blocks,
+                    source,
+                },
+                after: next,
+            },
+            warnings,
+        })
+    }
+
+    /// Return an iterator over the terms for this item.
+    pub fn terms(&'src self) -> Iter<'src, Content<'src>> {
+        self.terms.iter()
+    }
+
+    /// Return the term delimiter (e.g., `::`) that follows the first term.
+    pub fn marker(&self) -> Span<'src> {
+        self.marker
+    }
+
+    /// Return the principal text of the description, if any.
+    ///
+    /// The principal text is the text that follows the delimiter of the last
+    /// term, either on the same line or starting on the next line, along with
+    /// any lines that are wrapped onto it.
+    pub fn principal_text(&self) -> Option<&Content<'src>> {
+        self.principal_text.as_ref()
+    }
+}
+
+impl<'src> IsBlock<'src> for DescriptionListItem<'src> {
+    fn content_model(&self) -> ContentModel {
+        ContentModel::Compound
+    }
+
+    fn rendered_content(&'src self) -> Option<&'src str> {
+        self.principal_text.as_ref().map(|text| text.rendered())
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        "dlist_item".into()
+    }
+
+    fn nested_blocks(&'src self) -> Iter<'src, Block<'src>> {
+        self.blocks.iter()
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn title(&self) -> Option<&str> {
+        None
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        None
+    }
+}
+
+impl<'src> HasSpan<'src> for DescriptionListItem<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for DescriptionListItem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DescriptionListItem")
+            .field("terms", &self.terms)
+            .field("marker", &self.marker)
+            .field("principal_text", &self.principal_text)
+            .field("blocks", &DebugSliceReference(&self.blocks))
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// Parse the term that precedes `marker` on the first line of `source`.
+fn parse_term<'src>(
+    source: Span<'src>,
+    marker: &ListMarker<'src>,
+    parser: &Parser,
+) -> Content<'src> {
+    let term = source.discard_whitespace().trim_remainder(marker.span);
+    let mut content = Content::from(term);
+
+    SubstitutionGroup::Normal.apply(&mut content, parser, None);
+
+    content
+}
+
+/// Parse any blocks attached to a list item, appending them to `blocks`.
+///
+/// A block is attached either by a list continuation (a `+` on a line by
+/// itself) immediately preceding it or by being a nested list.
+///
+/// Returns the span following the last attached block.
+fn parse_attached_blocks<'src>(
+    mut next: Span<'src>,
+    markers: &[ListMarker<'src>],
+    parser: &mut Parser,
+    blocks: &mut Vec<Block<'src>>,
+    warnings: &mut Vec<Warning<'src>>,
+) -> Span<'src> {
+    loop {
+        let maybe_continuation = next.discard_empty_lines();
+
+        if let Some(continuation) = maybe_continuation.take_non_empty_line()
+            && continuation.item.data() == "+"
+        {
+            // Each empty line before a list continuation moves the attachment up one
+            // level from the most deeply nested list item. (This is the _ancestor list
+            // continuation_.) Only attach the block here if this item is the
+            // intended level; otherwise, leave it for an ancestor.
+            let empty_lines = maybe_continuation.line() - next.line();
+            if empty_lines != trailing_list_depth(blocks) {
+                break;
+            }
+
+            let attached = truncate_at_sibling_item(continuation.after, markers, parser);
+            let mut block_maw = Block::parse(attached, parser);
+
+            if !block_maw.warnings.is_empty() {
+                warnings.append(&mut block_maw.warnings);
+            }
+
+            let Some(block_mi) = block_maw.item else {
+                break;
+            };
+
+            next = after_block(continuation.after, &block_mi.item);
+            blocks.push(block_mi.item);
+            continue;
+        }
+
+        // A list item whose marker isn't already in use by this list or any of its
+        // ancestors starts a nested list, which is attached to this item.
+        let maybe_nested = next.discard_empty_lines();
+        let metadata = BlockMetadata::parse(maybe_nested, parser).item;
+
+        let Some(nested_marker) = ListMarker::parse(metadata.block_start) else {
+            break;
+        };
+
+        if markers
+            .iter()
+            .any(|m| m.is_same_kind_as(&nested_marker.item))
+        {
+            break;
+        }
+
+        // A block attribute line or title offset by an empty line forces the start
+        // of a new list rather than a nested one.
+        if !metadata.is_empty() && maybe_nested != next {
+            break;
+        }
+
+        let Some(mut list_maw) = ListBlock::parse_with_parents(&metadata, parser, markers) else {
+            break;
+        };
+
+        if !list_maw.warnings.is_empty() {
+            warnings.append(&mut list_maw.warnings);
+        }
+
+        let list = Block::List(list_maw.item.item);
+
+        Block::register_block_id(list.id(), list.reftext(), list.span(), parser, warnings);
+
+        blocks.push(list);
+        next = list_maw.item.after;
+    }
+
+    next
+}
+
+/// Returns the portion of `source` that may be consumed by a block attached
+/// to a list item by a list continuation.
+///
+/// A sibling list item (i.e., one whose marker is in use by this list or any
+/// of its ancestors) interrupts an attached paragraph. A nested list item does
+/// not.
+fn truncate_at_sibling_item<'src>(
+    source: Span<'src>,
+    markers: &[ListMarker<'src>],
+    parser: &mut Parser,
+) -> Span<'src> {
+    let block_start = BlockMetadata::parse(source, parser).item.block_start;
+
+    let Some(first_line) = block_start.take_non_empty_line() else {
+        return source;
+    };
+
+    // Delimited blocks have explicit boundaries and are not interrupted.
+    if RawDelimitedBlock::is_valid_delimiter(&first_line.item)
+        || CompoundDelimitedBlock::is_valid_delimiter(&first_line.item)
+        || TableBlock::is_valid_delimiter(&first_line.item)
+    {
+        return source;
+    }
+
+    let mut next = first_line.after;
+
+    while let Some(line_mi) = next.take_non_empty_line() {
+        if let Some(marker) = ListMarker::parse(next)
+            && markers.iter().any(|m| m.is_same_kind_as(&marker.item))
+        {
+            return source.trim_remainder(next);
+        }
+
+        // Any other line that interrupts a paragraph ends the search.
+        let line = line_mi.item;
+        if line.data() == "+"
+            || (line.starts_with('[') && line.ends_with(']'))
+            || RawDelimitedBlock::is_valid_delimiter(&line)
+            || CompoundDelimitedBlock::is_valid_delimiter(&line)
+            || TableBlock::is_valid_delimiter(&line)
+        {
+            break;
+        }
+
+        next = line_mi.after;
+    }
+
+    source
+}
+
+/// Returns the number of nested lists that end along with the last of `blocks`
+/// (i.e., the depth of the list item that was most recently parsed, relative
+/// to the item to which `blocks` are attached).
+fn trailing_list_depth(blocks: &[Block<'_>]) -> usize {
+    let Some(Block::List(list)) = blocks.last() else {
+        return 0;
+    };
+
+    1 + match list.items.last() {
+        Some(Block::ListItem(item)) => trailing_list_depth(&item.blocks),
+        Some(Block::DescriptionListItem(item)) => trailing_list_depth(&item.blocks),
+        _ => 0,
+    }
+}
+
+/// Return the portion of `source` that starts on the line after `block` ends.
+///
+/// Unlike the remainder returned by [`Block::parse`], this does not discard any
+/// empty lines that follow the block.
+fn after_block<'src>(source: Span<'src>, block: &Block<'src>) -> Span<'src> {
+    let span = block.span();
+    let block_end = span.byte_offset() + span.len();
+
+    source
+        .discard(block_end - source.byte_offset())
+        .take_line()
+        .after
+}
+
+/// Parse the checkbox (`[ ]`, `[x]`, or `[*]`) that marks an unordered list
+/// item as a checklist item.
+///
+/// Returns the checked state, if any, and the remaining principal text.
+fn parse_checkbox(text: Span<'_>) -> (Option<bool>, Span<'_>) {
+    let checked = if text.starts_with("[ ]") {
+        false
+    } else if text.starts_with("[x]") || text.starts_with("[*]") {
+        true
+    } else {
+        return (None, text);
+    };
+
+    // The checkbox must be followed by whitespace and then the principal text.
+    match text.discard(3).take_required_whitespace() {
+        Some(ws) if !ws.after.take_line().item.is_empty() => (Some(checked), ws.after),
+        _ => (None, text),
+    }
+}
+
+/// Parse the principal text of a list item.
+///
+/// The principal text ends at the first empty line, list continuation, block
+/// attribute line, or delimiter line. A sibling or nested list item also
+/// interrupts the principal text.
+fn parse_principal_text<'src>(
+    source: Span<'src>,
+    parser: &Parser,
+) -> MatchedItem<'src, Content<'src>> {
+    let mut next = source;
+    let mut filtered_lines: Vec<&'src str> = vec![];
+
+    while let Some(line_mi) = next.take_non_empty_line() {
+        let line = line_mi.item;
+
+        if !filtered_lines.is_empty() && interrupts_principal_text(line) {
+            break;
+        }
+
+        next = line_mi.after;
+
+        if !filtered_lines.is_empty() && line.starts_with("//") && !line.starts_with("///") {
+            continue;
+        }
+
+        filtered_lines.push(line.discard_whitespace().data());
+    }
+
+    let source = source.trim_remainder(next).trim_trailing_whitespace();
+    let mut content = Content::from_filtered(source, filtered_lines.join("\n"));
+
+    SubstitutionGroup::Normal.apply(&mut content, parser, None);
+
+    MatchedItem {
+        item: content,
+        after: next,
+    }
+}
+
+/// Returns `true` if `line` ends the principal text of a list item: a list
+/// continuation, block attribute line, sibling or nested list item, or
+/// delimiter line.
+fn interrupts_principal_text(line: Span<'_>) -> bool {
+    line.data() == "+"
+        || (line.starts_with('[') && line.ends_with(']'))
+        || ListMarker::parse(line).is_some()
+        || RawDelimitedBlock::is_valid_delimiter(&line)
+        || CompoundDelimitedBlock::is_valid_delimiter(&line)
+        || TableBlock::is_valid_delimiter(&line)
+}
+
+impl<'src> IsBlock<'src> for ListItem<'src> {
+    fn content_model(&self) -> ContentModel {
+        ContentModel::Compound
+    }
+
+    fn rendered_content(&'src self) -> Option<&'src str> {
+        Some(self.principal_text.rendered())
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        "list_item".into()
+    }
+
+    fn nested_blocks(&'src self) -> Iter<'src, Block<'src>> {
+        self.blocks.iter()
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn title(&self) -> Option<&str> {
+        None
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        None
+    }
+}
+
+impl<'src> HasSpan<'src> for ListItem<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for ListItem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListItem")
+            .field("marker", &self.marker)
+            .field("principal_text", &self.principal_text)
+            .field("checked", &self.checked)
+            .field("callout_ids", &self.callout_ids)
+            .field("blocks", &DebugSliceReference(&self.blocks))
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// A list marker as found at the start of a list item.
+#[derive(Clone, Copy, Debug)]
+struct ListMarker<'src> {
+    span: Span<'src>,
+    type_: ListType,
+    kind: ListMarkerKind<'src>,
+}
+
+/// Determines which list items are siblings of one another.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ListMarkerKind<'src> {
+    /// A marker made up of repeated symbols (e.g., `*`, `**`, `-`, or `..`).
+    /// Only identical markers are siblings.
+    Symbol(&'src str),
+
+    /// An explicitly-numbered marker (e.g., `1.`, `b.`, or `iv)`). All markers
+    /// of the same numbering style are siblings.
+    Explicit(NumberingStyle),
+
+    /// A callout list marker (e.g., `<1>` or `<.>`). All callout list markers
+    /// are siblings.
+    Callout,
+}
+
+impl<'src> ListMarker<'src> {
+    /// Parse a list marker from the first line of `source`.
+    ///
+    /// For unordered and ordered lists, the marker may be preceded by
+    /// whitespace and must be followed by at least one space or tab and then
+    /// the (non-empty) principal text.
+    ///
+    /// For description lists, the marker is the term delimiter, which must be
+    /// preceded by a (non-empty) term and followed by either the end of the
+    /// line or at least one space or tab and then the principal text.
+    ///
+    /// Returns the marker and a span that starts with the principal text (which
+    /// may be an empty remainder of the line for a description list).
+    fn parse(source: Span<'src>) -> Option<MatchedItem<'src, Self>> {
+        let line = source.take_normalized_line().item.discard_whitespace();
+
+        let MatchedItem { item, after: text } =
+            Self::parse_prefix(line).or_else(|| Self::parse_term_delimiter(line))?;
+
+        Some(MatchedItem {
+            item,
+            after: source.discard(text.byte_offset() - source.byte_offset()),
+        })
+    }
+
+    /// Parse an unordered or ordered list marker at the start of `line`.
+    fn parse_prefix(line: Span<'src>) -> Option<MatchedItem<'src, Self>> {
+        let first_char = line.chars().next()?;
+
+        let (marker, type_) = match first_char {
+            '<' => {
+                let marker_len = line.position(|c| c == '>')? + 1;
+                (line.into_parse_result(marker_len), ListType::Callout)
+            }
+            '-' => (line.into_parse_result(1), ListType::Unordered),
+            '*' => (line.take_while(|c| c == '*'), ListType::Unordered),
+            '.' => (line.take_while(|c| c == '.'), ListType::Ordered),
+            _ => {
+                let marker_len = line.position(|c| c == '.' || c == ')')? + 1;
+                (line.into_parse_result(marker_len), ListType::Ordered)
+            }
+        };
+
+        let kind = match type_ {
+            ListType::Ordered if first_char != '.' => {
+                ListMarkerKind::Explicit(explicit_ordinal(marker.item.data())?.0)
+            }
+            ListType::Callout => {
+                let marker = marker.item.data();
+                if marker != "<.>" && callout_ordinal(marker).is_none() {
+                    return None;
+                }
+                ListMarkerKind::Callout
+            }
+            _ => ListMarkerKind::Symbol(marker.item.data()),
+        };
+
+        let text = marker.after.take_required_whitespace()?.after;
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(MatchedItem {
+            item: Self {
+                span: marker.item,
+                type_,
+                kind,
+            },
+            after: text,
+        })
+    }
+
+    /// Parse a description list term delimiter (e.g., `::`) from `line`. The
+    /// first matching delimiter that is followed by whitespace or the end of
+    /// the line is used.
+    fn parse_term_delimiter(line: Span<'src>) -> Option<MatchedItem<'src, Self>> {
+        // A comment line is never a description list term.
+        if line.starts_with("//") {
+            return None;
+        }
+
+        for (index, c) in line.char_indices().skip(1) {
+            if c != ':' && c != ';' {
+                continue;
+            }
+
+            for delimiter in ["::::", ":::", "::", ";;"] {
+                let Some(rest) = line[index..].strip_prefix(delimiter) else {
+                    continue;
+                };
+
+                if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+                    continue;
+                }
+
+                let marker = line.slice_from(index..).into_parse_result(delimiter.len());
+
+                return Some(MatchedItem {
+                    item: Self {
+                        span: marker.item,
+                        type_: ListType::Description,
+                        kind: ListMarkerKind::Symbol(marker.item.data()),
+                    },
+                    after: marker.after.take_whitespace().after,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Returns `true` if `other` would be a sibling of a list item introduced
+    /// with this marker.
+    fn is_same_kind_as(&self, other: &Self) -> bool {
+        self.type_ == other.type_ && self.kind == other.kind
+    }
+
+    /// Returns the ordinal for an explicitly-numbered marker.
+    fn ordinal(&self) -> Option<u32> {
+        match self.kind {
+            ListMarkerKind::Explicit(_) => {
+                explicit_ordinal(self.span.data()).map(|(_, ordinal)| ordinal)
+            }
+            ListMarkerKind::Symbol(_) | ListMarkerKind::Callout => None,
+        }
+    }
+}
+
+/// Interpret an explicitly-numbered ordered list marker (e.g., `1.`, `b.`,
+/// or `iv)`), returning its numbering style and ordinal.
+///
+/// Returns `None` if `marker` is not an explicitly-numbered marker.
+fn explicit_ordinal(marker: &str) -> Option<(NumberingStyle, u32)> {
+    if let Some(number) = marker.strip_suffix('.') {
+        if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+            return Some((NumberingStyle::Arabic, number.parse().ok()?));
+        }
+
+        let mut chars = number.chars();
+        let letter = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+
+        if letter.is_ascii_lowercase() {
+            Some((NumberingStyle::LowerAlpha, letter as u32 - 'a' as u32 + 1))
+        } else if letter.is_ascii_uppercase() {
+            Some((NumberingStyle::UpperAlpha, letter as u32 - 'A' as u32 + 1))
+        } else {
+            None
+        }
+    } else if let Some(numeral) = marker.strip_suffix(')') {
+        if numeral.is_empty() {
+            None
+        } else if numeral.chars().all(|c| matches!(c, 'i' | 'v' | 'x')) {
+            Some((NumberingStyle::LowerRoman, roman_to_int(numeral)))
+        } else if numeral.chars().all(|c| matches!(c, 'I' | 'V' | 'X')) {
+            Some((NumberingStyle::UpperRoman, roman_to_int(numeral)))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Interpret a callout list marker (e.g., `<1>`), returning its ordinal.
+///
+/// Returns `None` if `marker` is not a numbered callout list marker. (Note
+/// that this includes the automatically-numbered marker `<.>`.)
+fn callout_ordinal(marker: &str) -> Option<usize> {
+    let number = marker.strip_prefix('<')?.strip_suffix('>')?;
+
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        number.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn roman_to_int(numeral: &str) -> u32 {
+    let values: Vec<u32> = numeral
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            'i' => 1,
+            'v' => 5,
+            _ => 10,
+        })
+        .collect();
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match values.get(i + 1) {
+            Some(next) if next > value => -(*value as i64),
+            _ => *value as i64,
+        })
+        .sum::<i64>()
+        .max(0) as u32
+}
+
+fn int_to_roman(mut value: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    let mut result = String::new();
+
+    for (n, numeral) in NUMERALS {
+        while value >= n {
+            result.push_str(numeral);
+            value -= n;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::panic)]
+    #![allow(clippy::unwrap_used)]
+
+    use std::ops::Deref;
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        HasSpan, Parser,
+        blocks::{ContentModel, DescriptionListStyle, IsBlock, ListType, metadata::BlockMetadata},
+        content::SubstitutionGroup,
+        tests::prelude::*,
+    };
+
+    #[test]
+    fn impl_clone() {
+        // Silly test to mark the #[derive(...)] line as covered.
+        let mut parser = Parser::default();
+
+        let b1 = crate::blocks::ListBlock::parse(&BlockMetadata::new("* abc"), &mut parser)
+            .unwrap()
+            .item
+            .item;
+
+        let b2 = b1.clone();
+        assert_eq!(b1, b2);
+    }
+
+    #[test]
+    fn empty_source() {
+        let mut parser = Parser::default();
+        assert!(crate::blocks::ListBlock::parse(&BlockMetadata::new(""), &mut parser).is_none());
+    }
+
+    #[test]
+    fn not_a_list() {
+        let mut parser = Parser::default();
+
+        for source in [
+            "abc",
+            "*bold* text",
+            "-- abc",
+            "*",
+            "* ",
+            "-",
+            "- \t",
+            "+ abc",
+            ".",
+            ". ",
+            "1.",
+            "1.abc",
+            "ab. abc",
+            "1) abc",
+            "ix. abc",
+            "Term::abc",
+            ":: abc",
+            "// comment:: abc",
+        ] {
+            assert!(
+                crate::blocks::ListBlock::parse(&BlockMetadata::new(source), &mut parser).is_none(),
+                "{source:?} should not be parsed as a list"
+            );
+        }
+    }
+
+    #[test]
+    fn is_list_item() {
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "* abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "  ** abc\ndef"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "-\tabc"
+        )));
+
+        assert!(!crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "abc\n* def"
+        )));
+
+        assert!(!crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "**abc**"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "... abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "10. abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "B. abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "xiv) abc"
+        )));
+
+        assert!(!crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            ".abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "CPU:: abc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "Hard drive::\nabc"
+        )));
+
+        assert!(crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "  term;;\tabc"
+        )));
+
+        assert!(!crate::blocks::ListBlock::is_list_item(crate::Span::new(
+            "std::vector is a container"
+        )));
+    }
+
+    #[test]
+    fn single_item() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(&BlockMetadata::new("* abc"), &mut parser)
+            .unwrap()
+            .unwrap_if_no_warnings();
+
+        assert_eq!(
+            mi.item,
+            ListBlock {
+                type_: ListType::Unordered,
+                items: &[Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "abc",
+                            line: 1,
+                            col: 3,
+                            offset: 2,
+                        },
+                        rendered: "abc",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* abc",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                })],
+                source: Span {
+                    data: "* abc",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                title_source: None,
+                title: None,
+                anchor: None,
+                anchor_reftext: None,
+                attrlist: None,
+            }
+        );
+
+        assert_eq!(mi.item.type_(), ListType::Unordered);
+        assert_eq!(mi.item.content_model(), ContentModel::Compound);
+        assert!(mi.item.rendered_content().is_none());
+        assert_eq!(mi.item.raw_context().deref(), "ulist");
+        assert_eq!(mi.item.resolved_context().deref(), "ulist");
+        assert!(mi.item.declared_style().is_none());
+        assert!(mi.item.id().is_none());
+        assert!(mi.item.roles().is_empty());
+        assert!(mi.item.options().is_empty());
+        assert!(mi.item.title_source().is_none());
+        assert!(mi.item.title().is_none());
+        assert!(mi.item.anchor().is_none());
+        assert!(mi.item.anchor_reftext().is_none());
+        assert!(mi.item.attrlist().is_none());
+        assert_eq!(mi.item.substitution_group(), SubstitutionGroup::Normal);
+
+        let item = mi.item.nested_blocks().next().unwrap();
+        let crate::blocks::Block::ListItem(item) = item else {
+            panic!("Unexpected block: {item:#?}");
+        };
+
+        assert_eq!(
+            item.marker(),
+            Span {
+                data: "*",
+                line: 1,
+                col: 1,
+                offset: 0,
+            }
+        );
+
+        assert_eq!(item.principal_text().rendered(), "abc");
+        assert_eq!(item.content_model(), ContentModel::Compound);
+        assert_eq!(item.rendered_content().unwrap(), "abc");
+        assert_eq!(item.raw_context().deref(), "list_item");
+        assert!(item.nested_blocks().next().is_none());
+        assert!(item.title_source().is_none());
+        assert!(item.title().is_none());
+        assert!(item.anchor().is_none());
+        assert!(item.anchor_reftext().is_none());
+        assert!(item.attrlist().is_none());
+
+        assert_eq!(
+            mi.after,
+            Span {
+                data: "",
+                line: 1,
+                col: 6,
+                offset: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn principal_text_applies_substitutions() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("- Some *bold* text\n  that wraps & continues"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        let item = mi.item.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            item.rendered_content().unwrap(),
+            "Some <strong>bold</strong> text\nthat wraps &amp; continues"
+        );
+
+        assert_eq!(
+            item.span(),
+            Span {
+                data: "- Some *bold* text\n  that wraps & continues",
+                line: 1,
+                col: 1,
+                offset: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn sibling_interrupts_principal_text() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("* first\n* another\nthat wraps\n* last"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        let items: Vec<&str> = mi
+            .item
+            .nested_blocks()
+            .map(|item| item.rendered_content().unwrap())
+            .collect();
+
+        assert_eq!(items, vec!["first", "another\nthat wraps", "last"]);
+    }
+
+    #[test]
+    fn comment_line_in_principal_text() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("* first\n// comment\nmore"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        let item = mi.item.nested_blocks().next().unwrap();
+        assert_eq!(item.rendered_content().unwrap(), "first\nmore");
+    }
+
+    #[test]
+    fn checklist_items() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("* [*] checked\n* [x] also checked\n* [ ] not checked\n* normal"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        let items: Vec<(Option<bool>, &str)> = mi
+            .item
+            .nested_blocks()
+            .map(|item| {
+                let crate::blocks::Block::ListItem(item) = item else {
+                    panic!("Unexpected block: {item:#?}");
+                };
+                (item.checked(), item.principal_text().rendered())
+            })
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                (Some(true), "checked"),
+                (Some(true), "also checked"),
+                (Some(false), "not checked"),
+                (None, "normal"),
+            ]
+        );
+
+        assert!(mi.item.has_option("checklist"));
+        assert_eq!(mi.item.options(), vec!["checklist"]);
+    }
+
+    #[test]
+    fn not_a_checkbox() {
+        for source in [
+            "* [X] upper case",
+            "* [x]no space",
+            "* [x]",
+            "* [ ]  ",
+            "* [y] other",
+            ". [x] ordered",
+        ] {
+            let mut parser = Parser::default();
+
+            let mi = crate::blocks::ListBlock::parse(&BlockMetadata::new(source), &mut parser)
+                .unwrap()
+                .unwrap_if_no_warnings();
+
+            let crate::blocks::Block::ListItem(item) = mi.item.nested_blocks().next().unwrap()
+            else {
+                panic!("Unexpected block for {source:?}");
+            };
+
+            assert!(item.checked().is_none(), "{source:?}");
+            assert!(item.principal_text().rendered().starts_with('['));
+            assert!(!mi.item.has_option("checklist"));
+            assert!(mi.item.options().is_empty());
+        }
+    }
+
+    #[test]
+    fn delimiter_ends_list() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("* first\n----\ncode\n----"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        assert_eq!(mi.item.nested_blocks().len(), 1);
+
+        assert_eq!(
+            mi.after,
+            Span {
+                data: "----\ncode\n----",
+                line: 2,
+                col: 1,
+                offset: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn nested_by_marker_depth() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("* one\n** two\n*** three\n** four\n* five\n\nafter"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        let mut items = mi.item.nested_blocks();
+
+        let one = items.next().unwrap();
+        assert_eq!(one.rendered_content().unwrap(), "one");
+
+        let mut one_blocks = one.nested_blocks();
+        let nested = one_blocks.next().unwrap();
+        assert!(one_blocks.next().is_none());
+        assert_eq!(nested.raw_context().deref(), "ulist");
+
+        let mut nested_items = nested.nested_blocks();
+
+        let two = nested_items.next().unwrap();
+        assert_eq!(two.rendered_content().unwrap(), "two");
+
+        let three = two
+            .nested_blocks()
+            .next()
+            .unwrap()
+            .nested_blocks()
+            .next()
+            .unwrap();
+
+        assert_eq!(three.rendered_content().unwrap(), "three");
+
+        let four = nested_items.next().unwrap();
+        assert_eq!(four.rendered_content().unwrap(), "four");
+        assert!(nested_items.next().is_none());
+
+        let five = items.next().unwrap();
+        assert_eq!(five.rendered_content().unwrap(), "five");
+        assert!(items.next().is_none());
+
+        assert_eq!(
+            mi.item.span(),
+            Span {
+                data: "* one\n** two\n*** three\n** four\n* five",
+                line: 1,
+                col: 1,
+                offset: 0,
+            }
+        );
+
+        assert_eq!(
+            mi.after,
+            Span {
+                data: "after",
+                line: 7,
+                col: 1,
+                offset: 39,
+            }
+        );
+    }
+
+    #[test]
+    fn single_description_list_item() {
+        let mut parser = Parser::default();
+
+        let mi =
+            crate::blocks::ListBlock::parse(&BlockMetadata::new("CPU:: The _brain_"), &mut parser)
+                .unwrap()
+                .unwrap_if_no_warnings();
+
+        assert_eq!(
+            mi.item,
+            ListBlock {
+                type_: ListType::Description,
+                items: &[Block::DescriptionListItem(DescriptionListItem {
+                    terms: &[Content {
+                        original: Span {
+                            data: "CPU",
+                            line: 1,
+                            col: 1,
+                            offset: 0,
+                        },
+                        rendered: "CPU",
+                    }],
+                    marker: Span {
+                        data: "::",
+                        line: 1,
+                        col: 4,
+                        offset: 3,
+                    },
+                    principal_text: Some(Content {
+                        original: Span {
+                            data: "The _brain_",
+                            line: 1,
+                            col: 7,
+                            offset: 6,
+                        },
+                        rendered: "The <em>brain</em>",
+                    }),
+                    blocks: &[],
+                    source: Span {
+                        data: "CPU:: The _brain_",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                })],
+                source: Span {
+                    data: "CPU:: The _brain_",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                title_source: None,
+                title: None,
+                anchor: None,
+                anchor_reftext: None,
+                attrlist: None,
+            }
+        );
+
+        assert_eq!(mi.item.type_(), ListType::Description);
+        assert_eq!(mi.item.raw_context().deref(), "dlist");
+        assert_eq!(
+            mi.item.description_list_style().unwrap(),
+            DescriptionListStyle::Default
+        );
+        assert!(mi.item.numbering_style().is_none());
+
+        let item = mi.item.nested_blocks().next().unwrap();
+        let crate::blocks::Block::DescriptionListItem(item) = item else {
+            panic!("Unexpected block: {item:#?}");
+        };
+
+        assert_eq!(item.terms().next().unwrap().rendered(), "CPU");
+
+        assert_eq!(
+            item.marker(),
+            Span {
+                data: "::",
+                line: 1,
+                col: 4,
+                offset: 3,
+            }
+        );
+
+        assert_eq!(
+            item.principal_text().unwrap().rendered(),
+            "The <em>brain</em>"
+        );
+
+        assert_eq!(item.content_model(), ContentModel::Compound);
+        assert_eq!(item.rendered_content().unwrap(), "The <em>brain</em>");
+        assert_eq!(item.raw_context().deref(), "dlist_item");
+        assert!(item.nested_blocks().next().is_none());
+        assert!(item.title_source().is_none());
+        assert!(item.title().is_none());
+        assert!(item.anchor().is_none());
+        assert!(item.anchor_reftext().is_none());
+        assert!(item.attrlist().is_none());
+    }
+
+    #[test]
+    fn description_list_item_without_description() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("Term A::\nTerm B::\n\nNot a description."),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        assert_eq!(mi.item.nested_blocks().len(), 1);
+
+        let item = mi.item.nested_blocks().next().unwrap();
+        let crate::blocks::Block::DescriptionListItem(item) = item else {
+            panic!("Unexpected block: {item:#?}");
+        };
+
+        let terms: Vec<&str> = item.terms().map(|term| term.rendered()).collect();
+        assert_eq!(terms, vec!["Term A", "Term B"]);
+
+        assert!(item.principal_text().is_none());
+        assert!(item.rendered_content().is_none());
+
+        assert_eq!(
+            mi.after,
+            Span {
+                data: "Not a description.",
+                line: 4,
+                col: 1,
+                offset: 19,
+            }
+        );
+    }
+
+    #[test]
+    fn term_delimiter_nests_description_list() {
+        let mut parser = Parser::default();
+
+        let mi = crate::blocks::ListBlock::parse(
+            &BlockMetadata::new("A:: one\nB::: two\nC:::: three\nD;; four\nE:: five"),
+            &mut parser,
+        )
+        .unwrap()
+        .unwrap_if_no_warnings();
+
+        assert_eq!(mi.item.nested_blocks().len(), 2);
+
+        let mut item = mi.item.nested_blocks().next().unwrap();
+        let mut depth = 0;
+
+        while let Some(nested) = item.nested_blocks().next() {
+            assert_eq!(nested.raw_context().deref(), "dlist");
+            depth += 1;
+            item = nested.nested_blocks().next().unwrap();
+        }
+
+        assert_eq!(depth, 3);
+        assert_eq!(item.rendered_content().unwrap(), "four");
+    }
+
+    #[test]
+    fn impl_debug() {
+        let mut parser = Parser::default();
+
+        let list = crate::blocks::ListBlock::parse(&BlockMetadata::new("- abc"), &mut parser)
+            .unwrap()
+            .item
+            .item;
+
+        assert_eq!(
+            format!("{list:#?}"),
+            r#"ListBlock {
+    type_: ListType::Unordered,
+    items: &[
+        Block::ListItem(
+            ListItem {
+                marker: Span {
+                    data: "-",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                principal_text: Content {
+                    original: Span {
+                        data: "abc",
+                        line: 1,
+                        col: 3,
+                        offset: 2,
+                    },
+                    rendered: "abc",
+                    passthroughs: [],
+                },
+                checked: None,
+                callout_ids: [],
+                blocks: &[],
+                source: Span {
+                    data: "- abc",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+            },
+        ),
+    ],
+    source: Span {
+        data: "- abc",
+        line: 1,
+        col: 1,
+        offset: 0,
+    },
+    title_source: None,
+    title: None,
+    anchor: None,
+    anchor_reftext: None,
+    attrlist: None,
+}"#
+        );
+    }
+
+    #[test]
+    fn description_list_item_impl_debug() {
+        let mut parser = Parser::default();
+
+        let list = crate::blocks::ListBlock::parse(&BlockMetadata::new("abc;;"), &mut parser)
+            .unwrap()
+            .item
+            .item;
+
+        let item = list.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            format!("{item:#?}"),
+            r#"Block::DescriptionListItem(
+    DescriptionListItem {
+        terms: [
+            Content {
+                original: Span {
+                    data: "abc",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                rendered: "abc",
+                passthroughs: [],
+            },
+        ],
+        marker: Span {
+            data: ";;",
+            line: 1,
+            col: 4,
+            offset: 3,
+        },
+        principal_text: None,
+        blocks: &[],
+        source: Span {
+            data: "abc;;",
+            line: 1,
+            col: 1,
+            offset: 0,
+        },
+    },
+)"#
+        );
+    }
+
+    mod list_type {
+        mod impl_debug {
+            use pretty_assertions_sorted::assert_eq;
+
+            use crate::blocks::ListType;
+
+            #[test]
+            fn unordered() {
+                let list_type = ListType::Unordered;
+                let debug_output = format!("{:?}", list_type);
+                assert_eq!(debug_output, "ListType::Unordered");
+            }
+
+            #[test]
+            fn ordered() {
+                let list_type = ListType::Ordered;
+                let debug_output = format!("{:?}", list_type);
+                assert_eq!(debug_output, "ListType::Ordered");
+            }
+
+            #[test]
+            fn description() {
+                let list_type = ListType::Description;
+                let debug_output = format!("{:?}", list_type);
+                assert_eq!(debug_output, "ListType::Description");
+            }
+
+            #[test]
+            fn callout() {
+                let list_type = ListType::Callout;
+                let debug_output = format!("{:?}", list_type);
+                assert_eq!(debug_output, "ListType::Callout");
+            }
+        }
+    }
+
+    mod description_list_style {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::blocks::DescriptionListStyle;
+
+        #[test]
+        fn impl_clone() {
+            // Silly test to mark the #[derive(...)] line as covered.
+            let s1 = DescriptionListStyle::Horizontal;
+            let s2 = s1;
+            assert_eq!(s1, s2);
+        }
+
+        #[test]
+        fn impl_debug() {
+            for (style, expected) in [
+                (
+                    DescriptionListStyle::Default,
+                    "DescriptionListStyle::Default",
+                ),
+                (
+                    DescriptionListStyle::Horizontal,
+                    "DescriptionListStyle::Horizontal",
+                ),
+                (DescriptionListStyle::Qanda, "DescriptionListStyle::Qanda"),
+            ] {
+                assert_eq!(format!("{style:?}"), expected);
+            }
+        }
+    }
+
+    mod numbering_style {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::blocks::NumberingStyle;
+
+        #[test]
+        fn impl_clone() {
+            // Silly test to mark the #[derive(...)] line as covered.
+            let s1 = NumberingStyle::LowerGreek;
+            let s2 = s1;
+            assert_eq!(s1, s2);
+        }
+
+        #[test]
+        fn from_block_style() {
+            for (name, style) in [
+                ("arabic", NumberingStyle::Arabic),
+                ("decimal", NumberingStyle::Decimal),
+                ("loweralpha", NumberingStyle::LowerAlpha),
+                ("upperalpha", NumberingStyle::UpperAlpha),
+                ("lowerroman", NumberingStyle::LowerRoman),
+                ("upperroman", NumberingStyle::UpperRoman),
+                ("lowergreek", NumberingStyle::LowerGreek),
+            ] {
+                assert_eq!(NumberingStyle::from_block_style(name).unwrap(), style);
+            }
+
+            assert!(NumberingStyle::from_block_style("circle").is_none());
+        }
+
+        #[test]
+        fn format_ordinal() {
+            assert_eq!(NumberingStyle::Arabic.format_ordinal(12), "12");
+            assert_eq!(NumberingStyle::LowerAlpha.format_ordinal(3), "c");
+            assert_eq!(NumberingStyle::UpperAlpha.format_ordinal(26), "Z");
+            assert_eq!(NumberingStyle::UpperAlpha.format_ordinal(27), "27");
+            assert_eq!(NumberingStyle::LowerRoman.format_ordinal(4), "iv");
+            assert_eq!(NumberingStyle::UpperRoman.format_ordinal(1994), "MCMXCIV");
+            assert_eq!(NumberingStyle::LowerGreek.format_ordinal(2), "2");
+        }
+
+        #[test]
+        fn roman_to_int() {
+            assert_eq!(super::super::roman_to_int("i"), 1);
+            assert_eq!(super::super::roman_to_int("iv"), 4);
+            assert_eq!(super::super::roman_to_int("IX"), 9);
+            assert_eq!(super::super::roman_to_int("xiv"), 14);
+            assert_eq!(super::super::roman_to_int("XXXVIII"), 38);
+        }
+
+        mod impl_debug {
+            use pretty_assertions_sorted::assert_eq;
+
+            use crate::blocks::NumberingStyle;
+
+            #[test]
+            fn all_variants() {
+                for (style, expected) in [
+                    (NumberingStyle::Arabic, "NumberingStyle::Arabic"),
+                    (NumberingStyle::Decimal, "NumberingStyle::Decimal"),
+                    (NumberingStyle::LowerAlpha, "NumberingStyle::LowerAlpha"),
+                    (NumberingStyle::UpperAlpha, "NumberingStyle::UpperAlpha"),
+                    (NumberingStyle::LowerRoman, "NumberingStyle::LowerRoman"),
+                    (NumberingStyle::UpperRoman, "NumberingStyle::UpperRoman"),
+                    (NumberingStyle::LowerGreek, "NumberingStyle::LowerGreek"),
+                ] {
+                    assert_eq!(format!("{style:?}"), expected);
+                }
+            }
+        }
+    }
+}
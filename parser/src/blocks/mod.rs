@@ -22,6 +22,9 @@
 //! Block>` throughout the codebase, but also needed to provide for
 //! externally-described block types.
 
+mod admonition;
+pub use admonition::{AdmonitionBlock, AdmonitionKind};
+
 mod block;
 pub use block::Block;
 
@@ -37,6 +40,11 @@ pub(crate) use context::is_built_in_context;
 mod is_block;
 pub use is_block::{ContentModel, IsBlock};
 
+mod list;
+pub use list::{
+    DescriptionListItem, DescriptionListStyle, ListBlock, ListItem, ListType, NumberingStyle,
+};
+
 mod media;
 pub use media::{MediaBlock, MediaType};
 
@@ -55,5 +63,11 @@ pub use section::{SectionBlock, SectionNumber, SectionType};
 mod simple;
 pub use simple::{SimpleBlock, SimpleBlockStyle};
 
+mod table;
+pub use table::{
+    ColumnWidth, HorizontalAlignment, TableBlock, TableCell, TableCellStyle, TableColumn,
+    TableFormat, TableRow, VerticalAlignment,
+};
+
 #[cfg(test)]
 mod tests;
@@ -7,13 +7,16 @@ use crate::{
 
 /// Parse blocks until end of input or a pre-determined stop condition is
 /// reached.
+///
+/// The stop condition is evaluated before each block with the current parser
+/// state, which reflects any document attributes set by preceding blocks.
 pub(crate) fn parse_blocks_until<'src, F>(
     mut source: Span<'src>,
     mut f: F,
     parser: &mut Parser,
 ) -> MatchAndWarnings<'src, MatchedItem<'src, Vec<Block<'src>>>>
 where
-    F: FnMut(&Span<'src>) -> bool,
+    F: FnMut(&Span<'src>, &Parser) -> bool,
 {
     let mut blocks: Vec<Block<'src>> = vec![];
     let mut warnings: Vec<Warning<'src>> = vec![];
@@ -21,7 +24,7 @@ where
     source = source.discard_empty_lines();
 
     while !source.data().is_empty() {
-        if f(&source) {
+        if f(&source, parser) {
             break;
         }
 
@@ -34,6 +37,10 @@ where
         if let Some(mi) = maw.item {
             source = mi.after;
             blocks.push(mi.item);
+        } else if source.discard_empty_lines().is_empty() {
+            // No block is found in a remainder that consists only of whitespace.
+            // Consume it so the loop terminates.
+            source = source.discard_empty_lines();
         }
     }
 
@@ -45,3 +52,23 @@ where
         warnings,
     }
 }
+
+/// Parse the content of an AsciiDoc (`a`) table cell as a nested document.
+///
+/// The cell content is parsed in its own context: Tables within the cell use
+/// `!` as their default cell separator, and any document attributes set within
+/// the cell do not affect the content that follows the table.
+pub(crate) fn parse_blocks_in_table_cell<'src>(
+    source: Span<'src>,
+    parser: &mut Parser,
+) -> MatchAndWarnings<'src, MatchedItem<'src, Vec<Block<'src>>>> {
+    let saved_attribute_values = parser.attribute_values.clone();
+    let was_in_table_cell = std::mem::replace(&mut parser.in_table_cell, true);
+
+    let maw = parse_blocks_until(source, |_, _| false, parser);
+
+    parser.in_table_cell = was_in_table_cell;
+    parser.attribute_values = saved_attribute_values;
+
+    maw
+}
@@ -2,7 +2,9 @@ use crate::{
     HasSpan, Parser, Span,
     attributes::Attrlist,
     blocks::{ContentModel, IsBlock, metadata::BlockMetadata},
-    content::{Content, SubstitutionGroup},
+    content::{
+        Content, StemNotation, SubstitutionGroup, SubstitutionStep, callouts::register_callouts,
+    },
     span::MatchedItem,
     strings::CowStr,
     warnings::{MatchAndWarnings, Warning, WarningType},
@@ -19,6 +21,13 @@ use crate::{
 /// | `----`    | Listing      |
 /// | `....`    | Literal      |
 /// | `++++`    | Passthrough  |
+///
+/// A passthrough block with the `stem`, `asciimath`, or `latexmath` style
+/// (e.g., `[stem]`) is a [STEM block] instead. Its context is `stem` and its
+/// notation is available via [`stem_notation()`].
+///
+/// [STEM block]: https://docs.asciidoctor.org/asciidoc/latest/stem/
+/// [`stem_notation()`]: Self::stem_notation
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RawDelimitedBlock<'src> {
     content: Content<'src>,
@@ -31,6 +40,7 @@ pub struct RawDelimitedBlock<'src> {
     anchor_reftext: Option<Span<'src>>,
     attrlist: Option<Attrlist<'src>>,
     substitution_group: SubstitutionGroup,
+    stem_notation: Option<StemNotation>,
 }
 
 impl<'src> RawDelimitedBlock<'src> {
@@ -68,7 +78,7 @@ impl<'src> RawDelimitedBlock<'src> {
             return None;
         }
 
-        let (content_model, context, mut substitution_group) =
+        let (content_model, mut context, mut substitution_group) =
             match delimiter.item.data().as_bytes().split_at(4).0 {
                 b"////" => (ContentModel::Raw, "comment", SubstitutionGroup::None),
                 b"----" => (
@@ -89,6 +99,23 @@ impl<'src> RawDelimitedBlock<'src> {
             return None;
         }
 
+        let stem_notation = if context == "pass" {
+            metadata
+                .attrlist
+                .as_ref()
+                .and_then(|attrlist| attrlist.nth_attribute(1))
+                .and_then(|attr| attr.block_style())
+                .and_then(|style| StemNotation::from_name(style, parser))
+        } else {
+            None
+        };
+
+        if stem_notation.is_some() {
+            context = "stem";
+            substitution_group =
+                SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]);
+        }
+
         let content_start = delimiter.after;
         let mut next = content_start;
 
@@ -102,6 +129,13 @@ impl<'src> RawDelimitedBlock<'src> {
                 substitution_group =
                     substitution_group.override_via_attrlist(metadata.attrlist.as_ref());
 
+                if substitution_group
+                    .steps()
+                    .contains(&SubstitutionStep::Callouts)
+                {
+                    register_callouts(content.original().data(), &mut parser.callouts);
+                }
+
                 substitution_group.apply(&mut content, parser, metadata.attrlist.as_ref());
 
                 return Some(MatchAndWarnings {
@@ -120,6 +154,7 @@ impl<'src> RawDelimitedBlock<'src> {
                             anchor_reftext: metadata.anchor_reftext,
                             attrlist: metadata.attrlist.clone(),
                             substitution_group,
+                            stem_notation,
                         },
                         after: line.after,
                     }),
@@ -148,6 +183,7 @@ impl<'src> RawDelimitedBlock<'src> {
                     anchor_reftext: metadata.anchor_reftext,
                     attrlist: metadata.attrlist.clone(),
                     substitution_group,
+                    stem_notation,
                 },
                 after: next,
             }),
@@ -162,6 +198,15 @@ impl<'src> RawDelimitedBlock<'src> {
     pub fn content(&self) -> &Content<'src> {
         &self.content
     }
+
+    /// Return the notation of the STEM expression in this block, if this is a
+    /// STEM block.
+    ///
+    /// For the `stem` block style, the notation is determined by the value of
+    /// the `stem` document attribute at the time the block was parsed.
+    pub fn stem_notation(&self) -> Option<StemNotation> {
+        self.stem_notation
+    }
 }
 
 impl<'src> IsBlock<'src> for RawDelimitedBlock<'src> {
@@ -499,6 +544,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::None,
+                    stem_notation: None,
                 }
             );
 
@@ -557,6 +603,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::None,
+                    stem_notation: None,
                 }
             );
 
@@ -627,6 +674,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::None,
+                    stem_notation: None,
                 }
             );
 
@@ -744,6 +792,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Verbatim,
+                    stem_notation: None,
                 }
             );
 
@@ -801,6 +850,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Verbatim,
+                    stem_notation: None,
                 }
             );
 
@@ -883,6 +933,7 @@ mod tests {
                         },
                     },),
                     substitution_group: SubstitutionGroup::Custom(vec![SubstitutionStep::Quotes]),
+                    stem_notation: None,
                 }
             );
 
@@ -973,6 +1024,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Verbatim,
+                    stem_notation: None,
                 }
             );
 
@@ -1175,6 +1227,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Pass,
+                    stem_notation: None,
                 }
             );
 
@@ -1232,6 +1285,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Pass,
+                    stem_notation: None,
                 }
             );
 
@@ -1301,6 +1355,7 @@ mod tests {
                     anchor_reftext: None,
                     attrlist: None,
                     substitution_group: SubstitutionGroup::Pass,
+                    stem_notation: None,
                 }
             );
 
@@ -45,7 +45,7 @@ impl<'src> SectionBlock<'src> {
         let discrete = metadata.is_discrete();
 
         let source = metadata.block_start.discard_empty_lines();
-        let level_and_title = parse_title_line(source, warnings)?;
+        let level_and_title = parse_title_line(source, parser.leveloffset(), warnings)?;
 
         // Take a snapshot of `sectids` value before reading child blocks because
         // the value might be altered while parsing.
@@ -81,17 +81,8 @@ impl<'src> SectionBlock<'src> {
                 None
             };
 
-        let mut most_recent_level = level;
-
-        let mut maw_blocks = parse_blocks_until(
-            level_and_title.after,
-            |i| discrete || peer_or_ancestor_section(*i, level, &mut most_recent_level, warnings),
-            parser,
-        );
-
-        let blocks = maw_blocks.item;
-        let source = metadata.source.trim_remainder(blocks.after);
-
+        // Register the section ID BEFORE parsing child blocks so that the section
+        // can be referenced from within its own content.
         let mut section_title = Content::from(level_and_title.item.1);
         SubstitutionGroup::Title.apply(&mut section_title, parser, metadata.attrlist.as_ref());
 
@@ -103,10 +94,17 @@ impl<'src> SectionBlock<'src> {
             .and_then(|a| a.id())
             .or_else(|| metadata.anchor.as_ref().map(|anchor| anchor.data()));
 
+        // Same precedence as `Block::reftext()`: anchor reftext, then the
+        // `reftext` attribute, then the section title.
         let reftext = metadata
-            .attrlist
-            .as_ref()
-            .and_then(|a| a.named_attribute("reftext").map(|a| a.value()))
+            .anchor_reftext
+            .map(|reftext| reftext.data())
+            .or_else(|| {
+                metadata
+                    .attrlist
+                    .as_ref()
+                    .and_then(|a| a.named_attribute("reftext").map(|a| a.value()))
+            })
             .unwrap_or_else(|| section_title.rendered());
 
         let section_id = if let Some(catalog) = parser.catalog_mut() {
@@ -134,6 +132,26 @@ impl<'src> SectionBlock<'src> {
             None
         };
 
+        let mut most_recent_level = level;
+
+        let mut maw_blocks = parse_blocks_until(
+            level_and_title.after,
+            |i, parser| {
+                discrete
+                    || peer_or_ancestor_section(
+                        *i,
+                        level,
+                        parser.leveloffset(),
+                        &mut most_recent_level,
+                        warnings,
+                    )
+            },
+            parser,
+        );
+
+        let blocks = maw_blocks.item;
+        let source = metadata.source.trim_remainder(blocks.after);
+
         // Restore "normal" top-level section type if exiting a level 1 appendix.
         if level == 1 && !discrete {
             parser.topmost_section_type = SectionType::Normal;
@@ -271,8 +289,11 @@ impl std::fmt::Debug for SectionBlock<'_> {
     }
 }
 
+/// Parse a section title line, returning the section level (adjusted by
+/// `leveloffset`) and the title.
 fn parse_title_line<'src>(
     source: Span<'src>,
+    leveloffset: isize,
     warnings: &mut Vec<Warning<'src>>,
 ) -> Option<MatchedItem<'src, (usize, Span<'src>)>> {
     let mi = source.take_non_empty_line()?;
@@ -292,19 +313,25 @@ fn parse_title_line<'src>(
         }
     }
 
-    if count == 1 {
+    if count == 0 {
+        return None;
+    }
+
+    if count > 6 {
         warnings.push(Warning {
             source: source.take_normalized_line().item,
-            warning: WarningType::Level0SectionHeadingNotSupported,
+            warning: WarningType::SectionHeadingLevelExceedsMaximum(count - 1),
         });
 
         return None;
     }
 
-    if count > 6 {
+    let level = (count - 1).saturating_add_signed(leveloffset);
+
+    if level == 0 {
         warnings.push(Warning {
             source: source.take_normalized_line().item,
-            warning: WarningType::SectionHeadingLevelExceedsMaximum(count - 1),
+            warning: WarningType::Level0SectionHeadingNotSupported,
         });
 
         return None;
@@ -313,7 +340,7 @@ fn parse_title_line<'src>(
     let title = line.take_required_whitespace()?;
 
     Some(MatchedItem {
-        item: (count - 1, title.after),
+        item: (level, title.after),
         after: mi.after,
     })
 }
@@ -321,6 +348,7 @@ fn parse_title_line<'src>(
 fn peer_or_ancestor_section<'src>(
     source: Span<'src>,
     level: usize,
+    leveloffset: isize,
     most_recent_level: &mut usize,
     warnings: &mut Vec<Warning<'src>>,
 ) -> bool {
@@ -338,7 +366,7 @@ fn peer_or_ancestor_section<'src>(
 
     let source_after_metadata = block_metadata.block_start;
 
-    if let Some(mi) = parse_title_line(source_after_metadata, warnings) {
+    if let Some(mi) = parse_title_line(source_after_metadata, leveloffset, warnings) {
         let found_level = mi.item.0;
 
         if found_level > *most_recent_level + 1 {
@@ -2889,6 +2917,7 @@ mod tests {
             offset: 3,
         },
         rendered: "Section Title",
+        passthroughs: [],
     },
     blocks: &[],
     source: Span {
@@ -3437,4 +3466,124 @@ mod tests {
             assert!(warnings.is_empty());
         }
     }
+
+    mod leveloffset {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{
+            Parser,
+            blocks::{IsBlock, metadata::BlockMetadata},
+            parser::ModificationContext,
+            warnings::WarningType,
+        };
+
+        /// Return the level and title of each section in the document, in
+        /// document order.
+        fn section_levels(source: &str) -> Vec<(usize, String)> {
+            fn collect<'a>(block: &crate::blocks::Block<'a>, levels: &mut Vec<(usize, String)>) {
+                if let crate::blocks::Block::Section(section) = block {
+                    levels.push((section.level(), section.section_title().to_owned()));
+                }
+
+                for child in block.nested_blocks() {
+                    collect(child, levels);
+                }
+            }
+
+            let doc = Parser::default().parse(source);
+            let mut levels = vec![];
+
+            for block in doc.nested_blocks() {
+                collect(block, &mut levels);
+            }
+
+            levels
+        }
+
+        #[test]
+        fn absolute_value() {
+            let mut parser = Parser::default().with_intrinsic_attribute(
+                "leveloffset",
+                "1",
+                ModificationContext::Anywhere,
+            );
+            let mut warnings: Vec<crate::warnings::Warning<'_>> = vec![];
+
+            let mi = crate::blocks::SectionBlock::parse(
+                &BlockMetadata::new("= Chapter\n\n== Section"),
+                &mut parser,
+                &mut warnings,
+            )
+            .unwrap();
+
+            assert!(warnings.is_empty());
+
+            assert_eq!(mi.item.level(), 1);
+            assert_eq!(mi.item.section_title(), "Chapter");
+            assert_eq!(mi.item.nested_blocks().len(), 1);
+        }
+
+        #[test]
+        fn attribute_entries_in_header_and_body() {
+            assert_eq!(
+                section_levels(
+                    "= Book\n:leveloffset: 1\n\n= Chapter 1\n\n== Section 1.1\n\n:leveloffset: 0\n\n== Chapter 2"
+                ),
+                vec![
+                    (1, "Chapter 1".to_owned()),
+                    (2, "Section 1.1".to_owned()),
+                    (1, "Chapter 2".to_owned()),
+                ]
+            );
+        }
+
+        #[test]
+        fn relative_values() {
+            assert_eq!(
+                section_levels(
+                    "= Book\n\n== A\n\n:leveloffset: +1\n\n== B\n\n:leveloffset: +1\n\n== C\n\n:leveloffset: -2\n\n== D"
+                ),
+                vec![
+                    (1, "A".to_owned()),
+                    (2, "B".to_owned()),
+                    (3, "C".to_owned()),
+                    (1, "D".to_owned()),
+                ]
+            );
+        }
+
+        #[test]
+        fn unset_restores_default() {
+            assert_eq!(
+                section_levels("= Book\n\n:leveloffset: 2\n\n== A\n\n:leveloffset!:\n\n== B"),
+                vec![(3, "A".to_owned()), (1, "B".to_owned())]
+            );
+        }
+
+        #[test]
+        fn err_negative_offset_yields_level_0() {
+            let mut parser = Parser::default().with_intrinsic_attribute(
+                "leveloffset",
+                "-1",
+                ModificationContext::Anywhere,
+            );
+            let mut warnings: Vec<crate::warnings::Warning<'_>> = vec![];
+
+            let result = crate::blocks::SectionBlock::parse(
+                &BlockMetadata::new("== Section"),
+                &mut parser,
+                &mut warnings,
+            );
+
+            assert!(result.is_none());
+
+            assert_eq!(
+                warnings
+                    .into_iter()
+                    .map(|warning| warning.warning)
+                    .collect::<Vec<_>>(),
+                vec![WarningType::Level0SectionHeadingNotSupported]
+            );
+        }
+    }
 }
@@ -2,7 +2,8 @@ use crate::{
     HasSpan, Parser, Span,
     attributes::Attrlist,
     blocks::{
-        CompoundDelimitedBlock, ContentModel, IsBlock, RawDelimitedBlock, metadata::BlockMetadata,
+        CompoundDelimitedBlock, ContentModel, IsBlock, RawDelimitedBlock, TableBlock,
+        metadata::BlockMetadata,
     },
     content::{Content, SubstitutionGroup},
     span::MatchedItem,
@@ -187,9 +188,14 @@ fn parse_lines<'src>(
                 || line.starts_with('+')
                 || line.starts_with('=')
                 || line.starts_with('*')
-                || line.starts_with('_'))
+                || line.starts_with('_')
+                || line.starts_with('|')
+                || line.starts_with(',')
+                || line.starts_with(':')
+                || line.starts_with('!'))
                 && (RawDelimitedBlock::is_valid_delimiter(&line)
-                    || CompoundDelimitedBlock::is_valid_delimiter(&line))
+                    || CompoundDelimitedBlock::is_valid_delimiter(&line)
+                    || TableBlock::is_valid_delimiter(&line))
             {
                 break;
             }
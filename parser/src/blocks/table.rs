@@ -0,0 +1,1867 @@
+use std::{slice::Iter, sync::LazyLock};
+
+use regex::{Captures, Regex};
+
+use crate::{
+    HasSpan, Parser, Span,
+    attributes::Attrlist,
+    blocks::{
+        Block, ContentModel, IsBlock, metadata::BlockMetadata,
+        parse_utils::parse_blocks_in_table_cell,
+    },
+    content::{Content, SubstitutionGroup},
+    internal::debug::DebugSliceReference,
+    span::MatchedItem,
+    strings::CowStr,
+    warnings::{MatchAndWarnings, Warning, WarningType},
+};
+
+/// A table is a delimited block that arranges its content into rows and
+/// columns of cells.
+///
+/// A table is delimited by `|===` and the content between the delimiters is
+/// split into cells using the cell separator (`|` unless overridden by the
+/// `separator` attribute). Cells are then grouped into rows according to the
+/// number of columns, which is taken from the `cols` attribute or, if that is
+/// absent, from the number of cells on the first line of the table.
+///
+/// The content may instead be given as delimiter-separated values (CSV, TSV,
+/// or DSV) by setting the `format` attribute or by using the `,===` (CSV) or
+/// `:===` (DSV) delimiters. See [`TableFormat`].
+///
+/// A table may be nested within a cell that has the AsciiDoc (`a`) style. A
+/// nested table is typically delimited by `!===` and, unless the `separator`
+/// attribute is set, uses `!` as its cell separator.
+///
+/// The rows are available as an optional header row (either declared using
+/// the `header` option or detected implicitly), the body rows, and an optional
+/// footer row (declared using the `footer` option).
+#[derive(Clone, Eq, PartialEq)]
+pub struct TableBlock<'src> {
+    columns: Vec<TableColumn>,
+    format: TableFormat,
+    header_row: Option<TableRow<'src>>,
+    body_rows: Vec<TableRow<'src>>,
+    footer_row: Option<TableRow<'src>>,
+    source: Span<'src>,
+    title_source: Option<Span<'src>>,
+    title: Option<String>,
+    anchor: Option<Span<'src>>,
+    anchor_reftext: Option<Span<'src>>,
+    attrlist: Option<Attrlist<'src>>,
+}
+
+impl<'src> TableBlock<'src> {
+    pub(crate) fn is_valid_delimiter(line: &Span<'src>) -> bool {
+        let data = line.data();
+
+        data.len() >= 4
+            && data.starts_with(['|', ',', ':', '!'])
+            && data[1..].chars().all(|c| c == '=')
+    }
+
+    pub(crate) fn parse(
+        metadata: &BlockMetadata<'src>,
+        parser: &mut Parser,
+    ) -> Option<MatchAndWarnings<'src, Option<MatchedItem<'src, Self>>>> {
+        let delimiter = metadata.block_start.take_normalized_line();
+
+        if !Self::is_valid_delimiter(&delimiter.item) {
+            return None;
+        }
+
+        let mut next = delimiter.after;
+        let (closing_delimiter, after) = loop {
+            if next.is_empty() {
+                break (next, next);
+            }
+
+            let line = next.take_normalized_line();
+            if line.item.data() == delimiter.item.data() {
+                break (line.item, line.after);
+            }
+            next = line.after;
+        };
+
+        let inside_delimiters = delimiter.after.trim_remainder(closing_delimiter);
+
+        let mut warnings: Vec<Warning<'src>> = vec![];
+        if closing_delimiter.is_empty() {
+            warnings.push(Warning {
+                source: delimiter.item,
+                warning: WarningType::UnterminatedDelimitedBlock,
+            });
+        }
+
+        let attrlist = metadata.attrlist.as_ref();
+
+        let implicit_format = match delimiter.item.chars().next() {
+            Some(',') => TableFormat::Csv,
+            Some(':') => TableFormat::Dsv,
+            _ => TableFormat::Psv,
+        };
+
+        let format = match attrlist
+            .and_then(|attrlist| attrlist.named_attribute("format"))
+            .map(|attr| attr.value())
+        {
+            None => implicit_format,
+            Some(format) => TableFormat::from_name(format).unwrap_or_else(|| {
+                warnings.push(Warning {
+                    source: delimiter.item,
+                    warning: WarningType::TableInvalidFormat(format.to_owned()),
+                });
+                implicit_format
+            }),
+        };
+
+        let separator = match attrlist
+            .and_then(|attrlist| attrlist.named_attribute("separator"))
+            .map(|attr| attr.value())
+            .filter(|separator| !separator.is_empty())
+        {
+            Some("\\t") => "\t",
+            Some(separator) => separator,
+            None if format == TableFormat::Psv && parser.in_table_cell => "!",
+            None => format.default_separator(),
+        }
+        .to_owned();
+
+        let columns = attrlist
+            .and_then(|attrlist| attrlist.named_attribute("cols"))
+            .map(|attr| {
+                let (columns, exceeds_limit) = parse_column_specs(attr.value());
+                if exceeds_limit {
+                    warnings.push(Warning {
+                        source: delimiter.item,
+                        warning: WarningType::TableCountExceedsLimit(MAX_TABLE_COUNT),
+                    });
+                }
+                columns
+            })
+            .unwrap_or_default();
+
+        let has_header_option = attrlist.is_some_and(|attrlist| attrlist.has_option("header"));
+        let has_noheader_option = attrlist.is_some_and(|attrlist| attrlist.has_option("noheader"));
+        let has_footer_option = attrlist.is_some_and(|attrlist| attrlist.has_option("footer"));
+
+        let mut row_parser = RowParser::new(inside_delimiters, &separator, columns);
+        let implicit_header = if format == TableFormat::Psv {
+            row_parser.parse(!has_header_option && !has_noheader_option)
+        } else {
+            row_parser.parse_data(!has_header_option && !has_noheader_option, format)
+        };
+        warnings.append(&mut row_parser.warnings);
+
+        let mut raw_rows = std::mem::take(&mut row_parser.rows).into_iter();
+
+        let header_row = if has_header_option || implicit_header {
+            raw_rows.next().map(|row| {
+                TableRow::from_raw_cells(row, true, format, &separator, parser, &mut warnings)
+            })
+        } else {
+            None
+        };
+
+        let mut body_rows: Vec<TableRow<'src>> = vec![];
+        for row in raw_rows {
+            body_rows.push(TableRow::from_raw_cells(
+                row,
+                false,
+                format,
+                &separator,
+                parser,
+                &mut warnings,
+            ));
+        }
+
+        let footer_row = if has_footer_option {
+            body_rows.pop()
+        } else {
+            None
+        };
+
+        let source = metadata
+            .source
+            .trim_remainder(closing_delimiter.discard_all());
+
+        Some(MatchAndWarnings {
+            item: Some(MatchedItem {
+                item: Self {
+                    columns: row_parser.columns,
+                    format,
+                    header_row,
+                    body_rows,
+                    footer_row,
+                    source: source.trim_trailing_whitespace(),
+                    title_source: metadata.title_source,
+                    title: metadata.title.clone(),
+                    anchor: metadata.anchor,
+                    anchor_reftext: metadata.anchor_reftext,
+                    attrlist: metadata.attrlist.clone(),
+                },
+                after: after.discard_empty_lines(),
+            }),
+            warnings,
+        })
+    }
+
+    /// Returns the column definitions for this table.
+    ///
+    /// If the `cols` attribute was not specified, the columns are inferred
+    /// from the cells on the first line of the table, in which case each
+    /// column has the default properties.
+    pub fn columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Returns the data format of this table's content.
+    pub fn format(&self) -> TableFormat {
+        self.format
+    }
+
+    /// Returns the header row, if any.
+    ///
+    /// A table has a header row if the `header` option is set or if the first
+    /// line of the table contains only cells and is followed by an empty line
+    /// (an _implicit header row_).
+    pub fn header_row(&self) -> Option<&TableRow<'src>> {
+        self.header_row.as_ref()
+    }
+
+    /// Returns the rows in the body of the table.
+    pub fn body_rows(&self) -> &[TableRow<'src>] {
+        &self.body_rows
+    }
+
+    /// Returns the footer row, if any.
+    ///
+    /// A table has a footer row if the `footer` option is set, in which case
+    /// the last row of the table is the footer row.
+    pub fn footer_row(&self) -> Option<&TableRow<'src>> {
+        self.footer_row.as_ref()
+    }
+}
+
+impl<'src> IsBlock<'src> for TableBlock<'src> {
+    fn content_model(&self) -> ContentModel {
+        ContentModel::Table
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        "table".into()
+    }
+
+    fn options(&'src self) -> Vec<&'src str> {
+        let mut options = match self.attrlist() {
+            Some(attrlist) => attrlist.options(),
+            None => vec![],
+        };
+
+        if self.header_row.is_some() && !options.contains(&"header") {
+            options.push("header");
+        }
+
+        options
+    }
+
+    fn has_option<N: AsRef<str>>(&'src self, name: N) -> bool {
+        (name.as_ref() == "header" && self.header_row.is_some())
+            || self
+                .attrlist()
+                .is_some_and(|attrlist| attrlist.has_option(name))
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        self.title_source
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        self.anchor
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        self.anchor_reftext
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        self.attrlist.as_ref()
+    }
+}
+
+impl<'src> HasSpan<'src> for TableBlock<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for TableBlock<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableBlock")
+            .field("columns", &DebugSliceReference(&self.columns))
+            .field("format", &self.format)
+            .field("header_row", &self.header_row)
+            .field("body_rows", &DebugSliceReference(&self.body_rows))
+            .field("footer_row", &self.footer_row)
+            .field("source", &self.source)
+            .field("title_source", &self.title_source)
+            .field("title", &self.title)
+            .field("anchor", &self.anchor)
+            .field("anchor_reftext", &self.anchor_reftext)
+            .field("attrlist", &self.attrlist)
+            .finish()
+    }
+}
+
+/// The data format of the content of a [`TableBlock`].
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TableFormat {
+    /// Prefix-separated values: each cell is preceded by the cell separator
+    /// (`|` by default) and may be preceded by a cell specifier.
+    Psv,
+
+    /// Comma-separated values, loosely based on RFC 4180. Values may be
+    /// enclosed in double quotes, in which case they may contain separators
+    /// and newlines.
+    Csv,
+
+    /// Delimiter-separated values: values are separated by a colon (`:`) by
+    /// default. A separator within a value may be escaped with a backslash.
+    Dsv,
+
+    /// Tab-separated values: the same as [`Csv`] with a tab as the separator.
+    ///
+    /// [`Csv`]: TableFormat::Csv
+    Tsv,
+}
+
+impl TableFormat {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "psv" => Some(Self::Psv),
+            "csv" => Some(Self::Csv),
+            "dsv" => Some(Self::Dsv),
+            "tsv" => Some(Self::Tsv),
+            _ => None,
+        }
+    }
+
+    fn default_separator(self) -> &'static str {
+        match self {
+            Self::Psv => "|",
+            Self::Csv => ",",
+            Self::Dsv => ":",
+            Self::Tsv => "\t",
+        }
+    }
+
+    fn is_quoted(self) -> bool {
+        matches!(self, Self::Csv | Self::Tsv)
+    }
+}
+
+impl std::fmt::Debug for TableFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableFormat::Psv => write!(f, "TableFormat::Psv"),
+            TableFormat::Csv => write!(f, "TableFormat::Csv"),
+            TableFormat::Dsv => write!(f, "TableFormat::Dsv"),
+            TableFormat::Tsv => write!(f, "TableFormat::Tsv"),
+        }
+    }
+}
+
+/// Describes the default properties of a column in a [`TableBlock`].
+///
+/// Columns are described by the `cols` attribute of the table. Each cell in
+/// the column inherits its alignment and style from the column unless the
+/// cell specifies its own.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TableColumn {
+    /// The width of the column.
+    pub width: ColumnWidth,
+
+    /// The default horizontal alignment of cells in this column.
+    pub halign: HorizontalAlignment,
+
+    /// The default vertical alignment of cells in this column.
+    pub valign: VerticalAlignment,
+
+    /// The default style of cells in this column.
+    pub style: TableCellStyle,
+}
+
+impl Default for TableColumn {
+    fn default() -> Self {
+        Self {
+            width: ColumnWidth::Proportional(1),
+            halign: HorizontalAlignment::Left,
+            valign: VerticalAlignment::Top,
+            style: TableCellStyle::Default,
+        }
+    }
+}
+
+/// The width of a [`TableColumn`].
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum ColumnWidth {
+    /// The column's width relative to the widths of the other columns.
+    ///
+    /// A percentage width (e.g., `25%`) is treated as a proportional width.
+    Proportional(u32),
+
+    /// The column's width is determined by its content (`~`).
+    Auto,
+}
+
+impl std::fmt::Debug for ColumnWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnWidth::Proportional(width) => f
+                .debug_tuple("ColumnWidth::Proportional")
+                .field(width)
+                .finish(),
+            ColumnWidth::Auto => write!(f, "ColumnWidth::Auto"),
+        }
+    }
+}
+
+/// The horizontal alignment of the content of a table cell.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum HorizontalAlignment {
+    /// Content is aligned to the left edge of the cell (`<`).
+    Left,
+
+    /// Content is centered within the cell (`^`).
+    Center,
+
+    /// Content is aligned to the right edge of the cell (`>`).
+    Right,
+}
+
+impl std::fmt::Debug for HorizontalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HorizontalAlignment::Left => write!(f, "HorizontalAlignment::Left"),
+            HorizontalAlignment::Center => write!(f, "HorizontalAlignment::Center"),
+            HorizontalAlignment::Right => write!(f, "HorizontalAlignment::Right"),
+        }
+    }
+}
+
+/// The vertical alignment of the content of a table cell.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum VerticalAlignment {
+    /// Content is aligned to the top of the cell (`.<`).
+    Top,
+
+    /// Content is centered vertically within the cell (`.^`).
+    Middle,
+
+    /// Content is aligned to the bottom of the cell (`.>`).
+    Bottom,
+}
+
+impl std::fmt::Debug for VerticalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerticalAlignment::Top => write!(f, "VerticalAlignment::Top"),
+            VerticalAlignment::Middle => write!(f, "VerticalAlignment::Middle"),
+            VerticalAlignment::Bottom => write!(f, "VerticalAlignment::Bottom"),
+        }
+    }
+}
+
+/// The style of a table cell determines how the cell's content is parsed and
+/// presented.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TableCellStyle {
+    /// Content is treated as a paragraph (`d`).
+    Default,
+
+    /// Content is parsed as a nested AsciiDoc document (`a`).
+    AsciiDoc,
+
+    /// Content is emphasized (`e`).
+    Emphasis,
+
+    /// Content is styled as a header cell (`h`).
+    Header,
+
+    /// Content is treated as literal text (`l`).
+    Literal,
+
+    /// Content is set in a monospace font (`m`).
+    Monospace,
+
+    /// Content is strongly emphasized (`s`).
+    Strong,
+
+    /// Content is treated as a verse (`v`).
+    Verse,
+}
+
+impl TableCellStyle {
+    fn from_letter(letter: &str) -> Option<Self> {
+        match letter {
+            "d" => Some(Self::Default),
+            "a" => Some(Self::AsciiDoc),
+            "e" => Some(Self::Emphasis),
+            "h" => Some(Self::Header),
+            "l" => Some(Self::Literal),
+            "m" => Some(Self::Monospace),
+            "s" => Some(Self::Strong),
+            "v" => Some(Self::Verse),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for TableCellStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableCellStyle::Default => write!(f, "TableCellStyle::Default"),
+            TableCellStyle::AsciiDoc => write!(f, "TableCellStyle::AsciiDoc"),
+            TableCellStyle::Emphasis => write!(f, "TableCellStyle::Emphasis"),
+            TableCellStyle::Header => write!(f, "TableCellStyle::Header"),
+            TableCellStyle::Literal => write!(f, "TableCellStyle::Literal"),
+            TableCellStyle::Monospace => write!(f, "TableCellStyle::Monospace"),
+            TableCellStyle::Strong => write!(f, "TableCellStyle::Strong"),
+            TableCellStyle::Verse => write!(f, "TableCellStyle::Verse"),
+        }
+    }
+}
+
+/// A single row of cells within a [`TableBlock`].
+#[derive(Clone, Eq, PartialEq)]
+pub struct TableRow<'src> {
+    cells: Vec<TableCell<'src>>,
+}
+
+impl<'src> TableRow<'src> {
+    fn from_raw_cells(
+        raw_cells: Vec<RawCell<'src>>,
+        in_header: bool,
+        format: TableFormat,
+        separator: &str,
+        parser: &mut Parser,
+        warnings: &mut Vec<Warning<'src>>,
+    ) -> Self {
+        Self {
+            cells: raw_cells
+                .into_iter()
+                .map(|raw| TableCell::from_raw(raw, in_header, format, separator, parser, warnings))
+                .collect(),
+        }
+    }
+
+    /// Returns the cells in this row.
+    ///
+    /// A cell that spans multiple columns or rows appears only once, in the
+    /// row where it starts.
+    pub fn cells(&self) -> &[TableCell<'src>] {
+        &self.cells
+    }
+}
+
+impl std::fmt::Debug for TableRow<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableRow")
+            .field("cells", &DebugSliceReference(&self.cells))
+            .finish()
+    }
+}
+
+/// A single cell within a [`TableRow`].
+///
+/// The content of a cell is either parsed as a paragraph of text (available
+/// via [`rendered_content()`]) or, for cells with the AsciiDoc (`a`) style, as
+/// a sequence of nested blocks (available via [`nested_blocks()`]).
+///
+/// [`rendered_content()`]: IsBlock::rendered_content
+/// [`nested_blocks()`]: IsBlock::nested_blocks
+#[derive(Clone, Eq, PartialEq)]
+pub struct TableCell<'src> {
+    content: Option<Content<'src>>,
+    blocks: Vec<Block<'src>>,
+    source: Span<'src>,
+    colspan: usize,
+    rowspan: usize,
+    halign: HorizontalAlignment,
+    valign: VerticalAlignment,
+    style: TableCellStyle,
+}
+
+impl<'src> TableCell<'src> {
+    fn from_raw(
+        raw: RawCell<'src>,
+        in_header: bool,
+        format: TableFormat,
+        separator: &str,
+        parser: &mut Parser,
+        warnings: &mut Vec<Warning<'src>>,
+    ) -> Self {
+        // The header row ignores any style assigned via column and cell
+        // specifiers and any alignment assigned via the column specifiers.
+        let column = if in_header {
+            TableColumn::default()
+        } else {
+            raw.column
+        };
+
+        let style = if in_header {
+            TableCellStyle::Default
+        } else {
+            raw.spec.style.unwrap_or(column.style)
+        };
+
+        let raw_text = if format.is_quoted() {
+            unquote(raw.text)
+        } else {
+            raw.text
+        };
+
+        let (content, blocks) = match style {
+            TableCellStyle::AsciiDoc => {
+                let text = raw_text.trim_trailing_whitespace();
+                let text = if text.starts_with('\n') || text.starts_with("\r\n") {
+                    text.discard_empty_lines()
+                } else {
+                    text.take_whitespace_with_newline().after
+                };
+
+                let maw_blocks = parse_blocks_in_table_cell(text, parser);
+                warnings.extend(maw_blocks.warnings);
+
+                (None, maw_blocks.item.item)
+            }
+
+            TableCellStyle::Literal => {
+                let text = raw_text.trim_trailing_whitespace();
+                let text = text.discard_empty_lines();
+
+                let mut content = cell_content(text, format, separator);
+                SubstitutionGroup::Verbatim.apply(&mut content, parser, None);
+
+                (Some(content), vec![])
+            }
+
+            _ => {
+                let text = raw_text
+                    .trim_trailing_whitespace()
+                    .take_whitespace_with_newline()
+                    .after;
+
+                let mut content = cell_content(text, format, separator);
+                SubstitutionGroup::Normal.apply(&mut content, parser, None);
+
+                (Some(content), vec![])
+            }
+        };
+
+        Self {
+            content,
+            blocks,
+            source: raw.source,
+            colspan: raw.spec.colspan,
+            rowspan: raw.spec.rowspan,
+            halign: raw.spec.halign.unwrap_or(column.halign),
+            valign: raw.spec.valign.unwrap_or(column.valign),
+            style,
+        }
+    }
+
+    /// Returns the interpreted content of this cell, if any.
+    ///
+    /// Cells with the AsciiDoc (`a`) style have no content; their nested
+    /// blocks are available via [`nested_blocks()`] instead.
+    ///
+    /// [`nested_blocks()`]: IsBlock::nested_blocks
+    pub fn content(&self) -> Option<&Content<'src>> {
+        self.content.as_ref()
+    }
+
+    /// Returns the number of columns this cell spans.
+    pub fn colspan(&self) -> usize {
+        self.colspan
+    }
+
+    /// Returns the number of rows this cell spans.
+    pub fn rowspan(&self) -> usize {
+        self.rowspan
+    }
+
+    /// Returns the horizontal alignment of this cell's content.
+    pub fn halign(&self) -> HorizontalAlignment {
+        self.halign
+    }
+
+    /// Returns the vertical alignment of this cell's content.
+    pub fn valign(&self) -> VerticalAlignment {
+        self.valign
+    }
+
+    /// Returns the style of this cell.
+    ///
+    /// Cells in the header row always have the default style.
+    pub fn style(&self) -> TableCellStyle {
+        self.style
+    }
+}
+
+impl<'src> IsBlock<'src> for TableCell<'src> {
+    fn content_model(&self) -> ContentModel {
+        match self.style {
+            TableCellStyle::AsciiDoc => ContentModel::Compound,
+            TableCellStyle::Literal => ContentModel::Verbatim,
+            _ => ContentModel::Simple,
+        }
+    }
+
+    fn rendered_content(&'src self) -> Option<&'src str> {
+        self.content.as_ref().map(|content| content.rendered())
+    }
+
+    fn raw_context(&self) -> CowStr<'src> {
+        "table_cell".into()
+    }
+
+    fn nested_blocks(&'src self) -> Iter<'src, Block<'src>> {
+        self.blocks.iter()
+    }
+
+    fn title_source(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn title(&self) -> Option<&str> {
+        None
+    }
+
+    fn anchor(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn anchor_reftext(&'src self) -> Option<Span<'src>> {
+        None
+    }
+
+    fn attrlist(&'src self) -> Option<&'src Attrlist<'src>> {
+        None
+    }
+
+    fn substitution_group(&'src self) -> SubstitutionGroup {
+        match self.style {
+            TableCellStyle::Literal => SubstitutionGroup::Verbatim,
+            _ => SubstitutionGroup::Normal,
+        }
+    }
+}
+
+impl<'src> HasSpan<'src> for TableCell<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+impl std::fmt::Debug for TableCell<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableCell")
+            .field("content", &self.content)
+            .field("blocks", &DebugSliceReference(&self.blocks))
+            .field("source", &self.source)
+            .field("colspan", &self.colspan)
+            .field("rowspan", &self.rowspan)
+            .field("halign", &self.halign)
+            .field("valign", &self.valign)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+/// Builds the [`Content`] for a cell, removing the backslash from any escaped
+/// cell separators or, for CSV and TSV tables, collapsing any escaped double
+/// quotes.
+fn cell_content<'src>(text: Span<'src>, format: TableFormat, separator: &str) -> Content<'src> {
+    let (escaped, unescaped) = if format.is_quoted() {
+        ("\"\"".to_owned(), "\"")
+    } else {
+        (format!("\\{separator}"), separator)
+    };
+
+    if text.contains(&escaped) {
+        Content::from_filtered(text, text.data().replace(&escaped, unescaped))
+    } else {
+        text.into()
+    }
+}
+
+/// Removes the enclosing double quotes (if any) from a CSV or TSV value.
+fn unquote(text: Span<'_>) -> Span<'_> {
+    let trimmed = text
+        .take_whitespace_with_newline()
+        .after
+        .trim_trailing_whitespace();
+
+    if trimmed.len() >= 2 && trimmed.starts_with('"') && trimmed.ends_with('"') {
+        trimmed.slice(1..trimmed.len() - 1)
+    } else {
+        text
+    }
+}
+
+/// Parses the value of the `cols` attribute into a list of column
+/// definitions.
+///
+/// The value may either be a single number (the number of columns, each with
+/// default properties) or a comma- or semicolon-separated list of column
+/// specifiers.
+///
+/// The number of columns is limited to [`MAX_TABLE_COUNT`]. The second value
+/// returned is `true` if the value asked for more columns than that.
+fn parse_column_specs(cols: &str) -> (Vec<TableColumn>, bool) {
+    let cols: String = cols.chars().filter(|c| *c != ' ').collect();
+
+    if !cols.is_empty() && cols.chars().all(|c| c.is_ascii_digit()) {
+        return match parse_count(&cols) {
+            Some(count) => (vec![TableColumn::default(); count], false),
+            None => (vec![TableColumn::default(); MAX_TABLE_COUNT], true),
+        };
+    }
+
+    let mut exceeds_limit = false;
+
+    let mut columns: Vec<TableColumn> = vec![];
+    let separator = if cols.contains(',') { ',' } else { ';' };
+
+    for record in cols.split(separator) {
+        if columns.len() >= MAX_TABLE_COUNT {
+            exceeds_limit = true;
+            break;
+        }
+
+        if record.is_empty() {
+            columns.push(TableColumn::default());
+            continue;
+        }
+
+        let Some(captures) = COLUMN_SPEC.captures(record) else {
+            continue;
+        };
+
+        let mut column = TableColumn::default();
+
+        if let Some(alignment) = captures.get(2) {
+            let (halign, valign) = parse_alignment(alignment.as_str());
+            column.halign = halign.unwrap_or(column.halign);
+            column.valign = valign.unwrap_or(column.valign);
+        }
+
+        if let Some(width) = captures.get(3) {
+            column.width = if width.as_str() == "~" {
+                ColumnWidth::Auto
+            } else {
+                ColumnWidth::Proportional(
+                    width
+                        .as_str()
+                        .trim_end_matches('%')
+                        .parse()
+                        .ok()
+                        .filter(|width| *width > 0)
+                        .unwrap_or(1),
+                )
+            };
+        }
+
+        if let Some(style) = captures
+            .get(4)
+            .and_then(|style| TableCellStyle::from_letter(style.as_str()))
+        {
+            column.style = style;
+        }
+
+        let repeat = captures
+            .get(1)
+            .map_or(Some(1), |repeat| parse_count(repeat.as_str()))
+            .unwrap_or_else(|| {
+                exceeds_limit = true;
+                MAX_TABLE_COUNT
+            });
+
+        for _ in 0..repeat {
+            if columns.len() >= MAX_TABLE_COUNT {
+                exceeds_limit = true;
+                break;
+            }
+            columns.push(column);
+        }
+    }
+
+    (columns, exceeds_limit)
+}
+
+static COLUMN_SPEC: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)
+        ^
+        (?:(\d+)\*)?                                    # Repeat count
+        ([<^>](?:\.[<^>]?)?|(?:[<^>]?\.)?[<^>])?        # Alignment
+        (\d+%?|~)?                                      # Width
+        ([a-z])?                                        # Style
+        $
+        "#,
+    )
+    .unwrap()
+});
+
+/// Matches a cell specifier at the start of a line (the text before the
+/// first cell separator on that line).
+static CELL_SPEC_START: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)
+        ^
+        [\ \t]*
+        (?:(\d+(?:\.\d*)?|(?:\d*\.)?\d+)([*+]))?        # Span or duplication
+        ([<^>](?:\.[<^>]?)?|(?:[<^>]?\.)?[<^>])?        # Alignment
+        ([a-z])?                                        # Style
+        $
+        "#,
+    )
+    .unwrap()
+});
+
+/// Matches a cell specifier at the end of the text preceding a cell
+/// separator.
+static CELL_SPEC_END: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)
+        [\ \t]+
+        (?:(\d+(?:\.\d*)?|(?:\d*\.)?\d+)([*+]))?        # Span or duplication
+        ([<^>](?:\.[<^>]?)?|(?:[<^>]?\.)?[<^>])?        # Alignment
+        ([a-z])?                                        # Style
+        $
+        "#,
+    )
+    .unwrap()
+});
+
+/// Returns `true` if a CSV or TSV value begins with a double quote that has
+/// not yet been closed.
+fn has_unclosed_quote(value: &str) -> bool {
+    let Some(quoted) = value.trim_start().strip_prefix('"') else {
+        return false;
+    };
+
+    let mut chars = quoted.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            if chars.peek() == Some(&'"') {
+                // Escaped double quote.
+                chars.next();
+            } else {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Maximum number of columns in a table, and the maximum colspan, rowspan,
+/// or repeat count of a single cell.
+const MAX_TABLE_COUNT: usize = 1000;
+
+/// Parses a column, span, or repeat count. A missing or zero count is treated
+/// as one. Returns `None` if the count exceeds [`MAX_TABLE_COUNT`].
+fn parse_count(count: &str) -> Option<usize> {
+    if count.is_empty() {
+        return Some(1);
+    }
+
+    count
+        .parse::<usize>()
+        .ok()
+        .filter(|count| *count <= MAX_TABLE_COUNT)
+        .map(|count| count.max(1))
+}
+
+fn parse_alignment(alignment: &str) -> (Option<HorizontalAlignment>, Option<VerticalAlignment>) {
+    let (halign, valign) = alignment.split_once('.').unwrap_or((alignment, ""));
+
+    let halign = match halign {
+        "<" => Some(HorizontalAlignment::Left),
+        "^" => Some(HorizontalAlignment::Center),
+        ">" => Some(HorizontalAlignment::Right),
+        _ => None,
+    };
+
+    let valign = match valign {
+        "<" => Some(VerticalAlignment::Top),
+        "^" => Some(VerticalAlignment::Middle),
+        ">" => Some(VerticalAlignment::Bottom),
+        _ => None,
+    };
+
+    (halign, valign)
+}
+
+/// Properties of a cell as declared by the cell specifier preceding the cell
+/// separator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct CellSpec {
+    colspan: usize,
+    rowspan: usize,
+    repeat: usize,
+    halign: Option<HorizontalAlignment>,
+    valign: Option<VerticalAlignment>,
+    style: Option<TableCellStyle>,
+
+    /// `true` if any count in the specifier exceeded [`MAX_TABLE_COUNT`] and
+    /// was clamped to that limit.
+    exceeds_limit: bool,
+}
+
+impl Default for CellSpec {
+    fn default() -> Self {
+        Self {
+            colspan: 1,
+            rowspan: 1,
+            repeat: 1,
+            halign: None,
+            valign: None,
+            style: None,
+            exceeds_limit: false,
+        }
+    }
+}
+
+impl CellSpec {
+    fn from_captures(captures: &Captures<'_>) -> Self {
+        let mut spec = Self::default();
+
+        if let (Some(factor), Some(operator)) = (captures.get(1), captures.get(2)) {
+            let factor = factor.as_str();
+
+            let mut clamp = |count: Option<usize>| {
+                count.unwrap_or_else(|| {
+                    spec.exceeds_limit = true;
+                    MAX_TABLE_COUNT
+                })
+            };
+
+            if operator.as_str() == "+" {
+                let (colspan, rowspan) = factor.split_once('.').unwrap_or((factor, ""));
+                let colspan = clamp(parse_count(colspan));
+                let rowspan = clamp(parse_count(rowspan));
+                (spec.colspan, spec.rowspan) = (colspan, rowspan);
+            } else {
+                let repeat = factor.split_once('.').map_or(factor, |(repeat, _)| repeat);
+                spec.repeat = clamp(parse_count(repeat));
+            }
+        }
+
+        if let Some(alignment) = captures.get(3) {
+            (spec.halign, spec.valign) = parse_alignment(alignment.as_str());
+        }
+
+        spec.style = captures
+            .get(4)
+            .and_then(|style| TableCellStyle::from_letter(style.as_str()));
+
+        spec
+    }
+}
+
+/// A cell whose boundaries have been found, but whose content has not yet
+/// been parsed.
+#[derive(Debug)]
+struct RawCell<'src> {
+    source: Span<'src>,
+    text: Span<'src>,
+    spec: CellSpec,
+    column: TableColumn,
+}
+
+/// A cell whose start has been found, but not yet its end. Offsets are
+/// relative to the start of the table content.
+#[derive(Debug)]
+struct OpenCell {
+    source_start: usize,
+    text_start: usize,
+    spec: Option<CellSpec>,
+}
+
+/// Splits the content of a table into cells and groups those cells into rows.
+struct RowParser<'a, 'src> {
+    content: Span<'src>,
+    separator: &'a str,
+    columns: Vec<TableColumn>,
+    column_count: Option<usize>,
+    rows: Vec<Vec<RawCell<'src>>>,
+    current_row: Vec<RawCell<'src>>,
+    column_visits: usize,
+    active_rowspans: Vec<usize>,
+    lines_started: usize,
+    open_cell: Option<OpenCell>,
+    warnings: Vec<Warning<'src>>,
+}
+
+impl<'a, 'src> RowParser<'a, 'src> {
+    fn new(content: Span<'src>, separator: &'a str, columns: Vec<TableColumn>) -> Self {
+        let column_count = if columns.is_empty() {
+            None
+        } else {
+            Some(columns.len())
+        };
+
+        Self {
+            content,
+            separator,
+            columns,
+            column_count,
+            rows: vec![],
+            current_row: vec![],
+            column_visits: 0,
+            active_rowspans: vec![0],
+            lines_started: 0,
+            open_cell: None,
+            warnings: vec![],
+        }
+    }
+
+    /// Parses the table content into rows. Returns `true` if the first row
+    /// should be treated as an implicit header row.
+    fn parse(&mut self, mut implicit_header: bool) -> bool {
+        let first_line = self.content.discard_empty_lines();
+        if first_line.byte_offset() != self.content.byte_offset() {
+            implicit_header = false;
+        }
+
+        let mut implicit_header_boundary: Option<usize> = None;
+        let mut next = first_line;
+        let mut line_index = 0;
+
+        while !next.is_empty() {
+            let line = next.take_normalized_line();
+            let line_start = self.offset_of(line.item);
+
+            if line_index > 0 && line.item.is_empty() {
+                if let Some(boundary) = implicit_header_boundary.as_mut() {
+                    *boundary += 1;
+                }
+            } else {
+                let data = line.item.data();
+                let mut scan_start = line_start;
+
+                if data.starts_with(self.separator) {
+                    scan_start += self.separator.len();
+                    self.close_open_cell(CellSpec::default(), line_start, line_start, scan_start);
+                    implicit_header_boundary = None;
+                } else if let Some((spec, spec_len, separator_index)) = self.start_spec(data) {
+                    scan_start += separator_index + self.separator.len();
+                    self.close_open_cell(spec, line_start, line_start + spec_len, scan_start);
+                    implicit_header_boundary = None;
+                } else if implicit_header_boundary == Some(line_index) {
+                    implicit_header = false;
+                    implicit_header_boundary = None;
+                }
+
+                if line_index == 0 && implicit_header {
+                    if !line.after.is_empty() && line.after.take_line().item.is_empty() {
+                        implicit_header_boundary = Some(1);
+                    } else {
+                        implicit_header = false;
+                    }
+                }
+
+                self.scan_line(scan_start, line_start + line.item.len());
+            }
+
+            line_index += 1;
+            next = line.after;
+        }
+
+        if self.open_cell.is_some() {
+            self.close_cell(self.content.len(), true);
+        }
+
+        if !self.current_row.is_empty() {
+            self.warnings.push(Warning {
+                source: self.current_row[0].source,
+                warning: WarningType::TableIncompleteRow,
+            });
+            self.current_row.clear();
+        }
+
+        implicit_header
+    }
+
+    /// Parses delimiter-separated (CSV, TSV, or DSV) table content into rows.
+    /// Returns `true` if the first row should be treated as an implicit header
+    /// row.
+    fn parse_data(&mut self, mut implicit_header: bool, format: TableFormat) -> bool {
+        let first_line = self.content.discard_empty_lines();
+        if first_line.byte_offset() != self.content.byte_offset() {
+            implicit_header = false;
+        }
+
+        let mut next = first_line;
+        let mut line_index = 0;
+
+        while !next.is_empty() {
+            let line = next.take_normalized_line();
+            let line_start = self.offset_of(line.item);
+            let line_end = line_start + line.item.len();
+
+            // Empty lines are skipped unless they are part of a quoted value.
+            if self.open_cell.is_some() || !line.item.is_empty() {
+                if self.open_cell.is_none() {
+                    self.lines_started += 1;
+                    self.open_data_cell(line_start);
+                }
+
+                self.scan_data_line(line_start, line_end, format);
+
+                if line_index == 0
+                    && implicit_header
+                    && (self.open_cell.is_some()
+                        || line.after.is_empty()
+                        || !line.after.take_line().item.is_empty())
+                {
+                    implicit_header = false;
+                }
+            }
+
+            line_index += 1;
+            next = line.after;
+        }
+
+        if let Some(open_cell) = self.open_cell.as_ref() {
+            self.warnings.push(Warning {
+                source: self
+                    .content
+                    .slice_from(open_cell.source_start..)
+                    .trim_trailing_whitespace(),
+                warning: WarningType::TableUnclosedQuote,
+            });
+
+            self.close_cell(self.content.len(), true);
+        }
+
+        if !self.current_row.is_empty() {
+            self.warnings.push(Warning {
+                source: self.current_row[0].source,
+                warning: WarningType::TableIncompleteRow,
+            });
+            self.current_row.clear();
+        }
+
+        implicit_header
+    }
+
+    fn open_data_cell(&mut self, start: usize) {
+        self.open_cell = Some(OpenCell {
+            source_start: start,
+            text_start: start,
+            spec: Some(CellSpec::default()),
+        });
+    }
+
+    /// Scans a line of delimiter-separated values for separators, closing and
+    /// opening cells as they are found. The cell that is open at the end of the
+    /// line is closed unless it is a quoted value that continues onto the next
+    /// line.
+    fn scan_data_line(&mut self, start: usize, end: usize, format: TableFormat) {
+        let data = self.content.data();
+        let mut search_start = start;
+
+        while let Some(index) = data[search_start..end]
+            .find(self.separator)
+            .map(|index| index + search_start)
+        {
+            let after_separator = index + self.separator.len();
+            let cell_start = self.open_cell_start();
+
+            let escaped = if format.is_quoted() {
+                has_unclosed_quote(&data[cell_start..index])
+            } else {
+                index > cell_start && data[..index].ends_with('\\')
+            };
+
+            if !escaped {
+                self.close_data_cell(index, false);
+                self.open_data_cell(after_separator);
+            }
+
+            search_start = after_separator;
+        }
+
+        if !(format.is_quoted() && has_unclosed_quote(&data[self.open_cell_start()..end])) {
+            self.close_data_cell(end, true);
+        }
+    }
+
+    fn open_cell_start(&self) -> usize {
+        self.open_cell
+            .as_ref()
+            .map_or(0, |open_cell| open_cell.text_start)
+    }
+
+    fn close_data_cell(&mut self, end: usize, end_of_line: bool) {
+        // The source of a value excludes any surrounding whitespace.
+        if let Some(open_cell) = self.open_cell.as_mut() {
+            let text = &self.content.data()[open_cell.source_start..end];
+            open_cell.source_start += text.len() - text.trim_start().len();
+        }
+
+        self.close_cell(end, end_of_line);
+    }
+
+    fn offset_of(&self, span: Span<'src>) -> usize {
+        span.byte_offset() - self.content.byte_offset()
+    }
+
+    /// Looks for a cell specifier before the first cell separator on a line.
+    /// Returns the parsed specifier, the length of any leading whitespace
+    /// before the specifier, and the index of the separator.
+    fn start_spec(&self, line: &str) -> Option<(CellSpec, usize, usize)> {
+        let separator_index = line.find(self.separator)?;
+        let spec_text = &line[..separator_index];
+        let captures = CELL_SPEC_START.captures(spec_text)?;
+
+        let leading_whitespace = spec_text.len() - spec_text.trim_start_matches([' ', '\t']).len();
+
+        Some((
+            CellSpec::from_captures(&captures),
+            leading_whitespace,
+            separator_index,
+        ))
+    }
+
+    /// Scans the remainder of a line for cell separators, closing and opening
+    /// cells as they are found.
+    fn scan_line(&mut self, start: usize, end: usize) {
+        if self.open_cell.is_none() {
+            self.open_cell = Some(OpenCell {
+                source_start: start,
+                text_start: start,
+                spec: None,
+            });
+        }
+
+        let data = self.content.data();
+        let mut segment_start = start;
+        let mut search_start = start;
+
+        while let Some(index) = data[search_start..end]
+            .find(self.separator)
+            .map(|index| index + search_start)
+        {
+            let after_separator = index + self.separator.len();
+
+            if index > segment_start && data[..index].ends_with('\\') {
+                // Escaped separator: The cell continues.
+                search_start = after_separator;
+                continue;
+            }
+
+            let segment = &data[segment_start..index];
+
+            let (spec, text_end, spec_start) = match CELL_SPEC_END.captures(segment) {
+                Some(captures) => {
+                    // SAFETY: Capture group 0 is always present.
+                    #[allow(clippy::unwrap_used)]
+                    let whole = captures.get(0).unwrap();
+
+                    if whole.as_str().trim_start().is_empty() {
+                        (CellSpec::default(), segment.trim_end().len(), segment.len())
+                    } else {
+                        let spec_start = whole.start() + whole.as_str().len()
+                            - whole.as_str().trim_start().len();
+
+                        (
+                            CellSpec::from_captures(&captures),
+                            whole.start(),
+                            spec_start,
+                        )
+                    }
+                }
+                None => (CellSpec::default(), segment.len(), segment.len()),
+            };
+
+            self.close_cell(segment_start + text_end, false);
+
+            self.open_cell = Some(OpenCell {
+                source_start: segment_start + spec_start,
+                text_start: after_separator,
+                spec: Some(spec),
+            });
+
+            segment_start = after_separator;
+            search_start = after_separator;
+        }
+    }
+
+    /// Closes the cell that is open from a previous line (if any) and opens a
+    /// new cell at the start of the current line.
+    fn close_open_cell(
+        &mut self,
+        spec: CellSpec,
+        line_start: usize,
+        source_start: usize,
+        text_start: usize,
+    ) {
+        if self.open_cell.is_some() {
+            self.close_cell(line_start, true);
+        }
+
+        self.lines_started += 1;
+
+        self.open_cell = Some(OpenCell {
+            source_start,
+            text_start,
+            spec: Some(spec),
+        });
+    }
+
+    fn close_cell(&mut self, end: usize, end_of_line: bool) {
+        let Some(open_cell) = self.open_cell.take() else {
+            return;
+        };
+
+        let source = self
+            .content
+            .slice(open_cell.source_start..end)
+            .trim_trailing_whitespace();
+
+        let text = self.content.slice(open_cell.text_start..end);
+
+        let spec = match open_cell.spec {
+            Some(spec) => spec,
+            None => {
+                self.warnings.push(Warning {
+                    source,
+                    warning: WarningType::TableMissingLeadingSeparator,
+                });
+                CellSpec::default()
+            }
+        };
+
+        if spec.exceeds_limit {
+            self.warnings.push(Warning {
+                source,
+                warning: WarningType::TableCountExceedsLimit(MAX_TABLE_COUNT),
+            });
+        }
+
+        for i in 1..=spec.repeat {
+            let column = if self.column_count.is_none() {
+                if self.columns.len() + spec.colspan > MAX_TABLE_COUNT {
+                    self.warnings.push(Warning {
+                        source,
+                        warning: WarningType::TableCountExceedsLimit(MAX_TABLE_COUNT),
+                    });
+                    return;
+                }
+
+                let column = TableColumn::default();
+                for _ in 0..spec.colspan {
+                    self.columns.push(column);
+                }
+                column
+            } else if let Some(column) = self.columns.get(self.consumed_columns()) {
+                // Cells are assigned to the column at which they start, after
+                // any columns already consumed by colspans and active rowspans.
+                *column
+            } else {
+                self.warnings.push(Warning {
+                    source,
+                    warning: WarningType::TableCellExceedsColumns,
+                });
+                return;
+            };
+
+            if spec.rowspan > 1 {
+                self.activate_rowspan(spec.rowspan, spec.colspan);
+            }
+
+            self.column_visits += spec.colspan;
+
+            if self
+                .column_count
+                .is_some_and(|column_count| self.consumed_columns() > column_count)
+            {
+                self.warnings.push(Warning {
+                    source,
+                    warning: WarningType::TableCellSpanExceedsColumns,
+                });
+            }
+
+            self.current_row.push(RawCell {
+                source,
+                text,
+                spec,
+                column,
+            });
+
+            // Don't close the row on the first line of the table unless the
+            // column count is known or the line has ended.
+            if self.is_end_of_row()
+                && (self.column_count.is_some()
+                    || self.lines_started > 1
+                    || (end_of_line && i == spec.repeat))
+            {
+                self.close_row();
+            }
+        }
+    }
+
+    fn activate_rowspan(&mut self, rowspan: usize, colspan: usize) {
+        for i in 1..rowspan {
+            if self.active_rowspans.len() <= i {
+                self.active_rowspans.resize(i + 1, 0);
+            }
+            self.active_rowspans[i] += colspan;
+        }
+    }
+
+    /// Returns the number of columns in the current row that are occupied
+    /// either by cells in this row or by cells in previous rows that span
+    /// into this row.
+    fn consumed_columns(&self) -> usize {
+        self.column_visits + self.active_rowspans.first().copied().unwrap_or(0)
+    }
+
+    fn is_end_of_row(&self) -> bool {
+        match self.column_count {
+            None => true,
+            Some(column_count) => self.consumed_columns() >= column_count,
+        }
+    }
+
+    fn close_row(&mut self) {
+        self.rows.push(std::mem::take(&mut self.current_row));
+
+        if self.column_count.is_none() {
+            self.column_count = Some(self.column_visits);
+        }
+
+        self.column_visits = 0;
+
+        if !self.active_rowspans.is_empty() {
+            self.active_rowspans.remove(0);
+        }
+
+        if self.active_rowspans.is_empty() {
+            self.active_rowspans.push(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::panic)]
+    #![allow(clippy::unwrap_used)]
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        Parser,
+        blocks::{
+            ColumnWidth, HorizontalAlignment, TableBlock, TableCellStyle, TableColumn, TableFormat,
+            VerticalAlignment, metadata::BlockMetadata,
+        },
+    };
+
+    #[test]
+    fn impl_clone() {
+        // Silly test to mark the #[derive(...)] line as covered.
+        let mut parser = Parser::default();
+
+        let b1 = TableBlock::parse(&BlockMetadata::new("|===\n|a\n|==="), &mut parser)
+            .unwrap()
+            .unwrap_if_no_warnings()
+            .unwrap()
+            .item;
+
+        let b2 = b1.clone();
+        assert_eq!(b1, b2);
+    }
+
+    #[test]
+    fn impl_debug() {
+        let mut parser = Parser::default();
+
+        let table = TableBlock::parse(&BlockMetadata::new("|===\n|a\n|==="), &mut parser)
+            .unwrap()
+            .unwrap_if_no_warnings()
+            .unwrap()
+            .item;
+
+        assert_eq!(
+            format!("{table:#?}"),
+            r#"TableBlock {
+    columns: &[
+        TableColumn {
+            width: ColumnWidth::Proportional(
+                1,
+            ),
+            halign: HorizontalAlignment::Left,
+            valign: VerticalAlignment::Top,
+            style: TableCellStyle::Default,
+        },
+    ],
+    format: TableFormat::Psv,
+    header_row: None,
+    body_rows: &[
+        TableRow {
+            cells: &[
+                TableCell {
+                    content: Some(
+                        Content {
+                            original: Span {
+                                data: "a",
+                                line: 2,
+                                col: 2,
+                                offset: 6,
+                            },
+                            rendered: "a",
+                            passthroughs: [],
+                        },
+                    ),
+                    blocks: &[],
+                    source: Span {
+                        data: "|a",
+                        line: 2,
+                        col: 1,
+                        offset: 5,
+                    },
+                    colspan: 1,
+                    rowspan: 1,
+                    halign: HorizontalAlignment::Left,
+                    valign: VerticalAlignment::Top,
+                    style: TableCellStyle::Default,
+                },
+            ],
+        },
+    ],
+    footer_row: None,
+    source: Span {
+        data: "|===\n|a\n|===",
+        line: 1,
+        col: 1,
+        offset: 0,
+    },
+    title_source: None,
+    title: None,
+    anchor: None,
+    anchor_reftext: None,
+    attrlist: None,
+}"#
+        );
+    }
+
+    #[test]
+    fn not_a_table() {
+        let mut parser = Parser::default();
+
+        assert!(TableBlock::parse(&BlockMetadata::new("|==="), &mut parser).is_some());
+        assert!(TableBlock::parse(&BlockMetadata::new("|=="), &mut parser).is_none());
+        assert!(TableBlock::parse(&BlockMetadata::new("|===x"), &mut parser).is_none());
+        assert!(TableBlock::parse(&BlockMetadata::new("====="), &mut parser).is_none());
+    }
+
+    #[test]
+    fn is_valid_delimiter() {
+        assert!(TableBlock::is_valid_delimiter(&crate::Span::new("|===")));
+        assert!(TableBlock::is_valid_delimiter(&crate::Span::new("|=====")));
+        assert!(TableBlock::is_valid_delimiter(&crate::Span::new(",===")));
+        assert!(TableBlock::is_valid_delimiter(&crate::Span::new(":====")));
+        assert!(TableBlock::is_valid_delimiter(&crate::Span::new("!===")));
+
+        assert!(!TableBlock::is_valid_delimiter(&crate::Span::new("|==")));
+        assert!(!TableBlock::is_valid_delimiter(&crate::Span::new("|==-=")));
+        assert!(!TableBlock::is_valid_delimiter(&crate::Span::new("|====x")));
+        assert!(!TableBlock::is_valid_delimiter(&crate::Span::new("====")));
+        assert!(!TableBlock::is_valid_delimiter(&crate::Span::new(";===")));
+    }
+
+    mod parse_column_specs {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::blocks::{
+            ColumnWidth, HorizontalAlignment, TableCellStyle, TableColumn, VerticalAlignment,
+            table::parse_column_specs,
+        };
+
+        #[test]
+        fn column_count() {
+            assert_eq!(parse_column_specs("3").0, vec![TableColumn::default(); 3]);
+        }
+
+        #[test]
+        fn widths() {
+            let widths: Vec<ColumnWidth> = parse_column_specs("1, 2,~,25%, ,0")
+                .0
+                .iter()
+                .map(|column| column.width)
+                .collect();
+
+            assert_eq!(
+                widths,
+                vec![
+                    ColumnWidth::Proportional(1),
+                    ColumnWidth::Proportional(2),
+                    ColumnWidth::Auto,
+                    ColumnWidth::Proportional(25),
+                    ColumnWidth::Proportional(1),
+                    ColumnWidth::Proportional(1),
+                ]
+            );
+        }
+
+        #[test]
+        fn semicolon_separator() {
+            assert_eq!(parse_column_specs("1;1").0.len(), 2);
+        }
+
+        #[test]
+        fn repeat_alignment_and_style() {
+            assert_eq!(
+                parse_column_specs("2*^.>3a,.^l,>x").0,
+                vec![
+                    TableColumn {
+                        width: ColumnWidth::Proportional(3),
+                        halign: HorizontalAlignment::Center,
+                        valign: VerticalAlignment::Bottom,
+                        style: TableCellStyle::AsciiDoc,
+                    },
+                    TableColumn {
+                        width: ColumnWidth::Proportional(3),
+                        halign: HorizontalAlignment::Center,
+                        valign: VerticalAlignment::Bottom,
+                        style: TableCellStyle::AsciiDoc,
+                    },
+                    TableColumn {
+                        width: ColumnWidth::Proportional(1),
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Middle,
+                        style: TableCellStyle::Literal,
+                    },
+                    TableColumn {
+                        width: ColumnWidth::Proportional(1),
+                        halign: HorizontalAlignment::Right,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn invalid_spec_ignored() {
+            assert_eq!(parse_column_specs("1,bogus,1").0.len(), 2);
+        }
+
+        #[test]
+        fn column_count_limit() {
+            let (columns, exceeds_limit) = parse_column_specs("100000000000000");
+            assert_eq!(columns.len(), 1000);
+            assert!(exceeds_limit);
+
+            let (columns, exceeds_limit) = parse_column_specs("1000");
+            assert_eq!(columns.len(), 1000);
+            assert!(!exceeds_limit);
+
+            let (columns, exceeds_limit) = parse_column_specs("100000000000000*1");
+            assert_eq!(columns.len(), 1000);
+            assert!(exceeds_limit);
+
+            let (columns, exceeds_limit) = parse_column_specs("999*1,2*2");
+            assert_eq!(columns.len(), 1000);
+            assert!(exceeds_limit);
+        }
+    }
+
+    #[test]
+    fn table_column_default() {
+        assert_eq!(
+            TableColumn::default(),
+            TableColumn {
+                width: ColumnWidth::Proportional(1),
+                halign: HorizontalAlignment::Left,
+                valign: VerticalAlignment::Top,
+                style: TableCellStyle::Default,
+            }
+        );
+    }
+
+    #[test]
+    fn enums_impl_debug() {
+        assert_eq!(
+            format!("{:?}", ColumnWidth::Proportional(2)),
+            "ColumnWidth::Proportional(2)"
+        );
+        assert_eq!(format!("{:?}", ColumnWidth::Auto), "ColumnWidth::Auto");
+
+        for (halign, expected) in [
+            (HorizontalAlignment::Left, "HorizontalAlignment::Left"),
+            (HorizontalAlignment::Center, "HorizontalAlignment::Center"),
+            (HorizontalAlignment::Right, "HorizontalAlignment::Right"),
+        ] {
+            assert_eq!(format!("{halign:?}"), expected);
+        }
+
+        for (valign, expected) in [
+            (VerticalAlignment::Top, "VerticalAlignment::Top"),
+            (VerticalAlignment::Middle, "VerticalAlignment::Middle"),
+            (VerticalAlignment::Bottom, "VerticalAlignment::Bottom"),
+        ] {
+            assert_eq!(format!("{valign:?}"), expected);
+        }
+
+        for (style, expected) in [
+            (TableCellStyle::Default, "TableCellStyle::Default"),
+            (TableCellStyle::AsciiDoc, "TableCellStyle::AsciiDoc"),
+            (TableCellStyle::Emphasis, "TableCellStyle::Emphasis"),
+            (TableCellStyle::Header, "TableCellStyle::Header"),
+            (TableCellStyle::Literal, "TableCellStyle::Literal"),
+            (TableCellStyle::Monospace, "TableCellStyle::Monospace"),
+            (TableCellStyle::Strong, "TableCellStyle::Strong"),
+            (TableCellStyle::Verse, "TableCellStyle::Verse"),
+        ] {
+            assert_eq!(format!("{style:?}"), expected);
+        }
+
+        for (format, expected) in [
+            (TableFormat::Psv, "TableFormat::Psv"),
+            (TableFormat::Csv, "TableFormat::Csv"),
+            (TableFormat::Dsv, "TableFormat::Dsv"),
+            (TableFormat::Tsv, "TableFormat::Tsv"),
+        ] {
+            assert_eq!(format!("{format:?}"), expected);
+        }
+    }
+
+    #[test]
+    fn unknown_cell_style_ignored() {
+        let mut parser = Parser::default();
+
+        let table = TableBlock::parse(&BlockMetadata::new("|===\nz|a\n|==="), &mut parser)
+            .unwrap()
+            .unwrap_if_no_warnings()
+            .unwrap()
+            .item;
+
+        let cell = &table.body_rows()[0].cells()[0];
+        assert_eq!(cell.style(), TableCellStyle::Default);
+        assert_eq!(cell.content().unwrap().rendered(), "a");
+    }
+}
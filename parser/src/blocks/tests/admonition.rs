@@ -0,0 +1,417 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{AdmonitionKind, ContentModel, IsBlock, SimpleBlockStyle},
+    content::SubstitutionGroup,
+    tests::prelude::*,
+    warnings::WarningType,
+};
+
+#[test]
+fn paragraph_with_label() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("NOTE: An admonition paragraph\nspans two lines.\n\nabc"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::Admonition(AdmonitionBlock {
+            kind: AdmonitionKind::Note,
+            textlabel: Some("Note"),
+            content: Some(Content {
+                original: Span {
+                    data: "An admonition paragraph\nspans two lines.",
+                    line: 1,
+                    col: 7,
+                    offset: 6,
+                },
+                rendered: "An admonition paragraph\nspans two lines.",
+            }),
+            blocks: &[],
+            source: Span {
+                data: "NOTE: An admonition paragraph\nspans two lines.",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert_eq!(mi.item.content_model(), ContentModel::Simple);
+    assert_eq!(mi.item.raw_context().deref(), "admonition");
+    assert_eq!(mi.item.resolved_context().deref(), "admonition");
+    assert!(mi.item.declared_style().is_none());
+    assert!(mi.item.nested_blocks().next().is_none());
+    assert_eq!(
+        mi.item.rendered_content().unwrap(),
+        "An admonition paragraph\nspans two lines."
+    );
+    assert_eq!(mi.item.substitution_group(), SubstitutionGroup::Normal);
+
+    let crate::blocks::Block::Admonition(admonition) = &mi.item else {
+        panic!("Wrong block type: {:?}", mi.item);
+    };
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Note);
+    assert_eq!(admonition.name(), "note");
+    assert_eq!(admonition.textlabel().unwrap(), "Note");
+
+    assert_eq!(
+        mi.after,
+        Span {
+            data: "abc",
+            line: 4,
+            col: 1,
+            offset: 48,
+        }
+    );
+}
+
+#[test]
+fn each_label() {
+    for (source, kind, name, textlabel) in [
+        ("NOTE: x", AdmonitionKind::Note, "note", "Note"),
+        ("TIP: x", AdmonitionKind::Tip, "tip", "Tip"),
+        (
+            "IMPORTANT: x",
+            AdmonitionKind::Important,
+            "important",
+            "Important",
+        ),
+        ("CAUTION: x", AdmonitionKind::Caution, "caution", "Caution"),
+        ("WARNING: x", AdmonitionKind::Warning, "warning", "Warning"),
+    ] {
+        let mut parser = Parser::default();
+        let doc = parser.parse(source);
+
+        let block = doc.nested_blocks().next().unwrap();
+        let crate::blocks::Block::Admonition(admonition) = block else {
+            panic!("Wrong block type: {block:?}");
+        };
+
+        assert_eq!(admonition.kind(), kind);
+        assert_eq!(admonition.name(), name);
+        assert_eq!(admonition.textlabel().unwrap(), textlabel);
+        assert_eq!(admonition.content().unwrap().rendered(), "x");
+    }
+}
+
+#[test]
+fn label_not_recognized() {
+    for source in [
+        "NOTE:x",
+        "Note: x",
+        "NOTES: x",
+        "TODO: x",
+        " NOTE: x",
+        "Some text\nNOTE: x",
+    ] {
+        let mut parser = Parser::default();
+        let doc = parser.parse(source);
+
+        let block = doc.nested_blocks().next().unwrap();
+        assert_eq!(block.raw_context().deref(), "paragraph");
+    }
+}
+
+#[test]
+fn label_ignored_when_style_declared() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[normal]\nNOTE: Not an admonition.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "paragraph");
+    assert_eq!(
+        block.rendered_content().unwrap(),
+        "NOTE: Not an admonition."
+    );
+}
+
+#[test]
+fn paragraph_with_style() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[TIP]\nIt's possible to use Unicode glyphs."),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::Admonition(AdmonitionBlock {
+            kind: AdmonitionKind::Tip,
+            textlabel: Some("Tip"),
+            content: Some(Content {
+                original: Span {
+                    data: "It's possible to use Unicode glyphs.",
+                    line: 2,
+                    col: 1,
+                    offset: 6,
+                },
+                rendered: "It&#8217;s possible to use Unicode glyphs.",
+            }),
+            blocks: &[],
+            source: Span {
+                data: "[TIP]\nIt's possible to use Unicode glyphs.",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: Some(Attrlist {
+                attributes: &[ElementAttribute {
+                    name: None,
+                    shorthand_items: &["TIP"],
+                    value: "TIP"
+                },],
+                anchor: None,
+                source: Span {
+                    data: "TIP",
+                    line: 1,
+                    col: 2,
+                    offset: 1,
+                },
+            },),
+        })
+    );
+
+    assert_eq!(mi.item.content_model(), ContentModel::Simple);
+    assert_eq!(mi.item.raw_context().deref(), "admonition");
+    assert_eq!(mi.item.declared_style().unwrap(), "TIP");
+}
+
+#[test]
+fn lowercase_style_is_not_admonition() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[tip]\nJust a paragraph.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "paragraph");
+}
+
+#[test]
+fn example_block_with_style() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[IMPORTANT]\n====\nabc\n\ndef\n====\n"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::Admonition(AdmonitionBlock {
+            kind: AdmonitionKind::Important,
+            textlabel: Some("Important"),
+            content: None,
+            blocks: &[
+                Block::Simple(SimpleBlock {
+                    content: Content {
+                        original: Span {
+                            data: "abc",
+                            line: 3,
+                            col: 1,
+                            offset: 17,
+                        },
+                        rendered: "abc",
+                    },
+                    source: Span {
+                        data: "abc",
+                        line: 3,
+                        col: 1,
+                        offset: 17,
+                    },
+                    style: SimpleBlockStyle::Paragraph,
+                    title_source: None,
+                    title: None,
+                    anchor: None,
+                    anchor_reftext: None,
+                    attrlist: None,
+                },),
+                Block::Simple(SimpleBlock {
+                    content: Content {
+                        original: Span {
+                            data: "def",
+                            line: 5,
+                            col: 1,
+                            offset: 22,
+                        },
+                        rendered: "def",
+                    },
+                    source: Span {
+                        data: "def",
+                        line: 5,
+                        col: 1,
+                        offset: 22,
+                    },
+                    style: SimpleBlockStyle::Paragraph,
+                    title_source: None,
+                    title: None,
+                    anchor: None,
+                    anchor_reftext: None,
+                    attrlist: None,
+                },),
+            ],
+            source: Span {
+                data: "[IMPORTANT]\n====\nabc\n\ndef\n====",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: Some(Attrlist {
+                attributes: &[ElementAttribute {
+                    name: None,
+                    shorthand_items: &["IMPORTANT"],
+                    value: "IMPORTANT"
+                },],
+                anchor: None,
+                source: Span {
+                    data: "IMPORTANT",
+                    line: 1,
+                    col: 2,
+                    offset: 1,
+                },
+            },),
+        })
+    );
+
+    assert_eq!(mi.item.content_model(), ContentModel::Compound);
+    assert_eq!(mi.item.raw_context().deref(), "admonition");
+    assert!(mi.item.rendered_content().is_none());
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    assert_eq!(
+        mi.after,
+        Span {
+            data: "",
+            line: 7,
+            col: 1,
+            offset: 31,
+        }
+    );
+}
+
+#[test]
+fn open_block_with_style() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[CAUTION]\n--\nabc\n--\n");
+
+    let block = doc.nested_blocks().next().unwrap();
+    let crate::blocks::Block::Admonition(admonition) = block else {
+        panic!("Wrong block type: {block:?}");
+    };
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Caution);
+    assert!(admonition.content().is_none());
+    assert_eq!(block.content_model(), ContentModel::Compound);
+
+    let nested = block.nested_blocks().next().unwrap();
+    assert_eq!(nested.rendered_content().unwrap(), "abc");
+}
+
+#[test]
+fn sidebar_with_style_is_not_admonition() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[NOTE]\n****\nabc\n****\n");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "sidebar");
+    assert_eq!(block.declared_style().unwrap(), "NOTE");
+}
+
+#[test]
+fn caption_attribute_overrides_textlabel() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[WARNING,caption=Danger]\nDon't do that.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    let crate::blocks::Block::Admonition(admonition) = block else {
+        panic!("Wrong block type: {block:?}");
+    };
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Warning);
+    assert_eq!(admonition.textlabel().unwrap(), "Danger");
+}
+
+#[test]
+fn textlabel_from_document_attribute() {
+    let mut parser = Parser::default();
+    let doc = parser.parse(":tip-caption: Hint\n\nTIP: Be kind.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    let crate::blocks::Block::Admonition(admonition) = block else {
+        panic!("Wrong block type: {block:?}");
+    };
+
+    assert_eq!(admonition.textlabel().unwrap(), "Hint");
+}
+
+#[test]
+fn textlabel_unset() {
+    let mut parser = Parser::default();
+    let doc = parser.parse(":!note-caption:\n\nNOTE: Be kind.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    let crate::blocks::Block::Admonition(admonition) = block else {
+        panic!("Wrong block type: {block:?}");
+    };
+
+    assert!(admonition.textlabel().is_none());
+}
+
+#[test]
+fn with_title_and_id() {
+    let mut parser = Parser::default();
+    let doc = parser.parse(".Heads up\n[#heads-up]\nWARNING: Slippery when wet.");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "admonition");
+    assert_eq!(block.title().unwrap(), "Heads up");
+    assert_eq!(block.id().unwrap(), "heads-up");
+    assert_eq!(block.rendered_content().unwrap(), "Slippery when wet.");
+
+    assert!(doc.catalog().contains_id("heads-up"));
+}
+
+#[test]
+fn err_unterminated_example_block() {
+    let mut parser = Parser::default();
+    let doc = parser.parse("[NOTE]\n====\nabc\n");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "admonition");
+    assert_eq!(block.nested_blocks().len(), 1);
+
+    let mut warnings = doc.warnings();
+
+    assert_eq!(
+        warnings.next().unwrap().warning,
+        WarningType::UnterminatedDelimitedBlock,
+    );
+
+    assert!(warnings.next().is_none());
+}
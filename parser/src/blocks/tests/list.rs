@@ -0,0 +1,1383 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    HasSpan, Parser,
+    blocks::{
+        ContentModel, DescriptionListStyle, IsBlock, ListType, NumberingStyle, SimpleBlockStyle,
+    },
+    content::SubstitutionGroup,
+    tests::prelude::*,
+    warnings::WarningType,
+};
+
+#[test]
+fn basic_unordered_list() {
+    let mut parser = Parser::default();
+
+    let mi =
+        crate::blocks::Block::parse(crate::Span::new("* one\n* two\n\n\n* three"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Unordered,
+            items: &[
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "one",
+                            line: 1,
+                            col: 3,
+                            offset: 2,
+                        },
+                        rendered: "one",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* one",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 2,
+                        col: 1,
+                        offset: 6,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "two",
+                            line: 2,
+                            col: 3,
+                            offset: 8,
+                        },
+                        rendered: "two",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* two",
+                        line: 2,
+                        col: 1,
+                        offset: 6,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 5,
+                        col: 1,
+                        offset: 14,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "three",
+                            line: 5,
+                            col: 3,
+                            offset: 16,
+                        },
+                        rendered: "three",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* three",
+                        line: 5,
+                        col: 1,
+                        offset: 14,
+                    },
+                }),
+            ],
+            source: Span {
+                data: "* one\n* two\n\n\n* three",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert_eq!(mi.item.content_model(), ContentModel::Compound);
+    assert!(mi.item.rendered_content().is_none());
+    assert_eq!(mi.item.raw_context().deref(), "ulist");
+    assert_eq!(mi.item.nested_blocks().len(), 3);
+    assert!(mi.item.title_source().is_none());
+    assert!(mi.item.title().is_none());
+    assert!(mi.item.anchor().is_none());
+    assert!(mi.item.anchor_reftext().is_none());
+    assert!(mi.item.attrlist().is_none());
+    assert_eq!(mi.item.substitution_group(), SubstitutionGroup::Normal);
+
+    assert_eq!(
+        mi.after,
+        Span {
+            data: "",
+            line: 5,
+            col: 8,
+            offset: 21,
+        }
+    );
+}
+
+#[test]
+fn with_title() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(".Kizmet's Favorite Authors\n- Edgar Allan Poe\n- Bill Bryson"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Unordered,
+            items: &[
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "-",
+                        line: 2,
+                        col: 1,
+                        offset: 27,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "Edgar Allan Poe",
+                            line: 2,
+                            col: 3,
+                            offset: 29,
+                        },
+                        rendered: "Edgar Allan Poe",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "- Edgar Allan Poe",
+                        line: 2,
+                        col: 1,
+                        offset: 27,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "-",
+                        line: 3,
+                        col: 1,
+                        offset: 45,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "Bill Bryson",
+                            line: 3,
+                            col: 3,
+                            offset: 47,
+                        },
+                        rendered: "Bill Bryson",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "- Bill Bryson",
+                        line: 3,
+                        col: 1,
+                        offset: 45,
+                    },
+                }),
+            ],
+            source: Span {
+                data: ".Kizmet's Favorite Authors\n- Edgar Allan Poe\n- Bill Bryson",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: Some(Span {
+                data: "Kizmet's Favorite Authors",
+                line: 1,
+                col: 2,
+                offset: 1,
+            }),
+            title: Some("Kizmet&#8217;s Favorite Authors"),
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert_eq!(mi.item.title().unwrap(), "Kizmet&#8217;s Favorite Authors");
+}
+
+#[test]
+fn nested_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("* one\n** two\n* three"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Unordered,
+            items: &[
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "one",
+                            line: 1,
+                            col: 3,
+                            offset: 2,
+                        },
+                        rendered: "one",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[Block::List(ListBlock {
+                        type_: ListType::Unordered,
+                        items: &[Block::ListItem(ListItem {
+                            marker: Span {
+                                data: "**",
+                                line: 2,
+                                col: 1,
+                                offset: 6,
+                            },
+                            principal_text: Content {
+                                original: Span {
+                                    data: "two",
+                                    line: 2,
+                                    col: 4,
+                                    offset: 9,
+                                },
+                                rendered: "two",
+                            },
+                            checked: None,
+                            callout_ids: &[],
+                            blocks: &[],
+                            source: Span {
+                                data: "** two",
+                                line: 2,
+                                col: 1,
+                                offset: 6,
+                            },
+                        })],
+                        source: Span {
+                            data: "** two",
+                            line: 2,
+                            col: 1,
+                            offset: 6,
+                        },
+                        title_source: None,
+                        title: None,
+                        anchor: None,
+                        anchor_reftext: None,
+                        attrlist: None,
+                    })],
+                    source: Span {
+                        data: "* one\n** two",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 3,
+                        col: 1,
+                        offset: 13,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "three",
+                            line: 3,
+                            col: 3,
+                            offset: 15,
+                        },
+                        rendered: "three",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* three",
+                        line: 3,
+                        col: 1,
+                        offset: 13,
+                    },
+                }),
+            ],
+            source: Span {
+                data: "* one\n** two\n* three",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+}
+
+#[test]
+fn nested_list_with_attrlist() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* squares\n[circle]\n** circles"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let item = mi.item.nested_blocks().next().unwrap();
+    let nested = item.nested_blocks().next().unwrap();
+
+    assert_eq!(nested.raw_context().deref(), "ulist");
+    assert_eq!(nested.declared_style().unwrap(), "circle");
+
+    assert_eq!(
+        nested.span(),
+        Span {
+            data: "[circle]\n** circles",
+            line: 2,
+            col: 1,
+            offset: 10,
+        }
+    );
+}
+
+#[test]
+fn hyphen_as_second_level() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("* one\n- two\n* three"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    let mut items = mi.item.nested_blocks();
+
+    let one = items.next().unwrap();
+    let nested = one.nested_blocks().next().unwrap();
+    let two = nested.nested_blocks().next().unwrap();
+    assert_eq!(two.rendered_content().unwrap(), "two");
+
+    let three = items.next().unwrap();
+    assert_eq!(three.rendered_content().unwrap(), "three");
+
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn separated_by_line_comment() {
+    let doc = Parser::default().parse("* Apples\n* Oranges\n\n//-\n\n* Walnuts\n* Almonds");
+
+    let lists: Vec<_> = doc
+        .nested_blocks()
+        .filter(|b| b.raw_context().deref() == "ulist")
+        .collect();
+
+    assert_eq!(lists.len(), 2);
+    assert_eq!(lists[0].nested_blocks().len(), 2);
+    assert_eq!(lists[1].nested_blocks().len(), 2);
+}
+
+#[test]
+fn separated_by_block_attribute_line() {
+    let doc = Parser::default().parse("* Apples\n* Oranges\n\n[]\n- Wash\n- Slice");
+
+    let mut blocks = doc.nested_blocks();
+
+    let first = blocks.next().unwrap();
+    assert_eq!(first.nested_blocks().len(), 2);
+
+    assert!(
+        first
+            .nested_blocks()
+            .all(|item| item.nested_blocks().next().is_none())
+    );
+
+    let second = blocks.next().unwrap();
+    assert_eq!(second.raw_context().deref(), "ulist");
+    assert_eq!(second.nested_blocks().len(), 2);
+
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn registers_id() {
+    let doc = Parser::default().parse("[#goals]\n* Goal 1\n* Goal 2");
+
+    let list = doc.nested_blocks().next().unwrap();
+    assert_eq!(list.id().unwrap(), "goals");
+    assert!(doc.catalog().contains_id("goals"));
+}
+
+#[test]
+fn literal_style_is_not_a_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("[literal]\n* not a list"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    assert_eq!(mi.item.raw_context().deref(), "paragraph");
+}
+
+#[test]
+fn indented_marker_is_not_a_literal_paragraph() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("  * one\n * two"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    assert_eq!(mi.item.raw_context().deref(), "ulist");
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+}
+
+#[test]
+fn dot_marker_is_ordered_list_not_title() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new(". abc\ndef"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Ordered,
+            items: &[Block::ListItem(ListItem {
+                marker: Span {
+                    data: ".",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                principal_text: Content {
+                    original: Span {
+                        data: "abc\ndef",
+                        line: 1,
+                        col: 3,
+                        offset: 2,
+                    },
+                    rendered: "abc\ndef",
+                },
+                checked: None,
+                callout_ids: &[],
+                blocks: &[],
+                source: Span {
+                    data: ". abc\ndef",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+            })],
+            source: Span {
+                data: ". abc\ndef",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert_eq!(mi.item.raw_context().deref(), "olist");
+    assert!(mi.item.title().is_none());
+}
+
+#[test]
+fn nested_ordered_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(". Step 1\n. Step 2\n.. Step 2a\n.. Step 2b\n. Step 3"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let crate::blocks::Block::List(list) = &mi.item else {
+        panic!("Unexpected block: {:#?}", mi.item);
+    };
+
+    assert_eq!(list.type_(), ListType::Ordered);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::Arabic);
+    assert!(list.start().is_none());
+    assert!(!list.is_reversed());
+    assert_eq!(list.nested_blocks().len(), 3);
+
+    let step2 = list.nested_blocks().nth(1).unwrap();
+    let crate::blocks::Block::List(nested) = step2.nested_blocks().next().unwrap() else {
+        panic!("Unexpected block: {step2:#?}");
+    };
+
+    assert_eq!(
+        nested.numbering_style().unwrap(),
+        NumberingStyle::LowerAlpha
+    );
+    assert_eq!(nested.nested_blocks().len(), 2);
+}
+
+#[test]
+fn ordered_list_nested_in_unordered_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* Linux\n. Fedora\n. Ubuntu\n* BSD"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.raw_context().deref(), "ulist");
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let linux = mi.item.nested_blocks().next().unwrap();
+    let distros = linux.nested_blocks().next().unwrap();
+    assert_eq!(distros.raw_context().deref(), "olist");
+    assert_eq!(distros.nested_blocks().len(), 2);
+}
+
+#[test]
+fn explicit_numbering() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("4. Step four\n5. Step five\n6. Step six"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let crate::blocks::Block::List(list) = &mi.item else {
+        panic!("Unexpected block: {:#?}", mi.item);
+    };
+
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::Arabic);
+    assert_eq!(list.start().unwrap(), 4);
+    assert_eq!(list.nested_blocks().len(), 3);
+}
+
+#[test]
+fn explicit_numbering_styles() {
+    let mut parser = Parser::default();
+
+    for (source, style) in [
+        ("a. one\nb. two", NumberingStyle::LowerAlpha),
+        ("A. one\nB. two", NumberingStyle::UpperAlpha),
+        ("i) one\nii) two", NumberingStyle::LowerRoman),
+        ("I) one\nII) two", NumberingStyle::UpperRoman),
+    ] {
+        let mi = crate::blocks::Block::parse(crate::Span::new(source), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let crate::blocks::Block::List(list) = &mi.item else {
+            panic!("Unexpected block: {:#?}", mi.item);
+        };
+
+        assert_eq!(list.numbering_style().unwrap(), style);
+        assert!(list.start().is_none());
+        assert_eq!(list.nested_blocks().len(), 2);
+    }
+}
+
+#[test]
+fn explicit_markers_of_different_styles_are_nested() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("1. one\na. one-a\nb. one-b\n2. two"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let one = mi.item.nested_blocks().next().unwrap();
+    let nested = one.nested_blocks().next().unwrap();
+    assert_eq!(nested.nested_blocks().len(), 2);
+}
+
+#[test]
+fn out_of_sequence_warning() {
+    let mut parser = Parser::default();
+
+    let maw =
+        crate::blocks::Block::parse(crate::Span::new("i) one\nii) two\nv) five"), &mut parser);
+
+    let mi = maw.item.unwrap();
+    assert_eq!(mi.item.nested_blocks().len(), 3);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "v)",
+                line: 3,
+                col: 1,
+                offset: 15,
+            },
+            warning: WarningType::ListItemOutOfSequence("iii".to_owned(), "v".to_owned()),
+        }]
+    );
+}
+
+#[test]
+fn out_of_sequence_check_at_maximum_ordinal() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(crate::Span::new("4294967295. a\n1. b"), &mut parser);
+
+    let mi = maw.item.unwrap();
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+    assert!(maw.warnings.is_empty());
+}
+
+#[test]
+fn implicit_numbering_does_not_warn() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(crate::Span::new(". one\n. two\n. three"), &mut parser);
+
+    assert!(maw.warnings.is_empty());
+}
+
+#[test]
+fn numbering_style_and_start_from_attrlist() {
+    let doc = Parser::default().parse("[upperroman%reversed,start=4]\n. four\n. three");
+
+    let crate::blocks::Block::List(list) = doc.nested_blocks().next().unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::UpperRoman);
+    assert_eq!(list.start().unwrap(), 4);
+    assert!(list.is_reversed());
+}
+
+#[test]
+fn unordered_list_has_no_numbering() {
+    let doc = Parser::default().parse("[%reversed,start=4]\n* one\n* two");
+
+    let crate::blocks::Block::List(list) = doc.nested_blocks().next().unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert!(list.numbering_style().is_none());
+    assert!(list.start().is_none());
+    assert!(!list.is_reversed());
+}
+
+#[test]
+fn not_an_explicit_marker() {
+    let mut parser = Parser::default();
+
+    for source in [
+        "ab. not a list",
+        "iv. not a list",
+        "1) not a list",
+        "a) not",
+    ] {
+        let mi = crate::blocks::Block::parse(crate::Span::new(source), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        assert_eq!(mi.item.raw_context().deref(), "paragraph", "{source:?}");
+    }
+}
+
+#[test]
+fn description_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("CPU:: The brain\nof the computer.\nRAM::\nTemporary storage."),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Description,
+            items: &[
+                Block::DescriptionListItem(DescriptionListItem {
+                    terms: &[Content {
+                        original: Span {
+                            data: "CPU",
+                            line: 1,
+                            col: 1,
+                            offset: 0,
+                        },
+                        rendered: "CPU",
+                    }],
+                    marker: Span {
+                        data: "::",
+                        line: 1,
+                        col: 4,
+                        offset: 3,
+                    },
+                    principal_text: Some(Content {
+                        original: Span {
+                            data: "The brain\nof the computer.",
+                            line: 1,
+                            col: 7,
+                            offset: 6,
+                        },
+                        rendered: "The brain\nof the computer.",
+                    }),
+                    blocks: &[],
+                    source: Span {
+                        data: "CPU:: The brain\nof the computer.",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }),
+                Block::DescriptionListItem(DescriptionListItem {
+                    terms: &[Content {
+                        original: Span {
+                            data: "RAM",
+                            line: 3,
+                            col: 1,
+                            offset: 33,
+                        },
+                        rendered: "RAM",
+                    }],
+                    marker: Span {
+                        data: "::",
+                        line: 3,
+                        col: 4,
+                        offset: 36,
+                    },
+                    principal_text: Some(Content {
+                        original: Span {
+                            data: "Temporary storage.",
+                            line: 4,
+                            col: 1,
+                            offset: 39,
+                        },
+                        rendered: "Temporary storage.",
+                    }),
+                    blocks: &[],
+                    source: Span {
+                        data: "RAM::\nTemporary storage.",
+                        line: 3,
+                        col: 1,
+                        offset: 33,
+                    },
+                }),
+            ],
+            source: Span {
+                data: "CPU:: The brain\nof the computer.\nRAM::\nTemporary storage.",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert_eq!(mi.item.raw_context().deref(), "dlist");
+}
+
+#[test]
+fn description_list_with_nested_unordered_list() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("Dairy::\n* Milk\n* Eggs\nBakery::\n* Bread"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let dairy = mi.item.nested_blocks().next().unwrap();
+    assert!(dairy.rendered_content().is_none());
+
+    let groceries = dairy.nested_blocks().next().unwrap();
+    assert_eq!(groceries.raw_context().deref(), "ulist");
+    assert_eq!(groceries.nested_blocks().len(), 2);
+}
+
+#[test]
+fn term_interrupts_principal_text() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* Fruit\nApple:: red\n* Vegetable"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let fruit = mi.item.nested_blocks().next().unwrap();
+    assert_eq!(fruit.rendered_content().unwrap(), "Fruit");
+
+    let nested = fruit.nested_blocks().next().unwrap();
+    assert_eq!(nested.raw_context().deref(), "dlist");
+}
+
+#[test]
+fn term_does_not_interrupt_paragraph() {
+    let doc = Parser::default().parse("Some text\nApple:: red");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "paragraph");
+}
+
+#[test]
+fn description_list_styles() {
+    for (source, style) in [
+        ("A:: a", DescriptionListStyle::Default),
+        ("[horizontal]\nA:: a", DescriptionListStyle::Horizontal),
+        ("[qanda]\nA:: a", DescriptionListStyle::Qanda),
+        ("[glossary]\nA:: a", DescriptionListStyle::Default),
+    ] {
+        let doc = Parser::default().parse(source);
+
+        let crate::blocks::Block::List(list) = doc.nested_blocks().next().unwrap() else {
+            panic!("Unexpected block");
+        };
+
+        assert_eq!(list.description_list_style().unwrap(), style);
+    }
+
+    let doc = Parser::default().parse("[horizontal]\n* a");
+
+    let crate::blocks::Block::List(list) = doc.nested_blocks().next().unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert!(list.description_list_style().is_none());
+}
+
+#[test]
+fn list_continuation() {
+    let mut parser = Parser::default();
+
+    let mi =
+        crate::blocks::Block::parse(crate::Span::new("* one\n+\nattached\n* two"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Unordered,
+            items: &[
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "one",
+                            line: 1,
+                            col: 3,
+                            offset: 2,
+                        },
+                        rendered: "one",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[Block::Simple(SimpleBlock {
+                        content: Content {
+                            original: Span {
+                                data: "attached",
+                                line: 3,
+                                col: 1,
+                                offset: 8,
+                            },
+                            rendered: "attached",
+                        },
+                        source: Span {
+                            data: "attached",
+                            line: 3,
+                            col: 1,
+                            offset: 8,
+                        },
+                        style: SimpleBlockStyle::Paragraph,
+                        title_source: None,
+                        title: None,
+                        anchor: None,
+                        anchor_reftext: None,
+                        attrlist: None,
+                    })],
+                    source: Span {
+                        data: "* one\n+\nattached",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 4,
+                        col: 1,
+                        offset: 17,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "two",
+                            line: 4,
+                            col: 3,
+                            offset: 19,
+                        },
+                        rendered: "two",
+                    },
+                    checked: None,
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* two",
+                        line: 4,
+                        col: 1,
+                        offset: 17,
+                    },
+                }),
+            ],
+            source: Span {
+                data: "* one\n+\nattached\n* two",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+}
+
+#[test]
+fn list_continuation_chain() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* one\n+\n----\nlisting\n----\n+\nparagraph\n\n* two"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let one = mi.item.nested_blocks().next().unwrap();
+    let mut attached = one.nested_blocks();
+    assert_eq!(attached.next().unwrap().raw_context().deref(), "listing");
+    assert_eq!(attached.next().unwrap().raw_context().deref(), "paragraph");
+    assert!(attached.next().is_none());
+}
+
+#[test]
+fn attached_open_block() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* one\n+\n--\nfirst\n\nsecond\n* not an item\n--\n* two"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let one = mi.item.nested_blocks().next().unwrap();
+    let open = one.nested_blocks().next().unwrap();
+    assert_eq!(open.raw_context().deref(), "open");
+
+    let mut blocks = open.nested_blocks();
+    assert_eq!(blocks.next().unwrap().rendered_content().unwrap(), "first");
+
+    assert_eq!(
+        blocks.next().unwrap().rendered_content().unwrap(),
+        "second\n* not an item"
+    );
+
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn sibling_item_interrupts_attached_paragraph() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* one\n+\nattached\n** nested\n* two"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    // Only a sibling list item interrupts the attached paragraph.
+    let one = mi.item.nested_blocks().next().unwrap();
+    let paragraph = one.nested_blocks().next().unwrap();
+
+    assert_eq!(paragraph.rendered_content().unwrap(), "attached\n** nested");
+}
+
+#[test]
+fn ancestor_list_continuation() {
+    let mut parser = Parser::default();
+
+    // Each empty line before a list continuation moves up one level from the most
+    // recently parsed list item. Once `parent` is attached to `two`, a single empty
+    // line is enough to attach `grandparent` to `one`.
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("* one\n** two\n*** three\n\n+\nparent\n\n+\ngrandparent"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let one = mi.item.nested_blocks().next().unwrap();
+    let mut one_blocks = one.nested_blocks();
+
+    let two_list = one_blocks.next().unwrap();
+    assert_eq!(
+        one_blocks.next().unwrap().rendered_content().unwrap(),
+        "grandparent"
+    );
+    assert!(one_blocks.next().is_none());
+
+    let two = two_list.nested_blocks().next().unwrap();
+    let mut two_blocks = two.nested_blocks();
+    assert_eq!(two_blocks.next().unwrap().raw_context().deref(), "ulist");
+
+    assert_eq!(
+        two_blocks.next().unwrap().rendered_content().unwrap(),
+        "parent"
+    );
+
+    assert!(two_blocks.next().is_none());
+}
+
+#[test]
+fn continuation_after_empty_line_without_nested_list() {
+    let doc = Parser::default().parse("* one\n\n+\nnot attached");
+
+    let mut blocks = doc.nested_blocks();
+
+    let list = blocks.next().unwrap();
+    let one = list.nested_blocks().next().unwrap();
+    assert!(one.nested_blocks().next().is_none());
+
+    let paragraph = blocks.next().unwrap();
+    assert_eq!(paragraph.rendered_content().unwrap(), "+\nnot attached");
+
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn description_list_continuation() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("Term::\n+\nattached\nOther:: text"),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.nested_blocks().len(), 2);
+
+    let term = mi.item.nested_blocks().next().unwrap();
+    assert!(term.rendered_content().is_none());
+
+    assert_eq!(
+        term.nested_blocks()
+            .next()
+            .unwrap()
+            .rendered_content()
+            .unwrap(),
+        "attached"
+    );
+}
+
+#[test]
+fn checklist() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("* [x] done\n* [ ] to do"), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::List(ListBlock {
+            type_: ListType::Unordered,
+            items: &[
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "done",
+                            line: 1,
+                            col: 7,
+                            offset: 6,
+                        },
+                        rendered: "done",
+                    },
+                    checked: Some(true),
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* [x] done",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }),
+                Block::ListItem(ListItem {
+                    marker: Span {
+                        data: "*",
+                        line: 2,
+                        col: 1,
+                        offset: 11,
+                    },
+                    principal_text: Content {
+                        original: Span {
+                            data: "to do",
+                            line: 2,
+                            col: 7,
+                            offset: 17,
+                        },
+                        rendered: "to do",
+                    },
+                    checked: Some(false),
+                    callout_ids: &[],
+                    blocks: &[],
+                    source: Span {
+                        data: "* [ ] to do",
+                        line: 2,
+                        col: 1,
+                        offset: 11,
+                    },
+                }),
+            ],
+            source: Span {
+                data: "* [x] done\n* [ ] to do",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert!(mi.item.has_option("checklist"));
+    assert_eq!(mi.item.options(), vec!["checklist"]);
+}
+
+#[test]
+fn checklist_with_declared_options() {
+    let doc = Parser::default().parse("[%interactive]\n* [*] checked\n* normal list item");
+
+    let list = doc.nested_blocks().next().unwrap();
+    assert_eq!(list.options(), vec!["interactive", "checklist"]);
+    assert!(list.has_option("interactive"));
+    assert!(list.has_option("checklist"));
+
+    // An explicitly declared `checklist` option is not repeated.
+    let doc = Parser::default().parse("[%checklist]\n* [*] checked");
+
+    let list = doc.nested_blocks().next().unwrap();
+    assert_eq!(list.options(), vec!["checklist"]);
+
+    // Only a list with at least one checklist item is a checklist.
+    let doc = Parser::default().parse("[%interactive]\n* normal list item");
+
+    let list = doc.nested_blocks().next().unwrap();
+    assert_eq!(list.options(), vec!["interactive"]);
+    assert!(!list.has_option("checklist"));
+}
+
+#[test]
+fn nested_checklist() {
+    let doc = Parser::default().parse("* parent\n** [x] child");
+
+    let list = doc.nested_blocks().next().unwrap();
+    assert!(!list.has_option("checklist"));
+
+    let parent = list.nested_blocks().next().unwrap();
+    let nested = parent.nested_blocks().next().unwrap();
+    assert!(nested.has_option("checklist"));
+}
+
+#[test]
+fn callout_list() {
+    let doc = Parser::default().parse(
+        "----\nrequire 'sinatra' // <1>\n\nget '/hi' do <2> <3>\n  \"Hello World!\" <3>\nend\n----\n<1> Library import\n<2> URL mapping\n<3> Response block",
+    );
+
+    assert_eq!(doc.warnings().count(), 0);
+
+    let mut blocks = doc.nested_blocks();
+
+    let listing = blocks.next().unwrap();
+    assert_eq!(
+        listing.rendered_content().unwrap(),
+        "require 'sinatra' // <b class=\"conum\">(1)</b>\n\nget '/hi' do <b class=\"conum\">(2)</b> <b class=\"conum\">(3)</b>\n  \"Hello World!\" <b class=\"conum\">(3)</b>\nend"
+    );
+
+    let crate::blocks::Block::List(colist) = blocks.next().unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert_eq!(colist.type_(), ListType::Callout);
+    assert_eq!(colist.raw_context().deref(), "colist");
+    assert!(colist.numbering_style().is_none());
+
+    let callout_ids: Vec<Vec<&str>> = colist
+        .nested_blocks()
+        .map(|item| {
+            let crate::blocks::Block::ListItem(item) = item else {
+                panic!("Unexpected block");
+            };
+            item.callout_ids().iter().map(|id| id.as_str()).collect()
+        })
+        .collect();
+
+    assert_eq!(
+        callout_ids,
+        vec![vec!["CO1-1"], vec!["CO1-2"], vec!["CO1-3", "CO1-4"]]
+    );
+}
+
+#[test]
+fn auto_numbered_callout_list() {
+    let doc = Parser::default().parse("....\nfirst <.>\nsecond <.>\n....\n<.> One\n<.> Two");
+
+    assert_eq!(doc.warnings().count(), 0);
+
+    let colist = doc.nested_blocks().nth(1).unwrap();
+    assert_eq!(colist.raw_context().deref(), "colist");
+    assert_eq!(colist.nested_blocks().len(), 2);
+
+    let crate::blocks::Block::ListItem(item) = colist.nested_blocks().nth(1).unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert_eq!(item.callout_ids(), &["CO1-2".to_owned()]);
+}
+
+#[test]
+fn each_callout_list_starts_a_new_list_of_callouts() {
+    let doc = Parser::default()
+        .parse("----\nfirst <1>\n----\n<1> One\n\n----\nsecond <1>\n----\n<1> Two");
+
+    assert_eq!(doc.warnings().count(), 0);
+
+    let crate::blocks::Block::List(colist) = doc.nested_blocks().nth(3).unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    let crate::blocks::Block::ListItem(item) = colist.nested_blocks().next().unwrap() else {
+        panic!("Unexpected block");
+    };
+
+    assert_eq!(item.callout_ids(), &["CO2-1".to_owned()]);
+}
+
+#[test]
+fn callout_list_warnings() {
+    let doc = Parser::default().parse("----\nfirst <1>\nsecond \\<2>\n----\n<1> One\n<3> Three");
+
+    let listing = doc.nested_blocks().next().unwrap();
+    assert_eq!(
+        listing.rendered_content().unwrap(),
+        "first <b class=\"conum\">(1)</b>\nsecond &lt;2&gt;"
+    );
+
+    let warnings: Vec<WarningType> = doc.warnings().map(|w| w.warning.clone()).collect();
+
+    assert_eq!(
+        warnings,
+        vec![
+            WarningType::ListItemOutOfSequence("2".to_owned(), "3".to_owned()),
+            WarningType::NoCalloutFound(2),
+        ]
+    );
+}
+
+#[test]
+fn not_a_callout_list_marker() {
+    let mut parser = Parser::default();
+
+    for source in ["<a> not a list", "<> not a list", "<1>not a list"] {
+        let mi = crate::blocks::Block::parse(crate::Span::new(source), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        assert_eq!(mi.item.raw_context().deref(), "paragraph", "{source:?}");
+    }
+}
@@ -5,12 +5,15 @@
 #![allow(clippy::panic)]
 #![allow(clippy::unwrap_used)]
 
+mod admonition;
 mod r#break;
 mod compound_delimited;
+mod list;
 mod media;
 mod raw_delimited;
 mod section;
 mod simple;
+mod table;
 
 mod content_model {
     use pretty_assertions_sorted::assert_eq;
@@ -27,7 +30,11 @@ mod content_model {
 }
 
 mod impl_debug {
-    use crate::{Parser, blocks::Block, span::Span};
+    use crate::{
+        Parser,
+        blocks::{Block, IsBlock},
+        span::Span,
+    };
 
     #[test]
     fn simple() {
@@ -84,6 +91,65 @@ mod impl_debug {
         assert!(debug_output.starts_with("Block::CompoundDelimited"));
     }
 
+    #[test]
+    fn admonition() {
+        let mut parser = Parser::default();
+        let mi = Block::parse(Span::new("NOTE: Pay attention."), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let debug_output = format!("{:?}", mi.item);
+        assert!(debug_output.starts_with("Block::Admonition"));
+    }
+
+    #[test]
+    fn table() {
+        let mut parser = Parser::default();
+        let mi = Block::parse(Span::new("|===\n|Cell\n|==="), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let debug_output = format!("{:?}", mi.item);
+        assert!(debug_output.starts_with("Block::Table"));
+    }
+
+    #[test]
+    fn list() {
+        let mut parser = Parser::default();
+        let mi = Block::parse(Span::new("* List item"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let debug_output = format!("{:?}", mi.item);
+        assert!(debug_output.starts_with("Block::List("));
+    }
+
+    #[test]
+    fn list_item() {
+        let mut parser = Parser::default();
+        let mi = Block::parse(Span::new("* List item"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let item = mi.item.nested_blocks().next().unwrap();
+
+        let debug_output = format!("{:?}", item);
+        assert!(debug_output.starts_with("Block::ListItem"));
+    }
+
+    #[test]
+    fn description_list_item() {
+        let mut parser = Parser::default();
+        let mi = Block::parse(Span::new("Term:: Description"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let item = mi.item.nested_blocks().next().unwrap();
+
+        let debug_output = format!("{:?}", item);
+        assert!(debug_output.starts_with("Block::DescriptionListItem"));
+    }
+
     #[test]
     fn document_attribute() {
         let mut parser = Parser::default();
@@ -107,6 +173,33 @@ mod impl_debug {
     }
 }
 
+mod document_attribute {
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        Parser,
+        blocks::{Block, IsBlock},
+    };
+
+    #[test]
+    fn consecutive_entries_separated_by_blank_line() {
+        let mut parser = Parser::default();
+        let doc = parser.parse("Before.\n\n:a: 1\n\n:b: 2\n\nAfter.");
+
+        let kinds: Vec<&str> = doc
+            .nested_blocks()
+            .map(|block| match block {
+                Block::DocumentAttribute(_) => "attribute",
+                Block::Simple(_) => "simple",
+                _ => "other",
+            })
+            .collect();
+
+        assert_eq!(kinds, vec!["simple", "attribute", "attribute", "simple"]);
+        assert!(doc.warnings().next().is_none());
+    }
+}
+
 mod error_cases {
     use std::ops::Deref;
 
@@ -116,7 +209,6 @@ mod error_cases {
         Parser,
         blocks::{ContentModel, IsBlock, SimpleBlockStyle, metadata::BlockMetadata},
         content::SubstitutionGroup,
-        span::HasSpan,
         tests::prelude::*,
         warnings::{MatchAndWarnings, WarningType},
     };
@@ -472,54 +564,6 @@ mod error_cases {
         );
     }
 
-    #[test]
-    #[allow(non_snake_case)]
-    fn TEMP_not_title() {
-        // IMPORTANT: This test will fail once we implement support for list items.
-        let mut parser = Parser::default();
-
-        let mi = crate::blocks::Block::parse(crate::Span::new(". abc\ndef"), &mut parser)
-            .unwrap_if_no_warnings()
-            .unwrap();
-
-        assert_eq!(
-            mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: ". abc\ndef",
-                        line: 1,
-                        col: 1,
-                        offset: 0,
-                    },
-                    rendered: ". abc\ndef",
-                },
-                source: Span {
-                    data: ". abc\ndef",
-                    line: 1,
-                    col: 1,
-                    offset: 0,
-                },
-                style: SimpleBlockStyle::Paragraph,
-                title_source: None,
-                title: None,
-                anchor: None,
-                anchor_reftext: None,
-                attrlist: None,
-            })
-        );
-
-        assert_eq!(
-            mi.item.span(),
-            Span {
-                data: ". abc\ndef",
-                line: 1,
-                col: 1,
-                offset: 0,
-            }
-        );
-    }
-
     #[test]
     fn duplicate_id_warning() {
         let mut parser = Parser::default();
@@ -186,6 +186,7 @@ mod parse {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },)
         );
 
@@ -248,6 +249,7 @@ mod comment {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::None,
+                stem_notation: None,
             })
         );
 
@@ -309,6 +311,7 @@ mod comment {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::None,
+                stem_notation: None,
             })
         );
 
@@ -377,6 +380,7 @@ mod comment {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::None,
+                stem_notation: None,
             })
         );
 
@@ -433,6 +437,7 @@ mod comment {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::None,
+                stem_notation: None,
             })
         );
 
@@ -498,6 +503,7 @@ mod listing {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             })
         );
 
@@ -554,6 +560,7 @@ mod listing {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             })
         );
 
@@ -626,6 +633,7 @@ mod listing {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             })
         );
 
@@ -705,6 +713,7 @@ mod listing {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             })
         );
 
@@ -734,6 +743,15 @@ mod listing {
             }
         );
     }
+
+    #[test]
+    fn followed_by_whitespace_only_line() {
+        let doc = Parser::default().parse("----\nx\n----\n\t");
+
+        let mut blocks = doc.nested_blocks();
+        assert_eq!(blocks.next().unwrap().raw_context().as_ref(), "listing");
+        assert!(blocks.next().is_none());
+    }
 }
 
 mod pass {
@@ -781,6 +799,7 @@ mod pass {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Pass,
+                stem_notation: None,
             })
         );
 
@@ -848,6 +867,7 @@ mod pass {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Pass,
+                stem_notation: None,
             })
         );
 
@@ -920,6 +940,7 @@ mod pass {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Pass,
+                stem_notation: None,
             })
         );
 
@@ -999,6 +1020,7 @@ mod pass {
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Pass,
+                stem_notation: None,
             })
         );
 
@@ -1029,3 +1051,143 @@ mod pass {
         );
     }
 }
+
+mod stem {
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        Parser,
+        blocks::{Block, ContentModel, IsBlock},
+        content::{StemNotation, SubstitutionGroup, SubstitutionStep},
+        parser::ModificationContext,
+    };
+
+    #[test]
+    fn stem_style() {
+        let mut parser = Parser::default();
+
+        let mi = Block::parse(crate::Span::new("[stem]\n++++\nx < y\n++++"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.content_model(), ContentModel::Raw);
+        assert_eq!(block.rendered_content(), Some("x &lt; y"));
+        assert_eq!(block.raw_context().as_ref(), "stem");
+        assert_eq!(block.resolved_context().as_ref(), "stem");
+        assert_eq!(block.declared_style(), Some("stem"));
+        assert_eq!(block.stem_notation(), Some(StemNotation::AsciiMath));
+
+        assert_eq!(
+            block.substitution_group(),
+            SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters])
+        );
+    }
+
+    #[test]
+    fn stem_style_follows_stem_attribute() {
+        let mut parser = Parser::default().with_intrinsic_attribute(
+            "stem",
+            "latexmath",
+            ModificationContext::Anywhere,
+        );
+
+        let mi = Block::parse(
+            crate::Span::new("[stem]\n++++\n\\sqrt{4} = 2\n++++"),
+            &mut parser,
+        )
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.rendered_content(), Some("\\sqrt{4} = 2"));
+        assert_eq!(block.raw_context().as_ref(), "stem");
+        assert_eq!(block.stem_notation(), Some(StemNotation::LatexMath));
+    }
+
+    #[test]
+    fn latexmath_style() {
+        let mut parser = Parser::default();
+
+        let mi = Block::parse(
+            crate::Span::new("[latexmath]\n++++\n\\sqrt{4} = 2\n++++"),
+            &mut parser,
+        )
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.raw_context().as_ref(), "stem");
+        assert_eq!(block.declared_style(), Some("latexmath"));
+        assert_eq!(block.stem_notation(), Some(StemNotation::LatexMath));
+    }
+
+    #[test]
+    fn asciimath_style() {
+        let mut parser = Parser::default().with_intrinsic_attribute(
+            "stem",
+            "latexmath",
+            ModificationContext::Anywhere,
+        );
+
+        let mi = Block::parse(
+            crate::Span::new("[asciimath]\n++++\nsqrt(4) = 2\n++++"),
+            &mut parser,
+        )
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.raw_context().as_ref(), "stem");
+        assert_eq!(block.stem_notation(), Some(StemNotation::AsciiMath));
+    }
+
+    #[test]
+    fn explicit_subs() {
+        let mut parser = Parser::default();
+
+        let mi = Block::parse(
+            crate::Span::new("[stem,subs=none]\n++++\nx < y\n++++"),
+            &mut parser,
+        )
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.rendered_content(), Some("x < y"));
+        assert_eq!(block.substitution_group(), SubstitutionGroup::None);
+        assert_eq!(block.stem_notation(), Some(StemNotation::AsciiMath));
+    }
+
+    #[test]
+    fn pass_block_has_no_notation() {
+        let mut parser = Parser::default();
+
+        let mi = Block::parse(crate::Span::new("++++\nx < y\n++++"), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+        let Block::RawDelimited(block) = mi.item else {
+            panic!("Expected a raw delimited block, got {:#?}", mi.item);
+        };
+
+        assert_eq!(block.rendered_content(), Some("x < y"));
+        assert_eq!(block.raw_context().as_ref(), "pass");
+        assert!(block.stem_notation().is_none());
+    }
+}
@@ -0,0 +1,1322 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    HasSpan, Parser,
+    blocks::{
+        ColumnWidth, ContentModel, HorizontalAlignment, IsBlock, TableCellStyle, TableColumn,
+        TableFormat, VerticalAlignment,
+    },
+    content::SubstitutionGroup,
+    tests::prelude::*,
+    warnings::WarningType,
+};
+
+const DEFAULT_COLUMN: TableColumn = TableColumn {
+    width: ColumnWidth::Proportional(1),
+    halign: HorizontalAlignment::Left,
+    valign: VerticalAlignment::Top,
+    style: TableCellStyle::Default,
+};
+
+fn only_table<'src>(
+    block: &'src crate::blocks::Block<'src>,
+) -> &'src crate::blocks::TableBlock<'src> {
+    let crate::blocks::Block::Table(table) = block else {
+        panic!("Expected table block; found {block:#?}");
+    };
+    table
+}
+
+fn cell_texts<'src>(row: &'src crate::blocks::TableRow<'src>) -> Vec<&'src str> {
+    row.cells()
+        .iter()
+        .map(|cell| cell.rendered_content().unwrap_or_default())
+        .collect()
+}
+
+#[test]
+fn basic_table_with_implicit_header() {
+    let mut parser = Parser::default();
+
+    let mi =
+        crate::blocks::Block::parse(crate::Span::new("|===\n|a |b\n\n|c |d\n|==="), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+    assert_eq!(
+        mi.item,
+        Block::Table(TableBlock {
+            columns: &[DEFAULT_COLUMN, DEFAULT_COLUMN],
+            format: TableFormat::Psv,
+            header_row: Some(TableRow {
+                cells: &[
+                    TableCell {
+                        content: Some(Content {
+                            original: Span {
+                                data: "a",
+                                line: 2,
+                                col: 2,
+                                offset: 6,
+                            },
+                            rendered: "a",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "|a",
+                            line: 2,
+                            col: 1,
+                            offset: 5,
+                        },
+                        colspan: 1,
+                        rowspan: 1,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                    TableCell {
+                        content: Some(Content {
+                            original: Span {
+                                data: "b",
+                                line: 2,
+                                col: 5,
+                                offset: 9,
+                            },
+                            rendered: "b",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "|b",
+                            line: 2,
+                            col: 4,
+                            offset: 8,
+                        },
+                        colspan: 1,
+                        rowspan: 1,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ],
+            }),
+            body_rows: &[TableRow {
+                cells: &[
+                    TableCell {
+                        content: Some(Content {
+                            original: Span {
+                                data: "c",
+                                line: 4,
+                                col: 2,
+                                offset: 13,
+                            },
+                            rendered: "c",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "|c",
+                            line: 4,
+                            col: 1,
+                            offset: 12,
+                        },
+                        colspan: 1,
+                        rowspan: 1,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                    TableCell {
+                        content: Some(Content {
+                            original: Span {
+                                data: "d",
+                                line: 4,
+                                col: 5,
+                                offset: 16,
+                            },
+                            rendered: "d",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "|d",
+                            line: 4,
+                            col: 4,
+                            offset: 15,
+                        },
+                        colspan: 1,
+                        rowspan: 1,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ],
+            }],
+            footer_row: None,
+            source: Span {
+                data: "|===\n|a |b\n\n|c |d\n|===",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            title_source: None,
+            title: None,
+            anchor: None,
+            anchor_reftext: None,
+            attrlist: None,
+        })
+    );
+
+    assert!(mi.after.is_empty());
+
+    assert_eq!(mi.item.content_model(), ContentModel::Table);
+    assert_eq!(mi.item.raw_context().as_ref(), "table");
+    assert_eq!(mi.item.resolved_context().as_ref(), "table");
+    assert!(mi.item.declared_style().is_none());
+    assert!(mi.item.rendered_content().is_none());
+    assert!(mi.item.nested_blocks().next().is_none());
+    assert!(mi.item.id().is_none());
+    assert!(mi.item.roles().is_empty());
+    assert_eq!(mi.item.options(), vec!["header"]);
+    assert!(mi.item.has_option("header"));
+    assert!(!mi.item.has_option("footer"));
+    assert!(mi.item.title_source().is_none());
+    assert!(mi.item.title().is_none());
+    assert!(mi.item.anchor().is_none());
+    assert!(mi.item.anchor_reftext().is_none());
+    assert!(mi.item.attrlist().is_none());
+    assert_eq!(mi.item.substitution_group(), SubstitutionGroup::Normal);
+
+    let table = only_table(&mi.item);
+    let cell = &table.body_rows()[0].cells()[0];
+
+    assert_eq!(cell.content_model(), ContentModel::Simple);
+    assert_eq!(cell.raw_context().as_ref(), "table_cell");
+    assert_eq!(cell.rendered_content(), Some("c"));
+    assert!(cell.nested_blocks().next().is_none());
+    assert!(cell.title_source().is_none());
+    assert!(cell.title().is_none());
+    assert!(cell.anchor().is_none());
+    assert!(cell.anchor_reftext().is_none());
+    assert!(cell.attrlist().is_none());
+    assert_eq!(cell.substitution_group(), SubstitutionGroup::Normal);
+}
+
+#[test]
+fn no_implicit_header_without_empty_line() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("|===\n|a |b\n|c |d\n|==="), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert!(table.header_row().is_none());
+    assert!(!mi.item.has_option("header"));
+    assert!(mi.item.options().is_empty());
+
+    assert_eq!(table.body_rows().len(), 2);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["c", "d"]);
+}
+
+#[test]
+fn no_implicit_header_when_first_line_has_one_cell_per_line() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=2]\n|===\n|a\n|b\n\n|c\n|d\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert!(table.header_row().is_none());
+    assert_eq!(table.body_rows().len(), 2);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["c", "d"]);
+}
+
+#[test]
+fn noheader_option_suppresses_implicit_header() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[%noheader]\n|===\n|a |b\n\n|c |d\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert!(table.header_row().is_none());
+    assert_eq!(table.body_rows().len(), 2);
+}
+
+#[test]
+fn header_and_footer_options() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[%header%footer,cols=\"1,1\"]\n|===\n|h1 |h2\n|b1 |b2\n|f1 |f2\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(cell_texts(table.header_row().unwrap()), vec!["h1", "h2"]);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["b1", "b2"]);
+    assert_eq!(cell_texts(table.footer_row().unwrap()), vec!["f1", "f2"]);
+
+    assert_eq!(mi.item.options(), vec!["header", "footer"]);
+    assert!(mi.item.has_option("footer"));
+}
+
+#[test]
+fn column_specs() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=\"2*>.^m,~,25%h\"]\n|===\n|a |b |c |d\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(
+        table.columns(),
+        &[
+            TableColumn {
+                width: ColumnWidth::Proportional(1),
+                halign: HorizontalAlignment::Right,
+                valign: VerticalAlignment::Middle,
+                style: TableCellStyle::Monospace,
+            },
+            TableColumn {
+                width: ColumnWidth::Proportional(1),
+                halign: HorizontalAlignment::Right,
+                valign: VerticalAlignment::Middle,
+                style: TableCellStyle::Monospace,
+            },
+            TableColumn {
+                width: ColumnWidth::Auto,
+                halign: HorizontalAlignment::Left,
+                valign: VerticalAlignment::Top,
+                style: TableCellStyle::Default,
+            },
+            TableColumn {
+                width: ColumnWidth::Proportional(25),
+                halign: HorizontalAlignment::Left,
+                valign: VerticalAlignment::Top,
+                style: TableCellStyle::Header,
+            },
+        ]
+    );
+
+    let cells = table.body_rows()[0].cells();
+    assert_eq!(cells[0].halign(), HorizontalAlignment::Right);
+    assert_eq!(cells[0].valign(), VerticalAlignment::Middle);
+    assert_eq!(cells[0].style(), TableCellStyle::Monospace);
+    assert_eq!(cells[2].style(), TableCellStyle::Default);
+    assert_eq!(cells[3].style(), TableCellStyle::Header);
+}
+
+#[test]
+fn column_count() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=3]\n|===\n|a\n|b\n|c\n|d\n|e\n|f\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(table.columns(), &[DEFAULT_COLUMN; 3]);
+    assert_eq!(table.body_rows().len(), 2);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b", "c"]);
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["d", "e", "f"]);
+}
+
+#[test]
+fn cell_spans() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=3]\n|===\n2+|a |b\n.2+|c |d |e\n|f |g\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let rows = table.body_rows();
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(cell_texts(&rows[0]), vec!["a", "b"]);
+    assert_eq!(rows[0].cells()[0].colspan(), 2);
+    assert_eq!(rows[0].cells()[0].rowspan(), 1);
+    assert_eq!(rows[0].cells()[0].span().data(), "2+|a");
+
+    assert_eq!(cell_texts(&rows[1]), vec!["c", "d", "e"]);
+    assert_eq!(rows[1].cells()[0].colspan(), 1);
+    assert_eq!(rows[1].cells()[0].rowspan(), 2);
+
+    assert_eq!(cell_texts(&rows[2]), vec!["f", "g"]);
+}
+
+#[test]
+fn cell_duplication() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=3]\n|===\n3*|same\n2*^|x |y\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let rows = table.body_rows();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(cell_texts(&rows[0]), vec!["same", "same", "same"]);
+    assert_eq!(cell_texts(&rows[1]), vec!["x", "x", "y"]);
+    assert_eq!(rows[1].cells()[1].halign(), HorizontalAlignment::Center);
+    assert_eq!(rows[1].cells()[2].halign(), HorizontalAlignment::Left);
+}
+
+#[test]
+fn cell_alignment() {
+    let mut parser = Parser::default();
+
+    let mi =
+        crate::blocks::Block::parse(crate::Span::new("|===\n^|a .>|b >.^|c\n|==="), &mut parser)
+            .unwrap_if_no_warnings()
+            .unwrap();
+
+    let table = only_table(&mi.item);
+    let cells = table.body_rows()[0].cells();
+
+    assert_eq!(cells[0].halign(), HorizontalAlignment::Center);
+    assert_eq!(cells[0].valign(), VerticalAlignment::Top);
+    assert_eq!(cells[1].halign(), HorizontalAlignment::Left);
+    assert_eq!(cells[1].valign(), VerticalAlignment::Bottom);
+    assert_eq!(cells[2].halign(), HorizontalAlignment::Right);
+    assert_eq!(cells[2].valign(), VerticalAlignment::Middle);
+
+    assert_eq!(cells[1].span().data(), ".>|b");
+}
+
+#[test]
+fn cell_styles() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=4]\n|===\nd|d e|_e_ h|h l|l\nm|m s|*s* v|v |x\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let rows = table.body_rows();
+
+    let styles: Vec<TableCellStyle> = rows
+        .iter()
+        .flat_map(|row| row.cells().iter().map(|cell| cell.style()))
+        .collect();
+
+    assert_eq!(
+        styles,
+        vec![
+            TableCellStyle::Default,
+            TableCellStyle::Emphasis,
+            TableCellStyle::Header,
+            TableCellStyle::Literal,
+            TableCellStyle::Monospace,
+            TableCellStyle::Strong,
+            TableCellStyle::Verse,
+            TableCellStyle::Default,
+        ]
+    );
+
+    assert_eq!(rows[0].cells()[1].rendered_content(), Some("<em>e</em>"));
+    assert_eq!(
+        rows[1].cells()[1].rendered_content(),
+        Some("<strong>s</strong>")
+    );
+}
+
+#[test]
+fn literal_cell() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("|===\nl|\n  *not bold* <tag>\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let cell = &table.body_rows()[0].cells()[0];
+
+    assert_eq!(cell.style(), TableCellStyle::Literal);
+    assert_eq!(cell.content_model(), ContentModel::Verbatim);
+    assert_eq!(cell.substitution_group(), SubstitutionGroup::Verbatim);
+    assert_eq!(cell.rendered_content(), Some("  *not bold* &lt;tag&gt;"));
+}
+
+#[test]
+fn asciidoc_cell() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=2]\n|===\na|Intro\n\n* one\n* two\n|plain\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let cells = table.body_rows()[0].cells();
+
+    assert_eq!(cells[0].style(), TableCellStyle::AsciiDoc);
+    assert_eq!(cells[0].content_model(), ContentModel::Compound);
+    assert!(cells[0].content().is_none());
+    assert!(cells[0].rendered_content().is_none());
+
+    let mut blocks = cells[0].nested_blocks();
+
+    let paragraph = blocks.next().unwrap();
+    assert_eq!(paragraph.raw_context().as_ref(), "paragraph");
+    assert_eq!(paragraph.rendered_content(), Some("Intro"));
+
+    let list = blocks.next().unwrap();
+    assert_eq!(list.raw_context().as_ref(), "ulist");
+    assert_eq!(list.nested_blocks().count(), 2);
+
+    assert!(blocks.next().is_none());
+
+    assert_eq!(cells[1].rendered_content(), Some("plain"));
+}
+
+#[test]
+fn nested_table() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=\"1,2a\"]\n|===\n|Outer\n|Before\n\n!===\n!a !b\n!===\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+
+    let cell = &table.body_rows()[0].cells()[1];
+    assert_eq!(cell.style(), TableCellStyle::AsciiDoc);
+
+    let mut blocks = cell.nested_blocks();
+
+    assert_eq!(blocks.next().unwrap().rendered_content().unwrap(), "Before");
+
+    let nested = only_table(blocks.next().unwrap());
+    assert!(blocks.next().is_none());
+
+    assert_eq!(nested.format(), TableFormat::Psv);
+    assert_eq!(nested.columns().len(), 2);
+    assert_eq!(cell_texts(&nested.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        nested.body_rows()[0].cells()[0].span(),
+        Span {
+            data: "!a",
+            line: 7,
+            col: 1,
+            offset: 40,
+        }
+    );
+}
+
+#[test]
+fn nested_table_custom_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[cols=1a]\n|===\na|\n[separator=;]\n!===\n;a!b ;c\n!===\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let cell = &table.body_rows()[0].cells()[0];
+
+    let nested = only_table(cell.nested_blocks().next().unwrap());
+    assert_eq!(cell_texts(&nested.body_rows()[0]), vec!["a!b", "c"]);
+}
+
+#[test]
+fn nested_table_in_data_table() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=csv,cols=\"1,1a\"]\n|===\nx,\"!===\n!a !b\n!===\"\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    let cell = &table.body_rows()[0].cells()[1];
+
+    let nested = only_table(cell.nested_blocks().next().unwrap());
+    assert_eq!(cell_texts(&nested.body_rows()[0]), vec!["a", "b"]);
+}
+
+#[test]
+fn table_cell_attributes_are_scoped_to_cell() {
+    let mut parser = Parser::default();
+
+    let doc = parser.parse("[cols=1a]\n|===\n|:name: inner\n{name}\n|===\n\n{name}");
+
+    let mut blocks = doc.nested_blocks();
+
+    let table = only_table(blocks.next().unwrap());
+    let cell = &table.body_rows()[0].cells()[0];
+
+    let mut cell_blocks = cell.nested_blocks();
+    assert!(matches!(
+        cell_blocks.next(),
+        Some(crate::blocks::Block::DocumentAttribute(_))
+    ));
+
+    assert_eq!(
+        cell_blocks.next().unwrap().rendered_content().unwrap(),
+        "inner"
+    );
+
+    assert_eq!(blocks.next().unwrap().rendered_content().unwrap(), "{name}");
+
+    assert_eq!(
+        parser.attribute_value("name"),
+        crate::document::InterpretedValue::Unset
+    );
+}
+
+#[test]
+fn bang_delimiter_outside_table_cell() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("!===\n|a |b\n!==="), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    // Outside of a table cell, the default cell separator is `|` regardless
+    // of the delimiter.
+    let table = only_table(&mi.item);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+}
+
+#[test]
+fn header_row_ignores_column_style_and_alignment() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[%header,cols=\">a,1m\"]\n|===\n|*a* ^s|b\n|c |d\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    let header_cells = table.header_row().unwrap().cells();
+    assert_eq!(header_cells[0].style(), TableCellStyle::Default);
+    assert_eq!(
+        header_cells[0].rendered_content(),
+        Some("<strong>a</strong>")
+    );
+    assert_eq!(header_cells[1].style(), TableCellStyle::Default);
+
+    let body_cells = table.body_rows()[0].cells();
+    assert_eq!(body_cells[0].style(), TableCellStyle::AsciiDoc);
+    assert_eq!(body_cells[0].halign(), HorizontalAlignment::Right);
+    assert_eq!(body_cells[1].style(), TableCellStyle::Monospace);
+}
+
+#[test]
+fn multiline_cell() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("|===\n|first line\nsecond line\n|b\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(table.columns().len(), 1);
+    assert_eq!(table.body_rows().len(), 2);
+    assert_eq!(
+        cell_texts(&table.body_rows()[0]),
+        vec!["first line\nsecond line"]
+    );
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["b"]);
+}
+
+#[test]
+fn escaped_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new("|===\n|a \\| b |c\n|==="), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a | b", "c"]);
+}
+
+#[test]
+fn custom_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[separator=¦]\n|===\n¦a ¦b|c\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b|c"]);
+}
+
+#[test]
+fn csv_format() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=csv]\n|===\nArtist,Track\n\nBaauer,Harlem Shake\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Csv);
+    assert_eq!(table.columns().len(), 2);
+
+    assert_eq!(
+        cell_texts(table.header_row().unwrap()),
+        vec!["Artist", "Track"]
+    );
+
+    assert_eq!(table.body_rows().len(), 1);
+
+    assert_eq!(
+        cell_texts(&table.body_rows()[0]),
+        vec!["Baauer", "Harlem Shake"]
+    );
+}
+
+#[test]
+fn csv_shorthand_delimiter() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(crate::Span::new(",===\na,b\nc,d\n,==="), &mut parser)
+        .unwrap_if_no_warnings()
+        .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Csv);
+    assert!(table.header_row().is_none());
+    assert_eq!(table.body_rows().len(), 2);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["c", "d"]);
+
+    assert_eq!(
+        mi.after,
+        Span {
+            data: "",
+            line: 4,
+            col: 5,
+            offset: 17,
+        }
+    );
+}
+
+#[test]
+fn csv_quoted_values() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(",===\n\"a, b\", \"say \"\"hi\"\"\" ,c\n,==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    let cells = table.body_rows()[0].cells();
+    assert_eq!(cells.len(), 3);
+    assert_eq!(cells[0].rendered_content().unwrap(), "a, b");
+    assert_eq!(cells[1].rendered_content().unwrap(), "say \"hi\"");
+    assert_eq!(cells[2].rendered_content().unwrap(), "c");
+
+    assert_eq!(
+        cells[1].span(),
+        Span {
+            data: "\"say \"\"hi\"\"\"",
+            line: 2,
+            col: 9,
+            offset: 13,
+        }
+    );
+}
+
+#[test]
+fn csv_quoted_value_spans_lines() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(",===\n\"first\n\nsecond\",b\nc,d\n,==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.columns().len(), 2);
+    assert_eq!(table.body_rows().len(), 2);
+
+    assert_eq!(
+        cell_texts(&table.body_rows()[0]),
+        vec!["first\n\nsecond", "b"]
+    );
+
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["c", "d"]);
+}
+
+#[test]
+fn csv_empty_lines_and_whitespace() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(",===\na , b\n\n\n c,d \n,==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    assert_eq!(table.columns().len(), 2);
+    assert_eq!(cell_texts(table.header_row().unwrap()), vec!["a", "b"]);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["c", "d"]);
+}
+
+#[test]
+fn tsv_format() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=tsv]\n|===\na\tb, c\nd\t\"e\tf\"\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Tsv);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b, c"]);
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["d", "e\tf"]);
+}
+
+#[test]
+fn csv_with_tab_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=csv,separator=\\t]\n|===\na\tb, c\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Csv);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b, c"]);
+}
+
+#[test]
+fn dsv_format() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(":===\nArtist:Track\n\nRobyn:\"Dancing\" On My Own\n:==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Dsv);
+
+    assert_eq!(
+        cell_texts(table.header_row().unwrap()),
+        vec!["Artist", "Track"]
+    );
+
+    // Double quotes have no special meaning in DSV.
+    assert_eq!(
+        cell_texts(&table.body_rows()[0]),
+        vec!["Robyn", "\"Dancing\" On My Own"]
+    );
+}
+
+#[test]
+fn dsv_escaped_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=dsv]\n|===\n10\\:30:Meeting\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["10:30", "Meeting"]);
+}
+
+#[test]
+fn dsv_custom_separator() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=dsv,separator=;]\n|===\na;b:c\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b:c"]);
+}
+
+#[test]
+fn data_table_column_styles() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new("[format=csv,cols=\"1h,1a\"]\n|===\nSky,* blue\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    let table = only_table(&mi.item);
+
+    let cells = table.body_rows()[0].cells();
+    assert_eq!(cells[0].style(), TableCellStyle::Header);
+    assert_eq!(cells[0].rendered_content().unwrap(), "Sky");
+
+    assert_eq!(cells[1].style(), TableCellStyle::AsciiDoc);
+    assert_eq!(cells[1].content_model(), ContentModel::Compound);
+
+    let mut blocks = cells[1].nested_blocks();
+    assert!(matches!(blocks.next(), Some(crate::blocks::Block::List(_))));
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn table_interrupts_paragraph() {
+    let mut parser = Parser::default();
+
+    let doc = parser.parse("Some text.\n|===\n|a\n|===");
+
+    let mut blocks = doc.nested_blocks();
+    assert_eq!(blocks.next().unwrap().raw_context().as_ref(), "paragraph");
+    assert_eq!(blocks.next().unwrap().raw_context().as_ref(), "table");
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn table_followed_by_whitespace_only_line() {
+    for source in [
+        "|===\n|===\n\t",
+        "|===\n|===\n ",
+        "|===\n|a\n|===\n\t",
+        "|===\na|x\n|===\n\t",
+    ] {
+        let doc = Parser::default().parse(source);
+
+        let mut blocks = doc.nested_blocks();
+        assert_eq!(blocks.next().unwrap().raw_context().as_ref(), "table");
+        assert!(blocks.next().is_none());
+    }
+}
+
+#[test]
+fn title_and_id() {
+    let mut parser = Parser::default();
+
+    let mi = crate::blocks::Block::parse(
+        crate::Span::new(".Table title\n[#my-table]\n|===\n|a\n|==="),
+        &mut parser,
+    )
+    .unwrap_if_no_warnings()
+    .unwrap();
+
+    assert_eq!(mi.item.title(), Some("Table title"));
+    assert_eq!(mi.item.id(), Some("my-table"));
+    assert_eq!(
+        mi.item.span().data(),
+        ".Table title\n[#my-table]\n|===\n|a\n|==="
+    );
+}
+
+#[test]
+fn cell_after_colspan_uses_column_where_it_starts() {
+    let doc = Parser::default().parse("[cols=\"1,1,>1m\"]\n|===\n2+|a |b\n|===");
+
+    let table = only_table(doc.nested_blocks().next().unwrap());
+    let cells = table.body_rows()[0].cells();
+    assert_eq!(cells.len(), 2);
+
+    assert_eq!(cells[0].halign(), HorizontalAlignment::Left);
+    assert_eq!(cells[0].style(), TableCellStyle::Default);
+
+    assert_eq!(cells[1].halign(), HorizontalAlignment::Right);
+    assert_eq!(cells[1].style(), TableCellStyle::Monospace);
+}
+
+#[test]
+fn cell_after_rowspan_uses_column_where_it_starts() {
+    let doc = Parser::default().parse("[cols=\"1,>1m\"]\n|===\n.2+|a |b\n|c\n|===");
+
+    let table = only_table(doc.nested_blocks().next().unwrap());
+    assert_eq!(table.body_rows().len(), 2);
+
+    let cells = table.body_rows()[1].cells();
+    assert_eq!(cell_texts(&table.body_rows()[1]), vec!["c"]);
+    assert_eq!(cells[0].halign(), HorizontalAlignment::Right);
+    assert_eq!(cells[0].style(), TableCellStyle::Monospace);
+}
+
+#[test]
+fn err_unterminated() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(crate::Span::new("|===\n|a |b"), &mut parser);
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "|===",
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            warning: WarningType::UnterminatedDelimitedBlock,
+        }]
+    );
+}
+
+#[test]
+fn err_missing_leading_separator() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(crate::Span::new("|===\na |b\n|==="), &mut parser);
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "a",
+                line: 2,
+                col: 1,
+                offset: 5,
+            },
+            warning: WarningType::TableMissingLeadingSeparator,
+        }]
+    );
+}
+
+#[test]
+fn err_cell_exceeds_columns() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("[cols=2]\n|===\n.2+|a .2+|b\n|c\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "|c",
+                line: 4,
+                col: 1,
+                offset: 26,
+            },
+            warning: WarningType::TableCellExceedsColumns,
+        }]
+    );
+}
+
+#[test]
+fn err_cell_span_exceeds_columns() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("[cols=2]\n|===\n|a 2+|b |c\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![
+            Warning {
+                source: Span {
+                    data: "2+|b",
+                    line: 3,
+                    col: 4,
+                    offset: 17,
+                },
+                warning: WarningType::TableCellSpanExceedsColumns,
+            },
+            Warning {
+                source: Span {
+                    data: "|c",
+                    line: 3,
+                    col: 9,
+                    offset: 22,
+                },
+                warning: WarningType::TableIncompleteRow,
+            },
+        ]
+    );
+}
+
+#[test]
+fn err_incomplete_row() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(crate::Span::new("|===\n|a |b\n|c\n|==="), &mut parser);
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "|c",
+                line: 3,
+                col: 1,
+                offset: 11,
+            },
+            warning: WarningType::TableIncompleteRow,
+        }]
+    );
+}
+
+#[test]
+fn err_invalid_format() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("[format=xsv]\n|===\n|a |b\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.format(), TableFormat::Psv);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "|===",
+                line: 2,
+                col: 1,
+                offset: 13,
+            },
+            warning: WarningType::TableInvalidFormat("xsv".to_owned()),
+        }]
+    );
+}
+
+#[test]
+fn err_column_count_exceeds_limit() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("[cols=\"100000000000000\"]\n|===\n|a\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.columns().len(), 1000);
+
+    assert_eq!(
+        maw.warnings,
+        vec![
+            Warning {
+                source: Span {
+                    data: "|===",
+                    line: 2,
+                    col: 1,
+                    offset: 25,
+                },
+                warning: WarningType::TableCountExceedsLimit(1000),
+            },
+            Warning {
+                source: Span {
+                    data: "|a",
+                    line: 3,
+                    col: 1,
+                    offset: 30,
+                },
+                warning: WarningType::TableIncompleteRow,
+            },
+        ]
+    );
+}
+
+#[test]
+fn err_cell_span_exceeds_limit() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("|===\n1.100000000000000+|x\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(table.body_rows()[0].cells()[0].rowspan(), 1000);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "1.100000000000000+|x",
+                line: 2,
+                col: 1,
+                offset: 5,
+            },
+            warning: WarningType::TableCountExceedsLimit(1000),
+        }]
+    );
+}
+
+#[test]
+fn err_cell_repeat_exceeds_limit() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new("[cols=1]\n|===\n100000000000000*|x\n|==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1000);
+
+    assert_eq!(
+        maw.warnings,
+        vec![Warning {
+            source: Span {
+                data: "100000000000000*|x",
+                line: 3,
+                col: 1,
+                offset: 14,
+            },
+            warning: WarningType::TableCountExceedsLimit(1000),
+        }]
+    );
+}
+
+#[test]
+fn err_csv_unclosed_quote() {
+    let mut parser = Parser::default();
+
+    let maw = crate::blocks::Block::parse(
+        crate::Span::new(",===\na,b\n\"unclosed,c\nd\n,==="),
+        &mut parser,
+    );
+
+    let mi = maw.item.as_ref().unwrap();
+    let table = only_table(&mi.item);
+    assert_eq!(table.body_rows().len(), 1);
+    assert_eq!(cell_texts(&table.body_rows()[0]), vec!["a", "b"]);
+
+    assert_eq!(
+        maw.warnings,
+        vec![
+            Warning {
+                source: Span {
+                    data: "\"unclosed,c\nd",
+                    line: 3,
+                    col: 1,
+                    offset: 9,
+                },
+                warning: WarningType::TableUnclosedQuote,
+            },
+            Warning {
+                source: Span {
+                    data: "\"unclosed,c\nd",
+                    line: 3,
+                    col: 1,
+                    offset: 9,
+                },
+                warning: WarningType::TableIncompleteRow,
+            },
+        ]
+    );
+}
@@ -0,0 +1,482 @@
+use crate::{
+    Parser,
+    attributes::Attrlist,
+    content::Content,
+    document::Callouts,
+    parser::{CalloutGuard, CalloutRenderParams},
+};
+
+/// Replace callout markers (e.g., `<1>`, `// <1>`, `<!--1-->`, or `<.>`) at
+/// the end of each line of verbatim content with the rendered callout.
+///
+/// This runs after special characters have been replaced, so the markers are
+/// matched in their escaped form (e.g., `&lt;1&gt;`).
+///
+/// A callout marker may be preceded by a line comment prefix (`//`, `#`, `--`,
+/// or `;;`) so that it does not disturb the source language. The
+/// `line-comment` block attribute replaces the default set of line comment
+/// prefixes. (An empty `line-comment` attribute disables the line comment
+/// prefix.)
+///
+/// A callout marker that is preceded by a backslash is not replaced; the
+/// backslash is removed instead.
+pub(super) fn apply_callouts(
+    content: &mut Content<'_>,
+    parser: &Parser,
+    attrlist: Option<&Attrlist<'_>>,
+) {
+    let text = content.rendered.as_ref();
+    if !text.contains("&gt;") {
+        return;
+    }
+
+    let line_comment = attrlist
+        .and_then(|attrlist| attrlist.named_attribute("line-comment"))
+        .map(|attr| attr.value());
+
+    let mut autonum = 0;
+    let mut changed = false;
+    let mut result = String::with_capacity(text.len());
+
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            result.push('\n');
+        }
+
+        let markers = scan_callouts(line, "&lt;", "&gt;", line_comment.is_none());
+        let Some(first) = markers.first() else {
+            result.push_str(line);
+            continue;
+        };
+
+        changed = true;
+
+        let (before, prefix) = split_line_comment(&line[0..first.start], line_comment);
+        result.push_str(before);
+
+        for (marker_index, marker) in markers.iter().enumerate() {
+            let prefix = if marker_index == 0 { prefix } else { "" };
+
+            if marker_index > 0 && marker.start > markers[marker_index - 1].end {
+                result.push(' ');
+            }
+
+            if marker.escaped {
+                // Honor the escape, but leave any line comment prefix in place.
+                result.push_str(prefix);
+                result.push_str(&line[marker.start + 1..marker.end]);
+                continue;
+            }
+
+            let number = marker.number.unwrap_or_else(|| {
+                autonum += 1;
+                autonum
+            });
+
+            let guard = if !prefix.is_empty() {
+                Some(CalloutGuard::LineComment(prefix))
+            } else if marker.xml_comment {
+                Some(CalloutGuard::XmlComment)
+            } else {
+                None
+            };
+
+            let params = CalloutRenderParams {
+                number,
+                guard,
+                parser,
+            };
+
+            parser.renderer.render_callout(&params, &mut result);
+        }
+    }
+
+    if changed {
+        content.rendered = result.into();
+    }
+}
+
+/// Register the callout markers found in the raw (unsubstituted) text of a
+/// verbatim block so that the callout list that follows can be linked to
+/// them.
+///
+/// Returns `true` if any callout markers (including escaped markers) were
+/// found.
+pub(crate) fn register_callouts(text: &str, callouts: &mut Callouts) -> bool {
+    if !text.contains('<') {
+        return false;
+    }
+
+    let mut autonum = 0;
+    let mut found = false;
+
+    for line in text.lines() {
+        for marker in scan_callouts(line, "<", ">", true) {
+            found = true;
+
+            if marker.escaped {
+                continue;
+            }
+
+            let number = marker.number.unwrap_or_else(|| {
+                autonum += 1;
+                autonum
+            });
+
+            callouts.register(number);
+        }
+    }
+
+    found
+}
+
+/// A callout marker found at the end of a line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct CalloutMarker {
+    /// Byte offset of the start of the marker (including any escaping
+    /// backslash) within the line.
+    start: usize,
+
+    /// Byte offset just past the end of the marker within the line.
+    end: usize,
+
+    /// `true` if the marker was preceded by a backslash.
+    escaped: bool,
+
+    /// The callout number, or `None` if the callout is to be numbered
+    /// automatically (`<.>`).
+    number: Option<usize>,
+
+    /// `true` if the marker was written as an XML comment (`<!--1-->`).
+    xml_comment: bool,
+}
+
+/// Find the run of callout markers at the end of `line`.
+///
+/// Callout markers are only recognized at the end of a line, but several may
+/// be placed side by side (optionally separated by a single space). All
+/// markers in the run must use the same form (i.e., plain or XML comment).
+///
+/// `lt` and `gt` are the (possibly escaped) angle brackets that enclose each
+/// marker.
+fn scan_callouts(line: &str, lt: &str, gt: &str, allow_xml_comment: bool) -> Vec<CalloutMarker> {
+    let mut candidates: Vec<CalloutMarker> = line
+        .match_indices(lt)
+        .filter_map(|(index, _)| parse_marker(line, index, lt, gt, allow_xml_comment))
+        .collect();
+
+    // Work backward from the end of the line to find the markers that are
+    // adjacent to one another.
+    let mut run: Vec<CalloutMarker> = vec![];
+    let mut run_start = line.len();
+
+    while let Some(marker) = candidates.pop() {
+        let adjacent = marker.end == run_start
+            || (marker.end + 1 == run_start && line[marker.end..].starts_with(' '));
+
+        let same_form = run
+            .last()
+            .is_none_or(|next| next.xml_comment == marker.xml_comment);
+
+        if !adjacent || !same_form {
+            break;
+        }
+
+        run_start = marker.start;
+        run.push(marker);
+    }
+
+    run.reverse();
+    run
+}
+
+/// Parse a callout marker that starts with `lt` at byte offset `index` of
+/// `line`.
+fn parse_marker(
+    line: &str,
+    index: usize,
+    lt: &str,
+    gt: &str,
+    allow_xml_comment: bool,
+) -> Option<CalloutMarker> {
+    let escaped = line[..index].ends_with('\\');
+    let mut rest = &line[index + lt.len()..];
+
+    let mut xml_comment = false;
+    if allow_xml_comment {
+        rest = rest.strip_prefix('!').unwrap_or(rest);
+
+        if let Some(after) = rest.strip_prefix("--") {
+            rest = after;
+            xml_comment = true;
+        }
+    }
+
+    let number_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+
+    let number = if number_len > 0 {
+        Some(rest[..number_len].parse().ok()?)
+    } else if rest.starts_with('.') {
+        None
+    } else {
+        return None;
+    };
+
+    rest = &rest[number_len.max(1)..];
+
+    if xml_comment {
+        rest = rest.strip_prefix("--")?;
+    }
+
+    rest = rest.strip_prefix(gt)?;
+
+    Some(CalloutMarker {
+        start: if escaped { index - 1 } else { index },
+        end: line.len() - rest.len(),
+        escaped,
+        number,
+        xml_comment,
+    })
+}
+
+/// Split off the line comment prefix (and any single space that follows it)
+/// that precedes the first callout marker on a line.
+///
+/// Returns the text before the prefix and the prefix itself (which may be
+/// empty).
+fn split_line_comment<'a>(before: &'a str, line_comment: Option<&str>) -> (&'a str, &'a str) {
+    let prefixes: &[&str] = match line_comment {
+        Some("") => &[],
+        Some(line_comment) => &[line_comment],
+        None => &["//", "#", "--", ";;"],
+    };
+
+    let trimmed = before.strip_suffix(' ').unwrap_or(before);
+
+    for candidate in [before, trimmed] {
+        for prefix in prefixes {
+            if let Some(text) = candidate.strip_suffix(prefix) {
+                return before.split_at(text.len());
+            }
+        }
+    }
+
+    (before, "")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        Parser, Span,
+        attributes::{Attrlist, AttrlistContext},
+        blocks::IsBlock,
+        content::{Content, SubstitutionStep, callouts::register_callouts},
+        document::Callouts,
+        parser::ModificationContext,
+    };
+
+    fn apply(source: &str, parser: &Parser, attrlist: Option<&str>) -> String {
+        let attrlist = attrlist.map(|attrlist| {
+            Attrlist::parse(Span::new(attrlist), parser, AttrlistContext::Block)
+                .item
+                .item
+        });
+
+        let mut content = Content::from(Span::new(source));
+        SubstitutionStep::SpecialCharacters.apply(&mut content, parser, attrlist.as_ref());
+        SubstitutionStep::Callouts.apply(&mut content, parser, attrlist.as_ref());
+        content.rendered().to_owned()
+    }
+
+    #[test]
+    fn no_callouts() {
+        let p = Parser::default();
+        assert_eq!(apply("", &p, None), "");
+        assert_eq!(apply("a < b", &p, None), "a &lt; b");
+        assert_eq!(apply("<1> not at end", &p, None), "&lt;1&gt; not at end");
+        assert_eq!(apply("<a>", &p, None), "&lt;a&gt;");
+        assert_eq!(apply("<>", &p, None), "&lt;&gt;");
+        assert_eq!(apply("<!--1>", &p, None), "&lt;!--1&gt;");
+    }
+
+    #[test]
+    fn plain_marker() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("puts 'Hello' <1>", &p, None),
+            r#"puts 'Hello' <b class="conum">(1)</b>"#
+        );
+    }
+
+    #[test]
+    fn multiple_markers_on_one_line() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("line <1> <2><3>", &p, None),
+            r#"line <b class="conum">(1)</b> <b class="conum">(2)</b><b class="conum">(3)</b>"#
+        );
+
+        // Only markers at the end of the line count.
+        assert_eq!(
+            apply("line <1> x <2>", &p, None),
+            r#"line &lt;1&gt; x <b class="conum">(2)</b>"#
+        );
+    }
+
+    #[test]
+    fn line_comment_guards() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("foo(); // <1>", &p, None),
+            r#"foo(); // <b class="conum">(1)</b>"#
+        );
+
+        assert_eq!(
+            apply("x = 1 # <1>", &p, None),
+            r#"x = 1 # <b class="conum">(1)</b>"#
+        );
+
+        assert_eq!(
+            apply("SELECT 1; --<1>", &p, None),
+            r#"SELECT 1; --<b class="conum">(1)</b>"#
+        );
+
+        assert_eq!(
+            apply("(foo) ;; <1> <2>", &p, None),
+            r#"(foo) ;; <b class="conum">(1)</b> <b class="conum">(2)</b>"#
+        );
+    }
+
+    #[test]
+    fn xml_comment_guard() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("<foo/> <!--1-->", &p, None),
+            r#"&lt;foo/&gt; &lt;!--<b class="conum">(1)</b>--&gt;"#
+        );
+
+        // Markers in a run must all use the same form.
+        assert_eq!(
+            apply("<foo/> <1> <!--2-->", &p, None),
+            r#"&lt;foo/&gt; &lt;1&gt; &lt;!--<b class="conum">(2)</b>--&gt;"#
+        );
+    }
+
+    #[test]
+    fn custom_line_comment() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("a % <1>", &p, Some("line-comment=%")),
+            r#"a % <b class="conum">(1)</b>"#
+        );
+
+        // The default line comment prefixes are no longer recognized.
+        assert_eq!(
+            apply("a // <1>", &p, Some("line-comment=%")),
+            r#"a // <b class="conum">(1)</b>"#
+        );
+
+        assert_eq!(
+            apply("a # <1>", &p, Some(r#"line-comment="""#)),
+            r#"a # <b class="conum">(1)</b>"#
+        );
+
+        // Nor are XML comment markers.
+        assert_eq!(
+            apply("<a/> <!--1-->", &p, Some("line-comment=%")),
+            "&lt;a/&gt; &lt;!--1--&gt;"
+        );
+    }
+
+    #[test]
+    fn escaped_marker() {
+        let p = Parser::default();
+
+        assert_eq!(apply(r"foo \<1>", &p, None), "foo &lt;1&gt;");
+        assert_eq!(apply(r"foo // \<1>", &p, None), "foo // &lt;1&gt;");
+
+        assert_eq!(
+            apply(r"foo \<1> <2>", &p, None),
+            r#"foo &lt;1&gt; <b class="conum">(2)</b>"#
+        );
+    }
+
+    #[test]
+    fn auto_numbered_markers() {
+        let p = Parser::default();
+
+        assert_eq!(
+            apply("a <.>\nb\nc <.> <.>\nd <5>", &p, None),
+            "a <b class=\"conum\">(1)</b>\nb\nc <b class=\"conum\">(2)</b> <b class=\"conum\">(3)</b>\nd <b class=\"conum\">(5)</b>"
+        );
+    }
+
+    #[test]
+    fn font_icons() {
+        let p = Parser::default().with_intrinsic_attribute(
+            "icons",
+            "font",
+            ModificationContext::Anywhere,
+        );
+
+        assert_eq!(
+            apply("foo(); // <1>", &p, None),
+            r#"foo(); <i class="conum" data-value="1"></i><b>(1)</b>"#
+        );
+    }
+
+    #[test]
+    fn image_icons() {
+        let p =
+            Parser::default().with_intrinsic_attribute("icons", "", ModificationContext::Anywhere);
+
+        assert_eq!(
+            apply("foo <2>", &p, None),
+            r#"foo <img src="./images/icons/callouts/2.png" alt="2">"#
+        );
+    }
+
+    #[test]
+    fn not_applied_to_inline_passthroughs() {
+        let doc = Parser::default().parse("Use +<1>+ here.\n\nAnd ++<2>++ or $$<3>$$");
+        let mut blocks = doc.nested_blocks();
+
+        assert_eq!(
+            blocks.next().unwrap().rendered_content().unwrap(),
+            "Use &lt;1&gt; here."
+        );
+
+        assert_eq!(
+            blocks.next().unwrap().rendered_content().unwrap(),
+            "And &lt;2&gt; or &lt;3&gt;"
+        );
+    }
+
+    #[test]
+    fn register() {
+        let mut callouts = Callouts::default();
+
+        assert!(!register_callouts("no callouts here", &mut callouts));
+        assert!(!register_callouts("<1> not at end", &mut callouts));
+
+        assert!(register_callouts(
+            "a <1>\nb // <2> <1>\nc \\<3>\nd <!--.-->",
+            &mut callouts
+        ));
+
+        assert_eq!(callouts.callout_ids(1), vec!["CO1-1", "CO1-3", "CO1-4"]);
+        assert_eq!(callouts.callout_ids(2), vec!["CO1-2"]);
+        assert!(callouts.callout_ids(3).is_empty());
+    }
+}
@@ -3,7 +3,9 @@
 //!
 //! [substitutions]: https://docs.asciidoctor.org/asciidoc/latest/subs/
 
-use crate::{Span, strings::CowStr};
+use std::slice::Iter;
+
+use crate::{Span, content::InlinePassthrough, strings::CowStr};
 
 /// Describes the annotated content of a block after any relevant
 /// [substitutions] have been performed.
@@ -21,6 +23,9 @@ pub struct Content<'src> {
 
     /// The possibly-modified text after substititions have been performed.
     pub(crate) rendered: CowStr<'src>,
+
+    /// Inline passthroughs that were restored into the rendered text.
+    pub(crate) passthroughs: Vec<InlinePassthrough>,
 }
 
 impl<'src> Content<'src> {
@@ -30,6 +35,7 @@ impl<'src> Content<'src> {
         Self {
             original: span,
             rendered: filtered.as_ref().to_string().into(),
+            passthroughs: vec![],
         }
     }
 
@@ -45,6 +51,17 @@ impl<'src> Content<'src> {
         self.rendered.as_ref()
     }
 
+    /// Returns an iterator over the inline passthroughs (e.g., `+text+`,
+    /// `pass:[text]`, or `stem:[text]`) that were found in this content, in
+    /// the order in which they appear in the rendered text.
+    ///
+    /// The content of each passthrough can be located in
+    /// [`rendered()`](Self::rendered) via
+    /// [`InlinePassthrough::rendered_range()`].
+    pub fn passthroughs(&self) -> Iter<'_, InlinePassthrough> {
+        self.passthroughs.iter()
+    }
+
     /// Returns `true` if `self` contains no text.
     pub fn is_empty(&self) -> bool {
         self.rendered.as_ref().is_empty()
@@ -56,6 +73,7 @@ impl<'src> From<Span<'src>> for Content<'src> {
         Self {
             original: span,
             rendered: CowStr::from(span.data()),
+            passthroughs: vec![],
         }
     }
 }
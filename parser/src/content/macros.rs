@@ -1,4 +1,4 @@
-use std::{borrow::Cow, path::Path, sync::LazyLock};
+use std::{borrow::Cow, ops::Range, path::Path, sync::LazyLock};
 
 use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
 use regex::{Captures, Regex, Replacer};
@@ -7,7 +7,12 @@ use crate::{
     Parser, Span,
     attributes::{Attrlist, AttrlistContext},
     content::Content,
-    parser::{IconRenderParams, ImageRenderParams, LinkRenderParams, LinkRenderType},
+    document::{Footnote, PendingIndexTerm},
+    parser::{
+        FootnoteRenderParams, FootnoteRenderType, IconRenderParams, ImageRenderParams,
+        IndexTermRenderParams, LinkRenderParams, LinkRenderType, MenuRenderParams,
+        XrefRenderParams,
+    },
 };
 
 pub(super) fn apply_macros(content: &mut Content<'_>, parser: &Parser) {
@@ -15,15 +20,41 @@ pub(super) fn apply_macros(content: &mut Content<'_>, parser: &Parser) {
     let found_square_bracket = text.contains('[');
     let found_colon = text.contains(':');
     let found_macroish = found_square_bracket && found_colon;
-    // let found_macroish_short = found_macroish && text.contains(":[");
+    let found_macroish_short = found_macroish && text.contains(":[");
 
     // TO DO (#262): Implement extensions that can define macros.
     // Port Ruby Asciidoctor's implementation from
     // https://github.com/asciidoctor/asciidoctor/blob/main/lib/asciidoctor/substitutors.rb#L306-L347.
 
-    // TO DO (#263): Implement `kbd:` and `btn:` macros.
-    // Port Ruby Asciidoctor's implementation from
-    // https://github.com/asciidoctor/asciidoctor/blob/main/lib/asciidoctor/substitutors.rb#L349-L377.
+    if parser.is_attribute_set("experimental") {
+        if found_macroish_short && (text.contains("kbd:") || text.contains("btn:")) {
+            let replacer = InlineKbdBtnMacroReplacer(parser);
+
+            if let Cow::Owned(new_result) =
+                INLINE_KBD_BTN_MACRO.replace_all(content.rendered(), replacer)
+            {
+                content.rendered = new_result.into();
+            }
+        }
+
+        if found_macroish && text.contains("menu:") {
+            let replacer = InlineMenuMacroReplacer(parser);
+
+            if let Cow::Owned(new_result) =
+                INLINE_MENU_MACRO.replace_all(content.rendered(), replacer)
+            {
+                content.rendered = new_result.into();
+            }
+        }
+
+        if text.contains('"') && text.contains("&gt;") {
+            let replacer = InlineMenuReplacer(parser);
+
+            if let Cow::Owned(new_result) = INLINE_MENU.replace_all(content.rendered(), replacer) {
+                content.rendered = new_result.into();
+            }
+        }
+    }
 
     if found_macroish && (text.contains("image:") || text.contains("icon:")) {
         let replacer = InlineImageMacroReplacer(parser);
@@ -34,14 +65,21 @@ pub(super) fn apply_macros(content: &mut Content<'_>, parser: &Parser) {
         }
     }
 
-    /*
     if (text.contains("((") && text.contains("))"))
         || (found_macroish_short && text.contains("dexterm"))
     {
-        todo!("Index term macro");
-        // Port Ruby Asciidoctor's implementation from lines 439..536.
+        let replacer = InlineIndexTermReplacer {
+            parser,
+            original: content.original(),
+            search_from: 0,
+        };
+
+        if let Cow::Owned(new_result) =
+            INLINE_INDEXTERM_MACRO.replace_all(content.rendered(), replacer)
+        {
+            content.rendered = new_result.into();
+        }
     }
-    */
 
     if found_colon && text.contains("://") {
         let replacer = InlineLinkReplacer(parser);
@@ -87,19 +125,220 @@ pub(super) fn apply_macros(content: &mut Content<'_>, parser: &Parser) {
         }
     }
 
-    // TODO (https://github.com/asciidoc-rs/asciidoc-parser/issues/476):
-    // Handle double-angle-bracket cross-reference syntax.
-    /*
-    if (text.contains('&') && text.contains(";&l") || (found_macroish && text.contains("xref:"))) {
-        todo!("Port cross-reference macro");
-        // Port Ruby Asciidoctor's implementation from lines 742..840.
+    if (text.contains('&') && text.contains(";&l")) || (found_macroish && text.contains("xref:")) {
+        let replacer = InlineXrefReplacer(parser);
+
+        if let Cow::Owned(new_result) = INLINE_XREF_MACRO.replace_all(content.rendered(), replacer)
+        {
+            content.rendered = new_result.into();
+        }
     }
 
     if found_macroish && text.contains("tnote") {
-        todo!("Port footnote macro");
-        // Port Ruby Asciidoctor's implementation from lines 842..884.
+        let replacer = InlineFootnoteReplacer(parser);
+
+        if let Cow::Owned(new_result) =
+            INLINE_FOOTNOTE_MACRO.replace_all(content.rendered(), replacer)
+        {
+            content.rendered = new_result.into();
+        }
+    }
+}
+
+/// Matches an inline keyboard (`kbd:`) or button (`btn:`) macro, which may
+/// span multiple lines.
+///
+/// ## Examples
+///
+/// * `kbd:[F3]`
+/// * `kbd:[Ctrl+Shift+T]`
+/// * `btn:[Save]`
+static INLINE_KBD_BTN_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?xs)
+    (\\)?                           # (1) optional escape backslash
+    (kbd|btn):                      # (2) macro name
+    \[
+      (.*?[^\\])                    # (3) keys or button label (ends with a non-escaped char)
+    \]
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineKbdBtnMacroReplacer<'p>(&'p Parser);
+
+impl Replacer for InlineKbdBtnMacroReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps.get(1).is_some() {
+            // Honor the escape.
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        if &caps[2] == "kbd" {
+            let keys = caps[3].trim().replace("\\]", "]");
+            self.0.renderer.render_kbd(&split_keys(&keys), dest);
+        } else {
+            let text = caps[3].trim().replace('\n', " ").replace("\\]", "]");
+            self.0.renderer.render_button(&text, dest);
+        }
+    }
+}
+
+/// Splits a key combination into its individual keys.
+///
+/// Keys may be delimited by `+` or `,` (whichever appears first after the
+/// first character). A trailing delimiter is treated as a key in its own
+/// right (e.g., `Ctrl++`).
+fn split_keys(keys: &str) -> Vec<String> {
+    let delim = keys
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == ',' || *c == '+')
+        .map(|(_, c)| c);
+
+    let Some(delim) = delim else {
+        return vec![keys.to_owned()];
+    };
+
+    if let Some(keys) = keys.strip_suffix(delim) {
+        let mut keys: Vec<String> = keys.split(delim).map(|k| k.trim().to_owned()).collect();
+
+        if let Some(last) = keys.last_mut() {
+            last.push(delim);
+        }
+
+        keys
+    } else {
+        keys.split(delim).map(|k| k.trim().to_owned()).collect()
+    }
+}
+
+/// Matches an inline menu macro, which may span multiple lines.
+///
+/// ## Examples
+///
+/// * `menu:File[]`
+/// * `menu:File[Save]`
+/// * `menu:View[Zoom &gt; Reset]`
+static INLINE_MENU_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?xs)
+    \\?                             # optional escape backslash
+    menu:
+    (                               # (1) top-level menu
+      \w
+      |
+      [\w&] [^\n\[]* [^\s\[]
+    )
+    \[\ *                           # opening '[' and any leading spaces
+      (?:
+        |
+        (.*?[^\\])                  # (2) menu items (ends with a non-escaped char)
+      )
+    \]
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineMenuMacroReplacer<'p>(&'p Parser);
+
+impl Replacer for InlineMenuMacroReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps[0].starts_with('\\') {
+            // Honor the escape.
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        let items = caps.get(2).map(|items| items.as_str().replace("\\]", "]"));
+
+        let (submenus, menuitem) = if let Some(items) = &items {
+            let delim = if items.contains("&gt;") {
+                Some("&gt;")
+            } else if items.contains(',') {
+                Some(",")
+            } else {
+                None
+            };
+
+            if let Some(delim) = delim {
+                let mut submenus: Vec<&str> = items.split(delim).map(|i| i.trim()).collect();
+                let menuitem = submenus.pop();
+                (submenus, menuitem)
+            } else {
+                (vec![], Some(items.trim_end()))
+            }
+        } else {
+            (vec![], None)
+        };
+
+        let params = MenuRenderParams {
+            menu: &caps[1],
+            submenus,
+            menuitem,
+            parser: self.0,
+        };
+
+        self.0.renderer.render_menu(&params, dest);
+    }
+}
+
+/// Matches an inline menu written using the double-quoted shorthand syntax.
+/// (Note that the `>` character will have already been escaped by the special
+/// characters substitution.)
+///
+/// ## Examples
+///
+/// * `"File > Save"`
+/// * `"Tools > Project > Build"`
+static INLINE_MENU: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)
+    \\?                             # optional escape backslash
+    "
+    (                               # (1) menu path
+      [\w&] [^"]*? [\ \n]+ &gt; [\ \n]+ [^"]*
+    )
+    "
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineMenuReplacer<'p>(&'p Parser);
+
+impl Replacer for InlineMenuReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps[0].starts_with('\\') {
+            // Honor the escape.
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        let mut items = caps[1].split("&gt;").map(|i| i.trim());
+        let menu = items.next().unwrap_or_default();
+
+        let mut submenus: Vec<&str> = items.collect();
+        let menuitem = submenus.pop();
+
+        let params = MenuRenderParams {
+            menu,
+            submenus,
+            menuitem,
+            parser: self.0,
+        };
+
+        self.0.renderer.render_menu(&params, dest);
     }
-    */
 }
 
 static INLINE_IMAGE_MACRO: LazyLock<Regex> = LazyLock::new(|| {
@@ -206,6 +445,260 @@ fn normalize_text_lf_escaped_bracket(text: &str) -> String {
     text.replace("\n", " ").replace("\\]", "]")
 }
 
+static INLINE_INDEXTERM_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?sx)
+        \\?                         # optional escape
+        (?:
+            (indexterm2?)           # (1) macro name
+            :\[
+            (.*?[^\\])              # (2) attrlist (ends with a non-escaped char)
+            \]
+          |
+            \(\(
+            (.+?)                   # (3) text of shorthand form
+            \)\)
+            (\)*)                   # (4) any additional closing round brackets
+        )
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineIndexTermReplacer<'p, 'src> {
+    parser: &'p Parser,
+
+    /// Original source of the content being substituted.
+    original: Span<'src>,
+
+    /// Offset into `original` at which to start looking for the next index
+    /// term.
+    search_from: usize,
+}
+
+impl Replacer for InlineIndexTermReplacer<'_, '_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        let escaped = caps[0].starts_with('\\');
+
+        if let Some(name) = caps.get(1) {
+            if escaped {
+                // Honor the escape.
+                dest.push_str(&caps[0][1..]);
+                return;
+            }
+
+            let text = normalize_text_lf_escaped_bracket(caps[2].trim());
+            let visible = name.as_str() == "indexterm2";
+
+            let (terms, see, see_also) = if text.contains('=') {
+                let attrlist =
+                    Attrlist::parse(Span::new(&text), self.parser, AttrlistContext::Inline)
+                        .item
+                        .item;
+
+                if let Some(primary) = attrlist.nth_attribute(1) {
+                    let terms: Vec<String> = if visible {
+                        vec![primary.value().to_owned()]
+                    } else {
+                        (1..=3)
+                            .map_while(|n| attrlist.nth_attribute(n))
+                            .map(|term| term.value().to_owned())
+                            .collect()
+                    };
+
+                    let see = attrlist
+                        .named_attribute("see")
+                        .map(|see| see.value().to_owned());
+
+                    let see_also = attrlist
+                        .named_attribute("see-also")
+                        .map(|see_also| {
+                            see_also
+                                .value()
+                                .split(',')
+                                .map(|term| term.trim().to_owned())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    (terms, see, see_also)
+                } else {
+                    (vec![text], None, vec![])
+                }
+            } else if visible {
+                (vec![text], None, vec![])
+            } else {
+                (split_simple_csv(&text), None, vec![])
+            };
+
+            self.register(&caps[0], terms, see, see_also, visible, dest);
+            return;
+        }
+
+        // Ruby Asciidoctor uses a negative lookahead to extend the text up to
+        // the last of a sequence of closing round brackets, which isn't
+        // supported by the `regex` crate.
+        let text = format!("{}{}", &caps[3], &caps[4]);
+
+        let mut text = text.as_str();
+        let mut visible = true;
+        let mut before = "";
+        let mut after = "";
+
+        if escaped {
+            // Escape concealed index term, but process nested visible index term.
+            if text.starts_with('(') && text.ends_with(')') {
+                text = &text[1..text.len() - 1];
+                before = "(";
+                after = ")";
+            } else {
+                dest.push_str(&caps[0][1..]);
+                return;
+            }
+        } else if let Some(rest) = text.strip_prefix('(') {
+            if let Some(inner) = rest.strip_suffix(')') {
+                text = inner;
+                visible = false;
+            } else {
+                text = rest;
+                before = "(";
+            }
+        } else if let Some(rest) = text.strip_suffix(')') {
+            text = rest;
+            after = ")";
+        }
+
+        let text = text.trim().replace('\n', " ");
+
+        let (text, see, see_also) = if !text.contains(";&") {
+            (text.as_str(), None, vec![])
+        } else if let Some((text, see)) = text.split_once("&gt;&gt;") {
+            (text.trim_end(), Some(see.trim().to_owned()), vec![])
+        } else if text.contains("&amp;&gt;") {
+            let mut parts = text.split("&amp;&gt;");
+            let text = parts.next().unwrap_or_default().trim_end();
+            let see_also = parts.map(|term| term.trim().to_owned()).collect();
+            (text, None, see_also)
+        } else {
+            (text.as_str(), None, vec![])
+        };
+
+        let terms = if visible {
+            vec![text.to_owned()]
+        } else {
+            split_simple_csv(text)
+        };
+
+        dest.push_str(before);
+        self.register(&caps[0], terms, see, see_also, visible, dest);
+        dest.push_str(after);
+    }
+}
+
+impl InlineIndexTermReplacer<'_, '_> {
+    /// Registers the index term with the parser and renders it.
+    fn register(
+        &mut self,
+        matched: &str,
+        terms: Vec<String>,
+        see: Option<String>,
+        see_also: Vec<String>,
+        visible: bool,
+        dest: &mut String,
+    ) {
+        let params = IndexTermRenderParams {
+            terms: &terms,
+            see: see.as_deref(),
+            see_also: &see_also,
+            visible,
+            parser: self.parser,
+        };
+
+        self.parser.renderer.render_index_term(&params, dest);
+
+        let (range, source_text) = self.locate(matched);
+
+        self.parser.index_terms.borrow_mut().push(PendingIndexTerm {
+            terms,
+            see,
+            see_also,
+            visible,
+            range,
+            source_text,
+        });
+    }
+
+    /// Finds the location of the next index term in the original source.
+    ///
+    /// Earlier substitutions may have altered the text of the index term, in
+    /// which case it can't be found and the entire original source of this
+    /// content is used instead.
+    fn locate(&mut self, matched: &str) -> (Range<usize>, String) {
+        let data = self.original.data();
+        let offset = self.original.byte_offset();
+
+        if let Some(start) = data
+            .get(self.search_from..)
+            .and_then(|rest| rest.find(matched))
+            .map(|index| self.search_from + index)
+        {
+            let end = start + matched.len();
+            self.search_from = end;
+            (offset + start..offset + end, matched.to_owned())
+        } else {
+            (offset..offset + data.len(), data.to_owned())
+        }
+    }
+}
+
+/// Splits a comma-separated list of values, trimming each value.
+///
+/// If the text contains double quotes, commas between a pair of double quotes
+/// are not treated as separators and the double quotes are removed.
+fn split_simple_csv(text: &str) -> Vec<String> {
+    if text.is_empty() {
+        return vec![];
+    }
+
+    if !text.contains('"') {
+        let mut values: Vec<&str> = text.split(',').collect();
+
+        // Like Ruby's `String#split`, ignore any trailing empty values.
+        while values.last().is_some_and(|value| value.is_empty()) {
+            values.pop();
+        }
+
+        return values
+            .into_iter()
+            .map(|value| value.trim().to_owned())
+            .collect();
+    }
+
+    let mut values = vec![];
+    let mut accum = String::new();
+    let mut quote_open = false;
+
+    for c in text.chars() {
+        match c {
+            ',' if !quote_open => {
+                values.push(accum.trim().to_owned());
+                accum.clear();
+            }
+            '"' => {
+                quote_open = !quote_open;
+            }
+            _ => {
+                accum.push(c);
+            }
+        }
+    }
+
+    values.push(accum.trim().to_owned());
+    values
+}
+
 static INLINE_LINK: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
     Regex::new(
@@ -702,63 +1195,450 @@ impl Replacer for InlineEmailReplacer<'_> {
 static INLINE_ANCHOR: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
     Regex::new(
-        r#"(?x)
-    (\\)?                           # (1) optional escape backslash before the anchor
-
-    (?:                             # either [[id[, reftext]]] OR anchor:id[reftext]
-      \[\[                          # [[
-        (                           # (2) anchor id for [[...]]
-          [\p{Alphabetic}_:]        #     first char: letter, '_' or ':'
-          [\p{Alphabetic}\p{Nd}_\-:.]*  # rest: letters/digits/_ or '-', ':', '.'
-        )
-        (?: , \s* (.+?) )?          # (3) optional reftext after comma (lazy)
-        \]\]                        # ]]
+        r#"(?x)
+    (\\)?                           # (1) optional escape backslash before the anchor
+
+    (?:                             # either [[id[, reftext]]] OR anchor:id[reftext]
+      \[\[                          # [[
+        (                           # (2) anchor id for [[...]]
+          [\p{Alphabetic}_:]        #     first char: letter, '_' or ':'
+          [\p{Alphabetic}\p{Nd}_\-:.]*  # rest: letters/digits/_ or '-', ':', '.'
+        )
+        (?: , \s* (.+?) )?          # (3) optional reftext after comma (lazy)
+        \]\]                        # ]]
+      |
+        anchor:                     # 'anchor:' prefix
+        (                           # (4) anchor id for anchor:...[]
+          [\p{Alphabetic}_:]        #     first char: letter, '_' or ':'
+          [\p{Alphabetic}\p{Nd}_\-:.]*  # rest: letters/digits/_ or '-', ':', '.'
+        )                           # end (4)
+        \[                          # opening '[' for reftext
+          (?:                       # either empty [] or a non-empty reftext
+            \]                      #   empty -> immediate ']'
+          |                         #   OR
+            (.*?[^\\])              # (5) non-empty reftext (ends with a non-escaped char)
+            \]                      #   closing ']'
+          )
+    )                               # end alternation
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineAnchorReplacer<'p>(&'p Parser);
+
+impl Replacer for InlineAnchorReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps.get(1).is_some() {
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        // NOTE: reftext is only relevant for DocBook output;
+        // in that case it is used as value of xreflabel attribute.
+
+        let (id, reftext) = if let Some(id) = caps.get(2) {
+            (id.as_str(), caps.get(3).map(|m| m.as_str().to_string()))
+        } else {
+            (
+                &caps[4],
+                caps.get(5)
+                    .map(|m| m.as_str().to_string().replace("\\]", "]")),
+            )
+        };
+
+        self.0.renderer.render_anchor(id, reftext, dest);
+    }
+}
+
+/// Matches an inline cross reference, in either the shorthand (`<<id>>`) or
+/// macro (`xref:id[]`) form. (Note that the `<` and `>` characters will have
+/// already been escaped by the special characters substitution.)
+///
+/// ## Examples
+///
+/// * `<<id>>`
+/// * `<<id,Reference Text>>`
+/// * `xref:id[]`
+/// * `xref:other.adoc#id[Reference Text]`
+static INLINE_XREF_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?xs)
+    \\?                             # optional escape backslash
+    (?:
+      &lt;&lt;                      # <<
+        ([\w\#/.:{] .*?)            # (1) refid (and optional text) for <<...>>
+      &gt;&gt;                      # >>
+    |
+      xref:                         # 'xref:' prefix
+        ([\w\#/.:{] .*?)            # (2) refid for xref:...[]
+      \[                            # opening '[' for link text
+        (?:
+          \]                        #   empty -> immediate ']'
+        |                           #   OR
+          (.*?[^\\])                # (3) non-empty link text (ends with a non-escaped char)
+          \]                        #   closing ']'
+        )
+    )
+        "#,
+    )
+    .unwrap()
+});
+
+const ASCIIDOC_EXTENSIONS: &[&str] = &[".adoc", ".asciidoc", ".asc", ".ad", ".txt"];
+
+#[derive(Debug)]
+struct InlineXrefReplacer<'p>(&'p Parser);
+
+impl Replacer for InlineXrefReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps[0].starts_with('\\') {
+            // Honor the escape.
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        let parser = self.0;
+
+        let mut attrlist: Option<Attrlist<'_>> = None;
+        let link_text_for_attrlist: String;
+        let span_for_attrlist: Span<'_>;
+
+        let (refid, link_text, is_macro) = if let Some(refid) = caps.get(1) {
+            match refid.as_str().split_once(',') {
+                Some((refid, link_text)) => {
+                    let link_text = link_text.trim_start();
+                    (
+                        refid.to_owned(),
+                        (!link_text.is_empty()).then(|| link_text.to_owned()),
+                        false,
+                    )
+                }
+                None => (refid.as_str().to_owned(), None, false),
+            }
+        } else {
+            let mut link_text = caps.get(3).map(|m| m.as_str().replace("\\]", "]"));
+
+            if let Some(lt) = &link_text
+                && lt.contains('=')
+            {
+                link_text_for_attrlist = lt.replace('\n', " ");
+                span_for_attrlist = Span::new(&link_text_for_attrlist);
+
+                let (lt, attrs) = extract_attributes_from_text(&span_for_attrlist, parser, None);
+                link_text = (!lt.is_empty()).then_some(lt);
+                attrlist = Some(attrs);
+            }
+
+            (caps[2].to_owned(), link_text, true)
+        };
+
+        let mut path: Option<String> = None;
+        let mut fragment: Option<String> = None;
+        let mut target: Option<String> = None;
+        let mut src2src: Option<String> = None;
+
+        if let Some(hash_idx) = refid.find('#') {
+            if hash_idx > 0 {
+                let (p, f) = (&refid[0..hash_idx], &refid[hash_idx + 1..]);
+                if !f.is_empty() {
+                    fragment = Some(f.to_owned());
+                }
+
+                if is_macro {
+                    if let Some(p) = p.strip_suffix(".adoc") {
+                        src2src = Some(p.to_owned());
+                        path = Some(p.to_owned());
+                    } else {
+                        if !has_extname(p) {
+                            src2src = Some(p.to_owned());
+                        }
+                        path = Some(p.to_owned());
+                    }
+                } else if ASCIIDOC_EXTENSIONS.iter().any(|ext| p.ends_with(ext)) {
+                    let p = p.rsplit_once('.').map(|(p, _)| p).unwrap_or(p);
+                    src2src = Some(p.to_owned());
+                    path = Some(p.to_owned());
+                } else {
+                    src2src = Some(p.to_owned());
+                    path = Some(p.to_owned());
+                }
+            } else {
+                target = Some(refid.clone());
+                fragment = Some(refid[1..].to_owned());
+            }
+        } else if is_macro {
+            if let Some(p) = refid.strip_suffix(".adoc") {
+                src2src = Some(p.to_owned());
+                path = Some(p.to_owned());
+            } else if has_extname(&refid) {
+                path = Some(refid.clone());
+            } else {
+                fragment = Some(refid.clone());
+            }
+        } else {
+            fragment = Some(refid.clone());
+        }
+
+        let catalog = parser.catalog();
+        let contains_id = |id: &str| catalog.is_some_and(|c| c.contains_id(id));
+
+        let refid: Option<String>;
+
+        if target.is_some() {
+            // Handles: #id
+            refid = fragment.clone();
+        } else if let Some(p) = path {
+            // Handles: path#, path#id, path.adoc#, path.adoc#id, or path.adoc (xref
+            // macro only).
+            let docname = parser.attribute_value("docname");
+
+            if src2src.is_some() && docname.as_maybe_str() == src2src.as_deref() {
+                // The referenced path is the current document.
+                path = None;
+                if let Some(f) = &fragment {
+                    refid = Some(f.clone());
+                    target = Some(format!("#{f}"));
+                } else {
+                    refid = None;
+                    target = Some("#".to_owned());
+                }
+            } else {
+                let relfileprefix = parser.attribute_value("relfileprefix");
+                let relfileprefix = relfileprefix.as_maybe_str().unwrap_or_default();
+
+                let suffix = if src2src.is_some() {
+                    let relfilesuffix = parser.attribute_value("relfilesuffix");
+                    let outfilesuffix = parser.attribute_value("outfilesuffix");
+                    relfilesuffix
+                        .as_maybe_str()
+                        .or(outfilesuffix.as_maybe_str())
+                        .unwrap_or(".html")
+                        .to_owned()
+                } else {
+                    "".to_owned()
+                };
+
+                let full_path = format!("{relfileprefix}{p}{suffix}");
+
+                if let Some(f) = &fragment {
+                    refid = Some(format!("{p}#{f}"));
+                    target = Some(format!("{full_path}#{f}"));
+                } else {
+                    refid = Some(p);
+                    target = Some(full_path.clone());
+                }
+
+                path = Some(full_path);
+            }
+        } else {
+            let f = fragment.clone().unwrap_or_default();
+
+            if contains_id(&f) {
+                // Handles: id
+                refid = Some(f.clone());
+                target = Some(format!("#{f}"));
+            } else if let Some(resolved_id) = (f.contains(' ') || f.to_lowercase() != f)
+                .then(|| catalog.and_then(|c| c.resolve_id(&f)))
+                .flatten()
+            {
+                // Handles: Node Title or Reference Text. Do reverse lookup on
+                // fragment if not a known ID and resembles reftext (contains a
+                // space or uppercase char).
+                refid = Some(resolved_id.clone());
+                target = Some(format!("#{resolved_id}"));
+                fragment = Some(resolved_id);
+            } else {
+                // Handles: id (possibly an invalid reference)
+                refid = Some(f.clone());
+                target = Some(format!("#{f}"));
+            }
+        }
+
+        let text = if let Some(link_text) = link_text {
+            link_text
+        } else if let Some(path) = &path {
+            path.clone()
+        } else if let Some(refid) = &refid {
+            // NOTE: Reference text is resolved while the document is being
+            // parsed, so only IDs registered before this point are known. A
+            // forward reference (an xref to an ID defined later in the
+            // document) falls back to `[id]` as its text.
+            catalog
+                .and_then(|c| c.get_ref(refid))
+                .and_then(|r| r.reftext.clone())
+                .unwrap_or_else(|| format!("[{refid}]"))
+        } else {
+            let doctitle = parser.attribute_value("doctitle");
+            doctitle
+                .as_maybe_str()
+                .map(|t| t.to_owned())
+                .unwrap_or_else(|| "[^top]".to_owned())
+        };
+
+        let attrlist = if let Some(attrlist) = attrlist {
+            attrlist
+        } else {
+            Attrlist::parse(Span::default(), parser, AttrlistContext::Inline)
+                .item
+                .item
+        };
+
+        let params = XrefRenderParams {
+            target: target.unwrap_or_default(),
+            text,
+            refid,
+            path,
+            fragment,
+            attrlist: &attrlist,
+            parser,
+        };
+
+        parser.renderer.render_xref(&params, dest);
+    }
+}
+
+/// Returns `true` if the path has a file extension (i.e., a `.` in the last
+/// path segment).
+fn has_extname(path: &str) -> bool {
+    path.rfind('.')
+        .is_some_and(|last_dot| !path[last_dot..].contains('/'))
+}
+
+/// Matches an inline footnote macro, which is allowed to span multiple lines.
+///
+/// ## Examples
+///
+/// * `footnote:[text]`
+/// * `footnote:id[text]`
+/// * `footnote:id[]`
+/// * `footnoteref:[id,text]` (legacy)
+/// * `footnoteref:[id]` (legacy)
+static INLINE_FOOTNOTE_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?xs)
+    \\?                             # optional escape backslash
+    footnote
+    (?:
+        (ref):                      # (1) legacy 'footnoteref:' form
       |
-        anchor:                     # 'anchor:' prefix
-        (                           # (4) anchor id for anchor:...[]
-          [\p{Alphabetic}_:]        #     first char: letter, '_' or ':'
-          [\p{Alphabetic}\p{Nd}_\-:.]*  # rest: letters/digits/_ or '-', ':', '.'
-        )                           # end (4)
-        \[                          # opening '[' for reftext
-          (?:                       # either empty [] or a non-empty reftext
-            \]                      #   empty -> immediate ']'
-          |                         #   OR
-            (.*?[^\\])              # (5) non-empty reftext (ends with a non-escaped char)
-            \]                      #   closing ']'
-          )
-    )                               # end alternation
+        :([\w-]+)?                  # (2) optional footnote id
+    )
+    \[                              # opening '['
+      (?:                           # either empty [] or non-empty text
+        |
+        (.*?[^\\])                  # (3) text (ends with a non-escaped char)
+      )
+    \]                              # closing ']'
+    (</a>)?                         # (4) if present, this is the text of a link; not a footnote
         "#,
     )
     .unwrap()
 });
 
 #[derive(Debug)]
-struct InlineAnchorReplacer<'p>(&'p Parser);
+struct InlineFootnoteReplacer<'p>(&'p Parser);
 
-impl Replacer for InlineAnchorReplacer<'_> {
+impl Replacer for InlineFootnoteReplacer<'_> {
     fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
-        if caps.get(1).is_some() {
+        if caps.get(4).is_some() {
+            // Ruby Asciidoctor uses a negative lookahead here, which isn't
+            // supported by the `regex` crate.
+            dest.push_str(&caps[0]);
+            return;
+        }
+
+        if caps[0].starts_with('\\') {
+            // Honor the escape.
             dest.push_str(&caps[0][1..]);
             return;
         }
 
-        // NOTE: reftext is only relevant for DocBook output;
-        // in that case it is used as value of xreflabel attribute.
+        let parser = self.0;
 
-        let (id, reftext) = if let Some(id) = caps.get(2) {
-            (id.as_str(), caps.get(3).map(|m| m.as_str().to_string()))
+        let (id, text) = if caps.get(1).is_some() {
+            // TO DO: Warn about deprecated `footnoteref` macro unless in compat mode.
+            let Some(text) = caps.get(3) else {
+                dest.push_str(&caps[0]);
+                return;
+            };
+
+            match text.as_str().split_once(',') {
+                Some((id, text)) => (Some(id), Some(text)),
+                None => (Some(text.as_str()), None),
+            }
         } else {
             (
-                &caps[4],
-                caps.get(5)
-                    .map(|m| m.as_str().to_string().replace("\\]", "]")),
+                caps.get(2).map(|id| id.as_str()),
+                caps.get(3).map(|t| t.as_str()),
             )
         };
 
-        self.0.renderer.render_anchor(id, reftext, dest);
+        let mut footnotes = parser.footnotes.borrow_mut();
+
+        let (type_, index, id, text) = if let Some(id) = id {
+            if let Some(footnote) = footnotes.iter().find(|f| f.id.as_deref() == Some(id)) {
+                (
+                    FootnoteRenderType::Reference,
+                    Some(footnote.index),
+                    None,
+                    footnote.text.clone(),
+                )
+            } else if let Some(text) = text {
+                let index = footnotes.len() + 1;
+                let text = normalize_footnote_text(text);
+
+                footnotes.push(Footnote {
+                    index,
+                    id: Some(id.to_owned()),
+                    text: text.clone(),
+                });
+
+                (FootnoteRenderType::Definition, Some(index), Some(id), text)
+            } else {
+                // TO DO: Warn about invalid footnote reference.
+                (FootnoteRenderType::Unresolved, None, None, id.to_owned())
+            }
+        } else if let Some(text) = text {
+            let index = footnotes.len() + 1;
+            let text = normalize_footnote_text(text);
+
+            footnotes.push(Footnote {
+                index,
+                id: None,
+                text: text.clone(),
+            });
+
+            (FootnoteRenderType::Definition, Some(index), None, text)
+        } else {
+            dest.push_str(&caps[0]);
+            return;
+        };
+
+        // Release the borrow before invoking the renderer.
+        drop(footnotes);
+
+        let params = FootnoteRenderParams {
+            type_,
+            index,
+            id,
+            text: &text,
+            parser,
+        };
+
+        parser.renderer.render_footnote(&params, dest);
     }
 }
 
+/// Trims the footnote text, joins lines, and unescapes closing square
+/// brackets.
+fn normalize_footnote_text(text: &str) -> String {
+    text.trim().replace('\n', " ").replace("\\]", "]")
+}
+
 static URI_SNIFF: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
     Regex::new(r#"^\p{alpha}[\p{alpha}\p{digit}.+-]+:/{0,2}"#).unwrap()
@@ -829,6 +1709,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -887,6 +1769,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -944,6 +1828,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1021,6 +1907,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1080,6 +1968,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1139,6 +2029,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1217,6 +2109,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1281,6 +2175,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1338,6 +2234,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1416,6 +2314,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1494,6 +2394,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1557,6 +2459,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1614,6 +2518,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1671,6 +2577,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1729,6 +2637,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1787,6 +2697,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1844,6 +2756,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1901,6 +2815,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -1958,6 +2874,8 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
@@ -2015,9 +2933,416 @@ mod tests {
                     },
                     warnings: &[],
                     source_map: SourceMap(&[]),
+                    footnotes: &[],
+                    index_terms: &[],
                     catalog: Catalog::default(),
                 }
             );
         }
     }
+
+    mod xref {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{
+            Parser,
+            blocks::{Block, IsBlock},
+            parser::ModificationContext,
+        };
+
+        /// Returns the rendered content of the last simple block in the
+        /// document, descending into sections as needed.
+        fn rendered(parser: &mut Parser, source: &str) -> String {
+            let doc = parser.parse(source);
+
+            let mut block = doc.nested_blocks().last().unwrap();
+            while let Block::Section(section) = block {
+                block = section.nested_blocks().last().unwrap();
+            }
+
+            block.rendered_content().unwrap().to_owned()
+        }
+
+        #[test]
+        fn shorthand_uses_section_title_as_default_text() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "== Install\n\nSee <<_install>> for details."
+                ),
+                r##"See <a href="#_install">Install</a> for details."##
+            );
+        }
+
+        #[test]
+        fn shorthand_with_text() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "== Install\n\nSee <<_install, how to install>>."
+                ),
+                r##"See <a href="#_install">how to install</a>."##
+            );
+        }
+
+        #[test]
+        fn shorthand_with_empty_text() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "== Install\n\nSee <<_install,>>."),
+                r##"See <a href="#_install">Install</a>."##
+            );
+        }
+
+        #[test]
+        fn escaped_shorthand() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "See \\<<_install>>."),
+                "See &lt;&lt;_install&gt;&gt;."
+            );
+        }
+
+        #[test]
+        fn escaped_macro() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "See \\xref:_install[]."),
+                "See xref:_install[]."
+            );
+        }
+
+        #[test]
+        fn unknown_id() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "See <<nowhere>>."),
+                r##"See <a href="#nowhere">[nowhere]</a>."##
+            );
+        }
+
+        #[test]
+        fn forward_reference_falls_back_to_id() {
+            let doc = Parser::default().parse("See <<later>>.\n\n[[later,Later Ref]]\nLater.");
+
+            assert_eq!(
+                doc.nested_blocks()
+                    .next()
+                    .unwrap()
+                    .rendered_content()
+                    .unwrap(),
+                r##"See <a href="#later">[later]</a>."##
+            );
+
+            assert_eq!(
+                doc.catalog().get_ref("later").unwrap().reftext.as_deref(),
+                Some("Later Ref")
+            );
+        }
+
+        #[test]
+        fn explicit_reftext() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "[[tigers,Tigers of the World]]\nTigers.\n\nSee <<tigers>>."
+                ),
+                r##"See <a href="#tigers">Tigers of the World</a>."##
+            );
+        }
+
+        #[test]
+        fn section_anchor_reftext() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "[[sec-a,Alpha Ref]]\n== Section A\n\nSee <<sec-a>>."
+                ),
+                r##"See <a href="#sec-a">Alpha Ref</a>."##
+            );
+        }
+
+        #[test]
+        fn section_anchor_reftext_takes_precedence_over_reftext_attribute() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "[[sec-a,Alpha Ref]]\n[reftext=Other Ref]\n== Section A\n\nSee <<sec-a>>."
+                ),
+                r##"See <a href="#sec-a">Alpha Ref</a>."##
+            );
+
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "[#sec-a,reftext=Other Ref]\n== Section A\n\nSee <<sec-a>>."
+                ),
+                r##"See <a href="#sec-a">Other Ref</a>."##
+            );
+        }
+
+        #[test]
+        fn nested_list_anchor_reftext() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "* a\n[[nested,Nested Ref]]\n** b\n\nSee <<nested>>."
+                ),
+                r##"See <a href="#nested">Nested Ref</a>."##
+            );
+        }
+
+        #[test]
+        fn natural_xref() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "== Install Guide\n\nSee <<Install Guide>>."
+                ),
+                r##"See <a href="#_install_guide">Install Guide</a>."##
+            );
+        }
+
+        #[test]
+        fn leading_hash() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "== Install\n\nSee <<#_install>>."),
+                r##"See <a href="#_install">Install</a>."##
+            );
+        }
+
+        #[test]
+        fn macro_form() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "== Install\n\nSee xref:_install[] or xref:_install[the install [guide\\]]."
+                ),
+                r##"See <a href="#_install">Install</a> or <a href="#_install">the install [guide]</a>."##
+            );
+        }
+
+        #[test]
+        fn macro_form_with_attributes() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "== Install\n\nSee xref:_install[Install,role=next]."
+                ),
+                r##"See <a href="#_install" class="next">Install</a>."##
+            );
+        }
+
+        #[test]
+        fn other_document() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "See xref:guide.adoc#install[] or <<guide#install,the guide>>."
+                ),
+                r##"See <a href="guide.html#install">guide.html</a> or <a href="guide.html#install">the guide</a>."##
+            );
+        }
+
+        #[test]
+        fn other_document_without_fragment() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "See xref:guide.adoc[] or <<guide.asciidoc#>>."
+                ),
+                r##"See <a href="guide.html">guide.html</a> or <a href="guide.html">guide.html</a>."##
+            );
+        }
+
+        #[test]
+        fn non_asciidoc_document() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "See xref:report.pdf[the report]."),
+                r##"See <a href="report.pdf">the report</a>."##
+            );
+        }
+
+        #[test]
+        fn relfileprefix_and_relfilesuffix() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    ":relfileprefix: ../\n:relfilesuffix: /\n\nSee xref:guide.adoc#install[]."
+                ),
+                r##"See <a href="../guide/#install">../guide/</a>."##
+            );
+        }
+
+        #[test]
+        fn outfilesuffix() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    ":outfilesuffix: .htm\n\nSee xref:guide.adoc#install[]."
+                ),
+                r##"See <a href="guide.htm#install">guide.htm</a>."##
+            );
+        }
+
+        #[test]
+        fn window_attribute_for_other_document() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "See xref:guide.adoc[the guide,window=_blank]."
+                ),
+                r##"See <a href="guide.html" target="_blank" rel="noopener">the guide</a>."##
+            );
+        }
+
+        #[test]
+        fn current_document() {
+            let mut parser = Parser::default().with_intrinsic_attribute(
+                "docname",
+                "manual",
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(
+                rendered(
+                    &mut parser,
+                    "= The Manual\n\n== Install\n\nSee xref:manual.adoc#_install[] or <<manual#>>."
+                ),
+                r##"See <a href="#_install">Install</a> or <a href="#">The Manual</a>."##
+            );
+
+            let mut parser = Parser::default().with_intrinsic_attribute(
+                "docname",
+                "manual",
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(
+                rendered(&mut parser, "See xref:manual.adoc[]."),
+                r##"See <a href="#">[^top]</a>."##
+            );
+        }
+    }
+
+    mod footnote {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{Parser, blocks::IsBlock};
+
+        #[test]
+        fn escaped_footnote_macro() {
+            let doc = Parser::default().parse("Not a footnote: \\footnote:[text].");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "Not a footnote: footnote:[text]."
+            );
+
+            assert_eq!(doc.footnotes().count(), 0);
+        }
+
+        #[test]
+        fn ignores_footnote_macro_in_link_text() {
+            let doc = Parser::default().parse("See https://example.com[about footnote:[text\\]].");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                r#"See <a href="https://example.com">about footnote:[text]</a>."#
+            );
+
+            assert_eq!(doc.footnotes().count(), 0);
+        }
+    }
+
+    mod index_term {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{Parser, blocks::IsBlock, content::macros::split_simple_csv};
+
+        #[test]
+        fn escaped_index_term_macros() {
+            let doc = Parser::default()
+                .parse("Not index terms: \\indexterm:[Tigers] \\indexterm2:[Lions].");
+
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "Not index terms: indexterm:[Tigers] indexterm2:[Lions]."
+            );
+
+            assert_eq!(doc.index_terms().count(), 0);
+        }
+
+        #[test]
+        fn index_term_macro_with_named_attribute_but_no_terms() {
+            let doc = Parser::default().parse("Tigers.indexterm:[see=Big cats]");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), "Tigers.");
+
+            let term = doc.index_terms().next().unwrap();
+            assert_eq!(term.terms, vec!["see=Big cats"]);
+            assert_eq!(term.see, None);
+        }
+
+        #[test]
+        fn index_term_2_macro_with_named_attribute_but_no_term() {
+            let doc = Parser::default().parse("The indexterm2:[see=Big cats] roam.");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), "The see=Big cats roam.");
+
+            let term = doc.index_terms().next().unwrap();
+            assert_eq!(term.terms, vec!["see=Big cats"]);
+            assert!(term.visible);
+        }
+
+        #[test]
+        fn index_term_2_macro_with_single_see_also() {
+            let doc = Parser::default().parse("The indexterm2:[tiger,see-also=Lions] roams.");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), "The tiger roams.");
+
+            let term = doc.index_terms().next().unwrap();
+            assert_eq!(term.terms, vec!["tiger"]);
+            assert_eq!(term.see_also, vec!["Lions"]);
+        }
+
+        #[test]
+        fn escaped_visible_index_term_with_unbalanced_brackets() {
+            let doc = Parser::default().parse("Not an index term: \\(((NIST)).");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "Not an index term: (((NIST))."
+            );
+
+            assert_eq!(doc.index_terms().count(), 0);
+        }
+
+        #[test]
+        fn shorthand_with_other_character_references() {
+            let doc = Parser::default().parse("The ((cats && dogs)) roam.");
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "The cats &amp;&amp; dogs roam."
+            );
+
+            let term = doc.index_terms().next().unwrap();
+            assert_eq!(term.terms, vec!["cats &amp;&amp; dogs"]);
+            assert_eq!(term.see, None);
+            assert!(term.see_also.is_empty());
+        }
+
+        #[test]
+        fn simple_csv() {
+            assert!(split_simple_csv("").is_empty());
+            assert_eq!(split_simple_csv("a, b,,c,,"), vec!["a", "b", "", "c"]);
+            assert_eq!(split_simple_csv(r#"a, "b, c", d"#), vec!["a", "b, c", "d"]);
+        }
+    }
 }
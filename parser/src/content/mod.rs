@@ -3,6 +3,8 @@
 //!
 //! [substitutions]: https://docs.asciidoctor.org/asciidoc/latest/subs/
 
+pub(crate) mod callouts;
+
 mod content;
 pub use content::Content;
 
@@ -10,6 +12,10 @@ mod macros;
 
 pub(crate) mod passthroughs;
 pub(crate) use passthroughs::Passthroughs;
+pub use passthroughs::{InlinePassthrough, PassthroughKind};
+
+mod stem;
+pub use stem::StemNotation;
 
 mod substitution_group;
 pub use substitution_group::SubstitutionGroup;
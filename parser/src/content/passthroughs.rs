@@ -1,18 +1,93 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::{borrow::Cow, ops::Range, sync::LazyLock};
 
 use regex::{Captures, Regex, Replacer};
 
 use crate::{
     Parser, Span,
     attributes::{Attrlist, AttrlistContext},
-    content::{Content, SubstitutionGroup},
+    content::{Content, StemNotation, SubstitutionGroup, SubstitutionStep},
     parser::{QuoteScope, QuoteType},
 };
 
+/// Identifies the syntax that was used to mark an [inline passthrough].
+///
+/// [inline passthrough]: https://docs.asciidoctor.org/asciidoc/latest/pass/pass-macro/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PassthroughKind {
+    /// Constrained single plus passthrough (`+text+` or `[role]+text+`).
+    SinglePlus,
+
+    /// Unconstrained double plus passthrough (`++text++`).
+    DoublePlus,
+
+    /// Triple plus passthrough (`+++text+++`).
+    TriplePlus,
+
+    /// Double dollar passthrough (`$$text$$`).
+    DoubleDollar,
+
+    /// Inline pass macro (`pass:[text]` or `pass:q,a[text]`).
+    PassMacro,
+
+    /// Legacy monospace passthrough (`` [x-]`text` ``).
+    LegacyMonospace,
+
+    /// Inline STEM macro (`stem:[text]`, `asciimath:[text]`, or
+    /// `latexmath:[text]`) using the given notation.
+    Stem(StemNotation),
+}
+
+/// Describes an inline passthrough that was found in and restored to a
+/// [`Content`].
+///
+/// The text of a passthrough is protected from the substitutions applied to
+/// the surrounding content; only the passthrough's own substitutions (if any)
+/// are applied to it. This makes it possible to find content such as raw HTML
+/// snippets or STEM expressions within the rendered text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InlinePassthrough {
+    kind: PassthroughKind,
+    text: String,
+    subs: SubstitutionGroup,
+    rendered_range: Range<usize>,
+}
+
+impl InlinePassthrough {
+    /// Returns the syntax that was used to mark this passthrough.
+    pub fn kind(&self) -> PassthroughKind {
+        self.kind
+    }
+
+    /// Returns the text enclosed by the passthrough before its substitutions
+    /// were applied.
+    ///
+    /// Any passthroughs nested within this one are reported separately and
+    /// are represented here by internal placeholders.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the substitutions that were applied to the passthrough text.
+    pub fn subs(&self) -> &SubstitutionGroup {
+        &self.subs
+    }
+
+    /// Returns the byte range of the passthrough's output within
+    /// [`Content::rendered()`].
+    ///
+    /// If the passthrough is nested inside another passthrough (e.g.,
+    /// `stem:[+x+]`), this range lies within the range of the outer
+    /// passthrough.
+    pub fn rendered_range(&self) -> Range<usize> {
+        self.rendered_range.clone()
+    }
+}
+
 /// Saves the content of one passthrough (`+++` or similarly bracketed) passage
 /// for later re-expansion.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct Passthrough {
+    pub(crate) kind: PassthroughKind,
     pub(crate) text: String,
     pub(crate) subs: SubstitutionGroup,
     pub(crate) type_: Option<QuoteType>,
@@ -25,7 +100,7 @@ pub(crate) struct Passthrough {
 pub(crate) struct Passthroughs(pub(crate) Vec<Passthrough>);
 
 impl Passthroughs {
-    pub(crate) fn extract_from(content: &mut Content<'_>) -> Self {
+    pub(crate) fn extract_from(content: &mut Content<'_>, parser: &Parser) -> Self {
         let mut passthroughs = Self(vec![]);
 
         // TRANSLATION GUIDE:
@@ -55,25 +130,47 @@ impl Passthroughs {
             }
         }
 
-        // TO DO (#261): When implementing STEM macros, look for the block that starts
-        // with `text.gsub InlineStemMacroRx do` in Ruby Asciidoctor's substitutors.rb
-        // file.
+        {
+            let text = content.rendered.as_ref();
+            if text.contains(':') && (text.contains("stem:") || text.contains("math:")) {
+                let replacer = InlineStemMacroReplacer(&mut passthroughs, parser);
+
+                if let Cow::Owned(new_result) = INLINE_STEM_MACRO.replace_all(text, replacer) {
+                    content.rendered = new_result.into();
+                }
+            }
+        }
 
         passthroughs
     }
 
+    /// Restores passthroughs in the content they were extracted from and
+    /// records where each passthrough ended up in the rendered text.
     pub(crate) fn restore_to(&self, content: &mut Content<'_>, parser: &Parser) {
-        if self.0.is_empty() {
-            return;
+        let mut found: Vec<InlinePassthrough> = vec![];
+
+        if !self.0.is_empty() {
+            let replacer = PassthroughRestoreReplacer(self, parser, Some(&mut found));
+
+            if let Cow::Owned(new_result) =
+                PASS_WITH_INDEX.replace_all(content.rendered().as_ref(), replacer)
+            {
+                content.rendered = new_result.into();
+            }
         }
 
-        let replacer = PassthroughRestoreReplacer(self, parser);
+        content.passthroughs = found;
+    }
 
-        if let Cow::Owned(new_result) =
-            PASS_WITH_INDEX.replace_all(content.rendered().as_ref(), replacer)
-        {
-            content.rendered = new_result.into();
+    /// Restores passthroughs in text that was derived from the content these
+    /// passthroughs were extracted from (e.g., the text of a footnote).
+    pub(crate) fn restore_in<'a>(&self, text: &'a str, parser: &Parser) -> Cow<'a, str> {
+        if self.0.is_empty() {
+            return Cow::Borrowed(text);
         }
+
+        let replacer = PassthroughRestoreReplacer(self, parser, None);
+        PASS_WITH_INDEX.replace_all(text, replacer)
     }
 
     pub(super) fn push(&mut self, passthrough: Passthrough, dest: &mut String) {
@@ -146,7 +243,7 @@ impl Replacer for InlinePassMacroReplacer<'_> {
             // ++
             self.handle_quoted_text(caps, 8, dest);
         } else if caps.get(10).is_some() {
-            // %%
+            // $$
             self.handle_quoted_text(caps, 11, dest);
         } else {
             // NOTE: We don't look for nested `pass:[]` macros.
@@ -175,6 +272,7 @@ impl Replacer for InlinePassMacroReplacer<'_> {
 
             self.0.push(
                 Passthrough {
+                    kind: PassthroughKind::PassMacro,
                     text,
                     subs,
                     type_: None,
@@ -201,6 +299,12 @@ impl InlinePassMacroReplacer<'_> {
         let quoted_text = caps.get(5).or_else(|| caps.get(8)).or_else(|| caps.get(11));
         let quoted_text = quoted_text.map(|m| m.as_str()).unwrap_or_default();
 
+        let kind = match boundary {
+            "+++" => PassthroughKind::TriplePlus,
+            "++" => PassthroughKind::DoublePlus,
+            _ => PassthroughKind::DoubleDollar,
+        };
+
         let mut old_behavior = false;
 
         let attrlist: Option<String> = if let Some(attrlist) = caps.get(2) {
@@ -248,6 +352,7 @@ impl InlinePassMacroReplacer<'_> {
         let passthrough = if let Some(attrlist) = attrlist {
             if old_behavior {
                 Passthrough {
+                    kind,
                     text: caps
                         .get(quoted_text_index)
                         .map(|m| m.as_str().to_owned())
@@ -258,6 +363,7 @@ impl InlinePassMacroReplacer<'_> {
                 }
             } else {
                 Passthrough {
+                    kind,
                     text: caps
                         .get(quoted_text_index)
                         .map(|m| m.as_str().to_owned())
@@ -265,7 +371,7 @@ impl InlinePassMacroReplacer<'_> {
                     subs: if boundary == "+++" {
                         SubstitutionGroup::None
                     } else {
-                        SubstitutionGroup::Verbatim
+                        SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters])
                     },
                     type_: Some(QuoteType::Unquoted),
                     attrlist: Some(attrlist),
@@ -273,6 +379,7 @@ impl InlinePassMacroReplacer<'_> {
             }
         } else {
             Passthrough {
+                kind,
                 text: caps
                     .get(quoted_text_index)
                     .map(|m| m.as_str().to_owned())
@@ -280,7 +387,7 @@ impl InlinePassMacroReplacer<'_> {
                 subs: if boundary == "+++" {
                     SubstitutionGroup::None
                 } else {
-                    SubstitutionGroup::Verbatim
+                    SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters])
                 },
                 type_: None,
                 attrlist: None,
@@ -405,7 +512,7 @@ impl Replacer for InlinePassReplacer<'_> {
         let subs = if attrlist_body.is_some() && old_behavior && format_mark != '`' {
             SubstitutionGroup::Normal
         } else {
-            SubstitutionGroup::Verbatim
+            SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters])
         };
 
         let type_ = if attrlist_body.is_some() {
@@ -418,8 +525,15 @@ impl Replacer for InlinePassReplacer<'_> {
             None
         };
 
+        let kind = if format_mark == '`' {
+            PassthroughKind::LegacyMonospace
+        } else {
+            PassthroughKind::SinglePlus
+        };
+
         self.0.push(
             Passthrough {
+                kind,
                 text: quoted_text.to_string(),
                 subs,
                 type_,
@@ -430,8 +544,83 @@ impl Replacer for InlinePassReplacer<'_> {
     }
 }
 
+/// Matches an inline STEM macro, which may span multiple lines.
+///
+/// ## Examples
+///
+/// * `stem:[sqrt(4) = 2]`
+/// * `asciimath:[sqrt(4) = 2]`
+/// * `latexmath:attributes[{equation}]`
+static INLINE_STEM_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?xs)
+            \\?                         # Optional escape
+            (stem|(?:latex|ascii)math)  # Group 1: macro name
+            :
+            ([a-z]+(?:,[a-z-]+)*)?      # Group 2: optional substitution step list
+            \[
+                (.*?[^\\])              # Group 3: content
+                                        # (avoiding escape of trailing bracket)
+            \]
+        "#,
+    )
+    .unwrap()
+});
+
+#[derive(Debug)]
+struct InlineStemMacroReplacer<'p>(&'p mut Passthroughs, &'p Parser);
+
+impl Replacer for InlineStemMacroReplacer<'_> {
+    fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
+        if caps[0].starts_with('\\') {
+            // Honor the escape.
+            dest.push_str(&caps[0][1..]);
+            return;
+        }
+
+        let Some(notation) = StemNotation::from_name(&caps[1], self.1) else {
+            dest.push_str(&caps[0]);
+            return;
+        };
+
+        let mut text = caps[3].replace("\\]", "]");
+
+        // Drop enclosing `$` signs around LaTeX math for backward compatibility
+        // with AsciiDoc.py.
+        if notation == StemNotation::LatexMath
+            && text.len() >= 2
+            && text.starts_with('$')
+            && text.ends_with('$')
+        {
+            text = text[1..text.len() - 1].to_owned();
+        }
+
+        let subs = match caps.get(2) {
+            Some(subs) => SubstitutionGroup::from_custom_string(None, subs.as_str())
+                .unwrap_or(SubstitutionGroup::None),
+            None => SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
+        };
+
+        self.0.push(
+            Passthrough {
+                kind: PassthroughKind::Stem(notation),
+                text,
+                subs,
+                type_: Some(notation.into()),
+                attrlist: None,
+            },
+            dest,
+        );
+    }
+}
+
 #[derive(Debug)]
-struct PassthroughRestoreReplacer<'p>(&'p Passthroughs, &'p Parser);
+struct PassthroughRestoreReplacer<'p>(
+    &'p Passthroughs,
+    &'p Parser,
+    Option<&'p mut Vec<InlinePassthrough>>,
+);
 
 impl Replacer for PassthroughRestoreReplacer<'_> {
     fn replace_append(&mut self, caps: &Captures<'_>, dest: &mut String) {
@@ -473,9 +662,12 @@ impl Replacer for PassthroughRestoreReplacer<'_> {
             subbed_text.rendered = new_text.into();
         }
 
+        let start = dest.len();
+        let mut nested: Option<Vec<InlinePassthrough>> = self.2.as_ref().map(|_| vec![]);
+
         if subbed_text.rendered().contains('\u{96}') {
             // Recursively apply passthrough replacement and write the result.
-            let replacer = PassthroughRestoreReplacer(self.0, self.1);
+            let replacer = PassthroughRestoreReplacer(self.0, self.1, nested.as_mut());
 
             let new_result = PASS_WITH_INDEX.replace_all(subbed_text.rendered().as_ref(), replacer);
 
@@ -483,6 +675,23 @@ impl Replacer for PassthroughRestoreReplacer<'_> {
         } else {
             dest.push_str(subbed_text.rendered());
         }
+
+        if let Some(found) = self.2.as_mut() {
+            found.push(InlinePassthrough {
+                kind: pass.kind,
+                text: pass.text.clone(),
+                subs: pass.subs.clone(),
+                rendered_range: start..dest.len(),
+            });
+
+            // Nested passthroughs were located relative to the text of this
+            // passthrough.
+            found.extend(nested.into_iter().flatten().map(|mut inner| {
+                inner.rendered_range =
+                    inner.rendered_range.start + start..inner.rendered_range.end + start;
+                inner
+            }));
+        }
     }
 }
 
@@ -495,7 +704,10 @@ mod tests {
     use crate::{
         Parser,
         blocks::SimpleBlockStyle,
-        content::{Passthroughs, SubstitutionGroup, SubstitutionStep, passthroughs::Passthrough},
+        content::{
+            PassthroughKind, Passthroughs, SubstitutionGroup, SubstitutionStep,
+            passthroughs::Passthrough,
+        },
         parser::ModificationContext,
         tests::prelude::*,
     };
@@ -539,7 +751,7 @@ mod tests {
     fn adds_warning_text_for_unresolved_passthrough_id() {
         let mut content =
             crate::content::Content::from(crate::Span::new("pass:q,a[*<{backend}>*]"));
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -557,6 +769,7 @@ mod tests {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: "*<{backend}>*".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::Quotes,
@@ -592,4 +805,132 @@ mod tests {
             }
         );
     }
+
+    mod inline_passthroughs {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{
+            Parser,
+            content::{PassthroughKind, StemNotation, SubstitutionGroup, SubstitutionStep},
+            parser::ModificationContext,
+        };
+
+        fn apply_normal_subs(source: &str) -> crate::content::Content<'_> {
+            let mut content = crate::content::Content::from(crate::Span::new(source));
+            SubstitutionGroup::Normal.apply(&mut content, &Parser::default(), None);
+            content
+        }
+
+        #[test]
+        fn none() {
+            let content = apply_normal_subs("No passthroughs *here*.");
+            assert_eq!(content.passthroughs().next(), None);
+        }
+
+        #[test]
+        fn all_kinds() {
+            let content = apply_normal_subs(
+                "a +<b>+ c ++<d>++ e +++<u>f</u>+++ g $$<h>$$ i pass:q[*<j>*] k [x-]`<l>`",
+            );
+
+            assert_eq!(
+                content.rendered(),
+                "a &lt;b&gt; c &lt;d&gt; e <u>f</u> g &lt;h&gt; i <strong><j></strong> k <code>&lt;l&gt;</code>"
+            );
+
+            let found: Vec<(PassthroughKind, &str, &str)> = content
+                .passthroughs()
+                .map(|pass| {
+                    (
+                        pass.kind(),
+                        pass.text(),
+                        &content.rendered()[pass.rendered_range()],
+                    )
+                })
+                .collect();
+
+            assert_eq!(
+                found,
+                vec![
+                    (PassthroughKind::SinglePlus, "<b>", "&lt;b&gt;"),
+                    (PassthroughKind::DoublePlus, "<d>", "&lt;d&gt;"),
+                    (PassthroughKind::TriplePlus, "<u>f</u>", "<u>f</u>"),
+                    (PassthroughKind::DoubleDollar, "<h>", "&lt;h&gt;"),
+                    (PassthroughKind::PassMacro, "*<j>*", "<strong><j></strong>"),
+                    (
+                        PassthroughKind::LegacyMonospace,
+                        "<l>",
+                        "<code>&lt;l&gt;</code>"
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn pass_macro_subs() {
+            let content = apply_normal_subs("pass:q,a[*{empty}x*] and pass:[y]");
+
+            let subs: Vec<&SubstitutionGroup> =
+                content.passthroughs().map(|pass| pass.subs()).collect();
+
+            assert_eq!(
+                subs,
+                vec![
+                    &SubstitutionGroup::Custom(vec![
+                        SubstitutionStep::Quotes,
+                        SubstitutionStep::AttributeReferences,
+                    ]),
+                    &SubstitutionGroup::None,
+                ]
+            );
+        }
+
+        #[test]
+        fn nested_in_stem() {
+            let mut content = crate::content::Content::from(crate::Span::new("x stem:[+1+] y"));
+
+            let parser = Parser::default().with_intrinsic_attribute(
+                "stem",
+                "",
+                ModificationContext::Anywhere,
+            );
+
+            SubstitutionGroup::Normal.apply(&mut content, &parser, None);
+
+            assert_eq!(content.rendered(), "x \\$1\\$ y");
+
+            let found: Vec<(PassthroughKind, &str, &str)> = content
+                .passthroughs()
+                .map(|pass| {
+                    (
+                        pass.kind(),
+                        pass.text(),
+                        &content.rendered()[pass.rendered_range()],
+                    )
+                })
+                .collect();
+
+            assert_eq!(
+                found,
+                vec![
+                    (
+                        PassthroughKind::Stem(StemNotation::AsciiMath),
+                        "\u{96}0\u{97}",
+                        "\\$1\\$"
+                    ),
+                    (PassthroughKind::SinglePlus, "1", "1"),
+                ]
+            );
+        }
+
+        #[test]
+        fn no_callouts_in_inline_passthrough() {
+            let content = apply_normal_subs("++a <1>++ and +b <2>+ and $$c <3>$$");
+
+            assert_eq!(
+                content.rendered(),
+                "a &lt;1&gt; and b &lt;2&gt; and c &lt;3&gt;"
+            );
+        }
+    }
 }
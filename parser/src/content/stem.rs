@@ -0,0 +1,142 @@
+use crate::{Parser, parser::QuoteType};
+
+/// Identifies the notation in which a [STEM] (science, technology,
+/// engineering, and math) expression is written.
+///
+/// The generic `stem:[]` macro and `[stem]` block style use the notation
+/// selected by the `stem` document attribute. The `asciimath:[]` and
+/// `latexmath:[]` macros (and the corresponding block styles) always use the
+/// notation for which they are named.
+///
+/// [STEM]: https://docs.asciidoctor.org/asciidoc/latest/stem/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StemNotation {
+    /// [AsciiMath](https://asciimath.org/) notation.
+    AsciiMath,
+
+    /// LaTeX math notation.
+    LatexMath,
+}
+
+impl StemNotation {
+    /// Returns the notation selected by the `stem` document attribute.
+    ///
+    /// The values `latexmath`, `latex`, and `tex` select
+    /// [`StemNotation::LatexMath`]; any other value (or no value) selects
+    /// [`StemNotation::AsciiMath`].
+    pub(crate) fn from_document(parser: &Parser) -> Self {
+        match parser.attribute_value("stem").as_maybe_str() {
+            Some("latexmath" | "latex" | "tex") => Self::LatexMath,
+            _ => Self::AsciiMath,
+        }
+    }
+
+    /// Returns the notation for a STEM macro name or block style (`stem`,
+    /// `asciimath`, or `latexmath`) or `None` if the name is not recognized.
+    pub(crate) fn from_name(name: &str, parser: &Parser) -> Option<Self> {
+        match name {
+            "stem" => Some(Self::from_document(parser)),
+            "asciimath" => Some(Self::AsciiMath),
+            "latexmath" => Some(Self::LatexMath),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of this notation (`asciimath` or `latexmath`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AsciiMath => "asciimath",
+            Self::LatexMath => "latexmath",
+        }
+    }
+}
+
+impl From<StemNotation> for QuoteType {
+    fn from(notation: StemNotation) -> Self {
+        match notation {
+            StemNotation::AsciiMath => Self::AsciiMath,
+            StemNotation::LatexMath => Self::LatexMath,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        Parser,
+        content::StemNotation,
+        parser::{ModificationContext, QuoteType},
+    };
+
+    #[test]
+    fn from_document() {
+        assert_eq!(
+            StemNotation::from_document(&Parser::default()),
+            StemNotation::AsciiMath
+        );
+
+        for (value, notation) in [
+            ("", StemNotation::AsciiMath),
+            ("asciimath", StemNotation::AsciiMath),
+            ("bogus", StemNotation::AsciiMath),
+            ("latexmath", StemNotation::LatexMath),
+            ("latex", StemNotation::LatexMath),
+            ("tex", StemNotation::LatexMath),
+        ] {
+            let parser = Parser::default().with_intrinsic_attribute(
+                "stem",
+                value,
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(StemNotation::from_document(&parser), notation);
+        }
+    }
+
+    #[test]
+    fn from_name() {
+        let parser = Parser::default().with_intrinsic_attribute(
+            "stem",
+            "tex",
+            ModificationContext::Anywhere,
+        );
+
+        assert_eq!(
+            StemNotation::from_name("stem", &parser),
+            Some(StemNotation::LatexMath)
+        );
+
+        assert_eq!(
+            StemNotation::from_name("asciimath", &parser),
+            Some(StemNotation::AsciiMath)
+        );
+
+        assert_eq!(
+            StemNotation::from_name("latexmath", &parser),
+            Some(StemNotation::LatexMath)
+        );
+
+        assert_eq!(StemNotation::from_name("pass", &parser), None);
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(StemNotation::AsciiMath.name(), "asciimath");
+        assert_eq!(StemNotation::LatexMath.name(), "latexmath");
+    }
+
+    #[test]
+    fn into_quote_type() {
+        assert_eq!(
+            QuoteType::from(StemNotation::AsciiMath),
+            QuoteType::AsciiMath
+        );
+
+        assert_eq!(
+            QuoteType::from(StemNotation::LatexMath),
+            QuoteType::LatexMath
+        );
+    }
+}
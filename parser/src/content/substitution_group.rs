@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::{
     Parser,
     attributes::Attrlist,
@@ -32,8 +34,8 @@ pub enum SubstitutionGroup {
     Header,
 
     /// Literal, listing, and source blocks are processed using the verbatim
-    /// substitution group. Only special characters are replaced in these
-    /// blocks.
+    /// substitution group. Only special characters and callouts are replaced
+    /// in these blocks.
     Verbatim,
 
     /// No substitutions are applied to three of the elements in the pass
@@ -104,7 +106,10 @@ impl SubstitutionGroup {
             }
 
             if step == "v" || step == "verbatim" {
-                steps = vec![SubstitutionStep::SpecialCharacters];
+                steps = vec![
+                    SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
+                ];
                 continue;
             }
 
@@ -143,6 +148,7 @@ impl SubstitutionGroup {
                 "r" | "replacements" => SubstitutionStep::CharacterReplacements,
                 "m" | "macros" => SubstitutionStep::Macros,
                 "p" | "post_replacements" => SubstitutionStep::PostReplacement,
+                "callouts" => SubstitutionStep::Callouts,
                 _ => {
                     return None;
                 }
@@ -172,17 +178,40 @@ impl SubstitutionGroup {
 
         let passthroughs: Option<Passthroughs> =
             if steps.contains(&SubstitutionStep::Macros) || self == &Self::Header {
-                Some(Passthroughs::extract_from(content))
+                Some(Passthroughs::extract_from(content, parser))
             } else {
                 None
             };
 
+        // Footnotes defined while applying these substitutions capture their text
+        // before passthroughs are restored, so they need to be patched up as well.
+        let footnote_count = parser.footnotes.borrow().len();
+
         for step in steps {
             step.apply(content, parser, attrlist);
         }
 
         if let Some(passthroughs) = passthroughs {
             passthroughs.restore_to(content, parser);
+
+            // NOTE: Restoring a passthrough may apply substitutions (and thus
+            // access the footnotes), so we must not hold a borrow while doing so.
+            let new_footnote_texts: Vec<(usize, String)> = parser
+                .footnotes
+                .borrow()
+                .iter()
+                .enumerate()
+                .skip(footnote_count)
+                .map(|(index, footnote)| (index, footnote.text.clone()))
+                .collect();
+
+            for (index, text) in new_footnote_texts {
+                if let Cow::Owned(text) = passthroughs.restore_in(&text, parser)
+                    && let Some(footnote) = parser.footnotes.borrow_mut().get_mut(index)
+                {
+                    footnote.text = text;
+                }
+            }
         }
     }
 
@@ -210,7 +239,7 @@ impl SubstitutionGroup {
         result
     }
 
-    fn steps(&self) -> &[SubstitutionStep] {
+    pub(crate) fn steps(&self) -> &[SubstitutionStep] {
         match self {
             Self::Normal | Self::Title => &[
                 SubstitutionStep::SpecialCharacters,
@@ -226,7 +255,10 @@ impl SubstitutionGroup {
                 SubstitutionStep::AttributeReferences,
             ],
 
-            Self::Verbatim => &[SubstitutionStep::SpecialCharacters],
+            Self::Verbatim => &[
+                SubstitutionStep::SpecialCharacters,
+                SubstitutionStep::Callouts,
+            ],
 
             Self::Pass | Self::None => &[],
 
@@ -390,6 +422,30 @@ mod tests {
             );
         }
 
+        #[test]
+        fn callouts() {
+            assert_eq!(
+                SubstitutionGroup::from_custom_string(None, "callouts"),
+                Some(SubstitutionGroup::Custom(vec![SubstitutionStep::Callouts]))
+            );
+
+            assert_eq!(
+                SubstitutionGroup::from_custom_string(
+                    Some(&SubstitutionGroup::Normal),
+                    "+callouts"
+                ),
+                Some(SubstitutionGroup::Custom(vec![
+                    SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Quotes,
+                    SubstitutionStep::AttributeReferences,
+                    SubstitutionStep::CharacterReplacements,
+                    SubstitutionStep::Macros,
+                    SubstitutionStep::PostReplacement,
+                    SubstitutionStep::Callouts,
+                ]))
+            );
+        }
+
         #[test]
         fn multiple() {
             assert_eq!(
@@ -449,12 +505,13 @@ mod tests {
                 SubstitutionGroup::from_custom_string(None, "v,-r"),
                 Some(SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                 ]))
             );
 
             assert_eq!(
                 SubstitutionGroup::from_custom_string(None, "v,-c"),
-                Some(SubstitutionGroup::Custom(vec![]))
+                Some(SubstitutionGroup::Custom(vec![SubstitutionStep::Callouts]))
             );
         }
 
@@ -477,6 +534,7 @@ mod tests {
                 SubstitutionGroup::from_custom_string(None, "v,m"),
                 Some(SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                     SubstitutionStep::Macros,
                 ]))
             );
@@ -501,6 +559,7 @@ mod tests {
                 SubstitutionGroup::from_custom_string(None, "v,m"),
                 Some(SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                     SubstitutionStep::Macros,
                 ]))
             );
@@ -516,6 +575,7 @@ mod tests {
                 Some(SubstitutionGroup::Custom(vec![
                     SubstitutionStep::AttributeReferences,
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                 ]))
             );
 
@@ -536,6 +596,7 @@ mod tests {
                 ),
                 Some(SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                     SubstitutionStep::AttributeReferences,
                 ]))
             );
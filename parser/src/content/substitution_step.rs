@@ -73,8 +73,8 @@ impl SubstitutionStep {
             Self::PostReplacement => {
                 apply_post_replacements(content, parser, attrlist);
             }
-            _ => {
-                todo!("Implement apply for SubstitutionStep::{self:?}");
+            Self::Callouts => {
+                super::callouts::apply_callouts(content, parser, attrlist);
             }
         }
     }
@@ -954,13 +954,28 @@ mod tests {
         };
 
         #[test]
-        #[should_panic]
-        fn not_yet_implemented() {
+        fn empty() {
             let mut content = Content::from(crate::Span::default());
             let p = Parser::default();
             SubstitutionStep::Callouts.apply(&mut content, &p, None);
             assert!(content.is_empty());
             assert_eq!(content.rendered, CowStr::Borrowed(""));
         }
+
+        #[test]
+        fn basic_callout() {
+            let mut content = Content::from(crate::Span::new("puts 'Hello' &lt;1&gt;"));
+            let p = Parser::default();
+            SubstitutionStep::Callouts.apply(&mut content, &p, None);
+            assert!(!content.is_empty());
+            assert_eq!(
+                content.rendered,
+                CowStr::Boxed(
+                    "puts 'Hello' <b class=\"conum\">(1)</b>"
+                        .to_string()
+                        .into_boxed_str()
+                )
+            );
+        }
     }
 }
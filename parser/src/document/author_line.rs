@@ -1065,6 +1065,8 @@ mod tests {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
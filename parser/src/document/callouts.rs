@@ -0,0 +1,103 @@
+/// Maintains a catalog of callouts and their associations.
+///
+/// Each callout marker found in a verbatim block (e.g., `<1>`) is registered
+/// with the ordinal of the callout list item that is expected to describe it.
+/// Each marker is assigned a unique ID of the form `CO<list>-<callout>` so
+/// that the callout list items can be linked back to their markers.
+///
+/// Callouts are grouped into lists: All of the callouts registered before a
+/// callout list is parsed belong to that callout list. Once the callout list
+/// has been parsed, [`next_list()`] moves on to a fresh list.
+///
+/// [`next_list()`]: Self::next_list
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Callouts {
+    /// Registered callouts for each list as `(ordinal, id)` pairs.
+    lists: Vec<Vec<(usize, String)>>,
+
+    /// One-based index of the current list.
+    list_index: usize,
+
+    /// One-based index of the next callout to be registered in the current
+    /// list.
+    co_index: usize,
+}
+
+impl Default for Callouts {
+    fn default() -> Self {
+        Self {
+            lists: vec![vec![]],
+            list_index: 1,
+            co_index: 1,
+        }
+    }
+}
+
+impl Callouts {
+    /// Register a new callout for the callout list item with the given
+    /// ordinal. Returns the ID that was assigned to the callout.
+    pub(crate) fn register(&mut self, ordinal: usize) -> String {
+        let id = format!("CO{list}-{co}", list = self.list_index, co = self.co_index);
+        self.co_index += 1;
+
+        if let Some(list) = self.lists.last_mut() {
+            list.push((ordinal, id.clone()));
+        }
+
+        id
+    }
+
+    /// Returns the IDs of all callouts in the current list that are
+    /// associated with the callout list item with the given ordinal.
+    pub(crate) fn callout_ids(&self, ordinal: usize) -> Vec<String> {
+        self.lists
+            .last()
+            .map(|list| {
+                list.iter()
+                    .filter(|(o, _)| *o == ordinal)
+                    .map(|(_, id)| id.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Advance to the next callout list.
+    pub(crate) fn next_list(&mut self) {
+        self.list_index += 1;
+        self.lists.push(vec![]);
+        self.co_index = 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::document::Callouts;
+
+    #[test]
+    fn register_and_lookup() {
+        let mut callouts = Callouts::default();
+
+        assert_eq!(callouts.register(1), "CO1-1");
+        assert_eq!(callouts.register(2), "CO1-2");
+        assert_eq!(callouts.register(1), "CO1-3");
+
+        assert_eq!(callouts.callout_ids(1), vec!["CO1-1", "CO1-3"]);
+        assert_eq!(callouts.callout_ids(2), vec!["CO1-2"]);
+        assert!(callouts.callout_ids(3).is_empty());
+    }
+
+    #[test]
+    fn next_list() {
+        let mut callouts = Callouts::default();
+
+        callouts.register(1);
+        callouts.next_list();
+
+        assert!(callouts.callout_ids(1).is_empty());
+
+        assert_eq!(callouts.register(1), "CO2-1");
+        assert_eq!(callouts.callout_ids(1), vec!["CO2-1"]);
+    }
+}
@@ -8,11 +8,11 @@ use crate::{
     Parser, Span,
     attributes::Attrlist,
     blocks::{Block, ContentModel, IsBlock, Preamble, parse_utils::parse_blocks_until},
-    document::{Catalog, Header},
+    document::{Catalog, Footnote, Header, IndexTerm},
     internal::debug::DebugSliceReference,
     parser::SourceMap,
     strings::CowStr,
-    warnings::Warning,
+    warnings::{Warning, WarningType},
 };
 
 /// A document represents the top-level block element in AsciiDoc. It consists
@@ -42,6 +42,8 @@ struct InternalDependent<'src> {
     source: Span<'src>,
     warnings: Vec<Warning<'src>>,
     source_map: SourceMap,
+    footnotes: Vec<Footnote>,
+    index_terms: Vec<IndexTerm<'src>>,
     catalog: Catalog,
 }
 
@@ -56,12 +58,28 @@ self_cell! {
 }
 
 impl<'src> Document<'src> {
-    pub(crate) fn parse(source: &str, source_map: SourceMap, parser: &mut Parser) -> Self {
+    pub(crate) fn parse(
+        source: &str,
+        source_map: SourceMap,
+        preprocessor_warnings: Vec<(usize, WarningType)>,
+        parser: &mut Parser,
+    ) -> Self {
         let owned_source = source.to_string();
 
         let internal = Internal::new(owned_source, |owned_src| {
             let source = Span::new(owned_src);
 
+            // Preprocessor warnings refer to directives that have been removed
+            // from the source, so they're reported as empty spans at the
+            // location where the directive would have been.
+            let mut warnings: Vec<Warning<'_>> = preprocessor_warnings
+                .into_iter()
+                .map(|(offset, warning)| Warning {
+                    source: source.slice(offset..offset),
+                    warning,
+                })
+                .collect();
+
             let mi = Header::parse(source, parser);
             let after_header = mi.item.after;
 
@@ -72,9 +90,10 @@ impl<'src> Document<'src> {
                 .unwrap_or(3);
 
             let header = mi.item.item;
-            let mut warnings = mi.warnings;
+            let mut header_warnings = mi.warnings;
+            warnings.append(&mut header_warnings);
 
-            let mut maw_blocks = parse_blocks_until(after_header, |_| false, parser);
+            let mut maw_blocks = parse_blocks_until(after_header, |_, _| false, parser);
 
             if !maw_blocks.warnings.is_empty() {
                 warnings.append(&mut maw_blocks.warnings);
@@ -114,6 +133,13 @@ impl<'src> Document<'src> {
                 source: source.trim_trailing_whitespace(),
                 warnings,
                 source_map,
+                footnotes: parser.footnotes.take(),
+                index_terms: parser
+                    .index_terms
+                    .take()
+                    .into_iter()
+                    .map(|term| term.resolve(source))
+                    .collect(),
                 catalog: parser.take_catalog(),
             }
         });
@@ -144,6 +170,18 @@ impl<'src> Document<'src> {
         &self.internal.borrow_dependent().source_map
     }
 
+    /// Return an iterator over the footnotes defined in this document, in the
+    /// order in which they were defined.
+    pub fn footnotes(&self) -> Iter<'_, Footnote> {
+        self.internal.borrow_dependent().footnotes.iter()
+    }
+
+    /// Return an iterator over the index terms found in this document, in the
+    /// order in which they appear.
+    pub fn index_terms(&self) -> Iter<'_, IndexTerm<'_>> {
+        self.internal.borrow_dependent().index_terms.iter()
+    }
+
     /// Return the document catalog for accessing referenceable elements.
     pub fn catalog(&self) -> &Catalog {
         &self.internal.borrow_dependent().catalog
@@ -196,6 +234,8 @@ impl std::fmt::Debug for Document<'_> {
             .field("source", &dependent.source)
             .field("warnings", &DebugSliceReference(&dependent.warnings))
             .field("source_map", &dependent.source_map)
+            .field("footnotes", &DebugSliceReference(&dependent.footnotes))
+            .field("index_terms", &DebugSliceReference(&dependent.index_terms))
             .field("catalog", &dependent.catalog)
             .finish()
     }
@@ -261,6 +301,8 @@ mod tests {
                 blocks: &[],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -294,6 +336,8 @@ mod tests {
                 blocks: &[],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -350,6 +394,8 @@ mod tests {
                 })],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -433,6 +479,8 @@ mod tests {
                 ],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -518,6 +566,8 @@ mod tests {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -605,6 +655,8 @@ mod tests {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -698,6 +750,8 @@ mod tests {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -758,6 +812,8 @@ mod tests {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -852,6 +908,8 @@ mod tests {
                     warning: WarningType::DocumentHeaderNotTerminated,
                 },],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1038,6 +1096,8 @@ mod tests {
                     },
                 ],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "_section_title",
@@ -1095,6 +1155,7 @@ mod tests {
                         offset: 17,
                     },
                     rendered: "abc",
+                    passthroughs: [],
                 },
                 source: Span {
                     data: "abc",
@@ -1120,6 +1181,7 @@ mod tests {
                         offset: 22,
                     },
                     rendered: "def",
+                    passthroughs: [],
                 },
                 source: Span {
                     data: "def",
@@ -1144,6 +1206,8 @@ mod tests {
     },
     warnings: &[],
     source_map: SourceMap(&[]),
+    footnotes: &[],
+    index_terms: &[],
     catalog: Catalog {
         refs: HashMap::from([]),
         reftext_to_id: HashMap::from([]),
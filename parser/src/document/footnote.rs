@@ -0,0 +1,19 @@
+/// A footnote that was defined in the document via the [footnote macro].
+///
+/// Footnotes are numbered in the order in which they are defined in the
+/// document. A footnote that is given an ID (e.g., `footnote:disclaimer[...]`)
+/// may be referenced again later in the document (e.g.,
+/// `footnote:disclaimer[]`); such references do not create a new footnote.
+///
+/// [footnote macro]: https://docs.asciidoctor.org/asciidoc/latest/macros/footnote/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Footnote {
+    /// The one-based number of this footnote.
+    pub index: usize,
+
+    /// The ID of this footnote, if one was provided.
+    pub id: Option<String>,
+
+    /// The footnote text, after substitutions have been applied.
+    pub text: String,
+}
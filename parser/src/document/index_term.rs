@@ -0,0 +1,156 @@
+use std::ops::Range;
+
+use crate::{HasSpan, Span};
+
+/// An occurrence of an [index term] in the document.
+///
+/// Index terms are collected in the order in which they appear in the
+/// document and can be used to generate an index (e.g., for a printed book).
+/// An index term may be _visible_ (e.g., `((Tigers))` or
+/// `indexterm2:[Tigers]`), in which case the primary term also appears in the
+/// rendered text, or _concealed_ (e.g., `(((Big cats,Tigers)))` or
+/// `indexterm:[Big cats,Tigers]`), in which case it is only recorded here.
+///
+/// [index term]: https://docs.asciidoctor.org/asciidoc/latest/sections/user-index/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexTerm<'src> {
+    /// The primary term, followed by the secondary and tertiary terms if
+    /// present. (Visible index terms only have a primary term.)
+    pub terms: Vec<String>,
+
+    /// A term that the reader should look up instead of this one, if any.
+    pub see: Option<String>,
+
+    /// Related terms that the reader may also look up, if any.
+    pub see_also: Vec<String>,
+
+    /// `true` if the primary term is also displayed in the rendered text.
+    pub visible: bool,
+
+    /// The source of this index term.
+    ///
+    /// If the exact location of the index term can not be determined (for
+    /// example, because substitutions prior to macro processing altered the
+    /// text of the macro), this is the entire span of content that contains
+    /// the index term. If the content itself was not taken directly from the
+    /// document source, this is an empty span at the start of the document.
+    pub source: Span<'src>,
+}
+
+impl<'src> HasSpan<'src> for IndexTerm<'src> {
+    fn span(&self) -> Span<'src> {
+        self.source
+    }
+}
+
+/// An index term that has been found during inline substitution, but not yet
+/// associated with the document source.
+///
+/// The parser does not have access to the document source while inline
+/// substitutions are applied, so the location of the index term is recorded
+/// as a byte range and later resolved via [`PendingIndexTerm::resolve()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PendingIndexTerm {
+    pub(crate) terms: Vec<String>,
+    pub(crate) see: Option<String>,
+    pub(crate) see_also: Vec<String>,
+    pub(crate) visible: bool,
+
+    /// Byte range of the index term in the document source.
+    pub(crate) range: Range<usize>,
+
+    /// Source text that is expected to be found at `range`.
+    pub(crate) source_text: String,
+}
+
+impl PendingIndexTerm {
+    /// Convert into an [`IndexTerm`] that refers to the given document
+    /// source.
+    ///
+    /// Content that was not taken directly from the document source can not
+    /// be located; such index terms are given an empty span at the start of
+    /// the document.
+    pub(crate) fn resolve<'src>(self, source: Span<'src>) -> IndexTerm<'src> {
+        let source = if source.data().get(self.range.clone()) == Some(self.source_text.as_str()) {
+            source.slice(self.range)
+        } else {
+            source.slice(0..0)
+        };
+
+        IndexTerm {
+            terms: self.terms,
+            see: self.see,
+            see_also: self.see_also,
+            visible: self.visible,
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{HasSpan, document::index_term::PendingIndexTerm, tests::prelude::*};
+
+    fn pending(range: std::ops::Range<usize>, source_text: &str) -> PendingIndexTerm {
+        PendingIndexTerm {
+            terms: vec!["Tigers".to_owned()],
+            see: None,
+            see_also: vec![],
+            visible: false,
+            range,
+            source_text: source_text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let source = crate::Span::new("Big cats.\n(((Tigers)))");
+        let term = pending(10..22, "(((Tigers)))").resolve(source);
+
+        assert_eq!(
+            term.span(),
+            Span {
+                data: "(((Tigers)))",
+                line: 2,
+                col: 1,
+                offset: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_mismatched_text() {
+        let source = crate::Span::new("Big cats.\n(((Tigers)))");
+        let term = pending(0..12, "(((Tigers)))").resolve(source);
+
+        assert_eq!(
+            term.span(),
+            Span {
+                data: "",
+                line: 1,
+                col: 1,
+                offset: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_out_of_range() {
+        let source = crate::Span::new("Big cats.");
+        let term = pending(10..22, "(((Tigers)))").resolve(source);
+
+        assert_eq!(
+            term.span(),
+            Span {
+                data: "",
+                line: 1,
+                col: 1,
+                offset: 0,
+            }
+        );
+    }
+}
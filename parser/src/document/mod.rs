@@ -9,6 +9,9 @@ pub use author::Author;
 mod author_line;
 pub use author_line::AuthorLine;
 
+mod callouts;
+pub(crate) use callouts::Callouts;
+
 mod catalog;
 #[allow(unused)] // TEMPORARY while building
 pub(crate) use catalog::DuplicateIdError;
@@ -17,8 +20,15 @@ pub use catalog::{Catalog, RefEntry, RefType};
 mod document;
 pub use document::Document;
 
+mod footnote;
+pub use footnote::Footnote;
+
 mod header;
 pub use header::Header;
 
+mod index_term;
+pub use index_term::IndexTerm;
+pub(crate) use index_term::PendingIndexTerm;
+
 mod revision_line;
 pub use revision_line::RevisionLine;
@@ -107,6 +107,51 @@ pub(super) fn built_in_attrs() -> HashMap<String, AttributeValue> {
         },
     );
 
+    attrs.insert(
+        "caution-caption".to_owned(),
+        AttributeValue {
+            allowable_value: AllowableValue::Any,
+            modification_context: ModificationContext::Anywhere,
+            value: InterpretedValue::Set,
+        },
+    );
+
+    attrs.insert(
+        "important-caption".to_owned(),
+        AttributeValue {
+            allowable_value: AllowableValue::Any,
+            modification_context: ModificationContext::Anywhere,
+            value: InterpretedValue::Set,
+        },
+    );
+
+    attrs.insert(
+        "note-caption".to_owned(),
+        AttributeValue {
+            allowable_value: AllowableValue::Any,
+            modification_context: ModificationContext::Anywhere,
+            value: InterpretedValue::Set,
+        },
+    );
+
+    attrs.insert(
+        "tip-caption".to_owned(),
+        AttributeValue {
+            allowable_value: AllowableValue::Any,
+            modification_context: ModificationContext::Anywhere,
+            value: InterpretedValue::Set,
+        },
+    );
+
+    attrs.insert(
+        "warning-caption".to_owned(),
+        AttributeValue {
+            allowable_value: AllowableValue::Any,
+            modification_context: ModificationContext::Anywhere,
+            value: InterpretedValue::Set,
+        },
+    );
+
     // TO DO: Replace ./images with value of imagesdir if that is non-default.
     attrs.insert(
         "iconsdir".to_owned(),
@@ -123,10 +168,15 @@ pub(super) fn built_in_attrs() -> HashMap<String, AttributeValue> {
 pub(super) fn built_in_default_values() -> HashMap<String, String> {
     let mut defaults: HashMap<String, String> = HashMap::new();
 
+    defaults.insert("caution-caption".to_owned(), "Caution".to_owned());
     defaults.insert("example-caption".to_owned(), "Example".to_owned());
     defaults.insert("iconsdir".to_owned(), "./images/icons".to_owned());
+    defaults.insert("important-caption".to_owned(), "Important".to_owned());
+    defaults.insert("note-caption".to_owned(), "Note".to_owned());
     defaults.insert("sectnums".to_owned(), "all".to_owned());
+    defaults.insert("tip-caption".to_owned(), "Tip".to_owned());
     defaults.insert("toc".to_owned(), "auto".to_owned());
+    defaults.insert("warning-caption".to_owned(), "Warning".to_owned());
 
     defaults
 }
@@ -0,0 +1,334 @@
+use std::path::Path;
+
+use crate::{
+    Parser,
+    attributes::Attrlist,
+    parser::{IncludeFileHandler, SafeMode, inline_substitution_renderer::is_uri_ish},
+};
+
+/// A `FileSystemIncludeHandler` resolves `include::` directives by reading
+/// files from the local file system.
+///
+/// Include targets are resolved relative to the file containing the include
+/// directive using the parser's [`PathResolver`]. The root document (and any
+/// relative file name provided via [`Parser::with_primary_file_name()`]) is
+/// considered to reside in the base directory.
+///
+/// The parser's [`SafeMode`] is respected as follows:
+///
+/// - [`SafeMode::Unsafe`]: Any file readable by the process may be included.
+/// - [`SafeMode::Safe`] and [`SafeMode::Server`]: Files are confined to the
+///   base directory. A target that would escape the base directory (via parent
+///   references or an absolute path) is resolved within the base directory
+///   instead, as Asciidoctor does.
+/// - [`SafeMode::Secure`]: No files are included.
+///
+/// URIs are never resolved by this handler.
+///
+/// [`PathResolver`]: crate::parser::PathResolver
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileSystemIncludeHandler {
+    base_dir: String,
+}
+
+impl FileSystemIncludeHandler {
+    /// Create a new `FileSystemIncludeHandler` which resolves include files
+    /// relative to `base_dir`. If `base_dir` is a relative path, it is taken
+    /// to be relative to the current working directory.
+    pub fn new<P: AsRef<Path>>(base_dir: P) -> Self {
+        let base_dir = base_dir.as_ref();
+
+        let base_dir = std::path::absolute(base_dir)
+            .unwrap_or_else(|_| base_dir.to_path_buf())
+            .to_string_lossy()
+            .into_owned();
+
+        Self { base_dir }
+    }
+
+    /// Returns the (absolute) base directory for this handler.
+    pub fn base_dir(&self) -> &str {
+        &self.base_dir
+    }
+}
+
+impl IncludeFileHandler for FileSystemIncludeHandler {
+    fn resolve_target<'src>(
+        &self,
+        source: Option<&str>,
+        target: &str,
+        _attrlist: &Attrlist<'src>,
+        parser: &Parser,
+    ) -> Option<String> {
+        let safe_mode = parser.safe_mode();
+
+        if safe_mode >= SafeMode::Secure || is_uri_ish(target) {
+            return None;
+        }
+
+        let resolver = &parser.path_resolver;
+        let base_dir = resolver.posixify(&self.base_dir);
+        let jail = (safe_mode >= SafeMode::Safe).then_some(base_dir.as_str());
+
+        let start_dir = match source {
+            Some(source) => {
+                let source_path = resolver.system_path(source, Some(&base_dir), jail);
+                match source_path.rsplit_once('/') {
+                    Some(("", _)) => "/".to_owned(),
+                    Some((dir, _)) => dir.to_owned(),
+                    None => base_dir.clone(),
+                }
+            }
+            None => base_dir.clone(),
+        };
+
+        let path = resolver.system_path(target, Some(&start_dir), jail);
+
+        std::fs::read_to_string(path).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::path::{Path, PathBuf};
+
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::{
+        HasSpan, Parser,
+        attributes::Attrlist,
+        blocks::IsBlock,
+        parser::{FileSystemIncludeHandler, IncludeFileHandler, SafeMode},
+    };
+
+    /// Creates a temporary directory tree for the duration of a test.
+    ///
+    /// The tree looks like this:
+    ///
+    /// ```text
+    /// outside.adoc
+    /// docs/
+    ///     chapter.adoc
+    ///     chapters/
+    ///         ch1.adoc
+    ///         sections/
+    ///             s1.adoc
+    /// ```
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "asciidoc-parser-{name}-{pid}",
+                pid = std::process::id()
+            ));
+
+            let _ = std::fs::remove_dir_all(&root);
+
+            std::fs::create_dir_all(root.join("docs/chapters/sections")).unwrap();
+            std::fs::write(root.join("outside.adoc"), "Outside.\n").unwrap();
+            std::fs::write(root.join("docs/chapter.adoc"), "Chapter.\n").unwrap();
+
+            std::fs::write(
+                root.join("docs/chapters/ch1.adoc"),
+                "Chapter 1.\n\ninclude::sections/s1.adoc[]\n",
+            )
+            .unwrap();
+
+            std::fs::write(root.join("docs/chapters/sections/s1.adoc"), "Section 1.\n").unwrap();
+
+            Self(root)
+        }
+
+        fn docs(&self) -> PathBuf {
+            self.0.join("docs")
+        }
+
+        fn outside(&self) -> PathBuf {
+            self.0.join("outside.adoc")
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn resolve(
+        handler: &FileSystemIncludeHandler,
+        source: Option<&str>,
+        target: &str,
+        safe_mode: SafeMode,
+    ) -> Option<String> {
+        let parser = Parser::default().with_safe_mode(safe_mode);
+        handler.resolve_target(source, target, &Attrlist::default(), &parser)
+    }
+
+    fn path_str(path: &Path) -> &str {
+        path.to_str().unwrap()
+    }
+
+    #[test]
+    fn impl_clone() {
+        // Silly test to mark the #[derive(...)] line as covered.
+        let h1 = FileSystemIncludeHandler::new("/path/to/docs");
+        let h2 = h1.clone();
+        assert_eq!(h1, h2);
+    }
+
+    #[test]
+    fn relative_base_dir_is_made_absolute() {
+        let handler = FileSystemIncludeHandler::new("docs");
+
+        assert!(Path::new(handler.base_dir()).is_absolute());
+        assert!(handler.base_dir().ends_with("docs"));
+    }
+
+    #[test]
+    fn resolves_relative_to_base_dir() {
+        let fixture = Fixture::new("fsih-relative");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        assert_eq!(
+            resolve(&handler, None, "chapter.adoc", SafeMode::Safe),
+            Some("Chapter.\n".to_owned())
+        );
+
+        assert_eq!(
+            resolve(&handler, Some("main.adoc"), "chapter.adoc", SafeMode::Safe),
+            Some("Chapter.\n".to_owned())
+        );
+
+        assert_eq!(
+            resolve(&handler, None, "missing.adoc", SafeMode::Safe),
+            None
+        );
+    }
+
+    #[test]
+    fn resolves_relative_to_including_file() {
+        let fixture = Fixture::new("fsih-nested");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        assert_eq!(
+            resolve(
+                &handler,
+                Some("chapters/ch1.adoc"),
+                "sections/s1.adoc",
+                SafeMode::Safe
+            ),
+            Some("Section 1.\n".to_owned())
+        );
+
+        assert_eq!(
+            resolve(
+                &handler,
+                Some("chapters/ch1.adoc"),
+                "../chapter.adoc",
+                SafeMode::Safe
+            ),
+            Some("Chapter.\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn unsafe_mode_can_escape_base_dir() {
+        let fixture = Fixture::new("fsih-unsafe");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        assert_eq!(
+            resolve(&handler, None, "../outside.adoc", SafeMode::Unsafe),
+            Some("Outside.\n".to_owned())
+        );
+
+        assert_eq!(
+            resolve(
+                &handler,
+                None,
+                path_str(&fixture.outside()),
+                SafeMode::Unsafe
+            ),
+            Some("Outside.\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn safe_mode_confines_to_base_dir() {
+        let fixture = Fixture::new("fsih-safe");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        for safe_mode in [SafeMode::Safe, SafeMode::Server] {
+            assert_eq!(resolve(&handler, None, "../outside.adoc", safe_mode), None);
+
+            assert_eq!(
+                resolve(
+                    &handler,
+                    Some("chapters/ch1.adoc"),
+                    "../../../outside.adoc",
+                    safe_mode
+                ),
+                None
+            );
+
+            assert_eq!(
+                resolve(&handler, None, path_str(&fixture.outside()), safe_mode),
+                None
+            );
+
+            // A parent reference that stays within the base directory is fine.
+            assert_eq!(
+                resolve(&handler, None, "chapters/../chapter.adoc", safe_mode),
+                Some("Chapter.\n".to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn secure_mode_includes_nothing() {
+        let fixture = Fixture::new("fsih-secure");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        assert_eq!(
+            resolve(&handler, None, "chapter.adoc", SafeMode::Secure),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_uris() {
+        let fixture = Fixture::new("fsih-uri");
+        let handler = FileSystemIncludeHandler::new(fixture.docs());
+
+        assert_eq!(
+            resolve(
+                &handler,
+                None,
+                "https://example.com/chapter.adoc",
+                SafeMode::Unsafe
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_with_nested_includes() {
+        let fixture = Fixture::new("fsih-parse");
+
+        let mut parser = Parser::default()
+            .with_safe_mode(SafeMode::Safe)
+            .with_include_file_handler(FileSystemIncludeHandler::new(fixture.docs()));
+
+        let doc = parser.parse("include::chapters/ch1.adoc[]");
+
+        let blocks: Vec<_> = doc
+            .nested_blocks()
+            .map(|block| block.span().data().to_owned())
+            .collect();
+
+        assert_eq!(blocks, vec!["Chapter 1.", "Section 1."]);
+        assert_eq!(doc.warnings().count(), 0);
+    }
+}
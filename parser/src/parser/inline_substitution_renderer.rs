@@ -2,7 +2,7 @@ use std::{fmt::Debug, sync::LazyLock};
 
 use regex::Regex;
 
-use crate::{Parser, attributes::Attrlist};
+use crate::{Parser, attributes::Attrlist, parser::SafeMode};
 
 /// An implementation of `InlineSubstitutionRenderer` is used when converting
 /// the basic raw text of a simple block to the format which will ultimately be
@@ -149,6 +149,72 @@ pub trait InlineSubstitutionRenderer: Debug {
     /// The rendered should write an appropriate rendering of the specified
     /// anchor with ID and possible ref text (only used by some renderers).
     fn render_anchor(&self, id: &str, reftext: Option<String>, dest: &mut String);
+
+    /// Renders a [cross reference].
+    ///
+    /// The renderer should write an appropriate rendering of the specified
+    /// cross reference to `dest`. The link text has already been resolved
+    /// (see [`XrefRenderParams::text`]).
+    ///
+    /// [cross reference]: https://docs.asciidoctor.org/asciidoc/latest/macros/xref/
+    fn render_xref(&self, params: &XrefRenderParams, dest: &mut String);
+
+    /// Renders a [footnote] marker at the location where a footnote is
+    /// defined or referenced.
+    ///
+    /// The text of the footnote itself is available via
+    /// [`Document::footnotes()`]; the renderer is only responsible for the
+    /// inline marker.
+    ///
+    /// [footnote]: https://docs.asciidoctor.org/asciidoc/latest/macros/footnote/
+    /// [`Document::footnotes()`]: crate::Document::footnotes
+    fn render_footnote(&self, params: &FootnoteRenderParams, dest: &mut String);
+
+    /// Renders an [index term] at the location where it appears.
+    ///
+    /// All index terms are also available via [`Document::index_terms()`];
+    /// the renderer is only responsible for the inline rendering. Typically,
+    /// a visible index term renders its primary term and a concealed index
+    /// term renders nothing.
+    ///
+    /// [index term]: https://docs.asciidoctor.org/asciidoc/latest/sections/user-index/
+    /// [`Document::index_terms()`]: crate::Document::index_terms
+    fn render_index_term(&self, params: &IndexTermRenderParams, dest: &mut String);
+
+    /// Renders a [button] (e.g., `btn:[Save]`).
+    ///
+    /// The renderer should write an appropriate rendering of the button with
+    /// the specified label to `dest`.
+    ///
+    /// [button]: https://docs.asciidoctor.org/asciidoc/latest/macros/ui-macros/#button-macro-syntax
+    fn render_button(&self, text: &str, dest: &mut String);
+
+    /// Renders a [keyboard shortcut] (e.g., `kbd:[Ctrl+T]`).
+    ///
+    /// The renderer should write an appropriate rendering of the specified
+    /// key or key combination to `dest`. If `keys` contains more than one
+    /// entry, they are to be pressed together.
+    ///
+    /// [keyboard shortcut]: https://docs.asciidoctor.org/asciidoc/latest/macros/keyboard-macro/
+    fn render_kbd(&self, keys: &[String], dest: &mut String);
+
+    /// Renders a [menu selection] (e.g., `menu:File[Save]`).
+    ///
+    /// The renderer should write an appropriate rendering of the specified
+    /// menu path to `dest`.
+    ///
+    /// [menu selection]: https://docs.asciidoctor.org/asciidoc/latest/macros/ui-macros/#menu-macro-syntax
+    fn render_menu(&self, params: &MenuRenderParams, dest: &mut String);
+
+    /// Renders a [callout] marker in verbatim content.
+    ///
+    /// The renderer should write an appropriate rendering of the specified
+    /// callout to `dest`. Any guard that preceded the callout marker (see
+    /// [`CalloutGuard`]) has already been removed from the content and should
+    /// be written to `dest` if appropriate.
+    ///
+    /// [callout]: https://docs.asciidoctor.org/asciidoc/latest/verbatim/callouts/
+    fn render_callout(&self, params: &CalloutRenderParams, dest: &mut String);
 }
 
 /// Specifies which special character is being replaced in a call to
@@ -196,6 +262,16 @@ pub enum QuoteType {
 
     /// Surrounds a block of text that may need a `<span>` or similar tag.
     Unquoted,
+
+    /// Inline [STEM] expression written in AsciiMath notation.
+    ///
+    /// [STEM]: https://docs.asciidoctor.org/asciidoc/latest/stem/
+    AsciiMath,
+
+    /// Inline [STEM] expression written in LaTeX math notation.
+    ///
+    /// [STEM]: https://docs.asciidoctor.org/asciidoc/latest/stem/
+    LatexMath,
 }
 
 /// Specifies whether the block is aligned to word boundaries or not.
@@ -326,6 +402,142 @@ pub enum LinkRenderType {
     Link,
 }
 
+/// Provides parsed parameters for a cross reference to be rendered.
+#[derive(Clone, Debug)]
+pub struct XrefRenderParams<'a> {
+    /// Target of the link (e.g., `#install` or `other.html#install`).
+    pub target: String,
+
+    /// Link text. If no text was specified in the cross reference, this is
+    /// the reference text of the referenced element (if known), the path of
+    /// the referenced document, or the ID in square brackets (e.g.,
+    /// `[install]`).
+    pub text: String,
+
+    /// ID of the referenced element, if any. For a reference to another
+    /// document, this includes the document path (e.g., `other#install`).
+    pub refid: Option<String>,
+
+    /// Path of the referenced document, if the reference is to another
+    /// document.
+    pub path: Option<String>,
+
+    /// Fragment (i.e., the ID within the referenced document), if any.
+    pub fragment: Option<String>,
+
+    /// Attribute list.
+    pub attrlist: &'a Attrlist<'a>,
+
+    /// Parser. The renderer may find document settings in the parser's
+    /// document attributes.
+    pub parser: &'a Parser,
+}
+
+/// Provides parsed parameters for a footnote marker to be rendered.
+#[derive(Clone, Debug)]
+pub struct FootnoteRenderParams<'a> {
+    /// What kind of footnote marker is being rendered?
+    pub type_: FootnoteRenderType,
+
+    /// One-based number of the footnote. Will be `None` if the type is
+    /// [`FootnoteRenderType::Unresolved`].
+    pub index: Option<usize>,
+
+    /// ID of the footnote, if one was provided when the footnote was defined.
+    /// Always `None` unless the type is [`FootnoteRenderType::Definition`].
+    pub id: Option<&'a str>,
+
+    /// Footnote text (after substitutions). For an unresolved reference, this
+    /// is the ID that could not be resolved.
+    pub text: &'a str,
+
+    /// Parser. The renderer may find document settings in the parser's
+    /// document attributes.
+    pub parser: &'a Parser,
+}
+
+/// What kind of footnote marker is being rendered?
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FootnoteRenderType {
+    /// The location where a new footnote is defined (e.g.,
+    /// `footnote:[text]` or `footnote:id[text]`).
+    Definition,
+
+    /// A subsequent reference to a footnote that was previously defined
+    /// with an ID (e.g., `footnote:id[]`).
+    Reference,
+
+    /// A reference to a footnote ID that has not been defined.
+    Unresolved,
+}
+
+/// Provides parsed parameters for an index term to be rendered.
+#[derive(Clone, Debug)]
+pub struct IndexTermRenderParams<'a> {
+    /// The primary term, followed by the secondary and tertiary terms if
+    /// present.
+    pub terms: &'a [String],
+
+    /// A term that the reader should look up instead of this one, if any.
+    pub see: Option<&'a str>,
+
+    /// Related terms that the reader may also look up.
+    pub see_also: &'a [String],
+
+    /// `true` if the primary term should also be displayed in the text.
+    pub visible: bool,
+
+    /// Parser. The renderer may find document settings in the parser's
+    /// document attributes.
+    pub parser: &'a Parser,
+}
+
+/// Provides parsed parameters for a menu selection to be rendered.
+#[derive(Clone, Debug)]
+pub struct MenuRenderParams<'a> {
+    /// Name of the top-level menu (e.g., `File`).
+    pub menu: &'a str,
+
+    /// Names of any submenus between the top-level menu and the menu item,
+    /// in order.
+    pub submenus: Vec<&'a str>,
+
+    /// Name of the selected menu item, if any. If `None`, this is a reference
+    /// to the top-level menu itself.
+    pub menuitem: Option<&'a str>,
+
+    /// Parser. The renderer may find document settings (such as the `icons`
+    /// setting) in the parser's document attributes.
+    pub parser: &'a Parser,
+}
+
+/// Provides parsed parameters for a callout to be rendered.
+#[derive(Clone, Debug)]
+pub struct CalloutRenderParams<'a> {
+    /// Callout number. (Automatically-numbered callouts, `<.>`, have already
+    /// been assigned a number.)
+    pub number: usize,
+
+    /// Guard that hid the callout marker from the source language, if any.
+    pub guard: Option<CalloutGuard<'a>>,
+
+    /// Parser. The rendered may find document settings (such as the `icons`
+    /// setting) in the parser's document attributes.
+    pub parser: &'a Parser,
+}
+
+/// Describes how a callout marker was hidden from the source language of a
+/// verbatim block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CalloutGuard<'a> {
+    /// The callout marker followed a line comment prefix (e.g., `// <1>` or
+    /// `# <1>`). The prefix, including any trailing space, is provided.
+    LineComment(&'a str),
+
+    /// The callout marker was written as an XML comment (e.g., `<!--1-->`).
+    XmlComment,
+}
+
 /// Implementation of [`InlineSubstitutionRenderer`] that renders substitutions
 /// for common HTML-based applications.
 #[derive(Debug)]
@@ -421,6 +633,18 @@ impl InlineSubstitutionRenderer for HtmlSubstitutionRenderer {
                     wrap_body_in_html_tag(attrlist.as_ref(), "span", id, roles, body, dest);
                 }
             }
+
+            QuoteType::AsciiMath => {
+                dest.push_str("\\$");
+                dest.push_str(body);
+                dest.push_str("\\$");
+            }
+
+            QuoteType::LatexMath => {
+                dest.push_str("\\(");
+                dest.push_str(body);
+                dest.push_str("\\)");
+            }
         }
     }
 
@@ -513,11 +737,9 @@ impl InlineSubstitutionRenderer for HtmlSubstitutionRenderer {
             .named_attribute("format")
             .map(|format| format.value());
 
-        // TO DO (https://github.com/scouten/asciidoc-parser/issues/277):
-        // Enforce non-safe mode. Add this contraint to following `if` clause:
-        // `&& node.document.safe < SafeMode::SECURE`
-
-        let img = if format == Some("svg") || params.target.contains(".svg") {
+        let img = if (format == Some("svg") || params.target.contains(".svg"))
+            && params.parser.safe_mode() < SafeMode::Secure
+        {
             // NOTE: In the SVG case we may have to ignore the attrs list.
             if params.attrlist.has_option("inline") {
                 todo!(
@@ -690,6 +912,138 @@ impl InlineSubstitutionRenderer for HtmlSubstitutionRenderer {
     fn render_anchor(&self, id: &str, _reftext: Option<String>, dest: &mut String) {
         dest.push_str(&format!("<a id=\"{id}\"></a>"));
     }
+
+    fn render_xref(&self, params: &XrefRenderParams, dest: &mut String) {
+        let roles = params.attrlist.roles();
+
+        let class = if roles.is_empty() {
+            "".to_owned()
+        } else {
+            format!(r#" class="{roles}""#, roles = roles.join(" "))
+        };
+
+        let link_constraint_attrs = if params.path.is_some() {
+            link_constraint_attrs(params.attrlist, None)
+        } else {
+            "".to_owned()
+        };
+
+        dest.push_str(&format!(
+            r#"<a href="{target}"{class}{link_constraint_attrs}>{text}</a>"#,
+            target = params.target,
+            text = params.text,
+        ));
+    }
+
+    fn render_footnote(&self, params: &FootnoteRenderParams, dest: &mut String) {
+        match (params.type_, params.index) {
+            (FootnoteRenderType::Definition, Some(index)) => {
+                let id = params
+                    .id
+                    .map(|id| format!(r#" id="_footnote_{id}""#))
+                    .unwrap_or_default();
+
+                dest.push_str(&format!(
+                    r##"<sup class="footnote"{id}>[<a id="_footnoteref_{index}" class="footnote" href="#_footnotedef_{index}" title="View footnote.">{index}</a>]</sup>"##
+                ));
+            }
+
+            (FootnoteRenderType::Reference, Some(index)) => {
+                dest.push_str(&format!(
+                    r##"<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_{index}" title="View footnote.">{index}</a>]</sup>"##
+                ));
+            }
+
+            _ => {
+                dest.push_str(&format!(
+                    r#"<sup class="footnoteref red" title="Unresolved footnote reference.">[{text}]</sup>"#,
+                    text = params.text
+                ));
+            }
+        }
+    }
+
+    fn render_index_term(&self, params: &IndexTermRenderParams, dest: &mut String) {
+        if params.visible
+            && let Some(term) = params.terms.first()
+        {
+            dest.push_str(term);
+        }
+    }
+
+    fn render_button(&self, text: &str, dest: &mut String) {
+        dest.push_str(&format!(r#"<b class="button">{text}</b>"#));
+    }
+
+    fn render_kbd(&self, keys: &[String], dest: &mut String) {
+        if let [key] = keys {
+            dest.push_str(&format!("<kbd>{key}</kbd>"));
+        } else {
+            dest.push_str(&format!(
+                r#"<span class="keyseq"><kbd>{keys}</kbd></span>"#,
+                keys = keys.join("</kbd>+<kbd>")
+            ));
+        }
+    }
+
+    fn render_menu(&self, params: &MenuRenderParams, dest: &mut String) {
+        let caret = if params.parser.attribute_value("icons").as_maybe_str() == Some("font") {
+            r#"&#160;<i class="fa fa-angle-right caret"></i> "#
+        } else {
+            r#"&#160;<b class="caret">&#8250;</b> "#
+        };
+
+        let menu = params.menu;
+
+        if params.submenus.is_empty() {
+            if let Some(menuitem) = params.menuitem {
+                dest.push_str(&format!(
+                    r#"<span class="menuseq"><b class="menu">{menu}</b>{caret}<b class="menuitem">{menuitem}</b></span>"#
+                ));
+            } else {
+                dest.push_str(&format!(r#"<b class="menuref">{menu}</b>"#));
+            }
+        } else {
+            let submenu_joiner = format!(r#"</b>{caret}<b class="submenu">"#);
+
+            dest.push_str(&format!(
+                r#"<span class="menuseq"><b class="menu">{menu}</b>{caret}<b class="submenu">{submenus}</b>{caret}<b class="menuitem">{menuitem}</b></span>"#,
+                submenus = params.submenus.join(&submenu_joiner),
+                menuitem = params.menuitem.unwrap_or_default(),
+            ));
+        }
+    }
+
+    fn render_callout(&self, params: &CalloutRenderParams, dest: &mut String) {
+        let number = params.number;
+        let icons = params.parser.attribute_value("icons");
+
+        if icons.as_maybe_str() == Some("font") {
+            dest.push_str(&format!(
+                r#"<i class="conum" data-value="{number}"></i><b>({number})</b>"#
+            ));
+        } else if params.parser.is_attribute_set("icons") {
+            let src = self.icon_uri(
+                &format!("callouts/{number}"),
+                &Attrlist::default(),
+                params.parser,
+            );
+
+            dest.push_str(&format!(r#"<img src="{src}" alt="{number}">"#));
+        } else {
+            match params.guard {
+                Some(CalloutGuard::XmlComment) => {
+                    dest.push_str(&format!(r#"&lt;!--<b class="conum">({number})</b>--&gt;"#));
+                }
+                Some(CalloutGuard::LineComment(prefix)) => {
+                    dest.push_str(&format!(r#"{prefix}<b class="conum">({number})</b>"#));
+                }
+                None => {
+                    dest.push_str(&format!(r#"<b class="conum">({number})</b>"#));
+                }
+            }
+        }
+    }
 }
 
 fn wrap_body_in_html_tag(
@@ -776,7 +1130,7 @@ fn normalize_web_path(
     }
 }
 
-fn is_uri_ish(path: &str) -> bool {
+pub(crate) fn is_uri_ish(path: &str) -> bool {
     path.contains(':') && URI_SNIFF.is_match(path)
 }
 
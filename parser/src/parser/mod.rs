@@ -7,14 +7,18 @@ pub use attribute_value::{AllowableValue, ModificationContext};
 
 mod built_in_attrs;
 
+mod file_system_include_handler;
+pub use file_system_include_handler::FileSystemIncludeHandler;
+
 mod include_file_handler;
 pub use include_file_handler::IncludeFileHandler;
 
 mod inline_substitution_renderer;
 pub use inline_substitution_renderer::{
-    CharacterReplacementType, HtmlSubstitutionRenderer, IconRenderParams, ImageRenderParams,
-    InlineSubstitutionRenderer, LinkRenderParams, LinkRenderType, QuoteScope, QuoteType,
-    SpecialCharacter,
+    CalloutGuard, CalloutRenderParams, CharacterReplacementType, FootnoteRenderParams,
+    FootnoteRenderType, HtmlSubstitutionRenderer, IconRenderParams, ImageRenderParams,
+    IndexTermRenderParams, InlineSubstitutionRenderer, LinkRenderParams, LinkRenderType,
+    MenuRenderParams, QuoteScope, QuoteType, SpecialCharacter, XrefRenderParams,
 };
 
 mod parser;
@@ -25,5 +29,8 @@ pub use path_resolver::PathResolver;
 
 pub(crate) mod preprocessor;
 
+mod safe_mode;
+pub use safe_mode::SafeMode;
+
 mod source_map;
 pub use source_map::{SourceLine, SourceMap};
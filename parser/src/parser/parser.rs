@@ -1,12 +1,12 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::{
     Document, HasSpan,
     blocks::{SectionNumber, SectionType},
-    document::{Attribute, Catalog, InterpretedValue},
+    document::{Attribute, Callouts, Catalog, Footnote, InterpretedValue, PendingIndexTerm},
     parser::{
         AllowableValue, AttributeValue, HtmlSubstitutionRenderer, IncludeFileHandler,
-        InlineSubstitutionRenderer, ModificationContext, PathResolver,
+        InlineSubstitutionRenderer, ModificationContext, PathResolver, SafeMode,
         built_in_attrs::{built_in_attrs, built_in_default_values},
         preprocessor::preprocess,
     },
@@ -40,11 +40,26 @@ pub struct Parser {
     /// Handler for resolving include:: directives.
     pub(crate) include_file_handler: Option<Rc<dyn IncludeFileHandler>>,
 
+    /// Safe mode under which the document is parsed.
+    safe_mode: SafeMode,
+
     /// Document catalog for tracking referenceable elements during parsing.
     /// This is created during parsing and transferred to the Document when
     /// complete.
     catalog: Option<Catalog>,
 
+    /// Callouts found in verbatim blocks, used to link callout list items to
+    /// their markers.
+    pub(crate) callouts: Callouts,
+
+    /// Footnotes defined so far. (Footnotes are registered while applying
+    /// the macros substitution, which only has shared access to the parser.)
+    pub(crate) footnotes: RefCell<Vec<Footnote>>,
+
+    /// Index terms found so far. (Like footnotes, these are registered while
+    /// applying the macros substitution.)
+    pub(crate) index_terms: RefCell<Vec<PendingIndexTerm>>,
+
     /// Most recently-assigned section number.
     pub(crate) last_section_number: SectionNumber,
 
@@ -57,6 +72,10 @@ pub struct Parser {
     /// Section type of outermost section. (Used to determine whether to number
     /// child sections as a normal section or appendix.)
     pub(crate) topmost_section_type: SectionType,
+
+    /// True while parsing the content of an AsciiDoc (`a`) table cell. (Used
+    /// to select the default cell separator for nested tables.)
+    pub(crate) in_table_cell: bool,
 }
 
 impl Default for Parser {
@@ -68,7 +87,11 @@ impl Default for Parser {
             primary_file_name: None,
             path_resolver: PathResolver::default(),
             include_file_handler: None,
+            safe_mode: SafeMode::default(),
             catalog: Some(Catalog::new()),
+            callouts: Callouts::default(),
+            footnotes: RefCell::new(vec![]),
+            index_terms: RefCell::new(vec![]),
             last_section_number: SectionNumber::default(),
             last_appendix_section_number: SectionNumber {
                 section_type: SectionType::Appendix,
@@ -76,7 +99,9 @@ impl Default for Parser {
             },
             sectnumlevels: 3,
             topmost_section_type: SectionType::Normal,
+            in_table_cell: false,
         }
+        .with_safe_mode(SafeMode::default())
     }
 }
 
@@ -113,7 +138,11 @@ impl Parser {
     /// [`warnings()`]: Document::warnings
     /// [`attribute_value()`]: Self::attribute_value
     pub fn parse(&mut self, source: &str) -> Document<'static> {
-        let (preprocessed_source, source_map) = preprocess(source, self);
+        // Attributes may have been provided via API after the safe mode was
+        // configured, so the safe mode restrictions are re-applied here.
+        self.apply_safe_mode_restrictions();
+
+        let (preprocessed_source, source_map, preprocessor_warnings) = preprocess(source, self);
 
         // NOTE: `Document::parse` will transfer the catalog to itself at the end of the
         // parsing operation.
@@ -124,7 +153,19 @@ impl Parser {
         // Reset section numbering for each new document.
         self.last_section_number = SectionNumber::default();
 
-        Document::parse(&preprocessed_source, source_map, self)
+        // Likewise for callouts.
+        self.callouts = Callouts::default();
+
+        // Likewise for footnotes and index terms.
+        self.footnotes = RefCell::new(vec![]);
+        self.index_terms = RefCell::new(vec![]);
+
+        Document::parse(
+            &preprocessed_source,
+            source_map,
+            preprocessor_warnings,
+            self,
+        )
     }
 
     /// Retrieves the current interpreted value of a [document attribute].
@@ -223,6 +264,14 @@ impl Parser {
         self
     }
 
+    /// Returns a reference to the document catalog.
+    ///
+    /// This is used during parsing to look up referenceable elements that
+    /// have been registered so far (e.g., to resolve cross references).
+    pub(crate) fn catalog(&self) -> Option<&Catalog> {
+        self.catalog.as_ref()
+    }
+
     /// Returns a mutable reference to the document catalog.
     ///
     /// This is used during parsing to allow code within `Document::parse` to
@@ -362,6 +411,127 @@ impl Parser {
         self
     }
 
+    /// Sets the [safe mode] under which documents will be parsed.
+    ///
+    /// This also sets the `safe-mode-name`, `safe-mode-level`, and
+    /// `safe-mode-<name>` intrinsic attributes, which may not be modified by
+    /// the document.
+    ///
+    /// As in Asciidoctor, the safe mode also restricts the following behaviors:
+    ///
+    /// - [`SafeMode::Server`] and above: The `docdir` attribute is hidden from
+    ///   the document and `docfile` is trimmed to a path relative to `docdir`.
+    ///   The document may not modify the `backend`, `copycss`, `docdir`,
+    ///   `source-highlighter`, or `user-home` attributes.
+    /// - [`SafeMode::Secure`]: `include::` directives are replaced with links
+    ///   to the include target, SVG images are not embedded or rendered as
+    ///   interactive objects, and the document may not modify the `icons`,
+    ///   `linkcss`, or `max-attribute-value-size` attributes.
+    ///
+    /// The default safe mode is [`SafeMode::Unsafe`].
+    ///
+    /// [safe mode]: https://docs.asciidoctor.org/asciidoctor/latest/safe-modes/
+    pub fn with_safe_mode(mut self, safe_mode: SafeMode) -> Self {
+        self.attribute_values
+            .remove(&format!("safe-mode-{}", self.safe_mode.name()));
+
+        self.safe_mode = safe_mode;
+
+        let mut parser = self
+            .with_intrinsic_attribute(
+                "safe-mode-name",
+                safe_mode.name(),
+                ModificationContext::ApiOnly,
+            )
+            .with_intrinsic_attribute(
+                "safe-mode-level",
+                safe_mode.level().to_string(),
+                ModificationContext::ApiOnly,
+            )
+            .with_intrinsic_attribute(
+                format!("safe-mode-{}", safe_mode.name()),
+                "",
+                ModificationContext::ApiOnly,
+            );
+
+        parser.apply_safe_mode_restrictions();
+        parser
+    }
+
+    /// Returns the [safe mode] under which documents will be parsed.
+    ///
+    /// [safe mode]: https://docs.asciidoctor.org/asciidoctor/latest/safe-modes/
+    pub fn safe_mode(&self) -> SafeMode {
+        self.safe_mode
+    }
+
+    /// Lock (and possibly override) the attributes which may not be modified by
+    /// the document under the current safe mode.
+    fn apply_safe_mode_restrictions(&mut self) {
+        if self.safe_mode < SafeMode::Server {
+            return;
+        }
+
+        // Restrict the document from seeing the `docdir` and trim `docfile` to a
+        // relative path.
+        if let InterpretedValue::Value(docdir) = self.attribute_value("docdir")
+            && !docdir.is_empty()
+            && let InterpretedValue::Value(docfile) = self.attribute_value("docfile")
+            && let Some(relative_docfile) = docfile
+                .strip_prefix(&docdir)
+                .and_then(|docfile| docfile.strip_prefix(['/', '\\']))
+        {
+            self.lock_attribute(
+                "docfile",
+                InterpretedValue::Value(relative_docfile.to_owned()),
+            );
+        }
+
+        self.lock_attribute("docdir", InterpretedValue::Value(String::new()));
+
+        self.lock_attribute_unless_present("backend", InterpretedValue::Value("html5".to_owned()));
+        self.lock_attribute_unless_present("copycss", InterpretedValue::Unset);
+        self.lock_attribute_unless_present("source-highlighter", InterpretedValue::Unset);
+        self.lock_attribute_unless_present("user-home", InterpretedValue::Value(".".to_owned()));
+
+        if self.safe_mode >= SafeMode::Secure {
+            self.lock_attribute_unless_present(
+                "max-attribute-value-size",
+                InterpretedValue::Value("4096".to_owned()),
+            );
+
+            // Assign `linkcss` (preventing CSS embedding) unless explicitly set
+            // via API.
+            self.lock_attribute_unless_present("linkcss", InterpretedValue::Set);
+            self.lock_attribute_unless_present("icons", InterpretedValue::Unset);
+        }
+    }
+
+    /// Set an attribute value which may not be modified by the document.
+    fn lock_attribute(&mut self, name: &str, value: InterpretedValue) {
+        self.attribute_values.insert(
+            name.to_owned(),
+            AttributeValue {
+                allowable_value: AllowableValue::Any,
+                modification_context: ModificationContext::ApiOnly,
+                value,
+            },
+        );
+    }
+
+    /// Prevent the document from modifying an attribute value. If the
+    /// attribute has not been set via API, it is set to `default_value`.
+    fn lock_attribute_unless_present(&mut self, name: &str, default_value: InterpretedValue) {
+        self.attribute_values
+            .entry(name.to_owned())
+            .or_insert(AttributeValue {
+                allowable_value: AllowableValue::Any,
+                modification_context: ModificationContext::ApiOnly,
+                value: default_value,
+            })
+            .modification_context = ModificationContext::ApiOnly;
+    }
+
     /// Called from [`Header::parse()`] to accept or reject an attribute value.
     ///
     /// [`Header::parse()`]: crate::document::Header::parse
@@ -386,7 +556,7 @@ impl Parser {
             return;
         }
 
-        let mut value = attr.value().clone();
+        let mut value = self.resolve_relative_value(&attr_name, attr.value());
 
         if let InterpretedValue::Set = value
             && let Some(default_value) = self.default_attribute_values.get(&attr_name)
@@ -449,12 +619,46 @@ impl Parser {
         let attribute_value = AttributeValue {
             allowable_value: AllowableValue::Any,
             modification_context: ModificationContext::Anywhere,
-            value: attr.value().clone(),
+            value: self.resolve_relative_value(&attr_name, attr.value()),
         };
 
         self.attribute_values.insert(attr_name, attribute_value);
     }
 
+    /// The `leveloffset` attribute may be assigned relative to its current
+    /// value (e.g., `:leveloffset: +1`). Convert such values to absolute
+    /// values.
+    fn resolve_relative_value(
+        &self,
+        attr_name: &str,
+        value: &InterpretedValue,
+    ) -> InterpretedValue {
+        if attr_name == "leveloffset"
+            && let InterpretedValue::Value(value) = value
+            && let Some(sign) = value.chars().next().filter(|c| *c == '+' || *c == '-')
+        {
+            let delta = value[1..].trim().parse::<isize>().unwrap_or(0);
+            let offset = if sign == '+' {
+                self.leveloffset() + delta
+            } else {
+                self.leveloffset() - delta
+            };
+
+            return InterpretedValue::Value(offset.to_string());
+        }
+
+        value.clone()
+    }
+
+    /// Returns the current value of the `leveloffset` attribute, which is added
+    /// to the level of each section heading.
+    pub(crate) fn leveloffset(&self) -> isize {
+        self.attribute_value("leveloffset")
+            .as_maybe_str()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
     /// Assign the next section number for a given level.
     pub(crate) fn assign_section_number(&mut self, level: usize) -> SectionNumber {
         match self.topmost_section_type {
@@ -496,9 +700,10 @@ mod tests {
         attributes::Attrlist,
         blocks::{Block, IsBlock},
         parser::{
-            CharacterReplacementType, IconRenderParams, ImageRenderParams,
-            InlineSubstitutionRenderer, LinkRenderParams, ModificationContext, QuoteScope,
-            QuoteType, SpecialCharacter,
+            CalloutRenderParams, CharacterReplacementType, FootnoteRenderParams, IconRenderParams,
+            ImageRenderParams, IndexTermRenderParams, InlineSubstitutionRenderer, LinkRenderParams,
+            MenuRenderParams, ModificationContext, QuoteScope, QuoteType, SafeMode,
+            SpecialCharacter, XrefRenderParams,
         },
         tests::prelude::*,
         warnings::WarningType,
@@ -568,6 +773,146 @@ mod tests {
         assert!(!p.is_attribute_set("xyz"));
     }
 
+    #[test]
+    fn with_safe_mode() {
+        let p = Parser::default();
+
+        assert_eq!(p.safe_mode(), SafeMode::Unsafe);
+        assert_eq!(
+            p.attribute_value("safe-mode-name"),
+            InterpretedValue::Value("unsafe")
+        );
+        assert_eq!(
+            p.attribute_value("safe-mode-level"),
+            InterpretedValue::Value("0")
+        );
+        assert!(p.is_attribute_set("safe-mode-unsafe"));
+
+        let p = p.with_safe_mode(SafeMode::Server);
+
+        assert_eq!(p.safe_mode(), SafeMode::Server);
+        assert_eq!(
+            p.attribute_value("safe-mode-name"),
+            InterpretedValue::Value("server")
+        );
+        assert_eq!(
+            p.attribute_value("safe-mode-level"),
+            InterpretedValue::Value("10")
+        );
+        assert!(p.is_attribute_set("safe-mode-server"));
+        assert!(!p.is_attribute_set("safe-mode-unsafe"));
+    }
+
+    #[test]
+    fn server_mode_restricts_attributes() {
+        let mut p = Parser::default()
+            .with_intrinsic_attribute("docdir", "/home/user/docs", ModificationContext::ApiOnly)
+            .with_intrinsic_attribute(
+                "docfile",
+                "/home/user/docs/guide/index.adoc",
+                ModificationContext::ApiOnly,
+            )
+            .with_intrinsic_attribute("copycss", "custom.css", ModificationContext::Anywhere)
+            .with_safe_mode(SafeMode::Server);
+
+        assert_eq!(p.attribute_value("docdir"), InterpretedValue::Value(""));
+        assert_eq!(
+            p.attribute_value("docfile"),
+            InterpretedValue::Value("guide/index.adoc")
+        );
+        assert_eq!(
+            p.attribute_value("backend"),
+            InterpretedValue::Value("html5")
+        );
+        assert_eq!(p.attribute_value("user-home"), InterpretedValue::Value("."));
+        assert_eq!(
+            p.attribute_value("copycss"),
+            InterpretedValue::Value("custom.css")
+        );
+        assert_eq!(p.attribute_value("linkcss"), InterpretedValue::Unset);
+
+        let doc = p.parse(":copycss: other.css\n:source-highlighter: rouge\n:linkcss:\n\n{docdir}");
+
+        assert_eq!(
+            doc.warnings()
+                .map(|w| w.warning.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                WarningType::AttributeValueIsLocked("copycss".to_owned()),
+                WarningType::AttributeValueIsLocked("source-highlighter".to_owned()),
+            ]
+        );
+
+        assert_eq!(
+            p.attribute_value("copycss"),
+            InterpretedValue::Value("custom.css")
+        );
+        assert_eq!(
+            p.attribute_value("source-highlighter"),
+            InterpretedValue::Unset
+        );
+        assert_eq!(p.attribute_value("linkcss"), InterpretedValue::Set);
+    }
+
+    #[test]
+    fn secure_mode_restricts_attributes() {
+        let p = Parser::default()
+            .with_intrinsic_attribute("icons", "font", ModificationContext::Anywhere)
+            .with_safe_mode(SafeMode::Secure);
+
+        assert_eq!(p.attribute_value("linkcss"), InterpretedValue::Set);
+        assert_eq!(
+            p.attribute_value("max-attribute-value-size"),
+            InterpretedValue::Value("4096")
+        );
+        assert_eq!(p.attribute_value("icons"), InterpretedValue::Value("font"));
+
+        let p = Parser::default().with_safe_mode(SafeMode::Safe);
+
+        assert_eq!(p.attribute_value("linkcss"), InterpretedValue::Unset);
+        assert_eq!(p.attribute_value("backend"), InterpretedValue::Unset);
+    }
+
+    #[test]
+    fn parse_hides_docdir_provided_after_safe_mode() {
+        let mut p = Parser::default()
+            .with_safe_mode(SafeMode::Server)
+            .with_intrinsic_attribute("docdir", "/home/user/docs", ModificationContext::Anywhere);
+
+        let doc = p.parse("Dir: {docdir}");
+
+        let block = doc.nested_blocks().next().unwrap();
+        let Block::Simple(block) = block else {
+            panic!("Unexpected block type: {block:?}");
+        };
+
+        assert_eq!(block.content().rendered(), "Dir: ");
+        assert_eq!(p.attribute_value("docdir"), InterpretedValue::Value(""));
+    }
+
+    #[test]
+    fn can_not_override_safe_mode_attributes() {
+        let mut parser = Parser::default().with_safe_mode(SafeMode::Secure);
+
+        let doc =
+            parser.parse(":safe-mode-name: unsafe\n\n:safe-mode-level: 0\n\n{safe-mode-name}");
+
+        assert_eq!(
+            doc.warnings()
+                .map(|w| w.warning.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                WarningType::AttributeValueIsLocked("safe-mode-name".to_owned()),
+                WarningType::AttributeValueIsLocked("safe-mode-level".to_owned()),
+            ]
+        );
+
+        assert_eq!(
+            parser.attribute_value("safe-mode-name"),
+            InterpretedValue::Value("secure")
+        );
+    }
+
     #[test]
     fn can_not_override_locked_default_value() {
         let mut parser = Parser::default();
@@ -670,6 +1015,34 @@ mod tests {
         fn render_anchor(&self, id: &str, _reftext: Option<String>, dest: &mut String) {
             dest.push_str(&format!("[ANCHOR:{}]", id));
         }
+
+        fn render_xref(&self, params: &XrefRenderParams, dest: &mut String) {
+            dest.push_str(&format!("[XREF:{}]", params.target));
+        }
+
+        fn render_footnote(&self, params: &FootnoteRenderParams, dest: &mut String) {
+            dest.push_str(&format!("[FOOTNOTE:{:?}]", params.index));
+        }
+
+        fn render_index_term(&self, params: &IndexTermRenderParams, dest: &mut String) {
+            dest.push_str(&format!("[INDEXTERM:{}]", params.terms.join(",")));
+        }
+
+        fn render_button(&self, text: &str, dest: &mut String) {
+            dest.push_str(&format!("[BUTTON:{text}]"));
+        }
+
+        fn render_kbd(&self, keys: &[String], dest: &mut String) {
+            dest.push_str(&format!("[KBD:{}]", keys.join("+")));
+        }
+
+        fn render_menu(&self, params: &MenuRenderParams, dest: &mut String) {
+            dest.push_str(&format!("[MENU:{}]", params.menu));
+        }
+
+        fn render_callout(&self, params: &CalloutRenderParams, dest: &mut String) {
+            dest.push_str(&format!("[CALLOUT:{}]", params.number));
+        }
     }
 
     #[test]
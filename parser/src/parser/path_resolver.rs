@@ -110,6 +110,137 @@ impl PathResolver {
         )
     }
 
+    /// Securely resolve a system path.
+    ///
+    /// Resolves the target to an absolute path on the current filesystem. The
+    /// target is assumed to be relative to the start path, jail path, or
+    /// working directory (specified in that order). If a jail path is
+    /// specified, the resolved path is forced to descend from the jail path.
+    ///
+    /// Unlike Asciidoctor, this crate does not track a working directory. If
+    /// neither a start path nor a jail path is provided, a relative target is
+    /// resolved to a (cleaned) relative path.
+    ///
+    /// # Parameters
+    /// - `target`: The target path to resolve.
+    /// - `start`: The path from which to resolve the target. If relative, it is
+    ///   taken to be relative to the jail path.
+    /// - `jail`: The absolute path which the resolved path may not escape. Any
+    ///   attempt to reference a location outside of the jail (via parent
+    ///   references or an absolute path) is silently recovered by confining the
+    ///   path to the jail.
+    ///
+    /// Returns a `String` path that joins the target path with the start path
+    /// with any parent references resolved and self references removed and
+    /// enforces that the resolved path is contained within the jail, if
+    /// provided.
+    pub fn system_path(&self, target: &str, start: Option<&str>, jail: Option<&str>) -> String {
+        let jail = jail.map(|jail| self.posixify(jail));
+
+        let mut target_segments: Vec<String> = if target.is_empty() {
+            vec![]
+        } else if self.is_root(target) {
+            let target_path = self.expand_path(target);
+
+            if let Some(jail) = &jail
+                && !self.descends_from(&target_path, jail)
+            {
+                let (target_segments, _) = self.partition_path(&target_path, WebPath(false));
+                let (mut jail_segments, jail_root) = self.partition_path(jail, WebPath(false));
+
+                jail_segments.extend(target_segments);
+                return self.join_path(&jail_segments, jail_root.as_deref());
+            }
+
+            return target_path;
+        } else {
+            self.partition_path(target, WebPath(false)).0
+        };
+
+        let start = start.filter(|start| !start.is_empty());
+
+        let start: String = if target_segments.is_empty() {
+            match start {
+                None => return jail.unwrap_or_default(),
+                Some(start) if self.is_root(start) => {
+                    if jail.is_none() {
+                        return self.expand_path(start);
+                    }
+                    self.posixify(start)
+                }
+                Some(start) => {
+                    target_segments = self.partition_path(start, WebPath(false)).0;
+                    jail.clone().unwrap_or_default()
+                }
+            }
+        } else {
+            match start {
+                None => jail.clone().unwrap_or_default(),
+                Some(start) if self.is_root(start) => self.posixify(start),
+                Some(start) => match &jail {
+                    Some(jail) => format!("{}/{start}", jail.trim_end_matches('/')),
+                    None => start.to_owned(),
+                },
+            }
+        };
+
+        let jail_segments = jail
+            .as_ref()
+            .map(|jail| self.partition_path(jail, WebPath(false)));
+
+        // Both jail and start have been posixified at this point if jail is set.
+        let mut recheck = jail
+            .as_ref()
+            .is_some_and(|jail| !self.descends_from(&start, jail));
+
+        let (start_segments, root) = if recheck && self.file_separator == '\\' {
+            let (start_segments, start_root) = self.partition_path(&start, WebPath(false));
+
+            if let Some((jail_segments, jail_root)) = &jail_segments
+                && &start_root != jail_root
+            {
+                recheck = false;
+                (jail_segments.clone(), jail_root.clone())
+            } else {
+                (start_segments, start_root)
+            }
+        } else {
+            self.partition_path(&start, WebPath(false))
+        };
+
+        let mut resolved_segments: Vec<String> = vec![];
+
+        for segment in start_segments.iter().chain(target_segments.iter()) {
+            if segment == ".." {
+                match &jail_segments {
+                    Some((jail_segments, _)) => {
+                        if resolved_segments.len() > jail_segments.len() {
+                            resolved_segments.pop();
+                        }
+                    }
+                    None => {
+                        resolved_segments.pop();
+                    }
+                }
+            } else {
+                resolved_segments.push(segment.clone());
+            }
+        }
+
+        let resolved_path = self.join_path(&resolved_segments, root.as_deref());
+
+        if recheck
+            && let Some(jail) = &jail
+            && !self.descends_from(&resolved_path, jail)
+            && let Some((mut jail_segments, jail_root)) = jail_segments
+        {
+            jail_segments.extend(target_segments);
+            self.join_path(&jail_segments, jail_root.as_deref())
+        } else {
+            resolved_path
+        }
+    }
+
     /// Partition the path into path segments and remove self references (`.`)
     /// and the trailing slash, if present. Prior to being partitioned, the path
     /// is converted to a Posix path.
@@ -134,31 +265,25 @@ impl PathResolver {
             } else {
                 None
             }
+        } else if self.is_root(&posix_path) {
+            if self.is_unc(&posix_path) {
+                // ex. //sample/path
+                Some("//".to_owned())
+            } else if posix_path.starts_with('/') {
+                // ex. /sample/path
+                Some("/".to_owned())
+            } else {
+                // ex. C:/sample/path
+                posix_path
+                    .find('/')
+                    .map(|index| posix_path[..=index].to_owned())
+            }
+        } else if posix_path.starts_with("./") {
+            // ex. ./sample/path
+            Some("./".to_owned())
         } else {
-            todo!(
-                "Port this: {}",
-                r#"
-				elsif root? posix_path
-				  # ex. //sample/path
-				  if unc? posix_path
-					root = DOUBLE_SLASH
-				  # ex. /sample/path
-				  elsif posix_path.start_with? SLASH
-					root = SLASH
-				  # ex. uri:classloader:sample/path (or uri:classloader:/sample/path)
-				  elsif posix_path.start_with? URI_CLASSLOADER
-					root = posix_path.slice 0, URI_CLASSLOADER.length
-				  # ex. C:/sample/path (or file:///sample/path in browser environment)
-				  else
-					root = posix_path.slice 0, (posix_path.index SLASH) + 1
-				  end
-				# ex. ./sample/path
-				elsif posix_path.start_with? DOT_SLASH
-				  root = DOT_SLASH
-				end
-				# otherwise ex. sample/path
-                "#
-            );
+            // otherwise ex. sample/path
+            None
         };
 
         let path_after_root = if let Some(root) = &root {
@@ -167,12 +292,16 @@ impl PathResolver {
             &posix_path
         };
 
-        let path_segments: Vec<String> = path_after_root
+        let mut path_segments: Vec<String> = path_after_root
             .split('/')
             .filter(|s| *s != ".")
             .map(|s| s.to_owned())
             .collect();
 
+        if !web.0 && path_segments.last().is_some_and(|s| s.is_empty()) {
+            path_segments.pop();
+        }
+
         // TO DO: Add cache write?
 
         (path_segments, root)
@@ -190,6 +319,52 @@ impl PathResolver {
         )
     }
 
+    /// Return `true` if the path is an absolute (root) path on the system
+    /// (i.e. starts with a `'/'` or, on Windows, a drive letter such as
+    /// `C:\`).
+    pub fn is_root(&self, path: &str) -> bool {
+        path.starts_with('/') || (self.file_separator == '\\' && WINDOWS_ROOT.is_match(path))
+    }
+
+    /// Return `true` if the path is a UNC path (i.e. starts with `//`).
+    fn is_unc(&self, path: &str) -> bool {
+        path.starts_with("//")
+    }
+
+    /// Return `true` if the path is equal to or a descendant of the base path.
+    fn descends_from(&self, path: &str, base: &str) -> bool {
+        if base == path {
+            true
+        } else if base == "/" {
+            path.starts_with('/')
+        } else {
+            path.strip_prefix(base)
+                .is_some_and(|rest| rest.starts_with('/'))
+        }
+    }
+
+    /// Expand the path by resolving any parent references (`..`) and cleaning
+    /// self references (`.`).
+    ///
+    /// The result will be relative if the path is relative and absolute if the
+    /// path is absolute. The file separator used in the expanded path is the
+    /// one specified when the struct was constructed.
+    fn expand_path(&self, path: &str) -> String {
+        let (path_segments, path_root) = self.partition_path(path, WebPath(false));
+
+        let mut resolved_segments: Vec<String> = vec![];
+
+        for segment in path_segments {
+            if segment == ".." {
+                resolved_segments.pop();
+            } else {
+                resolved_segments.push(segment);
+            }
+        }
+
+        self.join_path(&resolved_segments, path_root.as_deref())
+    }
+
     /// Return `true` if the path is an absolute (root) web path (i.e. starts
     /// with a `'/'`.
     pub fn is_web_root(&self, path: &str) -> bool {
@@ -217,6 +392,11 @@ fn extract_uri_prefix(s: &str) -> (String, Option<String>) {
     }
 }
 
+static WINDOWS_ROOT: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"^(?:[a-zA-Z]:)?[\\/]"#).unwrap()
+});
+
 // Also: Place this at module scope:
 static URI_SNIFF: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
@@ -454,6 +634,195 @@ mod tests {
         }
     }
 
+    mod system_path {
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::parser::PathResolver;
+
+        const JAIL: &str = "/home/user/docs";
+
+        fn posix() -> PathResolver {
+            PathResolver {
+                file_separator: '/',
+            }
+        }
+
+        #[test]
+        fn resolves_target_relative_to_start() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("images", Some(JAIL), None),
+                "/home/user/docs/images"
+            );
+            assert_eq!(
+                pr.system_path("../images", Some(JAIL), None),
+                "/home/user/images"
+            );
+            assert_eq!(
+                pr.system_path("./images/../assets/./tiger.png", Some(JAIL), None),
+                "/home/user/docs/assets/tiger.png"
+            );
+            assert_eq!(
+                pr.system_path("images/", Some("/home/user/docs/"), None),
+                "/home/user/docs/images"
+            );
+        }
+
+        #[test]
+        fn absolute_target_ignores_start() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("/etc/images", Some(JAIL), None),
+                "/etc/images"
+            );
+            assert_eq!(
+                pr.system_path("/etc/../var/images", Some(JAIL), None),
+                "/var/images"
+            );
+        }
+
+        #[test]
+        fn empty_target() {
+            let pr = posix();
+
+            assert_eq!(pr.system_path("", Some(JAIL), None), JAIL);
+            assert_eq!(pr.system_path("", None, Some(JAIL)), JAIL);
+            assert_eq!(
+                pr.system_path("", Some("/home/user/docs/../shared"), None),
+                "/home/user/shared"
+            );
+            assert_eq!(
+                pr.system_path("", Some("images"), Some(JAIL)),
+                "/home/user/docs/images"
+            );
+        }
+
+        #[test]
+        fn relative_without_start_or_jail() {
+            let pr = posix();
+
+            assert_eq!(pr.system_path("images", None, None), "images");
+            assert_eq!(pr.system_path("images/../assets", None, None), "assets");
+        }
+
+        #[test]
+        fn relative_start_is_relative_to_jail() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("tiger.png", Some("images"), Some(JAIL)),
+                "/home/user/docs/images/tiger.png"
+            );
+            assert_eq!(
+                pr.system_path("tiger.png", None, Some(JAIL)),
+                "/home/user/docs/tiger.png"
+            );
+        }
+
+        #[test]
+        fn parent_references_cannot_escape_jail() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("../../../etc/passwd", Some(JAIL), Some(JAIL)),
+                "/home/user/docs/etc/passwd"
+            );
+            assert_eq!(
+                pr.system_path("images/../../../etc/passwd", Some(JAIL), Some(JAIL)),
+                "/home/user/docs/etc/passwd"
+            );
+            assert_eq!(
+                pr.system_path("../../css", Some("../../.."), Some(JAIL)),
+                "/home/user/docs/css"
+            );
+            assert_eq!(
+                pr.system_path("../tiger.png", Some("/home/user/docs/images"), Some(JAIL)),
+                "/home/user/docs/tiger.png"
+            );
+        }
+
+        #[test]
+        fn absolute_target_is_confined_to_jail() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("/etc/passwd", Some(JAIL), Some(JAIL)),
+                "/home/user/docs/etc/passwd"
+            );
+            assert_eq!(
+                pr.system_path("/home/user/docs/images", None, Some(JAIL)),
+                "/home/user/docs/images"
+            );
+            assert_eq!(
+                pr.system_path("/home/user/docs-backup/secret", None, Some(JAIL)),
+                "/home/user/docs/home/user/docs-backup/secret"
+            );
+        }
+
+        #[test]
+        fn start_outside_jail_is_confined_to_jail() {
+            let pr = posix();
+
+            assert_eq!(
+                pr.system_path("images", Some("/etc"), Some(JAIL)),
+                "/home/user/docs/images"
+            );
+        }
+
+        #[test]
+        fn windows_paths() {
+            let pr = PathResolver {
+                file_separator: '\\',
+            };
+
+            assert_eq!(
+                pr.system_path("images\\tiger.png", Some("C:\\data\\docs"), None),
+                "C:/data/docs/images/tiger.png"
+            );
+            assert_eq!(
+                pr.system_path(
+                    "..\\..\\tiger.png",
+                    Some("C:\\data\\docs"),
+                    Some("C:\\data\\docs")
+                ),
+                "C:/data/docs/tiger.png"
+            );
+            assert_eq!(
+                pr.system_path("D:\\tiger.png", None, Some("C:\\data\\docs")),
+                "C:/data/docs/tiger.png"
+            );
+            assert_eq!(
+                pr.system_path("images", Some("D:\\data"), Some("C:\\data\\docs")),
+                "C:/data/docs/images"
+            );
+            assert_eq!(
+                pr.system_path("//server/share/tiger.png", None, None),
+                "//server/share/tiger.png"
+            );
+        }
+    }
+
+    #[test]
+    fn is_root() {
+        let pr = PathResolver {
+            file_separator: '/',
+        };
+        assert!(pr.is_root("/blah"));
+        assert!(!pr.is_root("blah"));
+        assert!(!pr.is_root("C:\\blah"));
+
+        let pr = PathResolver {
+            file_separator: '\\',
+        };
+        assert!(pr.is_root("/blah"));
+        assert!(pr.is_root("C:\\blah"));
+        assert!(pr.is_root("C:/blah"));
+        assert!(pr.is_root("\\blah"));
+        assert!(!pr.is_root("blah"));
+    }
+
     #[test]
     fn is_web_root() {
         let pr = PathResolver::default();
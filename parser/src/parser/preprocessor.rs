@@ -1,14 +1,17 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::{
+    borrow::Cow, cmp::Ordering, collections::HashSet, ops::RangeInclusive, path::Path,
+    sync::LazyLock,
+};
 
-use regex::{Regex, Replacer};
+use regex::{Captures, Match, Regex, Replacer};
 
 use crate::{
     HasSpan, Parser, Span,
     attributes::{Attrlist, AttrlistContext},
     document::{Attribute, InterpretedValue},
-    parser::{SourceLine, SourceMap},
+    parser::{SafeMode, SourceLine, SourceMap, inline_substitution_renderer::is_uri_ish},
     span::MatchedItem,
-    warnings::Warning,
+    warnings::{Warning, WarningType},
 };
 
 /// Given a root file (initial input to `Parser::parse`), convert this into a
@@ -18,18 +21,24 @@ use crate::{
 ///
 /// This function handles [include file] and [conditional] processing.
 ///
+/// Any warnings detected during preprocessing are returned along with the byte
+/// offset in the parse-ready text where the offending directive would have
+/// appeared.
+///
 /// [include file]: https://docs.asciidoctor.org/asciidoc/latest/directives/include/
 /// [conditional]: https://docs.asciidoctor.org/asciidoc/latest/directives/conditionals/
-pub(crate) fn preprocess(source: &str, parser: &Parser) -> (String, SourceMap) {
+pub(crate) fn preprocess(
+    source: &str,
+    parser: &Parser,
+) -> (String, SourceMap, Vec<(usize, WarningType)>) {
     // Short-circuit if the original source document has no pre-processor
     // directives.
-    if !source.starts_with("include::")
-        && !source.starts_with("if")
-        && !source.contains("\ninclude::")
-        && !source.contains("\nif")
+    if !["include::", "if", "endif::", "\\if", "\\endif::"]
+        .iter()
+        .any(|prefix| source.starts_with(prefix) || source.contains(&format!("\n{prefix}")))
         && parser.primary_file_name.is_none()
     {
-        return (source.to_owned(), SourceMap::default());
+        return (source.to_owned(), SourceMap::default(), vec![]);
     }
 
     // We use a temporary clone of the parser to track document attribute values
@@ -37,9 +46,9 @@ pub(crate) fn preprocess(source: &str, parser: &Parser) -> (String, SourceMap) {
     // document parsing.
     let mut temp_parser = parser.clone();
     let mut state = PreprocessorState::new(&mut temp_parser);
-    state.process_adoc_include(source, parser.primary_file_name.as_deref());
+    state.process_adoc_include(source, parser.primary_file_name.as_deref(), None);
 
-    (state.output, state.source_map)
+    (state.output, state.source_map, state.warnings)
 }
 
 #[derive(Debug)]
@@ -47,10 +56,36 @@ struct PreprocessorState<'p> {
     parser: &'p mut Parser,
     in_document_header: bool,
     can_have_attribute: bool,
-    include_depth: usize,
+    verbatim_delimiter: Option<String>,
+    include_stack: Vec<String>,
+    include_paths: Vec<String>,
+    reported_include_cycles: HashSet<Vec<String>>,
+    include_depth_limit: Option<IncludeDepthLimit>,
     output_line_number: usize,
     output: String,
     source_map: SourceMap,
+    conditional_stack: Vec<OpenConditional>,
+    warnings: Vec<(usize, WarningType)>,
+}
+
+/// A conditional preprocessor directive (e.g., `ifdef::attr[]`) that has not
+/// yet been closed by a matching `endif` directive.
+#[derive(Debug)]
+struct OpenConditional {
+    target: String,
+    skipping: bool,
+}
+
+/// The maximum nesting depth of include directives currently in effect.
+#[derive(Clone, Copy, Debug)]
+struct IncludeDepthLimit {
+    /// Maximum number of nested includes, counted from the root file.
+    absolute: usize,
+
+    /// Maximum depth as specified by the user (either via the
+    /// `max-include-depth` attribute or the `depth` attribute on an include
+    /// directive). Used when reporting that the limit has been exceeded.
+    relative: usize,
 }
 
 impl<'p> PreprocessorState<'p> {
@@ -59,15 +94,33 @@ impl<'p> PreprocessorState<'p> {
             parser,
             in_document_header: true,
             can_have_attribute: true,
-            include_depth: 0,
+            verbatim_delimiter: None,
+            include_stack: vec![],
+            include_paths: vec![],
+            reported_include_cycles: HashSet::new(),
+            include_depth_limit: None,
             output_line_number: 1,
             output: String::new(),
             source_map: SourceMap::default(),
+            conditional_stack: vec![],
+            warnings: vec![],
         }
     }
 
-    fn process_adoc_include(&mut self, source: &str, file_name: Option<&str>) {
-        self.include_depth += 1;
+    fn process_adoc_include(
+        &mut self,
+        source: &str,
+        file_name: Option<&str>,
+        lines: Option<&IncludeLines>,
+    ) {
+        self.include_stack
+            .push(file_name.unwrap_or("(root file)").to_owned());
+
+        let include_path = file_name
+            .map(|file_name| self.resolve_include_path(file_name))
+            .unwrap_or_default();
+
+        self.include_paths.push(include_path);
 
         let mut has_reported_file = file_name.is_none();
         let mut source_span = Span::new(source);
@@ -75,12 +128,47 @@ impl<'p> PreprocessorState<'p> {
         while !source_span.is_empty() {
             let original_source = source_span;
 
-            let MatchedItem { item: line, after } = source_span.take_line();
+            let MatchedItem {
+                item: mut line,
+                after,
+            } = source_span.take_line();
             source_span = after;
 
             let source_line_number = line.line();
 
+            if lines.is_some_and(|lines| !lines.contains(source_line_number)) {
+                has_reported_file = false;
+                continue;
+            }
+
+            // Single-line conditionals (e.g., `ifdef::attr[content]`) replace the
+            // directive line with their content, which can't be an attribute entry.
+            let mut is_conditional_content = false;
+
+            if (line.starts_with("if") || line.starts_with("endif") || line.starts_with('\\'))
+                && let Some(caps) = CONDITIONAL_DIRECTIVE.captures(line.data())
+            {
+                if caps.get(1).is_some() {
+                    // An escaped directive is passed through without the leading
+                    // backslash.
+                    line = line.slice_from(1..);
+                } else if let Some(content) = self.process_conditional(line.data(), &caps) {
+                    line = line.slice(content.range());
+                    is_conditional_content = true;
+                } else {
+                    has_reported_file = false;
+                    continue;
+                }
+            }
+
+            if self.is_skipping() {
+                has_reported_file = false;
+                continue;
+            }
+
             if self.can_have_attribute
+                && self.verbatim_delimiter.is_none()
+                && !is_conditional_content
                 && line.starts_with(':')
                 && (line.ends_with(':') || line.contains(": "))
                 && let Some(attr) = Attribute::parse(original_source, self.parser)
@@ -130,14 +218,124 @@ impl<'p> PreprocessorState<'p> {
                     })
                     .unwrap_or_default();
 
-                if let Some(include_text) =
+                // Guard against excessive nesting by leaving the directive in place
+                // once the maximum depth is reached.
+                let limit = self.include_depth_limit();
+                let nested_depth = self.include_stack.len().saturating_sub(1);
+
+                if self.parser.safe_mode() >= SafeMode::Secure {
+                    // As in Asciidoctor, the include directive is replaced with a
+                    // link to the target in secure mode.
+                    self.push_line(
+                        &format!("link:{target}[role=include]"),
+                        &mut has_reported_file,
+                        SourceLine(to_owned(file_name), source_line_number),
+                    );
+                } else if is_asciidoc_file(&target)
+                    && self
+                        .include_paths
+                        .contains(&self.resolve_include_path(&target))
+                {
+                    // A file that (directly or indirectly) includes itself is left
+                    // unexpanded. Otherwise, a file that includes itself more than
+                    // once would be expanded exponentially many times before the
+                    // depth limit is reached.
+                    let mut chain = self.include_stack.clone();
+                    chain.push(target);
+
+                    if !self.reported_include_cycles.contains(&chain) {
+                        self.warn(WarningType::IncludeCycle(chain.clone()));
+                        self.reported_include_cycles.insert(chain);
+                    }
+
+                    self.push_line(
+                        line.data(),
+                        &mut has_reported_file,
+                        SourceLine(to_owned(file_name), source_line_number),
+                    );
+                } else if nested_depth >= limit.absolute {
+                    let mut chain = self.include_stack.clone();
+                    chain.push(target);
+                    self.warn(WarningType::MaxIncludeDepthExceeded(limit.relative, chain));
+
+                    self.push_line(
+                        line.data(),
+                        &mut has_reported_file,
+                        SourceLine(to_owned(file_name), source_line_number),
+                    );
+                } else if let Some(include_text) =
                     self.parser.include_file_handler.as_ref().and_then(|ifh| {
                         ifh.resolve_target(file_name, &target, &attrlist, self.parser)
                     })
                 {
-                    // TODO: Use process_adoc_include or (TBD) depending on
-                    // whether it's an Asciidoc file type.
-                    self.process_adoc_include(&include_text, Some(&target));
+                    // As in Asciidoctor, `lines` takes precedence over `tag` and
+                    // `tags` when both are specified.
+                    let lines = match attrlist.named_attribute("lines") {
+                        Some(lines) => IncludeLines::parse(lines.value()),
+                        None => self.select_tagged_lines(&include_text, &target, &attrlist),
+                    };
+
+                    let include_text = match attrlist.named_attribute("indent") {
+                        Some(indent) => adjust_indentation(
+                            &include_text,
+                            numeric_prefix(indent.value(), false).parse().unwrap_or(0),
+                            self.tab_size(),
+                            lines.as_ref(),
+                        ),
+                        None => include_text,
+                    };
+
+                    // The `leveloffset` attribute is applied by wrapping the included
+                    // content in attribute entries that set and then restore the
+                    // document's `leveloffset` value.
+                    let leveloffset = attrlist.named_attribute("leveloffset").map(|leveloffset| {
+                        let restore = match self.parser.attribute_value("leveloffset") {
+                            InterpretedValue::Value(value) => format!(":leveloffset: {value}"),
+                            _ => ":leveloffset!:".to_owned(),
+                        };
+
+                        self.push_generated_lines(
+                            [&format!(":leveloffset: {}", leveloffset.value()), ""],
+                            SourceLine(to_owned(file_name), source_line_number),
+                        );
+
+                        restore
+                    });
+
+                    if is_asciidoc_file(&target) {
+                        // The `depth` attribute limits how deeply the included file may
+                        // itself nest includes.
+                        let prior_limit = self.include_depth_limit;
+
+                        if let Some(depth) = attrlist.named_attribute("depth") {
+                            let depth = numeric_prefix(depth.value(), false)
+                                .parse::<usize>()
+                                .unwrap_or(0)
+                                .max(1);
+
+                            // The document can not raise the limit beyond the hard maximum.
+                            let absolute = nested_depth
+                                .saturating_add(depth)
+                                .min(HARD_MAX_INCLUDE_DEPTH);
+
+                            self.include_depth_limit = Some(IncludeDepthLimit {
+                                absolute,
+                                relative: absolute - nested_depth,
+                            });
+                        }
+
+                        self.process_adoc_include(&include_text, Some(&target), lines.as_ref());
+                        self.include_depth_limit = prior_limit;
+                    } else {
+                        self.process_verbatim_include(&include_text, &target, lines.as_ref());
+                    }
+
+                    if let Some(restore) = leveloffset {
+                        self.push_generated_lines(
+                            ["", &restore],
+                            SourceLine(to_owned(file_name), source_line_number),
+                        );
+                    }
 
                     // Re-report the including file if there's more content.
                     has_reported_file = false;
@@ -161,9 +359,13 @@ impl<'p> PreprocessorState<'p> {
 
                 if line.is_empty() {
                     self.in_document_header = false;
-                    self.can_have_attribute = true;
-                } else if !self.in_document_header {
-                    self.can_have_attribute = false;
+                    self.can_have_attribute = self.verbatim_delimiter.is_none();
+                } else {
+                    if !self.in_document_header {
+                        self.can_have_attribute = false;
+                    }
+
+                    self.track_verbatim_delimiter(line.data());
                 }
 
                 self.output_line_number += 1;
@@ -172,7 +374,414 @@ impl<'p> PreprocessorState<'p> {
             }
         }
 
-        self.include_depth -= 1;
+        self.include_stack.pop();
+        self.include_paths.pop();
+    }
+
+    /// Insert the content of a non-AsciiDoc include file (e.g., source code or
+    /// CSV data) as is. Unlike [`Self::process_adoc_include`], preprocessor
+    /// directives and attribute entries within this content are not
+    /// interpreted.
+    fn process_verbatim_include(
+        &mut self,
+        source: &str,
+        file_name: &str,
+        lines: Option<&IncludeLines>,
+    ) {
+        let mut has_reported_file = false;
+
+        for (index, line) in source.lines().enumerate() {
+            let source_line_number = index + 1;
+
+            if lines.is_some_and(|lines| !lines.contains(source_line_number)) {
+                has_reported_file = false;
+                continue;
+            }
+
+            if !has_reported_file {
+                has_reported_file = true;
+                self.source_map.append(
+                    self.output_line_number,
+                    SourceLine(Some(file_name.to_owned()), source_line_number),
+                );
+            }
+
+            self.output_line_number += 1;
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+
+    /// Track whether the output is currently inside a delimited block with
+    /// verbatim content (e.g., a listing block). Attribute entries aren't
+    /// recognized within such blocks, but include directives still are.
+    fn track_verbatim_delimiter(&mut self, line: &str) {
+        match &self.verbatim_delimiter {
+            Some(delimiter) if delimiter == line => {
+                self.verbatim_delimiter = None;
+                self.can_have_attribute = true;
+            }
+            None if line.len() >= 4
+                && ['-', '.', '+', '/']
+                    .iter()
+                    .any(|&c| line.chars().all(|lc| lc == c)) =>
+            {
+                self.verbatim_delimiter = Some(line.to_owned());
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if lines are currently being skipped because of an
+    /// unsatisfied conditional directive.
+    fn is_skipping(&self) -> bool {
+        self.conditional_stack
+            .last()
+            .is_some_and(|conditional| conditional.skipping)
+    }
+
+    /// Evaluate a conditional preprocessor directive (`ifdef`, `ifndef`,
+    /// `ifeval`, or `endif`) against the current document attribute values.
+    ///
+    /// Returns the content of a single-line conditional (e.g.,
+    /// `ifdef::attr[content]`) if that content should be included in the
+    /// output.
+    fn process_conditional<'h>(&mut self, line: &str, caps: &Captures<'h>) -> Option<Match<'h>> {
+        let keyword = &caps[2];
+        let target = caps[3].to_lowercase();
+        let content = caps.get(5);
+
+        if keyword == "endif" {
+            if content.is_some() {
+                self.warn(WarningType::MalformedPreprocessorDirective(line.to_owned()));
+            } else if let Some(open) = self.conditional_stack.last() {
+                if target.is_empty() || target == open.target {
+                    self.conditional_stack.pop();
+                } else {
+                    let expected = format!("endif::{}[]", open.target);
+                    self.warn(WarningType::MismatchedPreprocessorDirective(
+                        line.to_owned(),
+                        expected,
+                    ));
+                }
+            } else {
+                self.warn(WarningType::UnmatchedPreprocessorDirective(line.to_owned()));
+            }
+
+            return None;
+        }
+
+        if self.is_skipping() {
+            // Nested conditionals must still be tracked so that each `endif`
+            // is paired with the correct opening directive.
+            if content.is_none() || keyword == "ifeval" {
+                self.conditional_stack.push(OpenConditional {
+                    target,
+                    skipping: true,
+                });
+            }
+
+            return None;
+        }
+
+        if keyword == "ifeval" {
+            // The expression must be given in the brackets; `ifeval` has no
+            // target and no single-line form.
+            let Some(expression) = content.filter(|_| target.is_empty()) else {
+                self.warn(WarningType::MalformedPreprocessorDirective(line.to_owned()));
+                return None;
+            };
+
+            let Some(include) = self.evaluate_expression(expression.as_str()) else {
+                self.warn(WarningType::InvalidIfevalExpression(line.to_owned()));
+                return None;
+            };
+
+            self.conditional_stack.push(OpenConditional {
+                target,
+                skipping: !include,
+            });
+
+            return None;
+        }
+
+        if target.is_empty() {
+            self.warn(WarningType::MalformedPreprocessorDirective(line.to_owned()));
+            return None;
+        }
+
+        let is_set = |name: &str| self.parser.is_attribute_set(name);
+
+        // With `,`, any of the attributes may be set; with `+`, all of them
+        // must be set.
+        let include = match (keyword, caps.get(4).map(|m| m.as_str())) {
+            ("ifdef", Some(",")) => target.split(',').any(is_set),
+            ("ifdef", Some(_)) => target.split('+').all(is_set),
+            ("ifdef", None) => is_set(&target),
+            (_, Some(",")) => !target.split(',').any(is_set),
+            (_, Some(_)) => !target.split('+').all(is_set),
+            (_, None) => !is_set(&target),
+        };
+
+        if content.is_some() {
+            return content.filter(|_| include);
+        }
+
+        self.conditional_stack.push(OpenConditional {
+            target,
+            skipping: !include,
+        });
+
+        None
+    }
+
+    /// Evaluate the expression of an `ifeval` directive (e.g.,
+    /// `{sectnumlevels} == 3`).
+    ///
+    /// Returns `None` if the expression is not of the form `<value>
+    /// <operator> <value>`.
+    fn evaluate_expression(&self, expression: &str) -> Option<bool> {
+        let caps = IFEVAL_EXPRESSION.captures(expression.trim())?;
+
+        let lhs = IfevalValue::resolve(&self.substitute_attributes(&caps[1]));
+        let rhs = IfevalValue::resolve(&self.substitute_attributes(&caps[3]));
+
+        // Values of different types are never equal and can't be ordered.
+        let ordering = match (&lhs, &rhs) {
+            (IfevalValue::Number(l), IfevalValue::Number(r)) => l.partial_cmp(r),
+            (IfevalValue::String(l), IfevalValue::String(r)) => Some(l.cmp(r)),
+            _ => None,
+        };
+
+        Some(match &caps[2] {
+            "==" => lhs == rhs,
+            "!=" => lhs != rhs,
+            "<" => ordering.is_some_and(Ordering::is_lt),
+            "<=" => ordering.is_some_and(Ordering::is_le),
+            ">" => ordering.is_some_and(Ordering::is_gt),
+            _ => ordering.is_some_and(Ordering::is_ge),
+        })
+    }
+
+    /// Select the lines of an include file that fall within the tagged regions
+    /// named by the `tag` or `tags` attribute of the include directive.
+    ///
+    /// Tagged regions are delimited by `tag::name[]` and `end::name[]` markers,
+    /// typically placed in comments. Lines containing these markers are never
+    /// included. A tag name prefixed with `!` excludes the region; the
+    /// wildcards `*` and `**` select all tagged regions and all lines,
+    /// respectively.
+    ///
+    /// Returns `None` if no tags were specified.
+    fn select_tagged_lines(
+        &mut self,
+        content: &str,
+        target: &str,
+        attrlist: &Attrlist<'_>,
+    ) -> Option<IncludeLines> {
+        let mut tags = include_tags(attrlist);
+        if tags.is_empty() {
+            return None;
+        }
+
+        fn take_tag(tags: &mut Vec<(&str, bool)>, name: &str) -> Option<bool> {
+            tags.iter()
+                .position(|(tag, _)| *tag == name)
+                .map(|index| tags.remove(index).1)
+        }
+
+        // As in Asciidoctor, `**` determines whether lines outside of any tagged
+        // region are selected and `*` determines whether tagged regions that
+        // aren't named explicitly are selected.
+        let (base_select, wildcard) = if let Some(select_all) = take_tag(&mut tags, "**") {
+            let wildcard = take_tag(&mut tags, "*").or_else(|| {
+                // An exclusion following `!**` implies `*`.
+                (!select_all && tags.first().is_some_and(|(_, select)| !select)).then_some(true)
+            });
+            (select_all, wildcard)
+        } else if tags.first().is_some_and(|(tag, _)| *tag == "*") {
+            // A leading `!*` selects the lines outside of any tagged region.
+            let wildcard = take_tag(&mut tags, "*");
+            (wildcard == Some(false), wildcard)
+        } else if let Some(wildcard) = take_tag(&mut tags, "*") {
+            (false, Some(wildcard))
+        } else {
+            // Lines outside of any tagged region are only selected if all of
+            // the named tags are exclusions.
+            (!tags.iter().any(|(_, select)| *select), None)
+        };
+
+        let source_line = |line_number: usize| SourceLine(Some(target.to_owned()), line_number);
+
+        let mut lines = IncludeLines(vec![]);
+        let mut select = base_select;
+        let mut tag_stack: Vec<(&str, bool, usize)> = vec![];
+        let mut found_tags: Vec<&str> = vec![];
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+
+            let Some(caps) = INCLUDE_TAG_DIRECTIVE
+                .captures(line)
+                .filter(|_| line.contains("::") && line.contains("[]"))
+            else {
+                if select {
+                    lines.push(line_number);
+                }
+                continue;
+            };
+
+            let name = caps.get(2).map_or("", |name| name.as_str());
+            let tag_select = tags.iter().find(|(tag, _)| *tag == name).map(|t| t.1);
+
+            if caps.get(1).is_some() {
+                // End of a tagged region.
+                if let Some(&(active_tag, ..)) = tag_stack.last()
+                    && active_tag == name
+                {
+                    tag_stack.pop();
+                    select = tag_stack.last().map_or(base_select, |t| t.1);
+                } else if tag_select.is_some() {
+                    if let Some(index) = tag_stack.iter().rposition(|t| t.0 == name) {
+                        tag_stack.remove(index);
+
+                        let expected = tag_stack.last().map_or("", |t| t.0).to_owned();
+                        self.warn(WarningType::MismatchedIncludeTag(
+                            name.to_owned(),
+                            expected,
+                            source_line(line_number),
+                        ));
+                    } else {
+                        self.warn(WarningType::UnexpectedIncludeTagEnd(
+                            name.to_owned(),
+                            source_line(line_number),
+                        ));
+                    }
+                }
+            } else if let Some(tag_select) = tag_select {
+                select = tag_select;
+                if select {
+                    found_tags.push(name);
+                }
+                tag_stack.push((name, select, line_number));
+            } else if let Some(wildcard) = wildcard {
+                // A wildcard doesn't select a region nested inside an excluded
+                // region.
+                select = wildcard && (tag_stack.is_empty() || select);
+                tag_stack.push((name, select, line_number));
+            }
+        }
+
+        for (name, _, line_number) in tag_stack {
+            self.warn(WarningType::UnclosedIncludeTag(
+                name.to_owned(),
+                source_line(line_number),
+            ));
+        }
+
+        for (name, select) in &tags {
+            if *select && !found_tags.contains(name) {
+                self.warn(WarningType::IncludeTagNotFound(
+                    (*name).to_owned(),
+                    target.to_owned(),
+                ));
+            }
+        }
+
+        Some(lines)
+    }
+
+    /// Add a single line to the output which doesn't need further
+    /// interpretation, reporting its source location if that hasn't
+    /// already been done.
+    fn push_line(&mut self, line: &str, has_reported_file: &mut bool, source_line: SourceLine) {
+        if !*has_reported_file {
+            *has_reported_file = true;
+            self.source_map.append(self.output_line_number, source_line);
+        }
+
+        self.output_line_number += 1;
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    /// Add lines to the output that don't appear in any source file (e.g., the
+    /// attribute entries that apply an include directive's `leveloffset`).
+    /// Each of these lines is attributed to `source_line`.
+    fn push_generated_lines(&mut self, lines: [&str; 2], source_line: SourceLine) {
+        for line in lines {
+            self.source_map
+                .append(self.output_line_number, source_line.clone());
+
+            if line.is_empty() {
+                self.in_document_header = false;
+                self.can_have_attribute = true;
+            } else if let Some(attr) = Attribute::parse(Span::new(line), self.parser) {
+                let mut warnings: Vec<Warning> = vec![];
+                self.parser
+                    .set_attribute_from_body(&attr.item, &mut warnings);
+            }
+
+            self.output_line_number += 1;
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+
+    /// Resolve an include target relative to the file currently being
+    /// processed. The resulting path is used only to detect include cycles;
+    /// the [`IncludeFileHandler`] remains responsible for locating the file.
+    ///
+    /// [`IncludeFileHandler`]: crate::parser::IncludeFileHandler
+    fn resolve_include_path(&self, target: &str) -> String {
+        if is_uri_ish(target) {
+            return target.to_owned();
+        }
+
+        let start = self
+            .include_paths
+            .last()
+            .and_then(|path| path.rsplit_once('/'))
+            .map(|(dir, _)| dir);
+
+        self.parser.path_resolver.web_path(target, start)
+    }
+
+    /// Return the include depth limit that applies at the current position. If
+    /// no include directive has overridden it using the `depth` attribute, this
+    /// is taken from the `max-include-depth` document attribute.
+    ///
+    /// In either case, the limit never exceeds [`HARD_MAX_INCLUDE_DEPTH`].
+    fn include_depth_limit(&self) -> IncludeDepthLimit {
+        self.include_depth_limit.unwrap_or_else(|| {
+            let max_depth = self
+                .parser
+                .attribute_value("max-include-depth")
+                .as_maybe_str()
+                .map(|value| numeric_prefix(value.trim(), false).parse().unwrap_or(0))
+                .unwrap_or(DEFAULT_MAX_INCLUDE_DEPTH)
+                .min(HARD_MAX_INCLUDE_DEPTH);
+
+            IncludeDepthLimit {
+                absolute: max_depth,
+                relative: max_depth,
+            }
+        })
+    }
+
+    /// Returns the value of the `tabsize` document attribute, or zero if tabs
+    /// should not be expanded.
+    fn tab_size(&self) -> usize {
+        self.parser
+            .attribute_value("tabsize")
+            .as_maybe_str()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    /// Record a warning at the current position in the output.
+    fn warn(&mut self, warning: WarningType) {
+        self.warnings.push((self.output.len(), warning));
     }
 
     /// Apply attribute substitution to a string, replacing {attribute-name}
@@ -217,10 +826,270 @@ impl<'p> PreprocessorState<'p> {
     }
 }
 
+/// An operand of an `ifeval` expression after attribute substitution.
+#[derive(Debug, PartialEq)]
+enum IfevalValue {
+    Nil,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+}
+
+impl IfevalValue {
+    /// Interpret an operand the way Asciidoctor does: quoted values are
+    /// strings, `true` and `false` are Booleans, and anything else is a
+    /// number (an integer unless it contains a `.`).
+    fn resolve(value: &str) -> Self {
+        if value.is_empty() {
+            return Self::Nil;
+        }
+
+        let bytes = value.as_bytes();
+        if bytes.len() >= 2
+            && (bytes[0] == b'"' || bytes[0] == b'\'')
+            && bytes[bytes.len() - 1] == bytes[0]
+        {
+            return Self::String(value[1..value.len() - 1].to_owned());
+        }
+
+        match value {
+            "true" => Self::Boolean(true),
+            "false" => Self::Boolean(false),
+            _ if value.trim().is_empty() => Self::String(" ".to_owned()),
+            _ => Self::Number(
+                numeric_prefix(value, value.contains('.'))
+                    .parse()
+                    .unwrap_or(0.0),
+            ),
+        }
+    }
+}
+
+/// The line numbers selected by the `lines` attribute of an include directive
+/// (e.g., `lines=1..5;10;20..-1`).
+#[derive(Debug)]
+struct IncludeLines(Vec<RangeInclusive<usize>>);
+
+impl IncludeLines {
+    /// Parse the value of the `lines` attribute. Ranges may be separated by
+    /// commas or semicolons. An open or negative end of a range (e.g., `12..`
+    /// or `12..-1`) refers to the last line of the file.
+    fn parse(value: &str) -> Option<Self> {
+        let ranges: Vec<RangeInclusive<usize>> = value
+            .split([',', ';'])
+            .map(str::trim)
+            .filter(|line_def| !line_def.is_empty())
+            .filter_map(|line_def| {
+                // Negative line numbers yield `None`.
+                let to_line_number = |n: &str| {
+                    usize::try_from(numeric_prefix(n, false).parse::<i64>().unwrap_or(0)).ok()
+                };
+
+                if let Some((from, to)) = line_def.split_once("..") {
+                    let from = to_line_number(from).unwrap_or(0);
+                    let to = if to.is_empty() {
+                        usize::MAX
+                    } else {
+                        to_line_number(to).unwrap_or(usize::MAX)
+                    };
+                    Some(from..=to)
+                } else {
+                    to_line_number(line_def).map(|n| n..=n)
+                }
+            })
+            .collect();
+
+        if ranges.is_empty() {
+            None
+        } else {
+            Some(Self(ranges))
+        }
+    }
+
+    /// Add a single line, extending the last range if the line immediately
+    /// follows it.
+    fn push(&mut self, line_number: usize) {
+        if let Some(last) = self.0.last_mut()
+            && *last.end() + 1 == line_number
+        {
+            *last = *last.start()..=line_number;
+        } else {
+            self.0.push(line_number..=line_number);
+        }
+    }
+
+    fn contains(&self, line_number: usize) -> bool {
+        self.0.iter().any(|range| range.contains(&line_number))
+    }
+}
+
+/// Apply the `indent` attribute of an include directive: Strip the leading
+/// block indent from `content` and, if `indent` is greater than zero, indent
+/// each line by that many spaces. A negative `indent` leaves the indentation
+/// as is. If `tab_size` is non-zero, tabs are first expanded to spaces.
+///
+/// Only the lines that will be included (per `selection`) are considered when
+/// determining the block indent.
+fn adjust_indentation(
+    content: &str,
+    indent: isize,
+    tab_size: usize,
+    selection: Option<&IncludeLines>,
+) -> String {
+    let lines: Vec<Cow<'_, str>> = content
+        .lines()
+        .map(|line| expand_tabs(line, tab_size))
+        .collect();
+
+    let leading_whitespace = |line: &str| {
+        line.len()
+            - line
+                .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                .len()
+    };
+
+    let block_indent = lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            !line.trim().is_empty() && selection.is_none_or(|lines| lines.contains(index + 1))
+        })
+        .map(|(_, line)| leading_whitespace(line))
+        .min()
+        .unwrap_or(0);
+
+    let new_indent = " ".repeat(indent.max(0).unsigned_abs());
+
+    let mut result = String::with_capacity(content.len());
+
+    for line in lines {
+        if indent < 0 {
+            result.push_str(&line);
+        } else if !line.trim().is_empty() {
+            // Lines outside of `selection` may be indented less than the block
+            // indent; they will be discarded anyway.
+            let strip = block_indent.min(leading_whitespace(&line));
+            result.push_str(&new_indent);
+            result.push_str(&line[strip..]);
+        }
+
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Replace each tab in `line` with spaces up to the next tab stop.
+fn expand_tabs(line: &str, tab_size: usize) -> Cow<'_, str> {
+    if tab_size == 0 || !line.contains('\t') {
+        return Cow::Borrowed(line);
+    }
+
+    let mut result = String::with_capacity(line.len() + tab_size);
+    let mut column = 0;
+
+    for c in line.chars() {
+        if c == '\t' {
+            let spaces = tab_size - column % tab_size;
+            result.push_str(&" ".repeat(spaces));
+            column += spaces;
+        } else {
+            result.push(c);
+            column += 1;
+        }
+    }
+
+    Cow::Owned(result)
+}
+
+/// Parse the `tag` or `tags` attribute of an include directive into a list of
+/// tag names, each of which is either selected or (if prefixed with `!`)
+/// excluded.
+fn include_tags<'a>(attrlist: &'a Attrlist<'_>) -> Vec<(&'a str, bool)> {
+    let tag_defs: Vec<&str> = if let Some(tag) = attrlist.named_attribute("tag") {
+        vec![tag.value()]
+    } else if let Some(tags) = attrlist.named_attribute("tags") {
+        tags.value().split([',', ';']).collect()
+    } else {
+        return vec![];
+    };
+
+    let mut tags: Vec<(&str, bool)> = vec![];
+
+    for tag_def in tag_defs.into_iter().map(str::trim) {
+        if tag_def.is_empty() || tag_def == "!" {
+            continue;
+        }
+
+        let (name, select) = match tag_def.strip_prefix('!') {
+            Some(name) => (name, false),
+            None => (tag_def, true),
+        };
+
+        if let Some(tag) = tags.iter_mut().find(|(tag, _)| *tag == name) {
+            tag.1 = select;
+        } else {
+            tags.push((name, select));
+        }
+    }
+
+    tags
+}
+
+/// Return the longest prefix of `value` that looks like a number. Parsing the
+/// result yields the same value as Ruby's `to_i` or `to_f` conversions, except
+/// that an empty prefix should be treated as zero.
+fn numeric_prefix(value: &str, allow_fraction: bool) -> &str {
+    let value = value.trim_start();
+    let bytes = value.as_bytes();
+
+    let mut end = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        end = 1;
+    }
+
+    let mut seen_dot = false;
+    while let Some(&b) = bytes.get(end) {
+        if b.is_ascii_digit() {
+            end += 1;
+        } else if b == b'.'
+            && allow_fraction
+            && !seen_dot
+            && bytes.get(end + 1).is_some_and(u8::is_ascii_digit)
+        {
+            seen_dot = true;
+            end += 1;
+        } else {
+            break;
+        }
+    }
+
+    &value[..end]
+}
+
+/// Returns `true` if the include target has one of the file extensions that
+/// Asciidoctor recognizes as AsciiDoc. Other files are included verbatim.
+fn is_asciidoc_file(target: &str) -> bool {
+    Path::new(target)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "adoc" | "asciidoc" | "ad" | "asc" | "txt"))
+}
+
 fn to_owned(maybe_file_name: Option<&str>) -> Option<String> {
     maybe_file_name.map(|n| n.to_string())
 }
 
+/// Maximum nesting depth of include directives when the `max-include-depth`
+/// attribute is not set. (Same as Asciidoctor.)
+const DEFAULT_MAX_INCLUDE_DEPTH: usize = 64;
+
+/// Maximum nesting depth of include directives regardless of the
+/// `max-include-depth` attribute or the `depth` attribute on an include
+/// directive. Include directives are expanded recursively, so this prevents a
+/// document from exhausting the stack.
+const HARD_MAX_INCLUDE_DEPTH: usize = 64;
+
 static INCLUDE_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
     Regex::new(
@@ -247,6 +1116,83 @@ static INCLUDE_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
+static CONDITIONAL_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)                      # Extended (verbose) mode
+
+        ^                           # Start of string
+
+        (\\)?                       # (1) Optional escape character
+
+        (ifdef|ifndef|ifeval|endif) # (2) Directive name
+
+        ::                          # Literal '::' separator
+
+        (                           # (3) Target attribute name(s)
+            \S*?                     #   First (or only) attribute name
+            (?:
+                ([,+])                #   (4) Separator for multiple names
+                \S*?                 #   Remaining attribute names
+            )?
+        )                           # end capture group 3
+
+        \[                          # Literal '[' starting the content
+
+        (.+)?                       # (5) Optional content for single-line form
+
+        \]                          # Literal closing bracket
+
+        $                           # End of line
+        "#,
+    )
+    .unwrap()
+});
+
+static IFEVAL_EXPRESSION: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)                      # Extended (verbose) mode
+
+        ^                           # Start of string
+
+        (.+?)                       # (1) Left-hand value
+
+        \x20*                       # Optional spaces
+
+        ([=!><]=|[><])              # (2) Comparison operator
+
+        \x20*                       # Optional spaces
+
+        (.+)                        # (3) Right-hand value
+
+        $                           # End of string
+        "#,
+    )
+    .unwrap()
+});
+
+static INCLUDE_TAG_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?x)                      # Extended (verbose) mode
+
+        \b                          # Word boundary (e.g., after a comment marker)
+
+        (?:tag|(e)nd)               # 'tag' or (1) 'end'
+
+        ::                          # Literal '::' separator
+
+        (\S+?)                      # (2) Tag name
+
+        \[\]                        # Literal '[]'
+
+        (?:$|[\x20\r])               # End of line or whitespace
+        "#,
+    )
+    .unwrap()
+});
+
 static ATTRIBUTE_REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
     #[allow(clippy::unwrap_used)]
     Regex::new(r#"\\?\{([A-Za-z0-9_][A-Za-z0-9_-]*)\}"#).unwrap()
@@ -257,9 +1203,15 @@ mod tests {
     #![allow(clippy::unwrap_used)]
 
     use crate::{
-        Parser,
-        parser::{SourceLine, preprocessor::preprocess},
+        Parser, Span,
+        attributes::Attrlist,
+        blocks::IsBlock,
+        parser::{
+            IncludeFileHandler, ModificationContext, SafeMode, SourceLine, SourceMap,
+            preprocessor::preprocess,
+        },
         tests::fixtures::inline_file_handler::InlineFileHandler,
+        warnings::{Warning, WarningType},
     };
 
     #[test]
@@ -268,7 +1220,7 @@ mod tests {
             "= Document Title\n\nThis is a simple document with no includes or conditionals.";
         let parser = Parser::default().with_primary_file_name("test.adoc");
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -294,7 +1246,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -342,7 +1294,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -392,7 +1344,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -452,7 +1404,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -490,7 +1442,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -512,7 +1464,7 @@ mod tests {
         // NOTE: No include file handler provided.
         let parser = Parser::default().with_primary_file_name("main.adoc");
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -550,7 +1502,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -576,7 +1528,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -614,7 +1566,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -651,7 +1603,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -691,7 +1643,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -713,30 +1665,28 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn attribute_substitution_in_target_with_attrlist() {
-        // TODO: Implement tag handling.
         let source = ":srcdir: examples\n:lang: java\n\ninclude::{srcdir}/hello.{lang}[tag=main]";
 
         let handler = InlineFileHandler::from_pairs([(
             "examples/hello.java",
-            "// tag::main\npublic class Hello {}\n// end::main",
+            "// tag::main[]\npublic class Hello {}\n// end::main[]",
         )]);
 
         let parser = Parser::default()
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
-            ":srcdir: examples\n:lang: java\n\n// tag::main\npublic class Hello {}\n// end::main\n"
+            ":srcdir: examples\n:lang: java\n\npublic class Hello {}\n"
         );
 
         assert_eq!(
             source_map.original_file_and_line(4),
-            Some(SourceLine(Some("examples/hello.java".to_owned()), 1))
+            Some(SourceLine(Some("examples/hello.java".to_owned()), 2))
         );
     }
 
@@ -757,7 +1707,7 @@ mod tests {
             .with_primary_file_name("main.adoc")
             .with_include_file_handler(handler);
 
-        let (processed_source, source_map) = preprocess(source, &parser);
+        let (processed_source, source_map, _) = preprocess(source, &parser);
 
         assert_eq!(
             processed_source,
@@ -788,4 +1738,1501 @@ mod tests {
             ))
         );
     }
+
+    fn lines_handler() -> InlineFileHandler {
+        InlineFileHandler::from_pairs([(
+            "lines.adoc",
+            "line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nline 8\n",
+        )])
+    }
+
+    fn include_lines(lines: &str) -> (String, SourceMap) {
+        let source = format!("Before.\ninclude::lines.adoc[{lines}]\nAfter.");
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(lines_handler());
+
+        let (processed_source, source_map, warnings) = preprocess(&source, &parser);
+        assert!(warnings.is_empty());
+
+        (processed_source, source_map)
+    }
+
+    #[test]
+    fn include_lines_range() {
+        let (processed_source, source_map) = include_lines("lines=3..5");
+
+        assert_eq!(
+            processed_source,
+            "Before.\nline 3\nline 4\nline 5\nAfter.\n"
+        );
+
+        assert_eq!(
+            source_map.original_file_and_line(1),
+            Some(SourceLine(Some("main.adoc".to_owned()), 1))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 5))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(5),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+    }
+
+    #[test]
+    fn include_lines_multiple_ranges() {
+        let (processed_source, source_map) = include_lines("lines=1;4..5;7");
+
+        assert_eq!(
+            processed_source,
+            "Before.\nline 1\nline 4\nline 5\nline 7\nAfter.\n"
+        );
+
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 1))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 4))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 5))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(5),
+            Some(SourceLine(Some("lines.adoc".to_owned()), 7))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(6),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+    }
+
+    #[test]
+    fn include_lines_comma_separated() {
+        let (processed_source, _) = include_lines("lines=\"2,5..6\"");
+
+        assert_eq!(
+            processed_source,
+            "Before.\nline 2\nline 5\nline 6\nAfter.\n"
+        );
+    }
+
+    #[test]
+    fn include_lines_to_end() {
+        let expected = "Before.\nline 6\nline 7\nline 8\nAfter.\n";
+
+        assert_eq!(include_lines("lines=6..-1").0, expected);
+        assert_eq!(include_lines("lines=6..").0, expected);
+    }
+
+    #[test]
+    fn include_lines_out_of_order() {
+        let (processed_source, _) = include_lines("lines=7;2");
+
+        // Lines are always included in their original order.
+        assert_eq!(processed_source, "Before.\nline 2\nline 7\nAfter.\n");
+    }
+
+    #[test]
+    fn include_lines_empty_or_invalid() {
+        let (processed_source, _) = include_lines("lines=");
+
+        assert_eq!(
+            processed_source,
+            "Before.\nline 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nline 8\nAfter.\n"
+        );
+
+        let (processed_source, _) = include_lines("lines=5..2;0;-3");
+        assert_eq!(processed_source, "Before.\nAfter.\n");
+    }
+
+    #[test]
+    fn include_lines_skips_directives_outside_range() {
+        let source = "include::lines.adoc[lines=2..3]\nAfter.";
+
+        let handler = InlineFileHandler::from_pairs([(
+            "lines.adoc",
+            "ifdef::foo[]\nline 2\nline 3\nendif::[]\n",
+        )]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "line 2\nline 3\nAfter.\n");
+        assert!(warnings.is_empty());
+    }
+
+    const TAGGED_FILE: &str = "preamble\n// tag::a[]\na1\n# tag::b[]\nb1\n# end::b[]\na2\n// end::a[]\nbetween\n<!-- tag::debug[] -->\ndebug\n<!-- end::debug[] -->\ntrailer\n";
+
+    fn include_with_tags(
+        file: &'static str,
+        attrlist: &str,
+    ) -> (String, Vec<(usize, WarningType)>) {
+        let source = format!("include::tags.adoc[{attrlist}]");
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(InlineFileHandler::from_pairs([("tags.adoc", file)]));
+
+        let (processed_source, _, warnings) = preprocess(&source, &parser);
+        (processed_source, warnings)
+    }
+
+    #[test]
+    fn include_single_tag() {
+        let source = "Before.\ninclude::tags.adoc[tag=a]\nAfter.";
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(InlineFileHandler::from_pairs([("tags.adoc", TAGGED_FILE)]));
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "Before.\na1\nb1\na2\nAfter.\n");
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(Some("tags.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("tags.adoc".to_owned()), 5))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("tags.adoc".to_owned()), 7))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(5),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+    }
+
+    #[test]
+    fn include_multiple_tags() {
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=b;debug"),
+            ("b1\ndebug\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=\"b,debug\""),
+            ("b1\ndebug\n".to_owned(), vec![])
+        );
+    }
+
+    #[test]
+    fn include_negated_tags() {
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tag=!a"),
+            ("preamble\nbetween\ndebug\ntrailer\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=a;!b"),
+            ("a1\na2\n".to_owned(), vec![])
+        );
+    }
+
+    #[test]
+    fn include_tag_wildcards() {
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=*"),
+            ("a1\nb1\na2\ndebug\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=**"),
+            (
+                "preamble\na1\nb1\na2\nbetween\ndebug\ntrailer\n".to_owned(),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=*;!debug"),
+            ("a1\nb1\na2\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=**;!debug"),
+            (
+                "preamble\na1\nb1\na2\nbetween\ntrailer\n".to_owned(),
+                vec![]
+            )
+        );
+
+        // A region nested inside an excluded region isn't selected by the
+        // wildcard.
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=*;!a"),
+            ("debug\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=!*"),
+            ("preamble\nbetween\ntrailer\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=!*;a"),
+            ("preamble\na1\na2\nbetween\ntrailer\n".to_owned(), vec![])
+        );
+
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=a;!*"),
+            ("a1\na2\n".to_owned(), vec![])
+        );
+    }
+
+    #[test]
+    fn include_lines_take_precedence_over_tags() {
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "lines=1;9,tag=a"),
+            ("preamble\nbetween\n".to_owned(), vec![])
+        );
+    }
+
+    #[test]
+    fn err_include_tag_not_found() {
+        assert_eq!(
+            include_with_tags(TAGGED_FILE, "tags=a;missing"),
+            (
+                "a1\nb1\na2\n".to_owned(),
+                vec![(
+                    0,
+                    WarningType::IncludeTagNotFound("missing".to_owned(), "tags.adoc".to_owned())
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn err_unclosed_include_tag() {
+        assert_eq!(
+            include_with_tags("before\n// tag::a[]\ninside\n", "tag=a"),
+            (
+                "inside\n".to_owned(),
+                vec![(
+                    0,
+                    WarningType::UnclosedIncludeTag(
+                        "a".to_owned(),
+                        SourceLine(Some("tags.adoc".to_owned()), 2)
+                    )
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn err_mismatched_include_tag() {
+        assert_eq!(
+            include_with_tags(
+                "// tag::a[]\n// tag::b[]\nx\n// end::a[]\ny\n// end::b[]\nz\n",
+                "tags=a;b"
+            ),
+            (
+                "x\ny\n".to_owned(),
+                vec![(
+                    0,
+                    WarningType::MismatchedIncludeTag(
+                        "a".to_owned(),
+                        "b".to_owned(),
+                        SourceLine(Some("tags.adoc".to_owned()), 4)
+                    )
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn err_unexpected_include_tag_end() {
+        assert_eq!(
+            include_with_tags("x\n// end::a[]\ny\n", "tags=a;!b"),
+            (
+                "".to_owned(),
+                vec![
+                    (
+                        0,
+                        WarningType::UnexpectedIncludeTagEnd(
+                            "a".to_owned(),
+                            SourceLine(Some("tags.adoc".to_owned()), 2)
+                        )
+                    ),
+                    (
+                        0,
+                        WarningType::IncludeTagNotFound("a".to_owned(), "tags.adoc".to_owned())
+                    ),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn include_with_leveloffset() {
+        let source = "= Book\n\ninclude::chapter1.adoc[leveloffset=+1]\n\nAfter.";
+
+        let handler =
+            InlineFileHandler::from_pairs([("chapter1.adoc", "= Chapter 1\n\nContent.\n")]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            "= Book\n\n:leveloffset: +1\n\n= Chapter 1\n\nContent.\n\n:leveloffset!:\n\nAfter.\n"
+        );
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(5),
+            Some(SourceLine(Some("chapter1.adoc".to_owned()), 1))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(7),
+            Some(SourceLine(Some("chapter1.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(9),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(11),
+            Some(SourceLine(Some("main.adoc".to_owned()), 5))
+        );
+    }
+
+    #[test]
+    fn include_with_leveloffset_restores_prior_value() {
+        let source = ":leveloffset: 1\n\ninclude::outer.adoc[leveloffset=+1]\n";
+
+        let handler = InlineFileHandler::from_pairs([
+            (
+                "outer.adoc",
+                "= Outer\n\ninclude::inner.adoc[leveloffset=-1]\n",
+            ),
+            ("inner.adoc", "= Inner\n"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            ":leveloffset: 1\n\n:leveloffset: +1\n\n= Outer\n\n:leveloffset: -1\n\n= Inner\n\n:leveloffset: 2\n\n:leveloffset: 1\n"
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn include_with_indent() {
+        let handler = InlineFileHandler::from_pairs([(
+            "code.rb",
+            "    def names\n      @name.split ' '\n\n    end\n",
+        )]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, _) = preprocess("include::code.rb[indent=0]", &parser);
+        assert_eq!(processed_source, "def names\n  @name.split ' '\n\nend\n");
+
+        let (processed_source, _, _) = preprocess("include::code.rb[indent=2]", &parser);
+        assert_eq!(
+            processed_source,
+            "  def names\n    @name.split ' '\n\n  end\n"
+        );
+
+        let (processed_source, _, _) = preprocess("include::code.rb[indent=-1]", &parser);
+        assert_eq!(
+            processed_source,
+            "    def names\n      @name.split ' '\n\n    end\n"
+        );
+    }
+
+    #[test]
+    fn include_with_indent_considers_selected_lines_only() {
+        let handler = InlineFileHandler::from_pairs([(
+            "code.rs",
+            "fn main() {\n    // tag::body[]\n    if true {\n        run();\n    }\n    // end::body[]\n}\n",
+        )]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, source_map, _) =
+            preprocess("include::code.rs[tag=body,indent=0]", &parser);
+
+        assert_eq!(processed_source, "if true {\n    run();\n}\n");
+
+        assert_eq!(
+            source_map.original_file_and_line(1),
+            Some(SourceLine(Some("code.rs".to_owned()), 3))
+        );
+
+        let (processed_source, _, _) = preprocess("include::code.rs[lines=4,indent=1]", &parser);
+        assert_eq!(processed_source, " run();\n");
+    }
+
+    #[test]
+    fn include_with_indent_expands_tabs() {
+        let handler = InlineFileHandler::from_pairs([("code.c", "\tif (x) {\n\t\ty();\n\t}\n")]);
+
+        let parser = Parser::default()
+            .with_intrinsic_attribute("tabsize", "4", ModificationContext::Anywhere)
+            .with_include_file_handler(handler);
+
+        let (processed_source, _, _) = preprocess("include::code.c[indent=0]", &parser);
+        assert_eq!(processed_source, "if (x) {\n    y();\n}\n");
+    }
+
+    #[test]
+    fn non_asciidoc_include_is_verbatim() {
+        let source = "[source,json]\n----\ninclude::data.json[]\n----\n\nAfter.";
+
+        let handler = InlineFileHandler::from_pairs([
+            (
+                "data.json",
+                ":not-an-attribute: value\n\ninclude::other.adoc[]\nifdef::foo[]\n{ \"key\": \"{value}\" }\nendif::[]\n",
+            ),
+            ("other.adoc", "Should not be included."),
+        ]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            "[source,json]\n----\n:not-an-attribute: value\n\ninclude::other.adoc[]\nifdef::foo[]\n{ \"key\": \"{value}\" }\nendif::[]\n----\n\nAfter.\n"
+        );
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("data.json".to_owned()), 1))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(8),
+            Some(SourceLine(Some("data.json".to_owned()), 6))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(9),
+            Some(SourceLine(Some("main.adoc".to_owned()), 4))
+        );
+    }
+
+    #[test]
+    fn non_asciidoc_include_with_lines() {
+        let source = "include::main.rs[lines=2..3]";
+
+        let handler =
+            InlineFileHandler::from_pairs([("main.rs", "fn main() {\n    run();\n}\n// end\n")]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, source_map, _) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "    run();\n}\n");
+
+        assert_eq!(
+            source_map.original_file_and_line(1),
+            Some(SourceLine(Some("main.rs".to_owned()), 2))
+        );
+    }
+
+    #[test]
+    fn asciidoc_file_extensions() {
+        let handler = InlineFileHandler::from_pairs([
+            ("a.adoc", "include::inner.yaml[]"),
+            ("a.asciidoc", "include::inner.yaml[]"),
+            ("a.ad", "include::inner.yaml[]"),
+            ("a.asc", "include::inner.yaml[]"),
+            ("a.txt", "include::inner.yaml[]"),
+            ("a.yaml", "include::inner.yaml[]"),
+            ("a", "include::inner.yaml[]"),
+            ("inner.yaml", "key: value"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        for target in ["a.adoc", "a.asciidoc", "a.ad", "a.asc", "a.txt"] {
+            let (processed_source, _, _) = preprocess(&format!("include::{target}[]"), &parser);
+            assert_eq!(processed_source, "key: value\n");
+        }
+
+        for target in ["a.yaml", "a"] {
+            let (processed_source, _, _) = preprocess(&format!("include::{target}[]"), &parser);
+            assert_eq!(processed_source, "include::inner.yaml[]\n");
+        }
+    }
+
+    /// Resolves `levelN.adoc` to a file that includes `levelN+1.adoc`, so
+    /// includes nest without end and without ever forming a cycle.
+    #[derive(Debug)]
+    struct EndlessIncludeHandler;
+
+    impl IncludeFileHandler for EndlessIncludeHandler {
+        fn resolve_target<'src>(
+            &self,
+            _source: Option<&str>,
+            target: &str,
+            _attrlist: &Attrlist<'src>,
+            _parser: &Parser,
+        ) -> Option<String> {
+            let level: usize = target
+                .strip_prefix("level")?
+                .strip_suffix(".adoc")?
+                .parse()
+                .ok()?;
+            Some(format!(
+                "Level {level}.\ninclude::level{next}.adoc[]\n",
+                next = level + 1
+            ))
+        }
+    }
+
+    /// Returns the expected output and include chain when
+    /// [`EndlessIncludeHandler`] stops at the given depth.
+    fn endless_include_output(depth: usize) -> (String, Vec<String>) {
+        let output = (1..=depth)
+            .map(|level| format!("Level {level}.\n"))
+            .chain(std::iter::once(format!(
+                "include::level{}.adoc[]\n",
+                depth + 1
+            )))
+            .collect();
+
+        let chain = std::iter::once("(root file)".to_owned())
+            .chain((1..=depth + 1).map(|level| format!("level{level}.adoc")))
+            .collect();
+
+        (output, chain)
+    }
+
+    #[test]
+    fn endless_include_stops_at_max_include_depth() {
+        let parser = Parser::default().with_include_file_handler(EndlessIncludeHandler);
+
+        let (processed_source, _, warnings) = preprocess("include::level1.adoc[]\n", &parser);
+
+        let (output, chain) = endless_include_output(64);
+        let offset = output.len() - "include::level65.adoc[]\n".len();
+
+        assert_eq!(processed_source, output);
+
+        assert_eq!(
+            warnings,
+            vec![(offset, WarningType::MaxIncludeDepthExceeded(64, chain))]
+        );
+    }
+
+    #[test]
+    fn endless_include_with_large_depth_is_capped() {
+        let parser = Parser::default().with_include_file_handler(EndlessIncludeHandler);
+
+        let (processed_source, _, warnings) =
+            preprocess("include::level1.adoc[depth=5000]\n", &parser);
+
+        let (output, chain) = endless_include_output(64);
+        let offset = output.len() - "include::level65.adoc[]\n".len();
+
+        assert_eq!(processed_source, output);
+
+        assert_eq!(
+            warnings,
+            vec![(offset, WarningType::MaxIncludeDepthExceeded(64, chain))]
+        );
+    }
+
+    #[test]
+    fn large_max_include_depth_attribute_is_capped() {
+        let parser = Parser::default().with_include_file_handler(EndlessIncludeHandler);
+
+        let (processed_source, _, warnings) = preprocess(
+            ":max-include-depth: 100000\n\ninclude::level1.adoc[]\n",
+            &parser,
+        );
+
+        let (output, chain) = endless_include_output(64);
+        let offset = 28 + output.len() - "include::level65.adoc[]\n".len();
+
+        assert_eq!(
+            processed_source,
+            format!(":max-include-depth: 100000\n\n{output}")
+        );
+
+        assert_eq!(
+            warnings,
+            vec![(offset, WarningType::MaxIncludeDepthExceeded(64, chain))]
+        );
+    }
+
+    #[test]
+    fn self_include_is_reported_as_cycle() {
+        let handler =
+            InlineFileHandler::from_pairs([("self.adoc", "Self.\ninclude::self.adoc[]\n")]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess("include::self.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "Self.\ninclude::self.adoc[]\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                6,
+                WarningType::IncludeCycle(vec![
+                    "(root file)".to_owned(),
+                    "self.adoc".to_owned(),
+                    "self.adoc".to_owned(),
+                ])
+            )]
+        );
+    }
+
+    #[test]
+    fn repeated_self_include_is_reported_once() {
+        let handler = InlineFileHandler::from_pairs([(
+            "a.adoc",
+            "x\ninclude::a.adoc[]\ninclude::a.adoc[]\n",
+        )]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess("include::a.adoc[]\n", &parser);
+
+        assert_eq!(
+            processed_source,
+            "x\ninclude::a.adoc[]\ninclude::a.adoc[]\n"
+        );
+
+        assert_eq!(
+            warnings,
+            vec![(
+                2,
+                WarningType::IncludeCycle(vec![
+                    "(root file)".to_owned(),
+                    "a.adoc".to_owned(),
+                    "a.adoc".to_owned(),
+                ])
+            )]
+        );
+    }
+
+    #[test]
+    fn indirect_include_cycle() {
+        let handler = InlineFileHandler::from_pairs([
+            ("a.adoc", "A\ninclude::b.adoc[]\n"),
+            ("b.adoc", "B\ninclude::a.adoc[]\n"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess("include::a.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "A\nB\ninclude::a.adoc[]\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                4,
+                WarningType::IncludeCycle(vec![
+                    "(root file)".to_owned(),
+                    "a.adoc".to_owned(),
+                    "b.adoc".to_owned(),
+                    "a.adoc".to_owned(),
+                ])
+            )]
+        );
+    }
+
+    #[test]
+    fn include_cycle_detected_through_relative_path() {
+        let handler =
+            InlineFileHandler::from_pairs([("docs/a.adoc", "A\ninclude::../docs/./a.adoc[]\n")]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess("include::docs/a.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "A\ninclude::../docs/./a.adoc[]\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                2,
+                WarningType::IncludeCycle(vec![
+                    "(root file)".to_owned(),
+                    "docs/a.adoc".to_owned(),
+                    "../docs/./a.adoc".to_owned(),
+                ])
+            )]
+        );
+    }
+
+    #[test]
+    fn primary_file_including_itself_is_cycle() {
+        let handler = InlineFileHandler::from_pairs([("main.adoc", "Main.\n")]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess("include::main.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "include::main.adoc[]\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                0,
+                WarningType::IncludeCycle(vec!["main.adoc".to_owned(), "main.adoc".to_owned()])
+            )]
+        );
+    }
+
+    #[test]
+    fn same_file_included_twice_is_not_cycle() {
+        let handler = InlineFileHandler::from_pairs([("b.adoc", "B\n")]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) =
+            preprocess("include::b.adoc[]\ninclude::b.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "B\nB\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn max_include_depth_attribute() {
+        let handler = InlineFileHandler::from_pairs([
+            ("a.adoc", "A\ninclude::b.adoc[]\n"),
+            ("b.adoc", "B\n"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, source_map, warnings) =
+            preprocess(":max-include-depth: 1\n\ninclude::a.adoc[]\n", &parser);
+
+        assert_eq!(
+            processed_source,
+            ":max-include-depth: 1\n\nA\ninclude::b.adoc[]\n"
+        );
+
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("a.adoc".to_owned()), 2))
+        );
+
+        assert_eq!(
+            warnings,
+            vec![(
+                25,
+                WarningType::MaxIncludeDepthExceeded(
+                    1,
+                    vec![
+                        "(root file)".to_owned(),
+                        "a.adoc".to_owned(),
+                        "b.adoc".to_owned()
+                    ]
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn max_include_depth_zero_disables_includes() {
+        let handler = InlineFileHandler::from_pairs([("a.adoc", "A\n")]);
+
+        let parser = Parser::default()
+            .with_include_file_handler(handler)
+            .with_intrinsic_attribute("max-include-depth", "0", ModificationContext::Anywhere);
+
+        let (processed_source, _, warnings) = preprocess("include::a.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "include::a.adoc[]\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                0,
+                WarningType::MaxIncludeDepthExceeded(
+                    0,
+                    vec!["(root file)".to_owned(), "a.adoc".to_owned()]
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn include_with_depth() {
+        let handler = InlineFileHandler::from_pairs([
+            ("a.adoc", "A\ninclude::b.adoc[]\n"),
+            ("b.adoc", "B\ninclude::c.adoc[]\n"),
+            ("c.adoc", "C\n"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) =
+            preprocess("include::a.adoc[depth=1]\nAfter.\n", &parser);
+
+        assert_eq!(processed_source, "A\ninclude::b.adoc[]\nAfter.\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                2,
+                WarningType::MaxIncludeDepthExceeded(
+                    1,
+                    vec![
+                        "(root file)".to_owned(),
+                        "a.adoc".to_owned(),
+                        "b.adoc".to_owned()
+                    ]
+                )
+            )]
+        );
+
+        let (processed_source, _, warnings) =
+            preprocess("include::a.adoc[depth=2]\nAfter.\n", &parser);
+
+        assert_eq!(processed_source, "A\nB\ninclude::c.adoc[]\nAfter.\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                4,
+                WarningType::MaxIncludeDepthExceeded(
+                    2,
+                    vec![
+                        "(root file)".to_owned(),
+                        "a.adoc".to_owned(),
+                        "b.adoc".to_owned(),
+                        "c.adoc".to_owned()
+                    ]
+                )
+            )]
+        );
+
+        // The `depth` limit only applies within the file being included.
+        let (processed_source, _, warnings) =
+            preprocess("include::b.adoc[depth=1]\ninclude::a.adoc[]\n", &parser);
+
+        assert_eq!(processed_source, "B\ninclude::c.adoc[]\nA\nB\nC\n");
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn include_in_secure_mode_becomes_link() {
+        let handler = InlineFileHandler::from_pairs([("a.adoc", "A\n")]);
+
+        let parser = Parser::default()
+            .with_include_file_handler(handler)
+            .with_safe_mode(SafeMode::Secure);
+
+        let (processed_source, source_map, warnings) = preprocess(
+            ":ext: adoc\n\nBefore.\ninclude::a.{ext}[lines=1]\nAfter.\n",
+            &parser,
+        );
+
+        assert_eq!(
+            processed_source,
+            ":ext: adoc\n\nBefore.\nlink:a.adoc[role=include]\nAfter.\n"
+        );
+
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(None, 4))
+        );
+
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn document_reports_max_include_depth_exceeded() {
+        let doc = Parser::default()
+            .with_include_file_handler(EndlessIncludeHandler)
+            .with_intrinsic_attribute("max-include-depth", "2", ModificationContext::Anywhere)
+            .parse("include::level1.adoc[]");
+
+        assert_eq!(
+            doc.warnings()
+                .map(|w| w.warning.clone())
+                .collect::<Vec<_>>(),
+            vec![WarningType::MaxIncludeDepthExceeded(
+                2,
+                vec![
+                    "(root file)".to_owned(),
+                    "level1.adoc".to_owned(),
+                    "level2.adoc".to_owned(),
+                    "level3.adoc".to_owned()
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn include_within_listing_block() {
+        let source = ":lang: rs\n\n----\ninclude::example.{lang}[]\n\n:lang: py\n\ninclude::example.{lang}[]\n----\n";
+
+        let handler = InlineFileHandler::from_pairs([
+            ("example.rs", "fn main() {}"),
+            ("example.py", "def main(): pass"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+
+        // The attribute entry inside the listing block is content, not an
+        // assignment, so it doesn't change the second include target.
+        assert_eq!(
+            processed_source,
+            ":lang: rs\n\n----\nfn main() {}\n\n:lang: py\n\nfn main() {}\n----\n"
+        );
+    }
+
+    #[test]
+    fn attribute_entry_after_listing_block() {
+        let source = ":lang: rs\n\n----\ninclude::example.{lang}[]\n----\n:lang: py\n\ninclude::example.{lang}[]\n";
+
+        let handler = InlineFileHandler::from_pairs([
+            ("example.rs", "fn main() {}"),
+            ("example.py", "def main(): pass"),
+        ]);
+
+        let parser = Parser::default().with_include_file_handler(handler);
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            ":lang: rs\n\n----\nfn main() {}\n----\n:lang: py\n\ndef main(): pass\n"
+        );
+    }
+
+    #[test]
+    fn non_asciidoc_include_in_source_block() {
+        let handler = InlineFileHandler::from_pairs([(
+            "config.yaml",
+            "# tag::settings[]\nname: {name}\nifdef::x[]\n# end::settings[]\n",
+        )]);
+
+        let mut parser = Parser::default().with_include_file_handler(handler);
+
+        let doc = parser.parse("[source,yaml]\n----\ninclude::config.yaml[tag=settings]\n----");
+
+        let block = doc.nested_blocks().next().unwrap();
+        assert_eq!(block.rendered_content(), Some("name: {name}\nifdef::x[]"));
+        assert!(doc.warnings().next().is_none());
+    }
+
+    #[test]
+    fn ifdef_attribute_set() {
+        let source = ":env-web:\n\nifdef::env-web[]\nWeb content.\nendif::[]\n\nMore content.";
+
+        let parser = Parser::default().with_primary_file_name("main.adoc");
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            ":env-web:\n\nWeb content.\n\nMore content.\n"
+        );
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(Some("main.adoc".to_owned()), 2))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("main.adoc".to_owned()), 4))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("main.adoc".to_owned()), 6))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(5),
+            Some(SourceLine(Some("main.adoc".to_owned()), 7))
+        );
+    }
+
+    #[test]
+    fn ifdef_attribute_not_set() {
+        let source =
+            "Before.\n\nifdef::env-web[]\nWeb content.\n:skipped: yes\nendif::env-web[]\n\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "Before.\n\n\nAfter.\n");
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(None, 2))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(None, 7))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(None, 8))
+        );
+    }
+
+    #[test]
+    fn attribute_entry_in_skipped_region_is_ignored() {
+        let source = "ifdef::nope[]\n:feature:\nendif::[]\nifdef::feature[]\nFeature.\nendif::[]";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "");
+    }
+
+    #[test]
+    fn ifdef_from_intrinsic_attribute() {
+        let source = "ifdef::env-github[]\nGitHub only.\nendif::[]";
+
+        let parser = Parser::default().with_intrinsic_attribute(
+            "env-github",
+            "",
+            ModificationContext::Anywhere,
+        );
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+        assert_eq!(processed_source, "GitHub only.\n");
+    }
+
+    #[test]
+    fn ifdef_unset_attribute() {
+        let source = ":!sectids:\nifdef::sectids[]\nIDs.\nendif::[]";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+        assert_eq!(processed_source, ":!sectids:\n");
+    }
+
+    #[test]
+    fn ifndef() {
+        let source = ":env-web:\nifndef::env-web[]\nNot web.\nendif::[]\nifndef::env-print[]\nNot print.\nendif::[]";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+        assert_eq!(processed_source, ":env-web:\nNot print.\n");
+    }
+
+    #[test]
+    fn multiple_attributes() {
+        let source = ":a:\n:b:\nifdef::a,c[any-ac]\nifdef::c,d[any-cd]\nifdef::a+b[all-ab]\nifdef::a+c[all-ac]\nifndef::a,c[unless-any-ac]\nifndef::c,d[unless-any-cd]\nifndef::a+b[unless-all-ab]\nifndef::a+c[unless-all-ac]";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            ":a:\n:b:\nany-ac\nall-ab\nunless-any-cd\nunless-all-ac\n"
+        );
+    }
+
+    #[test]
+    fn single_line_ifdef() {
+        let source = ":revnumber: 1.2\n\nifdef::revnumber[Version {revnumber}.]\nifdef::nope[Not shown.]\nDone.";
+
+        let parser = Parser::default().with_primary_file_name("main.adoc");
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            ":revnumber: 1.2\n\nVersion {revnumber}.\nDone.\n"
+        );
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(3),
+            Some(SourceLine(Some("main.adoc".to_owned()), 3))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(4),
+            Some(SourceLine(Some("main.adoc".to_owned()), 5))
+        );
+    }
+
+    #[test]
+    fn single_line_ifdef_with_include() {
+        let source = ":with-intro:\nifdef::with-intro[include::intro.adoc[]]";
+
+        let handler = InlineFileHandler::from_pairs([("intro.adoc", "Introduction.")]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, _, _) = preprocess(source, &parser);
+        assert_eq!(processed_source, ":with-intro:\nIntroduction.\n");
+    }
+
+    #[test]
+    fn nested_conditionals() {
+        let source = ":a:\nifdef::a[]\nA.\nifdef::b[]\nA and B.\nifdef::a[]\nStill skipped.\nendif::a[]\nendif::b[]\nA again.\nendif::a[]\nDone.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, ":a:\nA.\nA again.\nDone.\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn conditional_spans_include() {
+        let source = "ifdef::nope[]\ninclude::chapter.adoc[]\nendif::[]\nAfter.";
+
+        let handler =
+            InlineFileHandler::from_pairs([("chapter.adoc", "Chapter.\nifdef::nope[]\nendif::[]")]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "After.\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn conditional_in_included_file() {
+        let source = "include::chapter.adoc[]\nAfter.";
+
+        let handler = InlineFileHandler::from_pairs([(
+            "chapter.adoc",
+            "ifndef::nope[]\nChapter.\nendif::[]",
+        )]);
+
+        let parser = Parser::default()
+            .with_primary_file_name("main.adoc")
+            .with_include_file_handler(handler);
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "Chapter.\nAfter.\n");
+        assert!(warnings.is_empty());
+
+        assert_eq!(
+            source_map.original_file_and_line(1),
+            Some(SourceLine(Some("chapter.adoc".to_owned()), 2))
+        );
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(Some("main.adoc".to_owned()), 2))
+        );
+    }
+
+    #[test]
+    fn escaped_conditional() {
+        let source = "----\n\\ifdef::env-github[]\nGitHub only.\n\\endif::[]\n----";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(
+            processed_source,
+            "----\nifdef::env-github[]\nGitHub only.\nendif::[]\n----\n"
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn attribute_names_are_case_insensitive() {
+        let source = ":feature:\nifdef::Feature[]\nShown.\nendif::FEATURE[]";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, ":feature:\nShown.\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn err_unmatched_endif() {
+        let source = "Before.\nendif::foo[]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, source_map, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "Before.\nAfter.\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                8,
+                WarningType::UnmatchedPreprocessorDirective("endif::foo[]".to_owned())
+            )]
+        );
+
+        assert_eq!(
+            source_map.original_file_and_line(2),
+            Some(SourceLine(None, 3))
+        );
+    }
+
+    #[test]
+    fn err_mismatched_endif() {
+        let source = "ifdef::foo[]\nendif::bar[]\nStill skipped.\nendif::foo[]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "After.\n");
+
+        assert_eq!(
+            warnings,
+            vec![(
+                0,
+                WarningType::MismatchedPreprocessorDirective(
+                    "endif::bar[]".to_owned(),
+                    "endif::foo[]".to_owned()
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn err_malformed_directives() {
+        let source = "ifdef::[]\nifndef::[text]\nendif::foo[text]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "After.\n");
+
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    0,
+                    WarningType::MalformedPreprocessorDirective("ifdef::[]".to_owned())
+                ),
+                (
+                    0,
+                    WarningType::MalformedPreprocessorDirective("ifndef::[text]".to_owned())
+                ),
+                (
+                    0,
+                    WarningType::MalformedPreprocessorDirective("endif::foo[text]".to_owned())
+                ),
+            ]
+        );
+    }
+
+    fn ifeval_includes(expression: &str, parser: &Parser) -> bool {
+        let source = format!("ifeval::[{expression}]\nIncluded.\nendif::[]\nAfter.");
+        let (processed_source, _, warnings) = preprocess(&source, parser);
+        assert!(warnings.is_empty());
+        processed_source == "Included.\nAfter.\n"
+    }
+
+    #[test]
+    fn ifeval_numeric_comparison() {
+        let parser = Parser::default().with_intrinsic_attribute(
+            "product-version",
+            "3",
+            ModificationContext::Anywhere,
+        );
+
+        assert!(ifeval_includes("{product-version} >= 2", &parser));
+        assert!(ifeval_includes("{product-version} > 2", &parser));
+        assert!(ifeval_includes("{product-version} == 3", &parser));
+        assert!(ifeval_includes("{product-version} <= 3", &parser));
+        assert!(ifeval_includes("{product-version} != 4", &parser));
+        assert!(!ifeval_includes("{product-version} < 3", &parser));
+        assert!(!ifeval_includes("{product-version} >= 4", &parser));
+        assert!(ifeval_includes("{product-version}<4", &parser));
+    }
+
+    #[test]
+    fn ifeval_float_comparison() {
+        let parser = Parser::default();
+
+        assert!(ifeval_includes("1.5 > 1", &parser));
+        assert!(ifeval_includes("2.0 == 2", &parser));
+        assert!(!ifeval_includes("1.9 >= 2", &parser));
+        assert!(ifeval_includes("1.25 < 1.5", &parser));
+    }
+
+    #[test]
+    fn ifeval_string_comparison() {
+        let parser = Parser::default().with_intrinsic_attribute(
+            "backend",
+            "html5",
+            ModificationContext::Anywhere,
+        );
+
+        assert!(ifeval_includes("\"{backend}\" == \"html5\"", &parser));
+        assert!(ifeval_includes("'{backend}' != 'docbook5'", &parser));
+        assert!(ifeval_includes("\"abc\" < \"abd\"", &parser));
+        assert!(!ifeval_includes("\"{backend}\" == \"docbook5\"", &parser));
+
+        // A string is never equal to a number.
+        assert!(!ifeval_includes("\"3\" == 3", &parser));
+        assert!(!ifeval_includes("\"3\" <= 3", &parser));
+    }
+
+    #[test]
+    fn ifeval_boolean_comparison() {
+        let parser = Parser::default();
+
+        assert!(ifeval_includes("true == true", &parser));
+        assert!(ifeval_includes("true != false", &parser));
+        assert!(!ifeval_includes("true > false", &parser));
+    }
+
+    #[test]
+    fn ifeval_missing_attribute() {
+        let parser = Parser::default();
+
+        // An unresolved reference is left as-is and isn't a number, so it
+        // evaluates as zero.
+        assert!(ifeval_includes("{no-such-attr} == 0", &parser));
+        assert!(ifeval_includes(
+            "\"{no-such-attr}\" == \"{no-such-attr}\"",
+            &parser
+        ));
+    }
+
+    #[test]
+    fn ifeval_uses_attribute_entries() {
+        let source = ":level: 4\n\nifeval::[{level} > 3]\nDeep.\nendif::[]\nifeval::[{level} > 4]\nDeeper.\nendif::[]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, ":level: 4\n\nDeep.\nAfter.\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn ifeval_nested_in_skipped_region() {
+        let source = "ifdef::missing[]\nifeval::[1 == 1]\nHidden.\nendif::[]\nStill hidden.\nendif::missing[]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "After.\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn err_ifeval_invalid_expression() {
+        let source = "ifeval::[{product-version}]\nContent.\nendif::[]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "Content.\nAfter.\n");
+
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    0,
+                    WarningType::InvalidIfevalExpression("ifeval::[{product-version}]".to_owned())
+                ),
+                (
+                    9,
+                    WarningType::UnmatchedPreprocessorDirective("endif::[]".to_owned())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn err_ifeval_malformed() {
+        let source = "ifeval::[]\nifeval::attr[1 == 1]\nAfter.";
+
+        let parser = Parser::default();
+
+        let (processed_source, _, warnings) = preprocess(source, &parser);
+
+        assert_eq!(processed_source, "After.\n");
+
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    0,
+                    WarningType::MalformedPreprocessorDirective("ifeval::[]".to_owned())
+                ),
+                (
+                    0,
+                    WarningType::MalformedPreprocessorDirective("ifeval::attr[1 == 1]".to_owned())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn document_reports_preprocessor_warnings() {
+        let doc = Parser::default().parse("= Title\n\nBefore.\n\nendif::foo[]\nAfter.");
+
+        assert_eq!(
+            doc.warnings().cloned().collect::<Vec<_>>(),
+            vec![Warning {
+                source: Span::new("= Title\n\nBefore.\n\nAfter.\n").slice(18..18),
+                warning: WarningType::UnmatchedPreprocessorDirective("endif::foo[]".to_owned()),
+            }]
+        );
+
+        let warning = doc.warnings().next().unwrap();
+        assert_eq!(warning.source.line(), 5);
+        assert_eq!(warning.source.col(), 1);
+    }
 }
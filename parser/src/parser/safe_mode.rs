@@ -0,0 +1,87 @@
+/// The [safe mode] determines which potentially hazardous features of AsciiDoc
+/// (such as reading files from the file system) are available while parsing a
+/// document.
+///
+/// Safe modes are ordered from least to most restrictive, so a mode can be
+/// compared against a threshold (e.g., `safe_mode >= SafeMode::Secure`).
+///
+/// [safe mode]: https://docs.asciidoctor.org/asciidoctor/latest/safe-modes/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SafeMode {
+    /// No security restrictions are applied. Include directives may read any
+    /// file that the process has access to.
+    #[default]
+    Unsafe,
+
+    /// Files may only be read from within the base directory of the document.
+    Safe,
+
+    /// Same as [`SafeMode::Safe`], but also prevents the document from setting
+    /// attributes that affect how it is rendered (e.g., `copycss`) and hides
+    /// the document directory (`docdir`).
+    Server,
+
+    /// Include directives are disabled and other features that read files
+    /// (e.g., embedding images as data URIs) are not permitted.
+    Secure,
+}
+
+impl SafeMode {
+    /// Returns the name of this safe mode as exposed via the `safe-mode-name`
+    /// document attribute.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unsafe => "unsafe",
+            Self::Safe => "safe",
+            Self::Server => "server",
+            Self::Secure => "secure",
+        }
+    }
+
+    /// Returns the numeric level of this safe mode as exposed via the
+    /// `safe-mode-level` document attribute. (These values match those used by
+    /// Asciidoctor.)
+    pub fn level(self) -> u8 {
+        match self {
+            Self::Unsafe => 0,
+            Self::Safe => 1,
+            Self::Server => 10,
+            Self::Secure => 20,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+
+    use crate::parser::SafeMode;
+
+    #[test]
+    fn default() {
+        assert_eq!(SafeMode::default(), SafeMode::Unsafe);
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(SafeMode::Unsafe.name(), "unsafe");
+        assert_eq!(SafeMode::Safe.name(), "safe");
+        assert_eq!(SafeMode::Server.name(), "server");
+        assert_eq!(SafeMode::Secure.name(), "secure");
+    }
+
+    #[test]
+    fn level() {
+        assert_eq!(SafeMode::Unsafe.level(), 0);
+        assert_eq!(SafeMode::Safe.level(), 1);
+        assert_eq!(SafeMode::Server.level(), 10);
+        assert_eq!(SafeMode::Secure.level(), 20);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(SafeMode::Unsafe < SafeMode::Safe);
+        assert!(SafeMode::Safe < SafeMode::Server);
+        assert!(SafeMode::Server < SafeMode::Secure);
+    }
+}
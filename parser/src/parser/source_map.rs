@@ -43,6 +43,15 @@ impl SourceMap {
     }
 }
 
+impl fmt::Display for SourceLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(file_name) => write!(f, "{file_name}, line {}", self.1),
+            None => write!(f, "line {}", self.1),
+        }
+    }
+}
+
 impl fmt::Debug for SourceMap {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("SourceMap(&")?;
@@ -127,4 +136,14 @@ mod tests {
             "SourceMap(&[\n    (\n        1,\n        SourceLine(\n            None,\n            1,\n        ),\n    ),\n])"
         );
     }
+
+    #[test]
+    fn source_line_impl_display() {
+        assert_eq!(
+            SourceLine(Some("foo.adoc".to_owned()), 12).to_string(),
+            "foo.adoc, line 12"
+        );
+
+        assert_eq!(SourceLine(None, 3).to_string(), "line 3");
+    }
 }
@@ -161,6 +161,8 @@ At the end of the value, press kbd:[Enter].
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -257,6 +259,8 @@ That means you don't need to escape special characters such in an HTML tag.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -353,6 +357,8 @@ Attribute references in the value of an attribute entry are resolved immediately
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -426,6 +432,8 @@ If you set a built-in attribute and leave its value empty, the AsciiDoc processo
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
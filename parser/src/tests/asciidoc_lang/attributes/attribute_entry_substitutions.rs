@@ -183,7 +183,9 @@ You can inspect the value stored in an attribute using this trick:
                 substitution_group: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::AttributeReferences,
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                 ],),
+                stem_notation: None,
             },)
         );
     }
@@ -265,7 +267,9 @@ If the macro is absent, the value is processed with the header substitution grou
                 substitution_group: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::AttributeReferences,
                     SubstitutionStep::SpecialCharacters,
+                    SubstitutionStep::Callouts,
                 ],),
+                stem_notation: None,
             },)
         );
     }
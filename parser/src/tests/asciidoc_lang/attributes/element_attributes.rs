@@ -314,6 +314,8 @@ If this happens, append `+{empty}+` to the end of the line to disrupt the syntax
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -468,6 +470,8 @@ Specifically, it does not support named attributes, only the attribute shorthand
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -209,7 +209,7 @@ mod block_assignment {
 
     use crate::{
         Parser,
-        blocks::{IsBlock, SimpleBlockStyle},
+        blocks::{IsBlock, ListType, SimpleBlockStyle},
         tests::prelude::*,
     };
 
@@ -248,27 +248,70 @@ In the shorthand syntax, you prefix the name with a hash (`#`) in the first posi
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // NOTE: This test will have to be revised when we support lists.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "* Goal 1\n* Goal 2",
-                        line: 2,
-                        col: 1,
-                        offset: 9,
-                    },
-                    rendered: "* Goal 1\n* Goal 2",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Unordered,
+                items: &[
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 2,
+                            col: 1,
+                            offset: 9,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 1",
+                                line: 2,
+                                col: 3,
+                                offset: 11,
+                            },
+                            rendered: "Goal 1",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 1",
+                            line: 2,
+                            col: 1,
+                            offset: 9,
+                        },
+                    }),
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 3,
+                            col: 1,
+                            offset: 18,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 2",
+                                line: 3,
+                                col: 3,
+                                offset: 20,
+                            },
+                            rendered: "Goal 2",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 2",
+                            line: 3,
+                            col: 1,
+                            offset: 18,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[#goals]\n* Goal 1\n* Goal 2",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -316,27 +359,70 @@ In the longhand syntax, you use a standard named attribute.
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // NOTE: This test will have to be revised when we support lists.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "* Goal 1\n* Goal 2",
-                        line: 2,
-                        col: 1,
-                        offset: 11,
-                    },
-                    rendered: "* Goal 1\n* Goal 2",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Unordered,
+                items: &[
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 2,
+                            col: 1,
+                            offset: 11,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 1",
+                                line: 2,
+                                col: 3,
+                                offset: 13,
+                            },
+                            rendered: "Goal 1",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 1",
+                            line: 2,
+                            col: 1,
+                            offset: 11,
+                        },
+                    }),
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 3,
+                            col: 1,
+                            offset: 20,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 2",
+                                line: 3,
+                                col: 3,
+                                offset: 22,
+                            },
+                            rendered: "Goal 2",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 2",
+                            line: 3,
+                            col: 1,
+                            offset: 20,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[id=goals]\n* Goal 1\n* Goal 2",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -384,27 +470,70 @@ In the block anchor syntax, you surround the name with double square brackets:
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // NOTE: This test will have to be revised when we support lists.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "* Goal 1\n* Goal 2",
-                        line: 2,
-                        col: 1,
-                        offset: 10,
-                    },
-                    rendered: "* Goal 1\n* Goal 2",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Unordered,
+                items: &[
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 2,
+                            col: 1,
+                            offset: 10,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 1",
+                                line: 2,
+                                col: 3,
+                                offset: 12,
+                            },
+                            rendered: "Goal 1",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 1",
+                            line: 2,
+                            col: 1,
+                            offset: 10,
+                        },
+                    }),
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 3,
+                            col: 1,
+                            offset: 19,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Goal 2",
+                                line: 3,
+                                col: 3,
+                                offset: 21,
+                            },
+                            rendered: "Goal 2",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Goal 2",
+                            line: 3,
+                            col: 1,
+                            offset: 19,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[[goals]]\n* Goal 1\n* Goal 2",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: Some(Span {
@@ -618,6 +747,8 @@ The id (`#`) shorthand can be used on inline quoted text.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -688,6 +819,8 @@ The id (`#`) shorthand can be used on inline quoted text.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -774,6 +907,8 @@ According to the https://www.w3.org/TR/REC-xml/#NT-Name[XML Name] rules, a porta
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -830,6 +965,8 @@ According to the https://www.w3.org/TR/REC-xml/#NT-Name[XML Name] rules, a porta
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -886,6 +1023,8 @@ According to the https://www.w3.org/TR/REC-xml/#NT-Name[XML Name] rules, a porta
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -942,6 +1081,8 @@ According to the https://www.w3.org/TR/REC-xml/#NT-Name[XML Name] rules, a porta
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1007,6 +1148,8 @@ The shorthand form in an attribute list does not impose this restriction.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1095,6 +1238,8 @@ include::example$id.adoc[tag=block-id-shorthand]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "notice",
@@ -1182,6 +1327,8 @@ include::example$id.adoc[tag=block-id-brackets]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "notice",
@@ -1268,6 +1415,8 @@ include::example$id.adoc[tag=anchor-brackets]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1341,6 +1490,8 @@ include::example$id.adoc[tag=anchor-shorthand]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
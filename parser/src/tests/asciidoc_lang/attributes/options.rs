@@ -29,7 +29,10 @@ You can assign one or more options to a block using the shorthand or formal synt
 
     use crate::{
         Parser,
-        blocks::{IsBlock, SimpleBlockStyle},
+        blocks::{
+            ColumnWidth, HorizontalAlignment, IsBlock, SimpleBlockStyle, TableCellStyle,
+            TableColumn, TableFormat, VerticalAlignment,
+        },
         tests::prelude::*,
     };
 
@@ -252,27 +255,180 @@ For instance, consider a table with the three built-in option values, `header`,
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // IMPORTANT: This test will have to be revised once we parse tables.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "|===\n|Cell A1 |Cell B1",
-                        line: 2,
-                        col: 1,
-                        offset: 36,
+            Block::Table(TableBlock {
+                columns: &[
+                    TableColumn {
+                        width: ColumnWidth::Auto,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
                     },
-                    rendered: "|===\n|Cell A1 |Cell B1",
-                },
+                    TableColumn {
+                        width: ColumnWidth::Auto,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ],
+                format: TableFormat::Psv,
+                header_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A1",
+                                    line: 3,
+                                    col: 2,
+                                    offset: 42,
+                                },
+                                rendered: "Cell A1",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A1",
+                                line: 3,
+                                col: 1,
+                                offset: 41,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B1",
+                                    line: 3,
+                                    col: 11,
+                                    offset: 51,
+                                },
+                                rendered: "Cell B1",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B1",
+                                line: 3,
+                                col: 10,
+                                offset: 50,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
+                body_rows: &[TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A2",
+                                    line: 5,
+                                    col: 2,
+                                    offset: 61,
+                                },
+                                rendered: "Cell A2",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A2",
+                                line: 5,
+                                col: 1,
+                                offset: 60,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B2",
+                                    line: 5,
+                                    col: 11,
+                                    offset: 70,
+                                },
+                                rendered: "Cell B2",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B2",
+                                line: 5,
+                                col: 10,
+                                offset: 69,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },],
+                footer_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A3",
+                                    line: 7,
+                                    col: 2,
+                                    offset: 80,
+                                },
+                                rendered: "Cell A3",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A3",
+                                line: 7,
+                                col: 1,
+                                offset: 79,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B3",
+                                    line: 7,
+                                    col: 11,
+                                    offset: 89,
+                                },
+                                rendered: "Cell B3",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B3",
+                                line: 7,
+                                col: 10,
+                                offset: 88,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
                 source: Span {
-                    data: "[%header%footer%autowidth,cols=2*~]\n|===\n|Cell A1 |Cell B1",
+                    data: "[%header%footer%autowidth,cols=2*~]\n|===\n|Cell A1 |Cell B1\n\n|Cell A2 |Cell B2\n\n|Cell A3 |Cell B3\n|===",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -535,27 +691,180 @@ Instead of using the shorthand notation, <<ex-table-formal>> shows how the value
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // IMPORTANT: This test will have to be revised once we support tables.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "|===\n|Cell A1 |Cell B1",
-                        line: 2,
-                        col: 1,
-                        offset: 42,
+            Block::Table(TableBlock {
+                columns: &[
+                    TableColumn {
+                        width: ColumnWidth::Auto,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
                     },
-                    rendered: "|===\n|Cell A1 |Cell B1",
-                },
+                    TableColumn {
+                        width: ColumnWidth::Auto,
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ],
+                format: TableFormat::Psv,
+                header_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A1",
+                                    line: 3,
+                                    col: 2,
+                                    offset: 48,
+                                },
+                                rendered: "Cell A1",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A1",
+                                line: 3,
+                                col: 1,
+                                offset: 47,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B1",
+                                    line: 3,
+                                    col: 11,
+                                    offset: 57,
+                                },
+                                rendered: "Cell B1",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B1",
+                                line: 3,
+                                col: 10,
+                                offset: 56,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
+                body_rows: &[TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A2",
+                                    line: 5,
+                                    col: 2,
+                                    offset: 67,
+                                },
+                                rendered: "Cell A2",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A2",
+                                line: 5,
+                                col: 1,
+                                offset: 66,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B2",
+                                    line: 5,
+                                    col: 11,
+                                    offset: 76,
+                                },
+                                rendered: "Cell B2",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B2",
+                                line: 5,
+                                col: 10,
+                                offset: 75,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },],
+                footer_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell A3",
+                                    line: 7,
+                                    col: 2,
+                                    offset: 86,
+                                },
+                                rendered: "Cell A3",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell A3",
+                                line: 7,
+                                col: 1,
+                                offset: 85,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Cell B3",
+                                    line: 7,
+                                    col: 11,
+                                    offset: 95,
+                                },
+                                rendered: "Cell B3",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Cell B3",
+                                line: 7,
+                                col: 10,
+                                offset: 94,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
                 source: Span {
-                    data: "[cols=2*~,opts=\"header,footer,autowidth\"]\n|===\n|Cell A1 |Cell B1",
+                    data: "[cols=2*~,opts=\"header,footer,autowidth\"]\n|===\n|Cell A1 |Cell B1\n\n|Cell A2 |Cell B2\n\n|Cell A3 |Cell B3\n|===",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -615,7 +924,7 @@ Let's consider `options` when combined with other attributes.
 
     use crate::{
         Parser,
-        blocks::{IsBlock, SimpleBlockStyle},
+        blocks::{IsBlock, ListType},
         content::SubstitutionGroup,
         tests::prelude::*,
     };
@@ -650,27 +959,84 @@ The role and options attributes can be set in either order, i.e., `[horizontal%s
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // IMPORTANT: This test will have to be revised when we support attribute lists.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "property 1:: does stuff\nproperty 2:: does different stuff",
-                        line: 2,
-                        col: 1,
-                        offset: 29,
-                    },
-                    rendered: "property 1:: does stuff\nproperty 2:: does different stuff",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Description,
+                items: &[
+                    Block::DescriptionListItem(DescriptionListItem {
+                        terms: &[Content {
+                            original: Span {
+                                data: "property 1",
+                                line: 2,
+                                col: 1,
+                                offset: 29,
+                            },
+                            rendered: "property 1",
+                        }],
+                        marker: Span {
+                            data: "::",
+                            line: 2,
+                            col: 11,
+                            offset: 39,
+                        },
+                        principal_text: Some(Content {
+                            original: Span {
+                                data: "does stuff",
+                                line: 2,
+                                col: 14,
+                                offset: 42,
+                            },
+                            rendered: "does stuff",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "property 1:: does stuff",
+                            line: 2,
+                            col: 1,
+                            offset: 29,
+                        },
+                    }),
+                    Block::DescriptionListItem(DescriptionListItem {
+                        terms: &[Content {
+                            original: Span {
+                                data: "property 2",
+                                line: 3,
+                                col: 1,
+                                offset: 53,
+                            },
+                            rendered: "property 2",
+                        }],
+                        marker: Span {
+                            data: "::",
+                            line: 3,
+                            col: 11,
+                            offset: 63,
+                        },
+                        principal_text: Some(Content {
+                            original: Span {
+                                data: "does different stuff",
+                                line: 3,
+                                col: 14,
+                                offset: 66,
+                            },
+                            rendered: "does different stuff",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "property 2:: does different stuff",
+                            line: 3,
+                            col: 1,
+                            offset: 53,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[horizontal.properties%step]\nproperty 1:: does stuff\nproperty 2:: does different stuff",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -739,27 +1105,84 @@ property 2:: does different stuff
         .unwrap_if_no_warnings()
         .unwrap();
 
-        // IMPORTANT: This test will have to be revised when we support attribute lists.
-
         assert_eq!(
             mi.item,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "property 1:: does stuff\nproperty 2:: does different stuff",
-                        line: 2,
-                        col: 1,
-                        offset: 39,
-                    },
-                    rendered: "property 1:: does stuff\nproperty 2:: does different stuff",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Description,
+                items: &[
+                    Block::DescriptionListItem(DescriptionListItem {
+                        terms: &[Content {
+                            original: Span {
+                                data: "property 1",
+                                line: 2,
+                                col: 1,
+                                offset: 39,
+                            },
+                            rendered: "property 1",
+                        }],
+                        marker: Span {
+                            data: "::",
+                            line: 2,
+                            col: 11,
+                            offset: 49,
+                        },
+                        principal_text: Some(Content {
+                            original: Span {
+                                data: "does stuff",
+                                line: 2,
+                                col: 14,
+                                offset: 52,
+                            },
+                            rendered: "does stuff",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "property 1:: does stuff",
+                            line: 2,
+                            col: 1,
+                            offset: 39,
+                        },
+                    }),
+                    Block::DescriptionListItem(DescriptionListItem {
+                        terms: &[Content {
+                            original: Span {
+                                data: "property 2",
+                                line: 3,
+                                col: 1,
+                                offset: 63,
+                            },
+                            rendered: "property 2",
+                        }],
+                        marker: Span {
+                            data: "::",
+                            line: 3,
+                            col: 11,
+                            offset: 73,
+                        },
+                        principal_text: Some(Content {
+                            original: Span {
+                                data: "does different stuff",
+                                line: 3,
+                                col: 14,
+                                offset: 76,
+                            },
+                            rendered: "does different stuff",
+                        }),
+                        blocks: &[],
+                        source: Span {
+                            data: "property 2:: does different stuff",
+                            line: 3,
+                            col: 1,
+                            offset: 63,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[horizontal,role=properties,opts=step]\nproperty 1:: does stuff\nproperty 2:: does different stuff",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -16,7 +16,10 @@ mod positional_attribute {
 
     use crate::{
         Parser,
-        blocks::{IsBlock, SimpleBlockStyle, metadata::BlockMetadata},
+        blocks::{
+            ColumnWidth, HorizontalAlignment, IsBlock, ListType, SimpleBlockStyle, TableCellStyle,
+            TableColumn, TableFormat, VerticalAlignment, metadata::BlockMetadata,
+        },
         content::SubstitutionGroup,
         tests::prelude::*,
     };
@@ -306,26 +309,96 @@ Specifically, this syntax sets the ID to `rules`, adds the role `prominent`, and
         .unwrap()
         .item;
 
-        // TO DO: This will change when we understand lists.
         assert_eq!(
             block,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "* Work hard\n* Play hard\n* Be happy",
-                        line: 2,
-                        col: 1,
-                        offset: 31,
-                    },
-                    rendered: "* Work hard\n* Play hard\n* Be happy",
-                },
+            Block::List(ListBlock {
+                type_: ListType::Unordered,
+                items: &[
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 2,
+                            col: 1,
+                            offset: 31,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Work hard",
+                                line: 2,
+                                col: 3,
+                                offset: 33,
+                            },
+                            rendered: "Work hard",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Work hard",
+                            line: 2,
+                            col: 1,
+                            offset: 31,
+                        },
+                    }),
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 3,
+                            col: 1,
+                            offset: 43,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Play hard",
+                                line: 3,
+                                col: 3,
+                                offset: 45,
+                            },
+                            rendered: "Play hard",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Play hard",
+                            line: 3,
+                            col: 1,
+                            offset: 43,
+                        },
+                    }),
+                    Block::ListItem(ListItem {
+                        marker: Span {
+                            data: "*",
+                            line: 4,
+                            col: 1,
+                            offset: 55,
+                        },
+                        principal_text: Content {
+                            original: Span {
+                                data: "Be happy",
+                                line: 4,
+                                col: 3,
+                                offset: 57,
+                            },
+                            rendered: "Be happy",
+                        },
+                        checked: None,
+                        callout_ids: &[],
+                        blocks: &[],
+                        source: Span {
+                            data: "* Be happy",
+                            line: 4,
+                            col: 1,
+                            offset: 55,
+                        },
+                    }),
+                ],
                 source: Span {
                     data: "[#rules.prominent%incremental]\n* Work hard\n* Play hard\n* Be happy",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -383,23 +456,129 @@ Specifically, this syntax sets the `header`, `footer`, and `autowidth` options.
 
         assert_eq!(
             block,
-            Block::Simple(SimpleBlock {
-                content: Content {
-                    original: Span {
-                        data: "|===\n|Header A |Header B\n|Footer A |Footer B\n|===",
-                        line: 2,
-                        col: 1,
-                        offset: 27,
-                    },
-                    rendered: "|===\n|Header A |Header B\n|Footer A |Footer B\n|===",
-                },
+            Block::Table(TableBlock {
+                columns: &[
+                    TableColumn {
+                        width: ColumnWidth::Proportional(1,),
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                    TableColumn {
+                        width: ColumnWidth::Proportional(1,),
+                        halign: HorizontalAlignment::Left,
+                        valign: VerticalAlignment::Top,
+                        style: TableCellStyle::Default,
+                    },
+                ],
+                format: TableFormat::Psv,
+                header_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Header A",
+                                    line: 3,
+                                    col: 2,
+                                    offset: 33,
+                                },
+                                rendered: "Header A",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Header A",
+                                line: 3,
+                                col: 1,
+                                offset: 32,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Header B",
+                                    line: 3,
+                                    col: 12,
+                                    offset: 43,
+                                },
+                                rendered: "Header B",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Header B",
+                                line: 3,
+                                col: 11,
+                                offset: 42,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
+                body_rows: &[],
+                footer_row: Some(TableRow {
+                    cells: &[
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Footer A",
+                                    line: 4,
+                                    col: 2,
+                                    offset: 53,
+                                },
+                                rendered: "Footer A",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Footer A",
+                                line: 4,
+                                col: 1,
+                                offset: 52,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                        TableCell {
+                            content: Some(Content {
+                                original: Span {
+                                    data: "Footer B",
+                                    line: 4,
+                                    col: 12,
+                                    offset: 63,
+                                },
+                                rendered: "Footer B",
+                            },),
+                            blocks: &[],
+                            source: Span {
+                                data: "|Footer B",
+                                line: 4,
+                                col: 11,
+                                offset: 62,
+                            },
+                            colspan: 1,
+                            rowspan: 1,
+                            halign: HorizontalAlignment::Left,
+                            valign: VerticalAlignment::Top,
+                            style: TableCellStyle::Default,
+                        },
+                    ],
+                },),
                 source: Span {
                     data: "[%header%footer%autowidth]\n|===\n|Header A |Header B\n|Footer A |Footer B\n|===",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -488,6 +667,8 @@ Formatted text does not support a style, so the first and only positional attrib
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -582,6 +763,8 @@ A named attribute consists of a name and a value separated by an `=` character (
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -662,6 +845,8 @@ In all other cases, the surrounding quotes are optional.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -743,6 +928,8 @@ If enclosing quotes are used, they are dropped from the parsed value and the pre
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -826,6 +1013,8 @@ If enclosing quotes are used, they are dropped from the parsed value and the pre
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -931,6 +1120,8 @@ In these rules, `name` consists of a word character (letter or numeral) followed
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1037,6 +1228,8 @@ In these rules, `name` consists of a word character (letter or numeral) followed
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1104,6 +1297,8 @@ In these rules, `name` consists of a word character (letter or numeral) followed
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1189,6 +1384,8 @@ For subsequent attributes, any leading space or tab characters are skipped.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1276,6 +1473,8 @@ Space and tab characters around the equals sign (=) and at the end of the value
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1367,6 +1566,8 @@ Any space or tab characters at the boundaries of the value are ignored.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1458,6 +1659,8 @@ Any space or tab characters at the boundaries of the value are ignored.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1556,6 +1759,8 @@ Any space or tab characters at the boundaries of the value are ignored.
                     warning: WarningType::AttributeValueMissingTerminatingQuote,
                 },],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1646,6 +1851,8 @@ If there is a closing double quote, the enclosing double quote characters are re
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1744,6 +1951,8 @@ If there is a closing double quote, the enclosing double quote characters are re
                     warning: WarningType::AttributeValueMissingTerminatingQuote,
                 },],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1834,6 +2043,8 @@ If there is a closing single quote, the enclosing single quote characters are re
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1934,6 +2145,8 @@ If there is a closing single quote, and the first character is not an escaped si
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -2040,6 +2253,8 @@ That's because the parser already knows to look for the closing square bracket a
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -2108,6 +2323,8 @@ There are some exceptions to this rule, such as a link macro in a footnote, whic
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1,6 +1,10 @@
 use pretty_assertions_sorted::assert_eq;
 
-use crate::{Parser, blocks::SimpleBlockStyle, tests::prelude::*};
+use crate::{
+    Parser,
+    blocks::{AdmonitionKind, SimpleBlockStyle},
+    tests::prelude::*,
+};
 
 track_file!("docs/modules/attributes/pages/reference-attributes.adoc");
 
@@ -167,23 +171,25 @@ Actually, please don't.
                     anchor_reftext: None,
                     attrlist: None,
                 },),
-                Block::Simple(SimpleBlock {
-                    content: Content {
+                Block::Admonition(AdmonitionBlock {
+                    kind: AdmonitionKind::Warning,
+                    textlabel: Some("Warning"),
+                    content: Some(Content {
                         original: Span {
-                            data: "WARNING: {disclaimer}\nIf you're missing a lime colored sock, file a ticket in\nthe {url-repo}/issues[Asciidoctor issue tracker].\n(Actually, please don't).",
+                            data: "{disclaimer}\nIf you're missing a lime colored sock, file a ticket in\nthe {url-repo}/issues[Asciidoctor issue tracker].\n(Actually, please don't).",
                             line: 8,
-                            col: 1,
-                            offset: 230,
+                            col: 10,
+                            offset: 239,
                         },
-                        rendered: "WARNING: Don&#8217;t pet the wild Wolpertingers. We&#8217;re not responsible for any loss of hair, chocolate, or purple socks.\nIf you&#8217;re missing a lime colored sock, file a ticket in\nthe <a href=\"https://github.com/asciidoctor/asciidoctor/issues\">Asciidoctor issue tracker</a>.\n(Actually, please don&#8217;t).",
-                    },
+                        rendered: "Don&#8217;t pet the wild Wolpertingers. We&#8217;re not responsible for any loss of hair, chocolate, or purple socks.\nIf you&#8217;re missing a lime colored sock, file a ticket in\nthe <a href=\"https://github.com/asciidoctor/asciidoctor/issues\">Asciidoctor issue tracker</a>.\n(Actually, please don&#8217;t).",
+                    }),
+                    blocks: &[],
                     source: Span {
                         data: "WARNING: {disclaimer}\nIf you're missing a lime colored sock, file a ticket in\nthe {url-repo}/issues[Asciidoctor issue tracker].\n(Actually, please don't).",
                         line: 8,
                         col: 1,
                         offset: 230,
                     },
-                    style: SimpleBlockStyle::Paragraph,
                     title_source: None,
                     title: None,
                     anchor: None,
@@ -199,6 +205,8 @@ Actually, please don't.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -251,23 +259,25 @@ Our servers don't like them either.
                     offset: 0,
                 },
             },
-            blocks: &[Block::Simple(SimpleBlock {
-                content: Content {
+            blocks: &[Block::Admonition(AdmonitionBlock {
+                kind: AdmonitionKind::Tip,
+                textlabel: Some("Tip"),
+                content: Some(Content {
                     original: Span {
-                        data: "TIP: Wolpertingers don't like temperatures above 100{deg}C.\nOur servers don't like them either.",
+                        data: "Wolpertingers don't like temperatures above 100{deg}C.\nOur servers don't like them either.",
                         line: 1,
-                        col: 1,
-                        offset: 0,
+                        col: 6,
+                        offset: 5,
                     },
-                    rendered: "TIP: Wolpertingers don&#8217;t like temperatures above 100&#176;C.\nOur servers don&#8217;t like them either.",
-                },
+                    rendered: "Wolpertingers don&#8217;t like temperatures above 100&#176;C.\nOur servers don&#8217;t like them either.",
+                }),
+                blocks: &[],
                 source: Span {
                     data: "TIP: Wolpertingers don't like temperatures above 100{deg}C.\nOur servers don't like them either.",
                     line: 1,
                     col: 1,
                     offset: 0,
                 },
-                style: SimpleBlockStyle::Paragraph,
                 title_source: None,
                 title: None,
                 anchor: None,
@@ -282,6 +292,8 @@ Our servers don't like them either.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -403,6 +415,8 @@ In the path /items/\{id}, id is a path parameter.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -470,6 +484,8 @@ If the syntax that follows the backslash does not match an attribute reference,
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -573,6 +589,8 @@ In the path +/items/{id}+, id is a path parameter.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -640,6 +658,8 @@ All the text between the passthrough enclosure will get passed through to the ou
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
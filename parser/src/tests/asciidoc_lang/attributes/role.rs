@@ -566,6 +566,8 @@ The roles can also be accompanied by an ID assignment.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -174,6 +174,7 @@ Don't put a space between the dot and the first character of the title.
             anchor_reftext: None,
             attrlist: None,
             substitution_group: SubstitutionGroup::Verbatim,
+            stem_notation: None,
         },)
     );
 }
@@ -279,6 +280,7 @@ stages: [ init, verify, deploy ]
                 },
             },),
             substitution_group: SubstitutionGroup::Verbatim,
+            stem_notation: None,
         },)
     );
 }
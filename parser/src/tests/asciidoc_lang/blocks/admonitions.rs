@@ -0,0 +1,307 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{AdmonitionKind, Block, IsBlock},
+    tests::prelude::*,
+};
+
+track_file!("docs/modules/blocks/pages/admonitions.adoc");
+
+non_normative!(
+    r#"
+= Admonitions
+
+There are certain statements you may want to draw attention to by taking them out of the content's flow and labeling them with a priority.
+These are called admonitions.
+This page introduces you to admonition types AsciiDoc provides, how to add admonitions to your document, and how to enhance them using icons or emoji.
+
+NOTE: The examples on this page (and in these docs) use a visual theme that differs from the style provided by AsciiDoc processors such as Asciidoctor.
+The AsciiDoc language does not require that the admonitions be rendered using a particular style.
+The only requirement is that they be offset from the main text and labeled appropriately according to their admonition type.
+
+"#
+);
+
+#[test]
+fn admonition_types() {
+    verifies!(
+        r#"
+== Admonition types
+
+The rendered style of an admonition is determined by the assigned type (i.e., name).
+The AsciiDoc language provides five admonition types represented by the following labels:
+
+* `NOTE`
+* `TIP`
+* `IMPORTANT`
+* `CAUTION`
+* `WARNING`
+
+The label is specified either as the block style or as a special paragraph prefix.
+The label becomes visible to the reader unless icons are enabled, in which case the icon is shown in its place.
+
+"#
+    );
+
+    let doc = Parser::default().parse("NOTE: An admonition draws the reader's attention to auxiliary information.\n\nHere are the other built-in admonition types:\n\nIMPORTANT: Don't forget the children!\n\nTIP: Look for the warp zone under the bridge.\n\nCAUTION: Slippery when wet.\n\nWARNING: The software you're about to use is untested.\n\nIMPORTANT: Sign off before stepping away from your computer.");
+
+    let mut blocks = doc.nested_blocks();
+
+    for (kind, textlabel) in [
+        (Some(AdmonitionKind::Note), "Note"),
+        (None, ""),
+        (Some(AdmonitionKind::Important), "Important"),
+        (Some(AdmonitionKind::Tip), "Tip"),
+        (Some(AdmonitionKind::Caution), "Caution"),
+        (Some(AdmonitionKind::Warning), "Warning"),
+        (Some(AdmonitionKind::Important), "Important"),
+    ] {
+        let block = blocks.next().unwrap();
+
+        if let Some(kind) = kind {
+            let Block::Admonition(admonition) = block else {
+                panic!("Expected an admonition: {block:?}");
+            };
+
+            assert_eq!(admonition.kind(), kind);
+            assert_eq!(admonition.textlabel().unwrap(), textlabel);
+        } else {
+            assert_eq!(block.raw_context().deref(), "paragraph");
+        }
+    }
+
+    assert!(blocks.next().is_none());
+}
+
+non_normative!(
+    r#"
+.Caution vs. Warning
+[#caution-vs-warning]
+****
+When choosing the admonition type, you may find yourself getting confused between "`caution`" and "`warning`" as these words are often used interchangeably.
+Here's a simple rule to help you differentiate the two:
+
+* Use *CAUTION* to advise the reader to _act_ carefully (i.e., exercise care).
+* Use *WARNING* to inform the reader of danger, harm, or consequences that exist.
+
+The word caution in this context translates into attention in French, which is often a good reference for how it should be applied.
+
+To find a deeper analysis, see https://www.differencebetween.com/difference-between-caution-and-vs-warning/.
+****
+
+"#
+);
+
+#[test]
+fn admonition_paragraph_syntax() {
+    verifies!(
+        r#"
+== Admonition syntax
+
+When you want to call attention to a single paragraph, start the first line of the paragraph with the label you want to use.
+The label must be uppercase and followed by a colon (`:`).
+
+.Admonition paragraph syntax
+[#ex-label]
+----
+include::example$admonition.adoc[tag=para-c]
+----
+<.> The label must be uppercase and immediately followed by a colon (`:`).
+<.> Separate the first line of the paragraph from the label by a single space.
+
+The result of <<ex-label>> is displayed below.
+
+include::example$admonition.adoc[tag=para]
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "WARNING: Wolpertingers are known to nest in server racks.\nEnter at your own risk.",
+    );
+
+    let mut blocks = doc.nested_blocks();
+
+    let Some(Block::Admonition(admonition)) = blocks.next() else {
+        panic!("Expected an admonition");
+    };
+
+    assert!(blocks.next().is_none());
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Warning);
+    assert_eq!(admonition.name(), "warning");
+    assert_eq!(admonition.textlabel().unwrap(), "Warning");
+    assert_eq!(admonition.raw_context().deref(), "admonition");
+
+    assert_eq!(
+        admonition.rendered_content().unwrap(),
+        "Wolpertingers are known to nest in server racks.\nEnter at your own risk."
+    );
+}
+
+#[test]
+fn admonition_block_syntax() {
+    verifies!(
+        r#"
+When you want to apply an admonition to compound content, set the label as a style attribute on a block.
+As seen in the next example, admonition labels are commonly set on example blocks.
+This behavior is referred to as *masquerading*.
+The label must be uppercase when set as an attribute on a block.
+
+.Admonition block syntax
+[#ex-block]
+----
+include::example$admonition.adoc[tag=bl-c]
+----
+<.> Set the label in an attribute list on a delimited block.
+The label must be uppercase.
+<.> Admonition styles are commonly set on example blocks.
+Example blocks are delimited by four equal signs (`====`).
+
+The result of <<ex-block>> is displayed below.
+
+include::example$admonition.adoc[tag=bl-nest]
+
+"#
+    );
+
+    let doc = Parser::default().parse("[IMPORTANT]\n.Feeding the Werewolves\n======\nWhile werewolves are hardy community members, keep in mind the following dietary concerns:\n\n. They are allergic to cinnamon.\n. More than two glasses of orange juice in 24 hours makes them howl in harmony with alarms and sirens.\n. Celery makes them sad.\n======");
+
+    let mut blocks = doc.nested_blocks();
+
+    let Some(Block::Admonition(admonition)) = blocks.next() else {
+        panic!("Expected an admonition");
+    };
+
+    assert!(blocks.next().is_none());
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Important);
+    assert_eq!(admonition.name(), "important");
+    assert_eq!(admonition.textlabel().unwrap(), "Important");
+    assert_eq!(admonition.title().unwrap(), "Feeding the Werewolves");
+    assert_eq!(admonition.declared_style().unwrap(), "IMPORTANT");
+    assert!(admonition.content().is_none());
+
+    let mut nested = admonition.nested_blocks();
+
+    assert_eq!(nested.next().unwrap().raw_context().deref(), "paragraph");
+
+    let list = nested.next().unwrap();
+    assert_eq!(list.raw_context().deref(), "olist");
+    assert_eq!(list.nested_blocks().len(), 3);
+
+    assert!(nested.next().is_none());
+
+    let doc = Parser::default().parse("[NOTE]\n====\nAn admonition block may contain compound content.\n\n.A list\n- one\n- two\n- three\n\nAnother paragraph.\n====");
+
+    let Some(Block::Admonition(admonition)) = doc.nested_blocks().next() else {
+        panic!("Expected an admonition");
+    };
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Note);
+    assert_eq!(admonition.nested_blocks().len(), 3);
+}
+
+non_normative!(
+    r#"
+== Enable admonition icons
+
+In the examples above, the admonition is rendered in a callout box with the style label in the gutter.
+You can replace the textual labels with font icons by setting the `icons` attribute on the document and assigning it the value `font`.
+
+.Admonition paragraph with icons set
+[#ex-icon]
+----
+= Document Title
+:icons: font
+
+include::example$admonition.adoc[tag=para]
+----
+
+Learn more about using Font Awesome or custom icons with admonitions in xref:macros:icons-font.adoc[].
+
+"#
+);
+
+#[test]
+fn caption_attribute() {
+    verifies!(
+        r#"
+== Using emoji for admonition icons
+
+If image-based or font-based icons are not available, you can leverage the admonition caption to display an emoji (or any symbol from Unicode) in the place of the admonition label, thus giving you an alternative way to make admonition icons.
+
+If the `icons` attribute is not set on the document, the admonition label is shown as text (e.g., CAUTION).
+The text for this label comes from an AsciiDoc attribute.
+The name of the attribute is `<type>-caption`, where `<type>` is the admonition type in lowercase.
+For example, the attribute for a tip admonition is `tip-caption`.
+
+Instead of a word, you can assign a Unicode glyph to this attribute:
+
+----
+:tip-caption: 💡
+
+[TIP]
+It's possible to use Unicode glyphs as admonition icons.
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        ":tip-caption: 💡\n\n[TIP]\nIt's possible to use Unicode glyphs as admonition icons.",
+    );
+
+    let Some(Block::Admonition(admonition)) = doc.nested_blocks().next() else {
+        panic!("Expected an admonition");
+    };
+
+    assert_eq!(admonition.kind(), AdmonitionKind::Tip);
+    assert_eq!(admonition.textlabel().unwrap(), "💡");
+
+    assert_eq!(
+        admonition.rendered_content().unwrap(),
+        "It&#8217;s possible to use Unicode glyphs as admonition icons."
+    );
+}
+
+non_normative!(
+    r#"
+Here's the result you get in the HTML:
+
+[,html]
+----
+<td class="icon">
+<div class="title">💡</div>
+</td>
+----
+
+Instead of entering the glyph directly, you can enter a character reference instead.
+However, since you're defining the character reference in an attribute entry, you (currently) have to disable substitutions on the value.
+
+----
+:tip-caption: pass:[&#128161;]
+
+[TIP]
+It's possible to use Unicode glyphs as admonition icons.
+----
+
+On GitHub, the HTML output from the AsciiDoc processor is run through a postprocessing filter that substitutes emoji shortcodes with emoji symbols.
+That means you can use these shortcodes instead in the value of the attribute:
+
+----
+\ifdef::env-github[]
+:tip-caption: :bulb:
+\endif::[]
+
+[TIP]
+It's possible to use emojis as admonition icons on GitHub.
+----
+
+When the document is processed through the GitHub interface, the shortcodes get replaced with real emojis.
+This is the only known way to get admonition icons to work on GitHub.
+"#
+);
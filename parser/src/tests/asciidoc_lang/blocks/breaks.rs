@@ -114,6 +114,8 @@ include::partial$page-breaks.adoc[]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -175,6 +177,8 @@ include::partial$page-breaks.adoc[]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -284,6 +288,8 @@ include::partial$page-breaks.adoc[]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -217,6 +217,8 @@ This is more content in the sidebar block.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -75,6 +75,7 @@ This text will be treated as verbatim content.
             anchor_reftext: None,
             attrlist: None,
             substitution_group: SubstitutionGroup::Verbatim,
+            stem_notation: None,
         },)
     );
 }
@@ -127,6 +128,7 @@ The remaining lines define a block's content.
             anchor_reftext: None,
             attrlist: None,
             substitution_group: SubstitutionGroup::Verbatim,
+            stem_notation: None,
         },)
     );
 }
@@ -600,6 +602,7 @@ The document header is useful, but not required.
                         anchor_reftext: None,
                         attrlist: None,
                         substitution_group: SubstitutionGroup::Verbatim,
+                        stem_notation: None,
                     },),
                     Block::Simple(SimpleBlock {
                         content: Content {
@@ -188,6 +188,8 @@ Discrete headings can be used where sections are not permitted.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_discrete_heading",
@@ -345,6 +347,8 @@ DocBook refers to a discrete heading as a bridgehead, or free-floating heading.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_float_heading",
@@ -88,6 +88,8 @@ line two
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -175,6 +177,8 @@ line two
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -265,6 +269,8 @@ line two
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -340,6 +346,8 @@ line three
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -415,6 +423,8 @@ line two
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -492,6 +502,8 @@ Note that `empty` is a built-in document attribute in AsciiDoc.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -603,6 +615,8 @@ include::example$paragraph.adoc[tag=hb]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -698,6 +712,8 @@ include::example$paragraph.adoc[tag=hb-p]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -794,6 +810,8 @@ include::example$paragraph.adoc[tag=hb-attr]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
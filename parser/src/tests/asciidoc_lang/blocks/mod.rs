@@ -1,4 +1,5 @@
 mod add_title;
+mod admonitions;
 mod assign_id;
 mod breaks;
 mod build_basic_block;
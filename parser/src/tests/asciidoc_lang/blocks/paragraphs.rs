@@ -117,6 +117,8 @@ include::example$paragraph.adoc[tag=para]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
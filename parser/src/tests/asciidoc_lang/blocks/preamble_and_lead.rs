@@ -173,6 +173,8 @@ include::example$preamble.adoc[]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_certain_peril",
@@ -102,6 +102,8 @@ It will be styled as a sidebar.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -229,6 +231,8 @@ If you want the processor to recognize a closing delimiter, it must be the same
                 },
             ],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
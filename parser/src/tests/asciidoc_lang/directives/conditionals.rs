@@ -0,0 +1,89 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{Parser, blocks::IsBlock, parser::ModificationContext, tests::prelude::*};
+
+track_file!("docs/modules/directives/pages/conditionals.adoc");
+
+#[test]
+fn conditional_processing() {
+    verifies!(
+        r#"
+= Conditionals
+
+You can include or exclude lines of text in your document using the following conditional preprocessor directives:
+
+* xref:ifdef-ifndef.adoc#ifdef[ifdef]
+* xref:ifdef-ifndef.adoc#ifndef[ifndef]
+* xref:ifeval.adoc[ifeval]
+
+When the processor encounters one of these conditionals, it evaluates the specified condition.
+The condition is based on the presence or value of one or more document attributes.
+If the condition evaluates to true, the lines the conditional encloses are included.
+Otherwise, the lines are skipped.
+
+////
+For example, say you want to include a certain section of content only when converting to HTML.
+Conditional preprocessor directives make this possible.
+You simply check for the presence of the `basebackend-html` attribute using an `ifdef` directive.
+Details of this example and more `ifdef` and `ifndef` examples, are described in the following sections.
+See xref:ifeval.adoc[] for `ifeval` examples.
+////
+
+== Conditional processing
+
+Although a conditional preprocessor directive looks like a block macro, *it's not a macro and therefore isn't processed like one*.
+It's a preprocessor directive; it's important to understand the distinction.
+
+include::partial$preprocessor.adoc[]
+The conditional preprocessor directives determine which lines to add and which ones to take away based on the condition.
+
+"#
+    );
+
+    let source = "ifdef::flag[]\nIncluded if flag is set.\nendif::[]\n\nifndef::flag[]\nIncluded if flag is not set.\nendif::[]";
+
+    let doc = Parser::default().parse(source);
+    let mut blocks = doc.nested_blocks();
+    assert_eq!(
+        blocks.next().unwrap().rendered_content().unwrap(),
+        "Included if flag is not set."
+    );
+    assert!(blocks.next().is_none());
+
+    let doc = Parser::default()
+        .with_intrinsic_attribute("flag", "", ModificationContext::Anywhere)
+        .parse(source);
+    let mut blocks = doc.nested_blocks();
+    assert_eq!(
+        blocks.next().unwrap().rendered_content().unwrap(),
+        "Included if flag is set."
+    );
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn escape_conditional_directive() {
+    verifies!(
+        r#"
+== Escape a conditional directive
+
+If you don't want a conditional preprocessor directive to be processed, you must escape it using a backslash.
+
+// NOTE: the following listing uses indentation to prevent the directive from being processed
+[source,indent=0]
+----
+ \ifdef::just-an-example[]
+----
+
+Escaping the directive is necessary _even if it appears in a verbatim block_ since it's not aware of the surrounding document structure.
+"#
+    );
+
+    let doc = Parser::default().parse("[source]\n----\n\\ifdef::just-an-example[]\n----");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(
+        block.rendered_content().unwrap(),
+        "ifdef::just-an-example[]"
+    );
+}
@@ -0,0 +1,324 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser, blocks::IsBlock, parser::ModificationContext, tests::prelude::*, warnings::WarningType,
+};
+
+fn rendered_blocks(parser: Parser, source: &str) -> Vec<String> {
+    let mut parser = parser;
+    let doc = parser.parse(source);
+
+    doc.nested_blocks()
+        .map(|block| block.rendered_content().unwrap_or_default().to_owned())
+        .collect()
+}
+
+fn evaluates_true(parser: Parser, expression: &str) -> bool {
+    let source = format!("ifeval::[{expression}]\nIncluded.\nendif::[]");
+    rendered_blocks(parser, &source) == vec!["Included."]
+}
+
+fn with_attr(name: &str, value: &str) -> Parser {
+    Parser::default().with_intrinsic_attribute(name, value, ModificationContext::Anywhere)
+}
+
+track_file!("docs/modules/directives/pages/ifeval.adoc");
+
+#[test]
+fn ifeval_directive() {
+    verifies!(
+        r#"
+= ifeval Directive
+
+Lines enclosed by an `ifeval` directive (i.e., between the `ifeval` and `endif` directives) are included if the expression inside the square brackets of the `ifeval` directive evaluates to true.
+
+.ifeval example
+----
+\ifeval::[{sectnumlevels} == 3]
+If the `sectnumlevels` attribute has the value 3, this sentence is included.
+\endif::[]
+----
+
+"#
+    );
+
+    let source = "ifeval::[{sectnumlevels} == 3]\nIf the `sectnumlevels` attribute has the value 3, this sentence is included.\nendif::[]";
+
+    assert_eq!(
+        rendered_blocks(Parser::default(), source),
+        vec![
+            "If the <code>sectnumlevels</code> attribute has the value 3, this sentence is included."
+        ]
+    );
+
+    assert!(rendered_blocks(with_attr("sectnumlevels", "2"), source).is_empty());
+}
+
+#[test]
+fn terminating_ifeval() {
+    verifies!(
+        r#"
+The `ifeval` directive does not have a single-line or long-form variant like `ifdef` and `ifndef`.
+
+Unlike `ifdef` and `ifndef`, you cannot terminate a specific `ifeval` directive using its complement.
+For example, the following `ifeval` block is not valid:
+
+.Invalid ifeval terminator
+----
+\ifeval::[<condition>]
+conditional content
+\endif::[<condition>]
+----
+
+You can only terminate the previous `ifeval` directive using an anonymous `endif::[]` directive, as shown here:
+
+.Valid ineval terminator
+----
+\ifeval::[<condition>]
+conditional content
+\endif::[]
+----
+
+If you're mixing `ifeval` directives with `ifdef` or `ifndef` directives, you should always close multiline `ifdef` and `ifndef` directives by name (`endif::name-of-attribute[]`) so the `ifeval` directive does not end prematurely.
+
+"#
+    );
+
+    let mut parser = Parser::default();
+    let doc =
+        parser.parse("ifeval::[1 == 2]\nconditional content\nendif::[1 == 2]\nStill skipped.");
+
+    assert!(doc.nested_blocks().next().is_none());
+
+    assert_eq!(
+        doc.warnings()
+            .map(|w| w.warning.clone())
+            .collect::<Vec<_>>(),
+        vec![WarningType::MalformedPreprocessorDirective(
+            "endif::[1 == 2]".to_owned()
+        )]
+    );
+
+    assert_eq!(
+        rendered_blocks(
+            Parser::default(),
+            "ifeval::[1 == 2]\nconditional content\nendif::[]\nAfter."
+        ),
+        vec!["After."]
+    );
+
+    // Naming the `ifdef` in its `endif` keeps the `ifeval` open.
+    assert_eq!(
+        rendered_blocks(
+            Parser::default(),
+            "ifeval::[1 == 2]\nifdef::foo[]\nendif::foo[]\nSkipped.\nendif::[]\nAfter."
+        ),
+        vec!["After."]
+    );
+}
+
+#[test]
+fn anatomy() {
+    verifies!(
+        r#"
+== Anatomy
+
+The expression of an `ifeval` directive consists of a left-hand value and a right-hand value with an operator in between.
+It's customary to include a single space on either side of the operator.
+
+.ifeval expression examples
+----
+\ifeval::[2 > 1]
+...
+\endif::[]
+
+\ifeval::["{backend}" == "html5"]
+...
+\endif::[]
+
+\ifeval::[{sectnumlevels} == 3]
+...
+\endif::[]
+
+// the value of outfilesuffix includes a leading period (e.g., .html)
+\ifeval::["{docname}{outfilesuffix}" == "main.html"]
+...
+\endif::[]
+----
+
+"#
+    );
+
+    assert!(evaluates_true(Parser::default(), "2 > 1"));
+
+    assert!(evaluates_true(
+        with_attr("backend", "html5"),
+        "\"{backend}\" == \"html5\""
+    ));
+
+    assert!(evaluates_true(Parser::default(), "{sectnumlevels} == 3"));
+
+    let parser = with_attr("docname", "main").with_intrinsic_attribute(
+        "outfilesuffix",
+        ".html",
+        ModificationContext::Anywhere,
+    );
+
+    assert!(evaluates_true(
+        parser,
+        "\"{docname}{outfilesuffix}\" == \"main.html\""
+    ));
+}
+
+#[test]
+fn values() {
+    verifies!(
+        r#"
+== Values
+
+Each expression value can reference the name of zero or more AsciiDoc attributes using the attribute reference syntax (for example, `+{backend}+`).
+
+Attribute references are resolved (i.e., substituted) first.
+Once attributes references have been resolved, each value is coerced to a recognized type.
+
+When you expect the attribute reference to resolve to a string, that is, a sequence of characters, enclose that side of the expression in quotes.
+For example:
+
+.ifeval that compares two string expressions
+----
+\ifeval::["{backend}" == "html5"]
+----
+
+If you expect the attribute to resolve to a number, you do not need to enclose the expression in quotes.
+In this case, the values will be compared as numbers.
+The same rule applies to boolean values.
+
+You should not attempt to mix value types in a comparison.
+For example, the following expression is not valid:
+
+.Invalid ifeval expression
+----
+\ifeval::["{sectnumlevels}" > 3]
+----
+
+The following values types are recognized:
+
+number:: Either an integer or floating-point value.
+quoted string:: Enclosed in either single (`'`) or double (`"`) quotes.
+boolean:: Literal value of `true` or `false`.
+
+"#
+    );
+
+    assert!(evaluates_true(
+        with_attr("backend", "html5"),
+        "\"{backend}\" == \"html5\""
+    ));
+
+    assert!(!evaluates_true(
+        with_attr("backend", "docbook5"),
+        "\"{backend}\" == \"html5\""
+    ));
+
+    assert!(evaluates_true(with_attr("flag", "true"), "{flag} == true"));
+    assert!(evaluates_true(Parser::default(), "'abc' == \"abc\""));
+
+    assert!(!evaluates_true(
+        Parser::default(),
+        "\"{sectnumlevels}\" > 3"
+    ));
+    assert!(!evaluates_true(
+        Parser::default(),
+        "\"{sectnumlevels}\" <= 3"
+    ));
+}
+
+#[test]
+fn type_coercion() {
+    verifies!(
+        r#"
+=== How value type coercion works
+
+If a value is enclosed in quotes, the characters between the quotes is used and always coerced to a string.
+
+If a value is *not* enclosed in quotes, it's subject to the following type coercion rules:
+
+* an empty value becomes nil (aka null) (and thus safe for use in a comparison).
+* a value of `true` or `false` becomes a boolean value.
+* a value of only repeating whitespace becomes a single whitespace string.
+* a value containing a period becomes a floating-point number.
+* any other value is coerced to an integer value.
+
+"#
+    );
+
+    assert!(evaluates_true(Parser::default(), "\"3\" == '3'"));
+    assert!(!evaluates_true(Parser::default(), "\"3\" == 3"));
+
+    let parser = with_attr("empty", "");
+    assert!(evaluates_true(parser.clone(), "{empty} == {empty}"));
+    assert!(!evaluates_true(parser, "{empty} == 0"));
+
+    assert!(evaluates_true(Parser::default(), "true != false"));
+    assert!(!evaluates_true(Parser::default(), "true == 'true'"));
+
+    assert!(evaluates_true(with_attr("space", "   "), "{space} == ' '"));
+
+    assert!(evaluates_true(Parser::default(), "1.5 > 1"));
+    assert!(evaluates_true(Parser::default(), "2.0 == 2"));
+
+    assert!(evaluates_true(Parser::default(), "12abc == 12"));
+    assert!(evaluates_true(Parser::default(), "abc == 0"));
+}
+
+#[test]
+fn operators() {
+    verifies!(
+        r#"
+== Operators
+
+The value on each side is compared using the operator to derive an outcome.
+
+`==`::
+Checks if the two values are equal.
+`!=`::
+Checks if the two values are not equal.
+`<`::
+Checks whether the left-hand side is less than the right-hand side.
+`+<=+`::
+Checks whether the left-hand side is less than or equal to the right-hand side.
+`>`::
+Checks whether the left-hand side is greater than the right-hand side.
+`+>=+`::
+Checks whether the left-hand side is greater than or equal to the right-hand side.
+
+Both sides should be of the same value type.
+If they are not, the comparison will fail.
+If the comparison fails, the condition will evaluate to false (i.e., the content inside the directive will be skipped).
+
+The operators follow the same rules as operators in Ruby.
+"#
+    );
+
+    assert!(evaluates_true(Parser::default(), "1 == 1"));
+    assert!(!evaluates_true(Parser::default(), "1 == 2"));
+
+    assert!(evaluates_true(Parser::default(), "1 != 2"));
+    assert!(!evaluates_true(Parser::default(), "1 != 1"));
+
+    assert!(evaluates_true(Parser::default(), "1 < 2"));
+    assert!(!evaluates_true(Parser::default(), "2 < 2"));
+
+    assert!(evaluates_true(Parser::default(), "2 <= 2"));
+    assert!(!evaluates_true(Parser::default(), "3 <= 2"));
+
+    assert!(evaluates_true(Parser::default(), "3 > 2"));
+    assert!(!evaluates_true(Parser::default(), "2 > 2"));
+
+    assert!(evaluates_true(Parser::default(), "2 >= 2"));
+    assert!(!evaluates_true(Parser::default(), "1 >= 2"));
+
+    // Comparing values of different types fails, so the content is skipped.
+    assert!(!evaluates_true(Parser::default(), "'1' < 2"));
+    assert!(!evaluates_true(Parser::default(), "true > 1"));
+}
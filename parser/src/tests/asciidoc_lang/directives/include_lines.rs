@@ -0,0 +1,145 @@
+use std::sync::LazyLock;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::IsBlock,
+    tests::prelude::{inline_file_handler::InlineFileHandler, *},
+};
+
+static FILENAME_TXT: LazyLock<String> =
+    LazyLock::new(|| (1..=50).map(|n| format!("Line {n}.\n")).collect());
+
+fn rendered_blocks(source: &str) -> Vec<String> {
+    let handler = InlineFileHandler::from_pairs([("filename.txt", FILENAME_TXT.as_str())]);
+
+    let mut parser = Parser::default().with_include_file_handler(handler);
+    let doc = parser.parse(source);
+
+    doc.nested_blocks()
+        .map(|block| block.rendered_content().unwrap_or_default().to_owned())
+        .collect()
+}
+
+fn lines(numbers: impl IntoIterator<Item = usize>) -> Vec<String> {
+    vec![
+        numbers
+            .into_iter()
+            .map(|n| format!("Line {n}."))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ]
+}
+
+track_file!("docs/modules/directives/pages/include-lines.adoc");
+
+non_normative!(
+    r#"
+= Include Content by Line Ranges
+
+The include directive supports selecting portions of the document to include.
+Using the `lines` attribute, you can include ranges of line numbers.
+
+When including multiple line ranges, each entry in the list must be separated by either a comma or a semicolon.
+If commas are used, the entire value must be enclosed in quotes.
+Using the semicolon as the data separator eliminates this requirement.
+
+"#
+);
+
+#[test]
+fn line_range() {
+    verifies!(
+        r#"
+== Specifying line ranges
+
+To include content by line range, assign a starting line number and an ending line number separated by a pair of dots (e.g., `lines=1..5`) to the `lines` attribute.
+
+----
+include::example$include.adoc[tag=line]
+----
+"#
+    );
+
+    assert_eq!(
+        rendered_blocks("include::filename.txt[lines=5..10]"),
+        lines(5..=10)
+    );
+}
+
+#[test]
+fn comma_separated_ranges() {
+    verifies!(
+        r#"
+You can specify multiple ranges by separating each range by a comma.
+Since commas are normally used to separate individual attributes, you must quote the comma-separated list of ranges.
+
+----
+include::example$include.adoc[tag=m-line-comma]
+----
+
+"#
+    );
+
+    assert_eq!(
+        rendered_blocks("include::filename.txt[lines=\"1..10,15..20\"]"),
+        lines((1..=10).chain(15..=20))
+    );
+}
+
+#[test]
+fn semicolon_separated_ranges() {
+    verifies!(
+        r#"
+To avoid having to quote the list of ranges, you can instead separate them using semicolons.
+
+----
+include::example$include.adoc[tag=m-line]
+----
+
+"#
+    );
+
+    assert_eq!(
+        rendered_blocks("include::filename.txt[lines=7;14..25;28..43]"),
+        lines([7].into_iter().chain(14..=25).chain(28..=43))
+    );
+}
+
+#[test]
+fn last_line() {
+    verifies!(
+        r#"
+If you don't know the number of lines in the document, or you don't want to couple the range to the length of the file, you can refer to the last line of the document using the value -1.
+
+----
+include::example$include.adoc[tag=last]
+----
+
+"#
+    );
+
+    assert_eq!(
+        rendered_blocks("include::filename.txt[lines=12..-1]"),
+        lines(12..=50)
+    );
+}
+
+#[test]
+fn endless_range() {
+    verifies!(
+        r#"
+Alternately, you can leave the end range unspecified and it will default to -1.
+
+----
+include::example$include.adoc[tag=endless]
+----
+"#
+    );
+
+    assert_eq!(
+        rendered_blocks("include::filename.txt[lines=12..]"),
+        lines(12..=50)
+    );
+}
@@ -149,7 +149,7 @@ The single quotes around the variable name in the assignment are required to for
                                         col: 1,
                                         offset: 81,
                                     },
-                                    rendered: "See xref:chain-operation[].",
+                                    rendered: "See <a href=\"#chain-operation\">Chain</a>.",
                                 },
                                 source: Span {
                                     data: "See xref:chain-{chapter}[].",
@@ -259,7 +259,7 @@ The single quotes around the variable name in the assignment are required to for
                                     col: 1,
                                     offset: 180,
                                 },
-                                rendered: "See xref:chain-maintenance[].",
+                                rendered: "See <a href=\"#chain-maintenance\">Chain</a>.",
                             },
                             source: Span {
                                 data: "See xref:chain-{chapter}[].",
@@ -330,6 +330,8 @@ The single quotes around the variable name in the assignment are required to for
                 (10, SourceLine(None, 7,),),
                 (14, SourceLine(Some("fragment-chain.adoc",), 1,),),
             ],),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
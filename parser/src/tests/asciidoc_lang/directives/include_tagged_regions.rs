@@ -0,0 +1,439 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::IsBlock,
+    tests::prelude::{inline_file_handler::InlineFileHandler, *},
+};
+
+/// Include `content` in a literal block using the given attrlist on the
+/// include directive and return the resulting text.
+fn included(content: &'static str, attrlist: &str) -> String {
+    let mut parser = Parser::default()
+        .with_include_file_handler(InlineFileHandler::from_pairs([("file.txt", content)]));
+
+    let doc = parser.parse(&format!("....\ninclude::file.txt[{attrlist}]\n...."));
+
+    doc.nested_blocks()
+        .next()
+        .unwrap()
+        .rendered_content()
+        .unwrap()
+        .to_owned()
+}
+
+const CORE_RB: &str = "# tag::timings[]\nif timings\n  timings.record :read\n  timings.start :parse\nend\n# end::timings[]\n# tag::parse[]\ndoc = Document.new lines, options\ntimings.record :parse if timings\ndoc\n# end::parse[]\n";
+
+const SNIPPETS: &str = "// tag::snippets[]\n// tag::snippet-a[]\nsnippet a\n// end::snippet-a[]\n\n// tag::snippet-b[]\nsnippet b\n// end::snippet-b[]\n// end::snippets[]\n";
+
+const UNCOMMENTED_SNIPPETS: &str =
+    "text a\n\ntag::snippet-b[]\nsnippet b\nend::snippet-b[]\n\ntext c\n";
+
+const FOO_BAR: &str = "before\n// tag::foo[]\nfoo 1\n// tag::bar[]\nbar\n// end::bar[]\nfoo 2\n// end::foo[]\nbetween\n// tag::baz[]\nbaz\n// end::baz[]\nafter\n";
+
+track_file!("docs/modules/directives/pages/include-tagged-regions.adoc");
+
+#[test]
+fn select_portions() {
+    verifies!(
+        r#"
+= Include Content by Tagged Regions
+// aka Select Portions of a Document to Include
+
+The include directive enables you to select portions of a file to include instead of including the whole file.
+Use the `lines` attribute to include individual lines or a range of lines (by line number).
+Use the `tags` attribute (or `tag` attribute for the singular case) to select lines that fall between regions marked with user-defined tags.
+
+When including multiple line ranges or multiple tags, each entry in the list must be separated by either a comma or a semi-colon.
+If commas are used, the entire value must be enclosed in quotes (per attribute rules).
+You can eliminate the need for quotes by using the semi-colon as the data separator instead.
+
+"#
+    );
+
+    assert_eq!(
+        included(CORE_RB, "tags=\"timings,parse\""),
+        included(CORE_RB, "tags=timings;parse")
+    );
+
+    assert_eq!(
+        included(CORE_RB, "lines=2;8"),
+        "if timings\ndoc = Document.new lines, options"
+    );
+}
+
+#[test]
+fn tagging_regions() {
+    verifies!(
+        r#"
+== Tagging regions
+
+Tags are useful when you want to identify specific regions of a file to include.
+You can then select the lines between the boundaries of the include tag/end directives to include using the `tags` attribute.
+
+In the include file, the tag directives (e.g., `tag::name[]` and `end::name[]`) must follow a word boundary and precede a space character or the end of line.
+The tag name must not be empty and must consist exclusively of non-space characters.
+
+Typically, the tag directives will be placed after a line comment as defined by the language of the source file.
+This is especially important when using tags to include portions of an AsciiDoc document which may itself be converted.
+If the tag is not behind a line comment, the tag will be treated as regular content, which could disrupt the block structure, such as a table.
+
+For languages that only support circumfix comments, such as XML, you can enclose the tag directives between the circumfix comment markers, offset by a space on either side.
+For example, in XML files, you can use `+<!-- tag::name[] -->+` and `+<!-- end::name[] -->+`.
+
+Including by tag includes all regions marked with that tag.
+This makes it possible to include a group of lines from different regions of the document using a single tag.
+
+TIP: If the target file has tagged lines, and you just want to ignore those lines, use the `tags` attribute to filter them out.
+See <<tag-filtering>> for details.
+
+The example below shows how you tag a region of content inside a file containing multiple code examples.
+The tag directives are preceded by a hash (`#`) because that's the start of a line comment in Ruby.
+
+.Tagged code snippets in a file named core.rb
+[source,ruby,subs=attributes+]
+----
+include::example$include.adoc[tag=tag-co]
+----
+<.> To indicate the start of a tagged region, insert a comment line in the code.
+<.> Assign a name to the `tag` directive. In this example, the tag is named _timings_.
+<.> Insert another comment line where you want the tagged region to end.
+<.> Assign the name of the region you want to terminate to the `end` directive.
+<.> This is the start of a tagged snippet named _parse_.
+<.> This is the end of the tagged snippet named _parse_.
+
+"#
+    );
+
+    assert_eq!(
+        included(
+            "<root>\n<!-- tag::name[] -->\n<name>x</name>\n<!-- end::name[] -->\n</root>\n",
+            "tag=name"
+        ),
+        "&lt;name&gt;x&lt;/name&gt;"
+    );
+
+    assert_eq!(
+        included(
+            "// tag::a[]\none\n// end::a[]\ntwo\n// tag::a[]\nthree\n// end::a[]\n",
+            "tag=a"
+        ),
+        "one\nthree"
+    );
+
+    // A tag directive must follow a word boundary and precede a space or the end
+    // of the line.
+    assert_eq!(
+        included("// xtag::a[]\n// tag::a[]x\none\n", "tags=**"),
+        "// xtag::a[]\n// tag::a[]x\none"
+    );
+}
+
+#[test]
+fn selecting_tagged_regions() {
+    verifies!(
+        r#"
+In the next example, the tagged region named _parse_ is selected by the `include` directive.
+
+.Selecting the _parse_ code snippet from a document
+[source]
+....
+include::example$include.adoc[tag=target-co]
+....
+<.> In the directive's brackets, set the `tag` attribute and assign it the unique name of the code snippet you tagged in your code file.
+
+You can include multiple tags from the same file.
+
+.Selecting the _timings_ and the _parse_ code snippets from a document
+[source]
+....
+include::example$include.adoc[tag=target-co-multiple]
+....
+
+"#
+    );
+
+    assert_eq!(
+        included(CORE_RB, "tag=parse"),
+        "doc = Document.new lines, options\ntimings.record :parse if timings\ndoc"
+    );
+
+    assert_eq!(
+        included(CORE_RB, "tags=timings;parse"),
+        "if timings\n  timings.record :read\n  timings.start :parse\nend\ndoc = Document.new lines, options\ntimings.record :parse if timings\ndoc"
+    );
+}
+
+#[test]
+fn nested_tagged_regions() {
+    verifies!(
+        r#"
+It's also possible to have fine-grained tagged regions inside larger tagged regions.
+
+In the next example, tagged regions are defined behind line comments.
+By putting each tag behind a line comment, regardless of how the content is included, you don't have to worry about those lines appearing in the rendered document.
+
+----
+// tag::snippets[]
+// tag::snippet-a[]
+snippet a
+// end::snippet-a[]
+
+// tag::snippet-b[]
+snippet b
+// end::snippet-b[]
+// end::snippets[]
+----
+
+Let's assume you include this file using the following include directive:
+
+----
+\include::file-with-snippets.adoc[tag=snippets]
+----
+
+The following lines will be selected and displayed:
+
+....
+snippet a
+
+snippet b
+....
+
+You could also include the whole file without worry that the tags will be rendered:
+
+----
+\include::file-with-snippets.adoc[]
+----
+
+"#
+    );
+
+    assert_eq!(included(SNIPPETS, "tag=snippets"), "snippet a\n\nsnippet b");
+
+    assert_eq!(
+        included(SNIPPETS, ""),
+        "// tag::snippets[]\n// tag::snippet-a[]\nsnippet a\n// end::snippet-a[]\n\n// tag::snippet-b[]\nsnippet b\n// end::snippet-b[]\n// end::snippets[]"
+    );
+}
+
+#[test]
+fn uncommented_tags() {
+    verifies!(
+        r#"
+Now let's consider the case when the tags are not placed behind line comments.
+In this case, you need to ensure that xref:tag-filtering[tag filtering] is being used or else those tags will be visible in the rendered document.
+
+----
+text a
+
+tag::snippet-b[]
+snippet b
+end::snippet-b[]
+
+text c
+----
+
+If you only want to include a specific tagged region of the file, use the following include directive:
+
+----
+\include::file-with-snippets.adoc[tag=snippet-b]
+----
+
+The following lines will be selected and displayed:
+
+....
+snippet b
+....
+
+If you want to include the whole file, but also filter out any include tags, use the following include directive:
+
+----
+\include::file-with-snippets.adoc[tag=**]
+----
+
+The following lines will be selected and displayed:
+
+....
+text a
+snippet b
+text c
+....
+
+If you did not specify tag filtering, tag directives that aren't behind a line comment (e.g., `tag::snippet-b[]`) will also be printed too.
+Tag filtering is explained in more detail in the next section.
+
+"#
+    );
+
+    assert_eq!(included(UNCOMMENTED_SNIPPETS, "tag=snippet-b"), "snippet b");
+
+    assert_eq!(
+        included(UNCOMMENTED_SNIPPETS, "tag=**"),
+        "text a\n\nsnippet b\n\ntext c"
+    );
+
+    assert_eq!(
+        included(UNCOMMENTED_SNIPPETS, ""),
+        "text a\n\ntag::snippet-b[]\nsnippet b\nend::snippet-b[]\n\ntext c"
+    );
+}
+
+#[test]
+fn tag_filtering() {
+    verifies!(
+        r#"
+[#tag-filtering]
+== Tag filtering
+
+The previous section showed how to select tagged regions explicitly, but you can also use wildcards and exclusions.
+These expressions give you the ability to include or exclude tags in bulk.
+For example, here's how to include all lines that are not enclosed in a tag:
+
+----
+\include::file-with-snippets.adoc[tag=!*]
+----
+
+When tag filtering is used, lines that contain a tag directive _are always discarded_ (like a line comment).
+Even if you're not including content by tags, you can specify the double wildcard (`+**+`) to filter out all lines in the include file that contain a tag directive.
+
+The modifiers you can use for filtering are as follows:
+
+`*`::
+The single wildcard.
+Select all tagged regions.
+May only be specified once, negated or not.
+
+`**`::
+The double wildcard.
+Select all the lines in the document *except for lines that contain a tag directive*.
+Use this symbol if you want to include a file that has tag directives, but you want to discard the lines that contain a tag directive.
+May only be specified once, negated or not.
+
+`!`::
+Negate the wildcard or tag.
+
+The double wildcard is always applied first, regardless of where it appears in the list.
+If the double wildcard is not negated (i.e., `+**+`), it should only be combined with exclusions (e.g., `+**;!foo+`).
+A negated double wildcard (i.e., `+!**+`), which selects no lines, is usually implied as the starting point.
+A negated single wildcard has different meaning depending on whether it comes before tag names (e.g., `+!*;foo+`) or after at least one tag name (e.g., `+foo;!*+`).
+
+"#
+    );
+
+    assert_eq!(included(FOO_BAR, "tag=!*"), "before\nbetween\nafter");
+
+    assert_eq!(
+        included(FOO_BAR, "tags=**"),
+        "before\nfoo 1\nbar\nfoo 2\nbetween\nbaz\nafter"
+    );
+}
+
+#[test]
+fn tag_filtering_permutations() {
+    verifies!(
+        r#"
+Let's assume we have a region tagged `foo` with a nested region tagged `bar`.
+Here are some of the permutations you can use (along with their implied long-hand forms):
+
+`+**+`:: Selects all the lines in the document (except for lines that contain a tag directive).
+_(implies `+**;*+`)_
+
+`+*+`:: Selects all tagged regions in the document.
+Does not select lines outside of tagged regions.
+_(implies `+!**;*+`)_
+
+`+!*+`:: Selects only the regions in the document outside of tags (i.e., non-tagged regions).
+_(implies `+**;!*+`)_
+
+`foo`:: Selects only regions tagged _foo_.
+_(implies `+!**;foo+`)_
+
+`foo;!bar`:: Selects only regions tagged _foo_, but excludes any nested regions tagged _bar_.
+_(implies `+!**;foo;!bar+`)_
+
+`+foo;!*+`:: Selects only regions tagged _foo_, but excludes any nested tagged regions.
+_(implies `+!**;foo;!*+`)_
+
+`+*;!foo+`:: Selects all tagged regions, but excludes any regions tagged _foo_ (nested or otherwise).
+_(implies `+!**;*;!foo+`)_
+
+`!foo`:: Selects all the lines in the document except for regions tagged _foo_.
+_(implies `+**;!foo+`)_
+
+`!foo;!bar`:: Selects all the lines in the document except for regions tagged _foo_ or _bar_.
+_(implies `+**;!foo;!bar+`)_
+
+`+!*;foo+`:: Selects the regions in the document outside of tags (i.e., non-tagged regions) and inside regions tagged _foo_, excluding any nested tagged regions.
+To include nested tagged regions, they each must be named explicitly.
+_(implies `+**;!*;foo+`)_
+
+If the filter begins with a negated tag or single wildcard, it implies that the pattern begins with `+**+`.
+An exclusion not preceded by an inclusion implicitly starts by selecting all the lines that do not contain a tag directive.
+Otherwise, it implies that the pattern begins with `+!**+`.
+A leading inclusion implicitly starts by selecting no lines.
+"#
+    );
+
+    assert_eq!(
+        included(FOO_BAR, "tags=**"),
+        "before\nfoo 1\nbar\nfoo 2\nbetween\nbaz\nafter"
+    );
+
+    assert_eq!(included(FOO_BAR, "tags=*"), "foo 1\nbar\nfoo 2\nbaz");
+    assert_eq!(included(FOO_BAR, "tags=!*"), "before\nbetween\nafter");
+    assert_eq!(included(FOO_BAR, "tags=foo"), "foo 1\nbar\nfoo 2");
+    assert_eq!(included(FOO_BAR, "tags=foo;!bar"), "foo 1\nfoo 2");
+    assert_eq!(included(FOO_BAR, "tags=foo;!*"), "foo 1\nfoo 2");
+    assert_eq!(included(FOO_BAR, "tags=*;!foo"), "baz");
+
+    assert_eq!(
+        included(FOO_BAR, "tags=!foo"),
+        "before\nbetween\nbaz\nafter"
+    );
+
+    assert_eq!(
+        included(FOO_BAR, "tags=!foo;!bar"),
+        "before\nbetween\nbaz\nafter"
+    );
+
+    assert_eq!(
+        included(FOO_BAR, "tags=!*;foo"),
+        "before\nfoo 1\nfoo 2\nbetween\nafter"
+    );
+
+    // The implied long-hand forms are equivalent.
+    assert_eq!(included(FOO_BAR, "tags=**;*"), included(FOO_BAR, "tags=**"));
+    assert_eq!(included(FOO_BAR, "tags=!**;*"), included(FOO_BAR, "tags=*"));
+    assert_eq!(
+        included(FOO_BAR, "tags=**;!*"),
+        included(FOO_BAR, "tags=!*")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=!**;foo"),
+        included(FOO_BAR, "tags=foo")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=!**;foo;!bar"),
+        included(FOO_BAR, "tags=foo;!bar")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=!**;foo;!*"),
+        included(FOO_BAR, "tags=foo;!*")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=!**;*;!foo"),
+        included(FOO_BAR, "tags=*;!foo")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=**;!foo"),
+        included(FOO_BAR, "tags=!foo")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=**;!foo;!bar"),
+        included(FOO_BAR, "tags=!foo;!bar")
+    );
+    assert_eq!(
+        included(FOO_BAR, "tags=**;!*;foo"),
+        included(FOO_BAR, "tags=!*;foo")
+    );
+}
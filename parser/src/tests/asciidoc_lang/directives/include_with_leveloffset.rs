@@ -0,0 +1,195 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{Block, IsBlock},
+    tests::prelude::{inline_file_handler::InlineFileHandler, *},
+};
+
+/// Return the level and title of each section in the document, in document
+/// order.
+fn section_levels(source: &str) -> Vec<(usize, String)> {
+    fn collect(block: &Block<'_>, levels: &mut Vec<(usize, String)>) {
+        if let Block::Section(section) = block {
+            levels.push((section.level(), section.section_title().to_owned()));
+        }
+
+        for child in block.nested_blocks() {
+            collect(child, levels);
+        }
+    }
+
+    let handler = InlineFileHandler::from_pairs([
+        (
+            "chapter01.adoc",
+            "= Chapter 1\n\nChapter 1 content.\n\n== Section 1.1\n",
+        ),
+        ("chapter02.adoc", "= Chapter 2\n\nChapter 2 content.\n"),
+        ("chapter03.adoc", "= Chapter 3\n\nChapter 3 content.\n"),
+    ]);
+
+    let mut parser = Parser::default().with_include_file_handler(handler);
+    let doc = parser.parse(source);
+
+    let mut levels = vec![];
+    for block in doc.nested_blocks() {
+        collect(block, &mut levels);
+    }
+
+    levels
+}
+
+fn expected_chapters() -> Vec<(usize, String)> {
+    vec![
+        (1, "Chapter 1".to_owned()),
+        (2, "Section 1.1".to_owned()),
+        (1, "Chapter 2".to_owned()),
+        (1, "Chapter 3".to_owned()),
+    ]
+}
+
+track_file!("docs/modules/directives/pages/include-with-leveloffset.adoc");
+
+non_normative!(
+    r#"
+= Offset Section Levels
+//Partitioning Large Documents and using leveloffset
+// [#include-partitioning]
+
+When your document gets large, you can split it up into subdocuments for easier editing.
+
+----
+= My book
+
+\include::chapter01.adoc[]
+
+\include::chapter02.adoc[]
+
+\include::chapter03.adoc[]
+----
+
+TIP: Note the empty lines before and after the include directives.
+This practice is recommended whenever including AsciiDoc content to avoid unexpected results (e.g., a section title getting interpreted as a line at the end of a previous paragraph).
+
+"#
+);
+
+#[test]
+fn relative_leveloffset() {
+    verifies!(
+        r#"
+== Manipulate heading levels with leveloffset
+
+The `leveloffset` attribute can help here by pushing all headings in the included document down by the specified number of levels.
+This allows you to publish each chapter as a standalone document (complete with a document title), but still be able to include the chapters into a primary document (which has its own document title).
+
+You can easily assemble your book so that the chapter document titles become level 1 headings using:
+
+----
+= My Book
+
+\include::chapter01.adoc[leveloffset=+1]
+
+\include::chapter02.adoc[leveloffset=+1]
+
+\include::chapter03.adoc[leveloffset=+1]
+----
+
+Because the leveloffset is _relative_ (it begins with + or -), this works even if the included document has its own includes and leveloffsets.
+
+"#
+    );
+
+    assert_eq!(
+        section_levels(
+            "= My Book\n\ninclude::chapter01.adoc[leveloffset=+1]\n\ninclude::chapter02.adoc[leveloffset=+1]\n\ninclude::chapter03.adoc[leveloffset=+1]\n"
+        ),
+        expected_chapters()
+    );
+}
+
+#[test]
+fn leveloffset_around_includes() {
+    verifies!(
+        r#"
+If you have lots of chapters to include and want them all to have the same offset, you can save some typing by setting `leveloffset` around the includes:
+
+----
+= My book
+
+:leveloffset: +1
+
+\include::chapter01.adoc[]
+
+\include::chapter02.adoc[]
+
+\include::chapter03.adoc[]
+
+:leveloffset: -1
+----
+
+The final line returns the level offset to 0.
+
+"#
+    );
+
+    assert_eq!(
+        section_levels(
+            "= My book\n\n:leveloffset: +1\n\ninclude::chapter01.adoc[]\n\ninclude::chapter02.adoc[]\n\ninclude::chapter03.adoc[]\n\n:leveloffset: -1\n\n== Appendix"
+        ),
+        {
+            let mut expected = expected_chapters();
+            expected.push((1, "Appendix".to_owned()));
+            expected
+        }
+    );
+}
+
+#[test]
+fn absolute_leveloffset() {
+    verifies!(
+        r#"
+Alternatively, you could use absolute levels:
+
+----
+:leveloffset: 1
+
+//includes
+
+:leveloffset: 0
+----
+
+Relative levels are preferred.
+Absolute levels become awkward when you have nested includes since they aren't context aware.
+
+"#
+    );
+
+    assert_eq!(
+        section_levels(
+            "= My book\n\n:leveloffset: 1\n\ninclude::chapter01.adoc[]\n\ninclude::chapter02.adoc[]\n\ninclude::chapter03.adoc[]\n\n:leveloffset: 0\n\n== Appendix"
+        ),
+        {
+            let mut expected = expected_chapters();
+            expected.push((1, "Appendix".to_owned()));
+            expected
+        }
+    );
+}
+
+non_normative!(
+    r#"
+////
+That's also why it's important to surround the include directive by empty lines if it imports in a discrete structure.
+
+You only want to place include files directly adjacent to one another if the imported content should be directly adjacent.
+
+IMPORTANT: Take note of the empty lines between the include directives.
+The empty line between include directives prevents the first and last lines of the included files from being adjoined.
+This practice is *strongly* encouraged when combining document parts.
+If you don't include these empty lines, you might find that the AsciiDoc processor swallows section titles.
+This happens because the leading section title can get interpreted as the last line of the final paragraph in the preceding include.
+Only place include directives on consecutive lines if the intent is for the includes to run together (such as in a listing block).
+////
+"#
+);
@@ -1,2 +1,8 @@
+mod conditionals;
+mod ifdef_ifndef;
+mod ifeval;
+mod include_lines;
 mod include_multiple_times_in_same_document;
+mod include_tagged_regions;
 mod include_uri;
+mod include_with_leveloffset;
@@ -102,6 +102,8 @@ The author line also accepts xref:multiple-authors.adoc[multiple authors].
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -184,6 +186,8 @@ The processor assigns the content on the author line to the built-in attributes
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -104,6 +104,8 @@ Thus, it's important to use the underscore separator to ensure there are no more
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -197,6 +199,8 @@ image::author-line-with-compound-names.png[Compound author names displayed in th
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -333,6 +337,8 @@ image::reference-compound-names.png[Compound author names displayed in the docum
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -477,6 +483,8 @@ image::author-attribute-with-compound-name.png[Compound author name displayed in
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_about_mara_moss_wirribi",
@@ -73,6 +73,8 @@ In other words, the document must start with a document header if it has one.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -182,6 +184,8 @@ These implicit content lines are used to assign xref:author-information.adoc[] a
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -369,6 +373,8 @@ Since the document title is optional, it's possible for the header to only consi
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -501,6 +507,8 @@ Any attributes defined below the first empty line are not part of the document h
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -102,6 +102,8 @@ Directly after each author's last name or optional email, enter a semicolon (`;`
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -187,6 +189,8 @@ This limitation may be lifted in the future.
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -286,6 +290,8 @@ The information for each author can also be xref:reference-author-attributes.ado
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -361,6 +367,8 @@ AsciiDoc&#174;{empty} WG; Another Author
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
@@ -460,6 +468,8 @@ Even though the character reference is escaped, the segments of the author name
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
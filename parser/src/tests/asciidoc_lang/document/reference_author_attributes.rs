@@ -221,6 +221,8 @@ image::reference-author.png[Reference the built-in attributes for an author,role
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_about_kismet_r_lee",
@@ -502,6 +504,8 @@ image::reference-multiple-authors.png[Reference the built-in attributes for mult
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog::default(),
         }
     );
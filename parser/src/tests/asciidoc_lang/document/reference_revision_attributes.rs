@@ -156,6 +156,8 @@ image::reference-revision-line.png["Revision line and rendered revision referenc
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_colophon",
@@ -364,6 +366,8 @@ If you don't want the default version label to be displayed in the byline, xref:
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_colophon",
@@ -100,6 +100,8 @@ image::document-title.png[Title of document]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -231,6 +233,8 @@ The `doctitle` attribute can also be explicitly set and assigned a value using a
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
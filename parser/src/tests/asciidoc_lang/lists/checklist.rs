@@ -0,0 +1,131 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, ListType},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+fn checked_states<'src>(list: &'src crate::blocks::ListBlock<'src>) -> Vec<Option<bool>> {
+    list.nested_blocks()
+        .map(|item| {
+            let crate::blocks::Block::ListItem(item) = item else {
+                panic!("Unexpected block: {item:#?}");
+            };
+            item.checked()
+        })
+        .collect()
+}
+
+track_file!("docs/modules/lists/pages/checklist.adoc");
+
+non_normative!(
+    r#"
+= Checklists
+
+"#
+);
+
+#[test]
+fn checklist_syntax() {
+    verifies!(
+        r#"
+List items can be marked complete using checklists.
+
+Checklists (i.e., task lists) are unordered lists that have items marked as checked (`[*]` or `[x]`) or unchecked (`[ ]`).
+Here's an example:
+
+.Checklist syntax
+[#ex-syntax]
+----
+include::example$checklist.adoc[tag=check]
+----
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("* [*] checked\n* [x] also checked\n* [ ] not checked\n* normal list item");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Unordered);
+    assert!(list.has_option("checklist"));
+
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["checked", "also checked", "not checked", "normal list item"]
+    );
+
+    assert_eq!(
+        checked_states(list),
+        vec![Some(true), Some(true), Some(false), None]
+    );
+}
+
+non_normative!(
+    r#"
+
+The result of <<ex-syntax>> is displayed below.
+
+include::example$checklist.adoc[tag=check]
+
+TIP: Not all items in the list have to be checklist items, as <<ex-syntax>> shows.
+
+When checklists are converted to HTML, the checkbox markup is transformed into an HTML checkbox with the appropriate checked state.
+The `data-item-complete` attribute on the checkbox is set to 1 if the item is checked, 0 if not.
+The checkbox is used in place of the item's bullet.
+
+"#
+);
+
+#[test]
+fn interactive_checklist() {
+    verifies!(
+        r#"
+Since HTML generated from AsciiDoc is typically static, the checkbox is set as disabled to make it appear as a simple mark.
+If you want to make the checkbox interactive (i.e., clickable), add the `interactive` option to the checklist (shown here using the shorthand syntax for the xref:attributes:options.adoc[]):
+
+.Checklist with interactive checkboxes
+[#ex-interactive]
+----
+include::example$checklist.adoc[tag=check-int]
+----
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "[%interactive]\n* [*] checked\n* [x] also checked\n* [ ] not checked\n* normal list item",
+    );
+
+    let list = only_list_from(&doc);
+    assert!(list.has_option("interactive"));
+    assert!(list.has_option("checklist"));
+
+    assert_eq!(
+        checked_states(list),
+        vec![Some(true), Some(true), Some(false), None]
+    );
+}
+
+non_normative!(
+    r#"
+
+The result of <<ex-interactive>> is displayed below.
+
+include::example$checklist.adoc[tag=check-int]
+
+////
+This example doesn't seem quite right since nothing about it indicates font based icons.
+
+As a bonus, if you enable font-based icons, the checkbox markup (in non-interactive lists) is transformed into a font-based icon!
+
+.Checklist with font-based checkboxes
+[source]
+----
+include::{partialsdir}/ex-ulist.adoc[tag=check-icon]
+----
+////
+"#
+);
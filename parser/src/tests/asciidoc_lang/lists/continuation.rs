@@ -0,0 +1,568 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::IsBlock,
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/lists/pages/continuation.adoc");
+
+non_normative!(
+    r#"
+= Compound List Items
+
+This page covers how to create lists that have compound list items.
+
+A [.term]*compound list item* is a list item that has blocks attached to it, including paragraphs, which follow the (optionally empty) principal text.
+In other words, the list item contains block content.
+This scenario is different from a list item whose principal text merely spans multiple lines, a distinction which is further explained on this page.
+The page goes on to explain how to attach a block to a list item in an ancestor list.
+
+In additional to unordered and ordered lists, callout and description lists also support compound list items.
+On this page, the term list item refers to any list item in an unordered, ordered, callout, and description list.
+For a description list, it refers specifically to the description of the list item (not the list item term).
+
+The main focus of the syntax covered on this page is to keep the list continuous (i.e., to prevent the list from breaking).
+
+== Multiline principal text
+
+"#
+);
+
+#[test]
+fn multiline_principal_text() {
+    verifies!(
+        r#"
+As with regular paragraph text, the principal text in a list item can span any number of lines as long as those lines are contiguous (i.e., adjacent with no empty lines).
+Multiple lines are combined into a single paragraph and wrap as regular paragraph text.
+This behavior holds even if the lines are indented, as shown in the third bullet in this example:
+
+----
+include::example$complex.adoc[tag=indent]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* The document header in AsciiDoc is optional.\nIf present, it must start with a document title.\n\n* Optional author and revision information lines\nimmediately follow the document title.\n\n* The document header must be separated from\n  the remainder of the document by one or more\n  empty lines and it cannot contain empty lines.",
+    );
+
+    let list = only_list_from(&doc);
+
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec![
+            "The document header in AsciiDoc is optional.\nIf present, it must start with a document title.",
+            "Optional author and revision information lines\nimmediately follow the document title.",
+            "The document header must be separated from\nthe remainder of the document by one or more\nempty lines and it cannot contain empty lines.",
+        ]
+    );
+
+    assert!(
+        list.nested_blocks()
+            .all(|item| item.nested_blocks().next().is_none())
+    );
+}
+
+non_normative!(
+    r#"
+====
+include::example$complex.adoc[tag=indent]
+====
+
+TIP: When list items contain more than one line of text, leave an empty line between items to make the list easier to read while working in the code.
+An empty line between two list items will not break the list.
+
+"#
+);
+
+#[test]
+fn empty_lines_in_list() {
+    verifies!(
+        r#"
+=== Empty lines in a list
+
+Empty lines between two items in a list (ordered or unordered) will not break the list.
+For ordered lists, this means the numbering will be continuous rather than restarting at 1.
+(See xref:separating.adoc[] to learn how to force two adjacent lists apart).
+
+If an empty line after a list item is followed by the start of a block, such as a paragraph or delimited block rather than another list item, the list will terminate at this point.
+If this happens, you'll notice that a subsequent list item will be placed into a new list.
+For ordered lists, that means the numbering will restart (at 1).
+
+To keep the list continuous in those cases--such as when you're documenting complex steps in a procedure--you must use a <<list-continuation,list continuation>> to attach blocks to the list item.
+For ordered lists, this will ensure that the numbering continues from one list item to the next rather than being reset.
+
+"#
+    );
+
+    let doc = Parser::default().parse(". one\n\n. two\n\nparagraph\n\n. three");
+
+    let mut blocks = doc.nested_blocks();
+
+    let first = blocks.next().unwrap();
+    assert_eq!(first.raw_context().deref(), "olist");
+    assert_eq!(item_texts(first.nested_blocks()), vec!["one", "two"]);
+
+    let paragraph = blocks.next().unwrap();
+    assert_eq!(paragraph.raw_context().deref(), "paragraph");
+
+    let second = blocks.next().unwrap();
+    assert_eq!(second.raw_context().deref(), "olist");
+    assert_eq!(item_texts(second.nested_blocks()), vec!["three"]);
+
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn list_continuation() {
+    verifies!(
+        r#"
+[#list-continuation]
+== Attach blocks using a list continuation
+
+In addition to the principal text, a list item may contain block elements, including paragraphs, delimited blocks, and block macros.
+To add block elements to a list item, you must "`attach`" them (in a series) using a list continuation.
+This technique works for unordered and ordered lists as well as callout and description lists.
+
+A [.term]*list continuation* is a `{plus}` symbol on a line by itself, immediately adjacent to the block being attached.
+The attached block must be left-aligned, just like all blocks in AsciiDoc.
+
+NOTE: A `{plus}` at the end of a line, rather than on a line by itself, is not a list continuation.
+Instead, it creates a hard line break.
+
+Here's an example of a list item that uses a list continuation:
+
+----
+include::example$complex.adoc[tag=cont]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* The header in AsciiDoc must start with a document title.\n+\nThe header is optional.",
+    );
+
+    let list = only_list_from(&doc);
+    let item = list.nested_blocks().next().unwrap();
+
+    assert_eq!(
+        item.rendered_content().unwrap(),
+        "The header in AsciiDoc must start with a document title."
+    );
+
+    let mut attached = item.nested_blocks();
+
+    let paragraph = attached.next().unwrap();
+    assert_eq!(paragraph.raw_context().deref(), "paragraph");
+    assert_eq!(
+        paragraph.rendered_content().unwrap(),
+        "The header is optional."
+    );
+
+    assert!(attached.next().is_none());
+
+    // A `+` at the end of a line is not a list continuation.
+    let doc = Parser::default().parse("* one +\ntwo");
+
+    let list = only_list_from(&doc);
+    let item = list.nested_blocks().next().unwrap();
+    assert!(item.nested_blocks().next().is_none());
+}
+
+non_normative!(
+    r#"
+====
+include::example$complex.adoc[tag=cont]
+====
+
+"#
+);
+
+#[test]
+fn attach_multiple_blocks() {
+    verifies!(
+        r#"
+Using a list continuation, you can attach any number of block elements to a list item.
+Unless the block is inside a delimited block which itself has been attached, each block must be preceded by a list continuation to form a chain of blocks.
+
+Here's an example that attaches both a listing block and a paragraph to the first list item:
+
+[source]
+....
+include::example$complex.adoc[tag=complex]
+....
+
+Here's how the source is rendered:
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* The header in AsciiDoc must start with a document title.\n+\n----\n= Document Title\n----\n+\nKeep in mind that the header is optional.\n\n* Optional author and revision information lines immediately follow the document title.\n+\n----\n= Document Title\nDoc Writer <doc.writer@asciidoc.org>\nv1.0, 2022-01-01\n----",
+    );
+
+    let list = only_list_from(&doc);
+    let mut items = list.nested_blocks();
+
+    let first = items.next().unwrap();
+    let mut attached = first.nested_blocks();
+    assert_eq!(attached.next().unwrap().raw_context().deref(), "listing");
+
+    assert_eq!(
+        attached.next().unwrap().rendered_content().unwrap(),
+        "Keep in mind that the header is optional."
+    );
+
+    assert!(attached.next().is_none());
+
+    let second = items.next().unwrap();
+    let mut attached = second.nested_blocks();
+    assert_eq!(attached.next().unwrap().raw_context().deref(), "listing");
+    assert!(attached.next().is_none());
+
+    assert!(items.next().is_none());
+
+    // Only a sibling list item interrupts an attached paragraph.
+    let doc = Parser::default().parse("* one\n+\nattached\n** nested\n* two");
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["one", "two"]);
+
+    let one = list.nested_blocks().next().unwrap();
+    assert_eq!(
+        one.nested_blocks()
+            .next()
+            .unwrap()
+            .rendered_content()
+            .unwrap(),
+        "attached\n** nested"
+    );
+}
+
+non_normative!(
+    r#"
+.A list with compound content
+====
+include::example$complex.adoc[tag=complex]
+====
+
+"#
+);
+
+#[test]
+fn wrap_in_open_block() {
+    verifies!(
+        r#"
+Notice that we inserted an empty line after the attached paragraph block.
+That's because only a sibling list item can interrupt a paragraph.
+If the next list item had been a nested list item instead of a sibling, this empty line would have been required.
+Otherwise, the nested list marker and text would have just become the next line of the paragraph.
+For consistency, a best practice is to always include an empty line at the end of a compound list item.
+That way, you never have to remember when it's required.
+
+IMPORTANT: A sibling or nested list item acts as an interrupting line for the principal text of a list item.
+Only a sibling list item acts as an interrupting line for an attached block, such as a paragraph.
+(The AsciiDoc Language working group has decided that the latter exception will be removed, so it's best not to depend on it.)
+
+If you're attaching more than one block to a list item, you're strongly encouraged to wrap the content inside an open block.
+That way, you only need a single list continuation line to attach the open block to the list item.
+Within the open block, you write like you normally would, no longer having to worry about adding list continuations between the blocks to keep them attached to the list item.
+
+Here's an example of wrapping compound list content in an open block:
+
+[source]
+....
+include::example$complex.adoc[tag=complex-o]
+....
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* The header in AsciiDoc must start with a document title.\n+\n--\nHere's an example of a document title:\n\n----\n= Document Title\n----\n\nNOTE: The header is optional.\n--",
+    );
+
+    let list = only_list_from(&doc);
+    let item = list.nested_blocks().next().unwrap();
+
+    let mut attached = item.nested_blocks();
+    let open = attached.next().unwrap();
+    assert_eq!(open.raw_context().deref(), "open");
+    assert!(attached.next().is_none());
+
+    let mut blocks = open.nested_blocks();
+    assert_eq!(blocks.next().unwrap().raw_context().deref(), "paragraph");
+    assert_eq!(blocks.next().unwrap().raw_context().deref(), "listing");
+    assert_eq!(blocks.next().unwrap().raw_context().deref(), "admonition");
+    assert!(blocks.next().is_none());
+}
+
+non_normative!(
+    r#"
+Here's how that content is rendered:
+
+.A list with compound content wrapped in an open block
+====
+include::example$complex.adoc[tag=complex-o]
+====
+
+The open block wrapper is also useful if you're including content from a shared file into a list item.
+For example:
+
+----
+* list item
++
+--
+\include::shared-content.adoc[]
+--
+----
+
+By wrapping the include directive in an open block, the content can be used unmodified.
+
+The only limitation of this technique is that the content itself may not contain an open block since open blocks cannot (yet) be nested.
+
+"#
+);
+
+#[test]
+fn drop_principal_text() {
+    verifies!(
+        r#"
+[#drop-principal-text]
+== Drop the principal text
+
+If the principal text of a list item is empty, the node for the principal text is dropped.
+This is how you can get the first block (such as a listing block) to line up with the list marker.
+You can make the principal text empty by using the `+{empty}+` attribute reference.
+
+Here's an example of a list that has items with _only_ compound content.
+
+[source]
+....
+include::example$complex.adoc[tag=complex-only]
+....
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        ". {empty}\n+\n----\nprint(\"one\")\n----\n. {empty}\n+\n----\nprint(\"one\")\n----",
+    );
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["", ""]);
+
+    for item in list.nested_blocks() {
+        let mut attached = item.nested_blocks();
+        assert_eq!(attached.next().unwrap().raw_context().deref(), "listing");
+        assert!(attached.next().is_none());
+    }
+}
+
+non_normative!(
+    r#"
+Here's how the source is rendered:
+
+.A list with compound content
+====
+include::example$complex.adoc[tag=complex-only]
+====
+
+[#attach-to-ancestor-list]
+== Attach blocks to an ancestor list
+
+Instead of attaching a block to the current list item, you may need to end that list and attach a block to its ancestor instead.
+There are two ways to express this composition in the AsciiDoc syntax.
+You can either enclose the child list in an open block, or you can use insert empty lines above the list continuation to first escape from the nesting.
+Let's look at enclosing the child list in an open block first, since that is the preferred method.
+
+"#
+);
+
+#[test]
+fn enclose_in_open_block() {
+    verifies!(
+        r#"
+=== Enclose in open block
+
+If you plan to attach blocks to a list item as a sibling of a nested list, the most robust way of creating that structure is to enclose the nested list in an open block.
+That way, it's clear where the nested list ends and the current list item continues.
+
+Here's an example of a list item with a nested list followed by an attached paragraph.
+The open block makes the boundaries of the nested list clear.
+
+[source]
+....
+include::example$complex.adoc[tag=complex-enclosed]
+....
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* grandparent list item\n+\n--\n** parent list item\n*** child list item\n--\n+\nparagraph attached to grandparent list item",
+    );
+
+    let list = only_list_from(&doc);
+    let grandparent = list.nested_blocks().next().unwrap();
+
+    let mut attached = grandparent.nested_blocks();
+
+    let open = attached.next().unwrap();
+    assert_eq!(open.raw_context().deref(), "open");
+
+    let nested = open.nested_blocks().next().unwrap();
+    assert_eq!(nested.raw_context().deref(), "ulist");
+    assert_eq!(item_texts(nested.nested_blocks()), vec!["parent list item"]);
+
+    assert_eq!(
+        attached.next().unwrap().rendered_content().unwrap(),
+        "paragraph attached to grandparent list item"
+    );
+
+    assert!(attached.next().is_none());
+}
+
+non_normative!(
+    r#"
+Here's how the source is rendered:
+
+.A nested list enclosed in an open block
+====
+include::example$complex.adoc[tag=complex-enclosed]
+====
+
+The main limitation of this approach is that it can only be used once in the hierarchy (i.e., it can only enclose a single nested list).
+That's because the open block itself cannot be nested.
+If you require more control, then you must use the ancestor list continuation.
+
+"#
+);
+
+#[test]
+fn ancestor_list_continuation() {
+    verifies!(
+        r#"
+=== Ancestor list continuation
+
+Normally, a list continuation will attach a block to the current list item.
+For each empty line you add before the list continuation, the association will move up one level in the nesting.
+In other words, an empty line signals to the list continuation to back out of the current list by one level.
+As a result, the block will be attached to the current item in an ancestor list.
+This syntax is referred to as an [.term]*ancestor list continuation*.
+
+WARNING: The ancestor list continuation is a fragile syntax.
+For one, it may not be apparent to new authors that the empty lines before the list continuation are significant.
+That's because the AsciiDoc syntax generally ignores repeating empty lines.
+There are also scenarios where even these empty lines are collapsed, thus preventing the ancestor list continuation from working as expected.
+Use this feature of the syntax with caution.
+If possible, enclose the nested list in an open block, as described in the previous section.
+
+Here's an example of a paragraph that's attached to the parent list item after the nested list ends.
+The empty line above the list continuation indicates that the block should be attached to current list item in the parent list.
+
+[source]
+....
+include::example$complex.adoc[tag=complex-parent]
+....
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* parent list item\n** child list item\n\n+\nparagraph attached to parent list item",
+    );
+
+    let list = only_list_from(&doc);
+    let parent = list.nested_blocks().next().unwrap();
+
+    let mut attached = parent.nested_blocks();
+
+    let nested = attached.next().unwrap();
+    assert_eq!(nested.raw_context().deref(), "ulist");
+
+    let child = nested.nested_blocks().next().unwrap();
+    assert!(child.nested_blocks().next().is_none());
+
+    assert_eq!(
+        attached.next().unwrap().rendered_content().unwrap(),
+        "paragraph attached to parent list item"
+    );
+
+    assert!(attached.next().is_none());
+}
+
+non_normative!(
+    r#"
+Here's how the source is rendered:
+
+.A block attached to the parent list item
+====
+include::example$complex.adoc[tag=complex-parent]
+====
+
+"#
+);
+
+#[test]
+fn attach_to_grandparent() {
+    verifies!(
+        r#"
+Each empty line that precedes the list continuation signals a move up one level of nesting.
+Here's an example that shows how to attach a paragraph to a grandparent list item using two leading empty lines:
+
+[source]
+....
+include::example$complex.adoc[tag=complex-grandparent]
+....
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "* grandparent list item\n** parent list item\n*** child list item\n\n\n+\nparagraph attached to grandparent list item",
+    );
+
+    let list = only_list_from(&doc);
+    let grandparent = list.nested_blocks().next().unwrap();
+
+    let mut attached = grandparent.nested_blocks();
+
+    let nested = attached.next().unwrap();
+    let parent = nested.nested_blocks().next().unwrap();
+    assert_eq!(parent.nested_blocks().len(), 1);
+
+    assert_eq!(
+        attached.next().unwrap().rendered_content().unwrap(),
+        "paragraph attached to grandparent list item"
+    );
+
+    assert!(attached.next().is_none());
+}
+
+non_normative!(
+    r#"
+Here's how the source is rendered:
+
+.A block attached to the grandparent list item
+====
+include::example$complex.adoc[tag=complex-grandparent]
+====
+
+== Summary
+
+On this page, you learned that the principal text of a list item can span multiple contiguous lines, and that those lines can be indented for readability without affecting the output.
+You learned that you can attach any type of block content to a list item using the list continuation.
+You also learned that using this feature in combination with the open block makes it easier to create list items with compound content, to attach blocks to a parent list, or to drop the principal text.
+Finally, you learned that you can use the ancestor list continuation to attach blocks to the current item in an ancestor list, and the risks with doing so.
+"#
+);
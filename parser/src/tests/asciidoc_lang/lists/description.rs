@@ -0,0 +1,298 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, ListType},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/lists/pages/description.adoc");
+
+non_normative!(
+    r#"
+= Description Lists
+:keywords: dlist, definition list, labeled list
+
+A description list (often abbreviated as dlist in AsciiDoc) is an association list that consists of one or more terms (or sets of terms) that each have a description.
+This list type is useful when you have a list of terms that you want to emphasize and describe with text or other supporting content.
+
+NOTE: You may know this list variation by the antiquated term _definition list_.
+The preferred term is now _description list_, which matches the terminology used by the https://html.spec.whatwg.org/multipage/grouping-content.html#the-dl-element[HTML specification^].
+
+== Anatomy
+
+"#
+);
+
+#[test]
+fn anatomy() {
+    verifies!(
+        r#"
+A description list item marks the beginning of a description list.
+Each item in a description list consists of:
+
+* one or more terms, each followed by a term delimiter (typically a double colon, `::`, unless the list is nested)
+* one space or newline character
+* the description in the form of text, attached blocks, or both
+
+If a term has an anchor, the anchor must be defined at the start of the same line as the term.
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("CPU:: The brain of the computer.\nHard drive::\nPermanent storage.");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Description);
+    assert_eq!(list.raw_context().deref(), "dlist");
+
+    let mut items = list.nested_blocks();
+
+    let crate::blocks::Block::DescriptionListItem(cpu) = items.next().unwrap() else {
+        panic!("Expected a description list item");
+    };
+
+    assert_eq!(cpu.terms().next().unwrap().rendered(), "CPU");
+    assert_eq!(cpu.marker().data(), "::");
+    assert_eq!(
+        cpu.principal_text().unwrap().rendered(),
+        "The brain of the computer."
+    );
+
+    let crate::blocks::Block::DescriptionListItem(drive) = items.next().unwrap() else {
+        panic!("Expected a description list item");
+    };
+
+    assert_eq!(drive.terms().next().unwrap().rendered(), "Hard drive");
+    assert_eq!(
+        drive.principal_text().unwrap().rendered(),
+        "Permanent storage."
+    );
+
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn term_delimiters() {
+    verifies!(
+        r#"
+The first term defines which term delimiter is used for the description list.
+The terms for the remaining entries at that level must use the same delimiter.
+
+The valid set of term delimiters is fixed.
+When the term delimiter is changed, that term begins a new, nested description list (similar to how ordered and unordered lists work).
+The available term delimiters you can use for this purpose are as follows:
+
+* `::`
+* `:::`
+* `::::`
+* `;;`
+
+There's no direct correlation between the number of characters in the delimiter and the nesting level.
+Each time you change delimiters (selected from this set), it introduces a new level of nesting.
+This is how list depth is implied in a language with a left-aligned syntax.
+It's customary to use the delimiters in the order shown above to provide a hint that the list is nested at a certain level.
+
+"#
+    );
+
+    for delimiter in ["::", ":::", "::::", ";;"] {
+        let doc = Parser::default().parse(&format!("Term{delimiter} Description"));
+
+        let list = only_list_from(&doc);
+        assert_eq!(list.type_(), ListType::Description);
+
+        let crate::blocks::Block::DescriptionListItem(item) = list.nested_blocks().next().unwrap()
+        else {
+            panic!("Expected a description list item");
+        };
+
+        assert_eq!(item.marker().data(), delimiter);
+        assert_eq!(item.terms().next().unwrap().rendered(), "Term");
+    }
+
+    let doc = Parser::default().parse("Term A:: a\nTerm B::: b\nTerm C;; c\nTerm D:: d");
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["a", "d"]);
+
+    let a = list.nested_blocks().next().unwrap();
+    let level2 = a.nested_blocks().next().unwrap();
+    assert_eq!(level2.raw_context().deref(), "dlist");
+    assert_eq!(item_texts(level2.nested_blocks()), vec!["b"]);
+
+    let b = level2.nested_blocks().next().unwrap();
+    let level3 = b.nested_blocks().next().unwrap();
+    assert_eq!(item_texts(level3.nested_blocks()), vec!["c"]);
+}
+
+#[test]
+fn basic_description_list() {
+    verifies!(
+        r#"
+== Basic description list
+
+Here's an example of a description list that identifies parts of a computer:
+
+----
+include::example$description.adoc[tag=base]
+----
+
+By default, the content of each item is displayed below the label when rendered.
+Here's a preview of how this list is rendered:
+
+.A basic description list
+====
+include::example$description.adoc[tag=base]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse("CPU:: The brain of the computer.\nHard drive:: Permanent storage for operating system and/or user files.\nRAM:: Temporarily stores information the CPU uses during operation.\nKeyboard:: Used to enter text or control items on the screen.\nMouse:: Used to point to and select items on your computer screen.\nMonitor:: Displays information in visual form using text and graphics.");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 6);
+
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec![
+            "The brain of the computer.",
+            "Permanent storage for operating system and/or user files.",
+            "Temporarily stores information the CPU uses during operation.",
+            "Used to enter text or control items on the screen.",
+            "Used to point to and select items on your computer screen.",
+            "Displays information in visual form using text and graphics.",
+        ]
+    );
+}
+
+#[test]
+fn mixing_lists() {
+    verifies!(
+        r#"
+== Mixing lists
+
+The content of a description list can be any AsciiDoc element.
+For instance, we could split up a grocery list by aisle, using description list terms for the aisle names.
+
+----
+include::example$description.adoc[tag=base-mix]
+----
+
+====
+include::example$description.adoc[tag=base-mix]
+====
+
+"#
+    );
+
+    let doc =
+        Parser::default().parse("Dairy::\n* Milk\n* Eggs\nBakery::\n* Bread\nProduce::\n* Bananas");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 3);
+
+    let dairy = list.nested_blocks().next().unwrap();
+    assert!(dairy.rendered_content().is_none());
+
+    let groceries = dairy.nested_blocks().next().unwrap();
+    assert_eq!(groceries.raw_context().deref(), "ulist");
+    assert_eq!(item_texts(groceries.nested_blocks()), vec!["Milk", "Eggs"]);
+}
+
+#[test]
+fn spread_out_items() {
+    verifies!(
+        r#"
+Description lists are quite lenient about whitespace, so you can spread the items out and even indent the content if that makes it more readable for you:
+
+----
+include::example$description.adoc[tag=base-mix-alt]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        "Dairy::\n\n  * Milk\n  * Eggs\n\nBakery::\n\n  * Bread\n\nProduce::\n\n  * Bananas",
+    );
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 3);
+
+    let bakery = list.nested_blocks().nth(1).unwrap();
+    let groceries = bakery.nested_blocks().next().unwrap();
+    assert_eq!(item_texts(groceries.nested_blocks()), vec!["Bread"]);
+}
+
+#[test]
+fn nested_description_list() {
+    verifies!(
+        r#"
+== Nested description list
+
+[#three-hybrid]
+Finally, you can mix and match the three list types within a single hybrid list.
+The AsciiDoc syntax tries hard to infer the relationships between the items that are most intuitive to us humans.
+
+Here's a list that mixes description, ordered, and unordered lists.
+Notice how the term delimiter is changed from `::` to `:::` to create a nested description list.
+
+----
+include::example$description.adoc[tag=3-mix]
+----
+
+Here's how the list is rendered:
+
+.A hybrid list
+====
+include::example$description.adoc[tag=3-mix]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse("Operating Systems::\n  Linux:::\n    . Fedora\n      * Desktop\n    . Ubuntu\n      * Desktop\n      * Server\n  BSD:::\n    . FreeBSD\n    . NetBSD\n\nCloud Providers::\n  PaaS:::\n    . OpenShift\n    . CloudBees\n  IaaS:::\n    . Amazon EC2\n    . Rackspace");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 2);
+
+    let os = list.nested_blocks().next().unwrap();
+    let os_list = os.nested_blocks().next().unwrap();
+    assert_eq!(os_list.raw_context().deref(), "dlist");
+    assert_eq!(os_list.nested_blocks().len(), 2);
+
+    let linux = os_list.nested_blocks().next().unwrap();
+    let distros = linux.nested_blocks().next().unwrap();
+    assert_eq!(distros.raw_context().deref(), "olist");
+    assert_eq!(
+        item_texts(distros.nested_blocks()),
+        vec!["Fedora", "Ubuntu"]
+    );
+
+    let ubuntu = distros.nested_blocks().nth(1).unwrap();
+    let editions = ubuntu.nested_blocks().next().unwrap();
+    assert_eq!(editions.raw_context().deref(), "ulist");
+    assert_eq!(
+        item_texts(editions.nested_blocks()),
+        vec!["Desktop", "Server"]
+    );
+
+    let cloud = list.nested_blocks().nth(1).unwrap();
+    let cloud_list = cloud.nested_blocks().next().unwrap();
+    assert_eq!(cloud_list.nested_blocks().len(), 2);
+}
+
+non_normative!(
+    r#"
+You can include more xref:continuation.adoc[compound content in a list item] as well.
+
+"#
+);
@@ -0,0 +1,98 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{DescriptionListStyle, IsBlock},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/lists/pages/horizontal.adoc");
+
+#[test]
+fn horizontal_style() {
+    verifies!(
+        r#"
+= Horizontal Description List
+
+If you want the first term and description of an item to start on the same line (i.e., horizontal arrangement), add the horizontal style to the description list.
+
+[.wrap]
+----
+include::example$description.adoc[tag=base-horz]
+----
+
+====
+include::example$description.adoc[tag=base-horz]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse("[horizontal]\nCPU:: The brain of the computer.\nHard drive:: Permanent storage for operating system and/or user files.\nRAM:: Temporarily stores information the CPU uses during operation.");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.declared_style().unwrap(), "horizontal");
+    assert_eq!(
+        list.description_list_style().unwrap(),
+        DescriptionListStyle::Horizontal
+    );
+    assert_eq!(list.nested_blocks().len(), 3);
+}
+
+#[test]
+fn column_widths() {
+    verifies!(
+        r#"
+By default, the term and description columns will be sized automatically.
+If the content is not arranged in the way that you want, you need to adjust the width distribution.
+
+You can control the width of the term and description columns using the (improperly named) `labelwidth` and `itemwidth` attributes on the list, respectively.
+Both attributes are optional.
+The value of each attribute is a number from 0 to 100 (a unitless percentage).
+If both attributes are specified, their values should add up to 100.
+
+[.wrap]
+----
+include::example$description.adoc[tag=widths]
+----
+
+====
+include::example$description.adoc[tag=widths]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse("[horizontal,labelwidth=25,itemwidth=75]\nA short term:: The term for this item likely fits inside the column's width.\nA long term that wraps across multiple lines:: The term for this item wraps since the width of the term column is restricted using the `labelwidth` attribute.");
+
+    let list = only_list_from(&doc);
+    assert_eq!(
+        list.description_list_style().unwrap(),
+        DescriptionListStyle::Horizontal
+    );
+
+    let attrlist = list.attrlist().unwrap();
+    assert_eq!(
+        attrlist.named_attribute("labelwidth").unwrap().value(),
+        "25"
+    );
+    assert_eq!(attrlist.named_attribute("itemwidth").unwrap().value(), "75");
+
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec![
+            "The term for this item likely fits inside the column&#8217;s width.",
+            "The term for this item wraps since the width of the term column is restricted using the <code>labelwidth</code> attribute."
+        ]
+    );
+}
+
+non_normative!(
+    r#"
+When converting to HTML, you can assign a role to the description list instead to control the column widths using CSS.
+
+"#
+);
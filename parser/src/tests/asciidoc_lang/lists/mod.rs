@@ -0,0 +1,26 @@
+mod checklist;
+mod continuation;
+mod description;
+mod horizontal;
+mod ordered;
+mod qanda;
+mod unordered;
+
+use crate::blocks::IsBlock;
+
+fn only_list_from<'src>(doc: &'src crate::Document<'src>) -> &'src crate::blocks::ListBlock<'src> {
+    let mut blocks = doc.nested_blocks();
+
+    let block = blocks.next().unwrap();
+    let crate::blocks::Block::List(list) = block else {
+        panic!("Wrong block type: {block:#?}");
+    };
+
+    assert!(blocks.next().is_none());
+
+    list
+}
+
+fn item_texts<'src>(items: std::slice::Iter<'src, crate::blocks::Block<'src>>) -> Vec<&'src str> {
+    items.map(|item| item.rendered_content().unwrap()).collect()
+}
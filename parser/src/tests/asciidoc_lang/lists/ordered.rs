@@ -0,0 +1,651 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, ListType, NumberingStyle},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+    warnings::WarningType,
+};
+
+track_file!("docs/modules/lists/pages/ordered.adoc");
+
+non_normative!(
+    r#"
+= Ordered Lists
+:keywords: numbered list
+
+== Basic ordered list
+
+"#
+);
+
+#[test]
+fn explicit_numbering() {
+    verifies!(
+        r#"
+Sometimes, we need to number the items in a list.
+Instinct might tell you to prefix each item with a number, like in this next list:
+
+----
+include::example$ordered.adoc[tag=base-num]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("1. Protons\n2. Electrons\n3. Neutrons");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Ordered);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::Arabic);
+    assert!(list.start().is_none());
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Protons", "Electrons", "Neutrons"]
+    );
+}
+
+#[test]
+fn implicit_numbering() {
+    verifies!(
+        r#"
+The above works, but since the numbering is obvious, the AsciiDoc processor will insert the numbers for you if you omit them:
+
+----
+include::example$ordered.adoc[tag=base]
+----
+
+====
+include::example$ordered.adoc[tag=base]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse(". Protons\n. Electrons\n. Neutrons");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Ordered);
+    assert_eq!(list.raw_context().deref(), "olist");
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::Arabic);
+    assert!(list.start().is_none());
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Protons", "Electrons", "Neutrons"]
+    );
+}
+
+#[test]
+fn explicit_numbering_out_of_sequence() {
+    verifies!(
+        r#"
+If you number the ordered list explicitly, you have to manually keep the list numerals sequential.
+Otherwise, you will get a warning.
+This differs from other lightweight markup languages.
+But there's a reason for it.
+
+"#
+    );
+
+    let doc = Parser::default().parse("1. Protons\n3. Electrons\n4. Neutrons");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 3);
+
+    let mut warnings = doc.warnings();
+
+    assert_eq!(
+        warnings.next().unwrap(),
+        Warning {
+            source: Span {
+                data: "3.",
+                line: 2,
+                col: 1,
+                offset: 11,
+            },
+            warning: WarningType::ListItemOutOfSequence("2".to_owned(), "3".to_owned()),
+        }
+    );
+
+    assert_eq!(
+        warnings.next().unwrap(),
+        Warning {
+            source: Span {
+                data: "4.",
+                line: 3,
+                col: 1,
+                offset: 24,
+            },
+            warning: WarningType::ListItemOutOfSequence("3".to_owned(), "4".to_owned()),
+        }
+    );
+
+    assert!(warnings.next().is_none());
+}
+
+#[test]
+fn explicit_numbering_offset() {
+    verifies!(
+        r#"
+Using explicit numbering is one way to adjust the numbering offset of a list (only supported in Asciidoctor 2.1.0 or better).
+For instance, you can type:
+
+----
+include::example$ordered.adoc[tag=base-num-start]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("4. Step four\n5. Step five\n6. Step six");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.start().unwrap(), 4);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Step four", "Step five", "Step six"]
+    );
+
+    assert!(doc.warnings().next().is_none());
+}
+
+#[test]
+fn start_attribute() {
+    verifies!(
+        r#"
+However, there's a simpler way to accomplish the same result without the manual effort.
+You can use the `start` attribute on the list to define the number at which you want the numerals to start.
+
+----
+include::example$ordered.adoc[tag=base-start]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("[start=4]\n. Step four\n. Step five\n. Step six");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.start().unwrap(), 4);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Step four", "Step five", "Step six"]
+    );
+}
+
+#[test]
+fn start_is_integer() {
+    verifies!(
+        r#"
+The start value is always a positive integer value, even when using a different numeration style such as loweralpha.
+
+"#
+    );
+
+    let doc = Parser::default().parse("[loweralpha,start=3]\n. Step c\n. Step d");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::LowerAlpha);
+    assert_eq!(list.start().unwrap(), 3);
+}
+
+non_normative!(
+    r#"
+.When not to use the start attribute
+****
+When an ordered list item contains block content--such as an image, source block, or table--you may observe that the number of the next item in the list resets to 1.
+In fact, what's happened is that a new list has been started where the number resets due to a missing list continuation.
+
+In these cases, you should not resort to using the `start` attribute to fix the numbering.
+Not only does that require manual adjustment as items are added to the list, it doesn't address the underlying semantics problem, which is what is causing it to be broken.
+Instead, use a list continuation between each block element you want to attach to the list item to ensure the list item is continuous.
+The list continuation glues the blocks together within a given item and keeps them at the same level of indentation.
+
+* For details on how to use a list continuation, refer to the xref:continuation.adoc[] page.
+* For an example of the list continuation used in an ordered list, see the launch steps in https://github.com/aws-quickstart/quickstart-microsoft-sql-fci-fsx/blob/main/docs/partner_editable/deploy_steps.adoc[this .adoc file in GitHub^].
+* To see how those launch steps look in the final output, see the https://aws-quickstart.github.io/quickstart-microsoft-sql-fci-fsx/#_launch_the_quick_start[Launch the Quick Start^] section of the generated deployment guide.
+The list continuations prevent step 2 from resetting to 1.
+They also prevent step 5, which is pulled in from a separate AsciiDoc file, from resetting to 1.
+****
+
+"#
+);
+
+#[test]
+fn reversed_option() {
+    verifies!(
+        r#"
+To present list items in reverse order, add the `reversed` option:
+
+----
+include::example$ordered.adoc[tag=reversed]
+----
+
+====
+include::example$ordered.adoc[tag=reversed]
+====
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("[%reversed]\n.Parts of an atom\n. Protons\n. Electrons\n. Neutrons");
+
+    let list = only_list_from(&doc);
+    assert!(list.is_reversed());
+    assert_eq!(list.title().unwrap(), "Parts of an atom");
+    assert_eq!(list.nested_blocks().len(), 3);
+}
+
+#[test]
+fn list_title() {
+    verifies!(
+        r#"
+You can give a list a title by prefixing the line with a dot immediately followed by the text (without leaving any space after the dot).
+
+Here's an example of a list with a title:
+
+----
+include::example$ordered.adoc[tag=base-t]
+----
+
+====
+include::example$ordered.adoc[tag=base-t]
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse(".Parts of an atom\n. Protons\n. Electrons\n. Neutrons");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.title().unwrap(), "Parts of an atom");
+    assert!(!list.is_reversed());
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Protons", "Electrons", "Neutrons"]
+    );
+}
+
+non_normative!(
+    r#"
+== Nested ordered list
+
+"#
+);
+
+#[test]
+fn nested_ordered_list() {
+    verifies!(
+        r#"
+// tag::basic[]
+You create a nested item by using one or more dots in front of each the item.
+
+----
+include::example$ordered.adoc[tag=nest]
+----
+
+AsciiDoc selects a different number scheme for each level of nesting.
+Here's how the previous list renders:
+
+.A nested ordered list
+====
+include::example$ordered.adoc[tag=nest]
+====
+// end::basic[]
+
+"#
+    );
+
+    let doc = Parser::default().parse(". Step 1\n. Step 2\n.. Step 2a\n.. Step 2b\n. Step 3");
+
+    let list = only_list_from(&doc);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Step 1", "Step 2", "Step 3"]
+    );
+
+    let step2 = list.nested_blocks().nth(1).unwrap();
+    let crate::blocks::Block::List(nested) = step2.nested_blocks().next().unwrap() else {
+        panic!("Expected a nested list");
+    };
+
+    assert_eq!(
+        nested.numbering_style().unwrap(),
+        NumberingStyle::LowerAlpha
+    );
+    assert_eq!(
+        item_texts(nested.nested_blocks()),
+        vec!["Step 2a", "Step 2b"]
+    );
+}
+
+#[test]
+fn dots_do_not_represent_nesting_level() {
+    verifies!(
+        r#"
+[TIP]
+====
+Like with the asterisks in an unordered list, the number of dots in an ordered list doesn't represent the nesting level.
+However, it's much more intuitive to follow the convention that the number of dots equals the level of nesting.
+
+*# of dots = level of nesting*
+
+Again, we are shooting for plain text markup that is readable _as is_.
+====
+
+"#
+    );
+
+    let doc = Parser::default().parse(". Step 1\n... Step 1a\n. Step 2");
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["Step 1", "Step 2"]);
+
+    let step1 = list.nested_blocks().next().unwrap();
+    let crate::blocks::Block::List(nested) = step1.nested_blocks().next().unwrap() else {
+        panic!("Expected a nested list");
+    };
+
+    // The style is chosen by the number of dots, not the nesting level.
+    assert_eq!(
+        nested.numbering_style().unwrap(),
+        NumberingStyle::LowerRoman
+    );
+    assert_eq!(item_texts(nested.nested_blocks()), vec!["Step 1a"]);
+}
+
+#[test]
+fn unordered_in_ordered() {
+    verifies!(
+        r#"
+You can mix and match the three list types, ordered, xref:unordered.adoc[unordered], and xref:description.adoc[description], within a single hybrid list.
+The AsciiDoc syntax tries hard to infer the relationships between the items that are most intuitive to us humans.
+
+Here's an example of nesting an unordered list inside of an ordered list:
+
+----
+include::example$ordered.adoc[tag=mix]
+----
+
+====
+include::example$ordered.adoc[tag=mix]
+====
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse(". Linux\n* Fedora\n* Ubuntu\n* Slackware\n. BSD\n* FreeBSD\n* NetBSD");
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["Linux", "BSD"]);
+
+    let linux = list.nested_blocks().next().unwrap();
+    let distros = linux.nested_blocks().next().unwrap();
+    assert_eq!(distros.raw_context().deref(), "ulist");
+    assert_eq!(
+        item_texts(distros.nested_blocks()),
+        vec!["Fedora", "Ubuntu", "Slackware"]
+    );
+
+    let bsd = list.nested_blocks().nth(1).unwrap();
+    let distros = bsd.nested_blocks().next().unwrap();
+    assert_eq!(
+        item_texts(distros.nested_blocks()),
+        vec!["FreeBSD", "NetBSD"]
+    );
+}
+
+#[test]
+fn spread_out_nested_lists() {
+    verifies!(
+        r#"
+You can spread the items out and indent the nested lists if that makes it more readable for you:
+
+----
+include::example$ordered.adoc[tag=mix-alt]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(
+        ". Linux\n\n  * Fedora\n  * Ubuntu\n  * Slackware\n\n. BSD\n\n  * FreeBSD\n  * NetBSD",
+    );
+
+    let list = only_list_from(&doc);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["Linux", "BSD"]);
+
+    let linux = list.nested_blocks().next().unwrap();
+    let distros = linux.nested_blocks().next().unwrap();
+    assert_eq!(
+        item_texts(distros.nested_blocks()),
+        vec!["Fedora", "Ubuntu", "Slackware"]
+    );
+
+    let bsd = list.nested_blocks().nth(1).unwrap();
+    let distros = bsd.nested_blocks().next().unwrap();
+    assert_eq!(
+        item_texts(distros.nested_blocks()),
+        vec!["FreeBSD", "NetBSD"]
+    );
+}
+
+non_normative!(
+    r#"
+The description list page demonstrates how to xref:description.adoc#three-hybrid[combine all three list types].
+
+"#
+);
+
+#[test]
+fn numbering_styles() {
+    verifies!(
+        r#"
+[#styles]
+== Number styles
+
+For ordered lists, AsciiDoc supports the numeration styles such as lowergreek and decimal-leading-zero.
+The full list of numeration styles that can be applied to an ordered list are as follows:
+
+[%autowidth]
+|===
+|Block style |CSS list-style-type
+
+|arabic
+|decimal
+
+|decimal ^[1]^
+|decimal-leading-zero
+
+|loweralpha
+|lower-alpha
+
+|upperalpha
+|upper-alpha
+
+|lowerroman
+|lower-roman
+
+|upperroman
+|upper-roman
+
+|lowergreek ^[1]^
+|lower-greek
+|===
+^[1]^ These styles are only supported by the HTML converters.
+
+Here are a few examples showing various numeration styles as defined by the block style shown in the header row:
+
+[%autowidth]
+|===
+|[arabic] ^[2]^ |[decimal] |[loweralpha] |[lowergreek]
+
+a|
+. one
+. two
+. three
+
+a|
+[decimal]
+. one
+. two
+. three
+
+a|
+[loweralpha]
+. one
+. two
+. three
+
+a|
+[lowergreek]
+. one
+. two
+. three
+|===
+^[2]^ Default numeration if block style is not specified
+
+"#
+    );
+
+    for (style, expected) in [
+        ("arabic", NumberingStyle::Arabic),
+        ("decimal", NumberingStyle::Decimal),
+        ("loweralpha", NumberingStyle::LowerAlpha),
+        ("upperalpha", NumberingStyle::UpperAlpha),
+        ("lowerroman", NumberingStyle::LowerRoman),
+        ("upperroman", NumberingStyle::UpperRoman),
+        ("lowergreek", NumberingStyle::LowerGreek),
+    ] {
+        let doc = Parser::default().parse(&format!("[{style}]\n. one\n. two\n. three"));
+
+        let list = only_list_from(&doc);
+        assert_eq!(list.numbering_style().unwrap(), expected);
+        assert_eq!(list.nested_blocks().len(), 3);
+    }
+}
+
+#[test]
+fn custom_role() {
+    verifies!(
+        r#"
+TIP: Custom numeration styles can be implemented using a custom role.
+Define a new class selector (e.g., `.custom`) in your stylesheet that sets the `list-style-type` property to the value of your choice.
+Then, assign the name of that class as a role on any list to which you want that numeration applied.
+
+When the role shorthand (`.custom`) is used on an ordered list, the numeration style is no longer omitted.
+
+"#
+    );
+
+    let doc = Parser::default().parse("[.custom]\n. one\n. two\n. three");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.roles(), vec!["custom"]);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::Arabic);
+}
+
+#[test]
+fn override_numbering_style() {
+    verifies!(
+        r#"
+You can override the number scheme for any level by setting its style (the first positional entry in a block attribute list).
+You can also set the starting number using the `start` attribute:
+
+----
+include::example$ordered.adoc[tag=num]
+----
+
+====
+include::example$ordered.adoc[tag=num]
+====
+
+IMPORTANT: The `start` attribute must be a number, even when using a different numeration style.
+For instance, to start an alphabetic list at letter "c", set the numeration style to loweralpha and the start attribute to 3.
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("[lowerroman,start=5]\n. Five\n. Six\n[loweralpha]\n.. a\n.. b\n.. c\n. Seven");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::LowerRoman);
+    assert_eq!(list.start().unwrap(), 5);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Five", "Six", "Seven"]
+    );
+
+    let six = list.nested_blocks().nth(1).unwrap();
+    let crate::blocks::Block::List(nested) = six.nested_blocks().next().unwrap() else {
+        panic!("Expected a nested list");
+    };
+
+    assert_eq!(
+        nested.numbering_style().unwrap(),
+        NumberingStyle::LowerAlpha
+    );
+    assert!(nested.start().is_none());
+    assert_eq!(item_texts(nested.nested_blocks()), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn paragraph_starting_with_list_marker() {
+    verifies!(
+        r#"
+== Escaping the list marker
+
+If you have paragraph text that begins with a list marker, but you don't intend it to be a list item, you need to escape that marker by using the attribute reference to disrupt the pattern.
+
+Consider the case when the line starts with a P.O. box reference:
+
+----
+P. O. Box
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("P. O. Box");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.numbering_style().unwrap(), NumberingStyle::UpperAlpha);
+    assert_eq!(item_texts(list.nested_blocks()), vec!["O. Box"]);
+}
+
+#[test]
+fn escape_list_marker() {
+    verifies!(
+        r#"
+In order to prevent this paragraph from being parsed as an ordered list, you need to replace the first space with `\{empty}`.
+
+----
+P.{empty}O. Box
+----
+
+Now the paragraph will remain as a paragraph.
+
+"#
+    );
+
+    let doc = Parser::default().parse("P.{empty}O. Box");
+
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "paragraph");
+    assert_eq!(block.rendered_content().unwrap(), "P.O. Box");
+}
+
+non_normative!(
+    r#"
+In the future, it will be possible to escape an ordered list marker using a backslash, but that is not currently possible.
+
+"#
+);
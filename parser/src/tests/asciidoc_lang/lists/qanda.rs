@@ -0,0 +1,68 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{DescriptionListStyle, IsBlock},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/lists/pages/qanda.adoc");
+
+non_normative!(
+    r#"
+= Question and Answer Lists
+:keywords: qanda, Q & A, Q and A
+
+A question and answer (qanda) list is a special form of a description list that renders as an ordered list.
+The entries are numbered using Arabic numerals starting at 1.
+
+"#
+);
+
+#[test]
+fn qanda_list() {
+    verifies!(
+        r#"
+== Question and answer list syntax
+
+Each entry in the description list represents one question and answer combination.
+The term or terms are used as the question and the description is used as the answer.
+If an entry has multiple questions, each question is rendered on a new line.
+
+----
+include::example$description.adoc[tag=qa]
+----
+
+.Rendered qanda list
+====
+include::example$description.adoc[tag=qa]
+====
+"#
+    );
+
+    let doc = Parser::default().parse("[qanda]\nWhat is the answer?::\nThis is the answer.\n\nAre cameras allowed?::\nAre backpacks allowed?::\nNo.");
+
+    let list = only_list_from(&doc);
+    assert_eq!(
+        list.description_list_style().unwrap(),
+        DescriptionListStyle::Qanda
+    );
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["This is the answer.", "No."]
+    );
+
+    let crate::blocks::Block::DescriptionListItem(item) = list.nested_blocks().nth(1).unwrap()
+    else {
+        panic!("Expected a description list item");
+    };
+
+    let questions: Vec<&str> = item.terms().map(|term| term.rendered()).collect();
+    assert_eq!(
+        questions,
+        vec!["Are cameras allowed?", "Are backpacks allowed?"]
+    );
+}
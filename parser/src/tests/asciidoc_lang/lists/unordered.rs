@@ -0,0 +1,522 @@
+use std::ops::Deref;
+
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, ListType},
+    tests::{
+        asciidoc_lang::lists::{item_texts, only_list_from},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/lists/pages/unordered.adoc");
+
+non_normative!(
+    r#"
+= Unordered Lists
+:keywords: bulleted list
+
+You can make unordered lists in AsciiDoc by starting lines with a designated marker.
+An unordered list is a list with items prefixed with symbol, such as a disc (aka bullet).
+
+AsciiDoc builds on the well-established convention of using either an asterisk or hyphen to identify a list item.
+Adjacent list items are joined into a single list.
+Unordered lists can be nested by varying the marker character or length (asterisk only).
+List items may contain attached blocks.
+They can also be interleaved with other types of lists.
+
+== Basic unordered list
+
+"#
+);
+
+#[test]
+fn basic_unordered_list() {
+    verifies!(
+        r#"
+In the example below, each list item is marked using an asterisk (`+*+`), the AsciiDoc syntax specifying an unordered list item.
+
+----
+include::example$unordered.adoc[tag=base]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("* Edgar Allan Poe\n* Sheri S. Tepper\n* Bill Bryson");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Unordered);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Edgar Allan Poe", "Sheri S. Tepper", "Bill Bryson"]
+    );
+}
+
+#[test]
+fn marker_offset_and_empty_lines() {
+    verifies!(
+        r#"
+A list item's first line of text must be offset from the marker (`+*+`) by at least one space.
+Empty lines are required before and after a list.
+Additionally, empty lines are permitted, but not required, between list items.
+
+"#
+    );
+
+    let doc = Parser::default().parse("*Edgar Allan Poe");
+    let block = doc.nested_blocks().next().unwrap();
+    assert_eq!(block.raw_context().deref(), "paragraph");
+
+    let doc = Parser::default().parse("* Edgar Allan Poe\n\n* Sheri S. Tepper\n\n\n* Bill Bryson");
+
+    let list = only_list_from(&doc);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Edgar Allan Poe", "Sheri S. Tepper", "Bill Bryson"]
+    );
+}
+
+non_normative!(
+    r#"
+.Rendered unordered list
+====
+include::example$unordered.adoc[tag=base]
+====
+
+"#
+);
+
+#[test]
+fn list_title() {
+    verifies!(
+        r#"
+You can add a title to a list by prefixing the title with a period (`.`).
+
+----
+include::example$unordered.adoc[tag=base-t]
+----
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse(".Kizmet's Favorite Authors\n* Edgar Allan Poe\n* Sheri S. Tepper\n* Bill Bryson");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.title().unwrap(), "Kizmet&#8217;s Favorite Authors");
+    assert_eq!(list.nested_blocks().len(), 3);
+}
+
+non_normative!(
+    r#"
+.Rendered unordered list with a title
+====
+include::example$unordered.adoc[tag=base-t]
+====
+
+"#
+);
+
+#[test]
+fn hyphen_marker() {
+    verifies!(
+        r#"
+Was your instinct to use a hyphen (`-`) instead of an asterisk to mark list items?
+Guess what?
+That works too!
+
+----
+include::example$unordered.adoc[tag=base-alt]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("- Edgar Allan Poe\n- Sheri S. Tepper\n- Bill Bryson");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.type_(), ListType::Unordered);
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["Edgar Allan Poe", "Sheri S. Tepper", "Bill Bryson"]
+    );
+}
+
+non_normative!(
+    r#"
+You should reserve the hyphen for lists that only have a single level because the hyphen marker (`-`) doesn't work for nested lists.
+Now that we've mentioned nested lists, let's go to the next section and learn how to create lists with multiple levels.
+
+"#
+);
+
+#[test]
+fn forcing_lists_apart() {
+    verifies!(
+        r#"
+[#separating-lists]
+.Forcing lists apart
+****
+If you have adjacent lists, they have the tendency to want to fuse together.
+To force lists apart, insert a line comment (`//`) surrounded by empty lines between the two lists.
+Here's an example, where the `-` text in the line comment indicates the line serves as an "`end of list`" marker:
+
+----
+include::example$unordered.adoc[tag=divide]
+----
+
+This technique works for all list types.
+See xref:separating.adoc[] for more details.
+****
+
+"#
+    );
+
+    let doc = Parser::default().parse("* Apples\n* Oranges\n\n//-\n\n* Walnuts\n* Almonds");
+
+    let lists: Vec<_> = doc
+        .nested_blocks()
+        .filter(|block| block.raw_context().deref() == "ulist")
+        .collect();
+
+    assert_eq!(lists.len(), 2);
+    assert_eq!(
+        item_texts(lists[0].nested_blocks()),
+        vec!["Apples", "Oranges"]
+    );
+    assert_eq!(
+        item_texts(lists[1].nested_blocks()),
+        vec!["Walnuts", "Almonds"]
+    );
+}
+
+#[test]
+fn nested_unordered_list() {
+    verifies!(
+        r#"
+== Nested unordered list
+
+To nest an item, just add another asterisk (`+*+`) to the marker.
+Continue doing this for each subsequent level.
+
+----
+include::example$unordered.adoc[tag=nest]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse(".Possible DefOps manual locations\n* West wood maze\n** Maze heart\n*** Reflection pool\n** Secret exit\n* Untracked file in git repository");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.title().unwrap(), "Possible DefOps manual locations");
+    assert_eq!(
+        item_texts(list.nested_blocks()),
+        vec!["West wood maze", "Untracked file in git repository"]
+    );
+
+    let maze = list.nested_blocks().next().unwrap();
+    let level2 = maze.nested_blocks().next().unwrap();
+    assert_eq!(level2.raw_context().deref(), "ulist");
+    assert_eq!(
+        item_texts(level2.nested_blocks()),
+        vec!["Maze heart", "Secret exit"]
+    );
+
+    let heart = level2.nested_blocks().next().unwrap();
+    let level3 = heart.nested_blocks().next().unwrap();
+    assert_eq!(item_texts(level3.nested_blocks()), vec!["Reflection pool"]);
+}
+
+non_normative!(
+    r#"
+.Rendered nested, unordered list
+====
+include::example$unordered.adoc[tag=nest]
+====
+
+"#
+);
+
+#[test]
+fn indented_markers() {
+    verifies!(
+        r#"
+If you prefer, you can indent the marker an arbitrary number of spaces from the left margin.
+The indentation is not significant and may aid in visualizing the nesting level.
+
+"#
+    );
+
+    let doc = Parser::default().parse("* List item\n ** Another nested list item\n* List item");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 2);
+
+    let first = list.nested_blocks().next().unwrap();
+    let nested = first.nested_blocks().next().unwrap();
+    assert_eq!(
+        item_texts(nested.nested_blocks()),
+        vec!["Another nested list item"]
+    );
+}
+
+#[test]
+fn nest_to_any_depth() {
+    verifies!(
+        r#"
+You can nest unordered lists to any depth.
+Keep in mind, however, that some interfaces will begin flattening lists after a certain depth.
+For instance, GitHub starts flattening list after 10 levels of nesting.
+
+----
+include::example$unordered.adoc[tag=max]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("* Level 1 list item\n** Level 2 list item\n*** Level 3 list item\n**** Level 4 list item\n***** Level 5 list item\n****** etc.\n* Level 1 list item");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 2);
+
+    let mut depth = 0;
+    let mut item = list.nested_blocks().next().unwrap();
+
+    while let Some(nested) = item.nested_blocks().next() {
+        depth += 1;
+        item = nested.nested_blocks().next().unwrap();
+    }
+
+    assert_eq!(depth, 5);
+    assert_eq!(item.rendered_content().unwrap(), "etc.");
+}
+
+non_normative!(
+    r#"
+[#ex-deep]
+.Unordered lists can be nested to any depth
+====
+include::example$unordered.adoc[tag=max]
+====
+
+=== Determining list depth
+
+"#
+);
+
+#[test]
+fn determining_list_depth() {
+    verifies!(
+        r#"
+While it would seem as though the number of asterisks represents the nesting level, that's not how depth is determined.
+A new level is created for each unique marker encountered.
+For example, you can create a second level using the hyphen marker instead of two asterisks.
+
+.Using hyphen to mark the second level is not recommended
+----
+include::example$unordered.adoc[tag=nest-alt]
+----
+
+"#
+    );
+
+    let doc =
+        Parser::default().parse("* Level 1 list item\n- Level 2 list item\n* Level 1 list item");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.nested_blocks().len(), 2);
+
+    let first = list.nested_blocks().next().unwrap();
+    let nested = first.nested_blocks().next().unwrap();
+    assert_eq!(
+        item_texts(nested.nested_blocks()),
+        vec!["Level 2 list item"]
+    );
+}
+
+non_normative!(
+    r#"
+However, it's much more intuitive to follow the convention that the marker length (i.e., number of asterisks) equals the level of nesting.
+The hyphen should only be used as the marker for the first level.
+
+*marker length = level of nesting*
+
+After all, we're shooting for plain text markup that is readable _as is_.
+
+[#markers]
+== Markers
+
+When rendered, an unordered list item is designated by a leading marker (bullet) (not to be confused with the marker used to define the list).
+This marker can be controlled using the list style.
+If no marker is specified, a default marker will be selected by the renderer.
+
+=== Default markers
+
+By default, AsciiDoc assumes that the first three levels of an unordered list will be styled using the markers disc, circle, and squared when rendered.
+Consider the following list:
+
+----
+include::example$unordered.adoc[tag=markers]
+----
+
+.Default alternating markers for nested lists
+====
+include::example$unordered.adoc[tag=markers]
+====
+
+Observe that the marker for the first level is a disc (filled circle), the second level is a circle (outline), and the third level is a square (filled).
+The AsciiDoc processor does not specify these markers explicitly in the model or converted output.
+Rather, these defaults are added by the renderer (e.g., CSS), adhering to a convention established by HTML.
+
+Beyond the third level of nesting, the marker choice is not specified.
+Typically, the renderer will continue to use the square marker, as shown in <<ex-deep,an earlier example>>.
+
+=== Custom markers
+
+AsciiDoc offers numerous marker styles for lists.
+The list marker can be specified using the list's block style.
+
+The unordered list marker can be set using any of the following block styles:
+
+* square
+* circle
+* disc
+* none or no-bullet (indented, but no bullet)
+* unstyled (no indentation or bullet) (not supported in DocBook output)
+
+NOTE: These styles are supported by the default Asciidoctor stylesheet.
+
+When present, the style name is assigned to the unordered list element as follows:
+
+For HTML:: the style name is assigned to the `class` attribute on the `<ul>` element.
+
+For DocBook:: the style name is assigned to the `mark` attribute on the `<itemizedlist>` element.
+
+Here's an unordered list that has square markers:
+
+"#
+);
+
+#[test]
+fn square_markers() {
+    verifies!(
+        r#"
+----
+include::example$unordered.adoc[tag=square]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("[square]\n* one\n* two\n* three");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.declared_style().unwrap(), "square");
+    assert_eq!(list.resolved_context().deref(), "ulist");
+    assert_eq!(list.nested_blocks().len(), 3);
+}
+
+non_normative!(
+    r#"
+.A list with square markers
+====
+include::example$unordered.adoc[tag=square]
+====
+
+"#
+);
+
+#[test]
+fn list_style_is_inherited() {
+    verifies!(
+        r#"
+Once the list style is set, that style is used for all nested lists until it is set again.
+The assumption is that it's no longer possible to infer the alternation, so it stops.
+The inherited style is not specified in the model, but rather applied by the renderer (e.g., CSS).
+For example, if we set the list style to circle on the top-level list, it will be used for all levels.
+
+----
+include::example$unordered.adoc[tag=marker-lock]
+----
+
+"#
+    );
+
+    let doc = Parser::default().parse("[circle]\n* circles\n** all\n*** the\n**** way\n***** down");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.declared_style().unwrap(), "circle");
+
+    // The inherited style is not specified in the model.
+    let nested = list
+        .nested_blocks()
+        .next()
+        .unwrap()
+        .nested_blocks()
+        .next()
+        .unwrap();
+    assert!(nested.declared_style().is_none());
+}
+
+non_normative!(
+    r#"
+.The list style is inherited once set
+====
+include::example$unordered.adoc[tag=marker-lock]
+====
+
+"#
+);
+
+#[test]
+fn list_style_can_be_reset() {
+    verifies!(
+        r#"
+The inherited style can be set or reset at any level.
+
+----
+include::example$unordered.adoc[tag=marker-override]
+----
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("[square]\n* squares\n** up top\n[circle]\n*** circles\n**** down below");
+
+    let list = only_list_from(&doc);
+    assert_eq!(list.declared_style().unwrap(), "square");
+
+    let level2 = list
+        .nested_blocks()
+        .next()
+        .unwrap()
+        .nested_blocks()
+        .next()
+        .unwrap();
+    assert!(level2.declared_style().is_none());
+
+    let level3 = level2
+        .nested_blocks()
+        .next()
+        .unwrap()
+        .nested_blocks()
+        .next()
+        .unwrap();
+    assert_eq!(level3.declared_style().unwrap(), "circle");
+    assert_eq!(item_texts(level3.nested_blocks()), vec!["circles"]);
+}
+
+non_normative!(
+    r#"
+.The list style can be reset
+====
+include::example$unordered.adoc[tag=marker-override]
+====
+"#
+);
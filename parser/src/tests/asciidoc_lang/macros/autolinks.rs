@@ -85,6 +85,8 @@ AsciiDoc recognizes the following common URL schemes without the help of any mar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -148,6 +150,8 @@ AsciiDoc recognizes the following common URL schemes without the help of any mar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -211,6 +215,8 @@ AsciiDoc recognizes the following common URL schemes without the help of any mar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -274,6 +280,8 @@ AsciiDoc recognizes the following common URL schemes without the help of any mar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -338,6 +346,8 @@ AsciiDoc recognizes the following common URL schemes without the help of any mar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -412,6 +422,8 @@ If you want to use xref:url-macro.adoc#link-text[custom link text], you must use
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -484,6 +496,8 @@ To accommodate this convention, the AsciiDoc processor will still recognize the
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -562,6 +576,8 @@ This allows the theming system (e.g., CSS) to recognize autolinks (and other bar
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -642,6 +658,8 @@ For email address which do not conform to these restriction, you can use the xre
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -749,6 +767,8 @@ The URL and email address will both be shown in plain text.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -835,6 +855,8 @@ The `subs` attribute is only recognized on a leaf block, such as a paragraph.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -122,6 +122,8 @@ image::logo.png[Logo]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -208,6 +210,8 @@ image::logo.png[Logo,link=https://example.org]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -277,6 +281,8 @@ image:apply.jpg[Apply,link=https://apply.example.org] today!
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -397,6 +403,8 @@ image::logo.png[Logo,link=https://example.org,window=_blank,opts=nofollow]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -115,6 +115,8 @@ image::flower.jpg[Flower,640,480]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -207,6 +209,8 @@ image::flower.jpg[alt=Flower,width=640,height=480]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -166,6 +166,8 @@ include::example$image.adoc[tag=base]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -255,6 +257,8 @@ include::example$image.adoc[tag=alt]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -343,6 +347,8 @@ You can also give the image an ID, title, set its dimensions and make it a link.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -478,6 +484,8 @@ include::example$image.adoc[tag=attr]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "img-sunset",
@@ -88,6 +88,8 @@ https://chat.asciidoc.org[Discuss AsciiDoc]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -170,6 +172,8 @@ https://chat.asciidoc.org[Discuss AsciiDoc,role=resource,window=_blank]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -241,6 +245,8 @@ https://example.org["Google, DuckDuckGo, Ecosia",role=teal]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -311,6 +317,8 @@ https://example.org["1=2 posits the problem of inequality"]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -382,6 +390,8 @@ https://example.org["href=\"#top\" attribute"] creates link to top of page
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -462,6 +472,8 @@ https://chat.asciidoc.org[role=button,window=_blank,opts=nofollow]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -557,6 +569,8 @@ In the HTML output, the value of the `window` attribute is assigned to the `targ
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -633,6 +647,8 @@ If the target is `_blank`, the processor will automatically add the <<noopener a
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -717,6 +733,8 @@ https://asciidoctor.org[Asciidoctor,window=_blank]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -787,6 +805,8 @@ https://asciidoctor.org[Asciidoctor,window=read-later,opts=noopener]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -858,6 +878,8 @@ https://asciidoctor.org[Asciidoctor,window=_blank,opts=nofollow]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -929,6 +951,8 @@ https://asciidoctor.org[Asciidoctor,window=read-later,opts="noopener,nofollow"]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -998,6 +1022,8 @@ link:post.html[My Post,opts=nofollow]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1073,6 +1099,8 @@ include::example$url.adoc[tag=linkattrs-s]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1151,6 +1179,8 @@ https://example.org["Google, DuckDuckGo, Ecosia^",role=btn]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -1219,6 +1249,8 @@ https://example.org[Google, DuckDuckGo, Ecosia^]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -90,6 +90,8 @@ mailto:join@discuss.example.org[Subscribe]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -158,6 +160,8 @@ mailto:join@discuss.example.org[Subscribe,role=email]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -228,6 +232,8 @@ mailto:join@discuss.example.org["Click, subscribe, and participate!"]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -322,6 +328,8 @@ When the reader clicks the link, a conforming email client will fill in the subj
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -394,6 +402,8 @@ When the reader clicks the link, a conforming email client will fill in the body
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -463,6 +473,8 @@ mailto:join@discuss.example.org[,Subscribe me,I want to participate.]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -531,6 +543,8 @@ mailto:join@discuss.example.org[,Subscribe me]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -599,6 +613,8 @@ mailto:join@discuss.example.org[Subscribe,"I want to participate, so please subs
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
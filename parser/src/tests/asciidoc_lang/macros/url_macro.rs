@@ -105,6 +105,8 @@ With the exception of the xref:mailto-macro.adoc[mailto macro], all the URL macr
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -176,6 +178,8 @@ Type "https://asciidoctor.org[]" into the location bar of your browser.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -249,6 +253,8 @@ A more common reason is to specify custom link text.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -338,6 +344,8 @@ include::example$url.adoc[tag=irc]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -408,6 +416,8 @@ include::example$url.adoc[tag=text]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -490,6 +500,8 @@ include::example$url.adoc[tag=css]
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -27,10 +27,12 @@ mod attributes;
 mod blocks;
 mod directives;
 mod document;
+mod lists;
 mod macros;
 mod pass;
 mod root;
 mod sections;
 mod subs;
+mod tables;
 mod text;
 mod verbatim;
@@ -94,6 +94,8 @@ This is a basic AsciiDoc document.
                 })],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -196,6 +198,8 @@ This document contains two paragraphs.
                 ],
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
@@ -334,6 +338,8 @@ It also has a header that specifies the document title and some attibutes.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -603,6 +609,8 @@ For example, a single empty line separates the header from the body.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog::default(),
             }
         );
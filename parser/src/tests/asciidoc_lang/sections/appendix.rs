@@ -294,6 +294,8 @@ include::example$appendix.adoc[tag=appx-article-out]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
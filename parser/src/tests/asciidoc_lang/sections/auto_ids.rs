@@ -391,6 +391,8 @@ The AsciiDoc processor builds an ID from the title using the following order of
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([
                         (
@@ -655,6 +657,8 @@ By doing so, you can disable ID generation for only certain sections and discret
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([
                         (
@@ -101,6 +101,8 @@ include::example$section.adoc[tag=with-anchor-shorthand]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "tigers-subspecies",
@@ -205,6 +207,8 @@ include::example$section.adoc[tag=with-anchor-and-reftext-shorthand]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "tigers-subspecies",
@@ -304,6 +308,8 @@ IMPORTANT: The value of the reftext attribute must be quoted if it contains spac
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "tigers-subspecies",
@@ -397,16 +403,18 @@ include::example$section.adoc[tag=with-anchor-and-reftext]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "tigers-subspecies",
                     RefEntry {
                         id: "tigers-subspecies",
-                        reftext: Some("Subspecies of Tiger",),
+                        reftext: Some("Subspecies",),
                         ref_type: RefType::Section,
                     }
                 ),]),
-                reftext_to_id: HashMap::from([("Subspecies of Tiger", "tigers-subspecies"),]),
+                reftext_to_id: HashMap::from([("Subspecies", "tigers-subspecies"),]),
             },
         }
     );
@@ -500,6 +508,8 @@ Here's how to register auxiliary IDs using inline anchors when using an autogene
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_section_title",
@@ -587,6 +597,8 @@ fn assign_auxiliary_ids_2() {
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "_section_title",
@@ -699,6 +711,8 @@ These additional anchor points don't interfere with the declaration of the prima
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([(
                     "primary-id",
@@ -122,6 +122,8 @@ The value of `idprefix` must begin with a valid ID start character and can have
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "id_section_title",
@@ -220,6 +222,8 @@ If you want to remove the prefix, set the attribute to an empty value.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "section_title",
@@ -349,6 +353,8 @@ Unless empty, the value of the `idseparator` must be _exactly one valid ID chara
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "_section.title",
@@ -447,6 +453,8 @@ If you don't want to use a separator, set the attribute to an empty value.
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([(
                         "_sectiontitle",
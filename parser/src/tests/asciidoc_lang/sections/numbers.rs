@@ -195,6 +195,8 @@ When `sectnums` is set, level 1 (`==`) through level 3 (`====`) section titles a
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
@@ -444,7 +446,7 @@ The section number does not increment in regions of the document where section n
                         anchor_reftext: None,
                         attrlist: None,
                         section_type: SectionType::Normal,
-                        section_id: Some("_unnumbered_section-2",),
+                        section_id: Some("_unnumbered_section-3",),
                         section_number: None,
                     },),],
                     source: Span {
@@ -459,7 +461,7 @@ The section number does not increment in regions of the document where section n
                     anchor_reftext: None,
                     attrlist: None,
                     section_type: SectionType::Normal,
-                    section_id: Some("_unnumbered_section-3",),
+                    section_id: Some("_unnumbered_section-2",),
                     section_number: None,
                 },),
                 Block::Section(SectionBlock {
@@ -501,6 +503,8 @@ The section number does not increment in regions of the document where section n
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
@@ -780,6 +784,8 @@ include::example$section.adoc[tag=sectnuml]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
@@ -251,6 +251,8 @@ include::example$section.adoc[tag=b-base]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
@@ -491,6 +493,8 @@ include::example$section.adoc[tag=content]
                 },
             ],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
@@ -725,6 +729,8 @@ That means the outline of a Markdown document will be converted just fine as an
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([
                     (
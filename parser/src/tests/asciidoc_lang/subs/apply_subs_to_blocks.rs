@@ -138,32 +138,30 @@ For source blocks, this substitution step enables syntax highlighting as well.
         );
     }
 
-    #[ignore]
     #[test]
     fn callouts() {
-        // TO DO (https://github.com/scouten/asciidoc-parser/issues/311):
-        // Implement this test when implementing callouts.
-        to_do_verifies!(
+        verifies!(
             r#"
 `callouts`:: Substitution step that processes callouts in literal, listing, and source blocks.
 
 "#
         );
 
-        let doc = Parser::default().parse(
-            "[subs=verbatim]\nThis & _that_ and icon:github[] +\nanother line with a{sp}space there ...",
-        );
+        let doc = Parser::default()
+            .parse("[subs=\"specialchars,callouts\"]\n----\nThis & _that_ <1>\n----\n<1> Callout");
 
         let block1 = doc.nested_blocks().next().unwrap();
 
-        let Block::Simple(block1) = block1 else {
+        let Block::RawDelimited(block1) = block1 else {
             panic!("Unexpected block type: {block1:?}");
         };
 
         assert_eq!(
             block1.content().rendered(),
-            "This &amp; that and icon:github[] +\nanother line with a{sp}space there ..."
+            "This &amp; _that_ <b class=\"conum\">(1)</b>"
         );
+
+        assert_eq!(doc.warnings().count(), 0);
     }
 
     #[test]
@@ -483,6 +481,7 @@ mod add_remove_steps {
     use crate::{
         Parser,
         blocks::{Block, IsBlock},
+        content::{SubstitutionGroup, SubstitutionStep},
         tests::prelude::*,
     };
 
@@ -611,13 +610,9 @@ include::example$subs.adoc[tag=subs-add]
         );
     }
 
-    #[ignore]
     #[test]
     fn example_subtract() {
-        // TO DO (https://github.com/scouten/asciidoc-parser/issues/311):
-        // Implement this test when implementing callouts.
-
-        to_do_verifies!(
+        verifies!(
             r#"
 Similarly, you can remove the `callouts` substitution from a block's default substitution group by placing the minus (`-`) modifier in front of the `callouts` value.
 
@@ -629,15 +624,25 @@ include::example$subs.adoc[tag=subs-sub]
 
 "#
         );
+
+        let doc = Parser::default()
+            .parse("[source,xml,subs=\"-callouts\"]\n----\n<version>1.42</version> <!--1-->\n----");
+
+        let block1 = doc.nested_blocks().next().unwrap();
+
+        let Block::RawDelimited(block1) = block1 else {
+            panic!("Unexpected block type: {block1:?}");
+        };
+
+        assert_eq!(
+            block1.content().rendered(),
+            "&lt;version&gt;1.42&lt;/version&gt; &lt;!--1--&gt;"
+        );
     }
 
-    #[ignore]
     #[test]
     fn plus_before_or_after() {
-        // TO DO (https://github.com/scouten/asciidoc-parser/issues/311):
-        // Implement this test when implementing callouts.
-
-        to_do_verifies!(
+        verifies!(
             r#"
 You can also specify whether the substitution type is added to the end of the substitution group.
 If a `{plus}` comes before the name of the substitution, then it's added to the end of the existing list, whereas if a `{plus}` comes after the name, it's added to the beginning of the list.
@@ -651,6 +656,30 @@ In the above example, the `attributes` substitution step is added to the beginni
 
 "#
         );
+
+        let doc = Parser::default().parse(
+            ":version: 1.42\n\n[source,xml,subs=\"attributes+,+replacements,-callouts\"]\n----\n<version>{version}</version> (C) <1>\n----",
+        );
+
+        let block1 = doc.nested_blocks().next().unwrap();
+
+        let Block::RawDelimited(block1) = block1 else {
+            panic!("Unexpected block type: {block1:?}");
+        };
+
+        assert_eq!(
+            block1.content().rendered(),
+            "&lt;version&gt;1.42&lt;/version&gt; &#169; &lt;1&gt;"
+        );
+
+        assert_eq!(
+            block1.substitution_group(),
+            SubstitutionGroup::Custom(vec![
+                SubstitutionStep::AttributeReferences,
+                SubstitutionStep::SpecialCharacters,
+                SubstitutionStep::CharacterReplacements,
+            ])
+        );
     }
 
     non_normative!(
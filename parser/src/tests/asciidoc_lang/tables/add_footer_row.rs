@@ -0,0 +1,129 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::IsBlock,
+    tests::{
+        asciidoc_lang::tables::{only_table_from, row_texts},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/tables/pages/add-footer-row.adoc");
+
+non_normative!(
+    r#"
+= Create a Footer Row
+
+The last row of a table is promoted to a footer row if the `footer` value is assigned to the table's `options` attribute.
+
+"#
+);
+
+#[test]
+fn footer_shorthand() {
+    verifies!(
+        r#"
+== Assign footer to the last row
+
+The footer row semantics and styles are applied to the last row in a table by assigning `footer` to the `options` attribute.
+The `options` attribute is set in the table's attribute list using the shorthand (`%value`) or formal syntax (`options="value"`).
+
+The `options` attribute is represented by the percent sign (`%`) when it's set using the shorthand syntax.
+In <<ex-short>>, `footer` is assigned using the shorthand syntax for `options`.
+
+.Table with footer assigned using the shorthand syntax
+[source#ex-short]
+----
+[%header%footer,cols="2,2,1"] <.>
+|===
+|Column 1, header row
+|Column 2, header row
+|Column 3, header row
+
+|Cell in column 1, row 2
+|Cell in column 2, row 2
+|Cell in column 3, row 2
+
+|Column 1, footer row
+|Column 2, footer row
+|Column 3, footer row
+|===
+----
+<.> Values assigned using the shorthand syntax must be entered before the `cols` attribute (or any other named attributes) in a table's attribute list, otherwise the processor will ignore them.
+
+The table from <<ex-short>> is displayed below.
+
+.Result of <<ex-short>>
+[%header%footer,cols="2,2,1"]
+|===
+|Column 1, header row
+|Column 2, header row
+|Column 3, header row
+
+|Cell in column 1, row 2
+|Cell in column 2, row 2
+|Cell in column 3, row 2
+
+|Column 1, footer row
+|Column 2, footer row
+|Column 3, footer row
+|===
+
+"#
+    );
+
+    let doc = Parser::default().parse("[%header%footer,cols=\"2,2,1\"]\n|===\n|Column 1, header row\n|Column 2, header row\n|Column 3, header row\n\n|Cell in column 1, row 2\n|Cell in column 2, row 2\n|Cell in column 3, row 2\n\n|Column 1, footer row\n|Column 2, footer row\n|Column 3, footer row\n|===");
+
+    let table = only_table_from(&doc);
+    assert!(table.header_row().is_some());
+    assert_eq!(table.body_rows().len(), 1);
+
+    assert_eq!(
+        row_texts(table.footer_row().unwrap()),
+        vec![
+            "Column 1, footer row",
+            "Column 2, footer row",
+            "Column 3, footer row"
+        ]
+    );
+
+    assert!(table.has_option("footer"));
+}
+
+#[test]
+fn footer_formal() {
+    verifies!(
+        r#"
+In <<ex-formal>>, the `options` attribute is set and assigned the `footer` value using the formal syntax.
+The `options` attribute accepts a comma-separated list of values.
+
+.Table with footer assigned to the options attribute
+[source#ex-formal]
+----
+include::example$row.adoc[tag=opt-f]
+----
+
+The last row of the table in <<ex-formal>> is rendered using the corresponding footer styles.
+
+.Result of <<ex-formal>>
+include::example$row.adoc[tag=opt-f]
+"#
+    );
+
+    let doc = Parser::default().parse("[options=\"footer\"]\n|===\n|Column 1, header row |Column 2, header row\n\n|Cell in column 1, row 2\n|Cell in column 2, row 2\n\n|Cell in column 1, row 3\n|Cell in column 2, row 3\n\n|Column 1, footer row\n|Column 2, footer row\n|===");
+
+    let table = only_table_from(&doc);
+
+    assert_eq!(
+        row_texts(table.header_row().unwrap()),
+        vec!["Column 1, header row", "Column 2, header row"]
+    );
+
+    assert_eq!(table.body_rows().len(), 2);
+
+    assert_eq!(
+        row_texts(table.footer_row().unwrap()),
+        vec!["Column 1, footer row", "Column 2, footer row"]
+    );
+}
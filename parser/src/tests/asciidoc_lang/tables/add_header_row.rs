@@ -0,0 +1,240 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, TableCellStyle},
+    tests::{
+        asciidoc_lang::tables::{only_table_from, row_texts},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/tables/pages/add-header-row.adoc");
+
+non_normative!(
+    r#"
+= Create a Header Row
+
+The first row of a table is promoted to a header row if the `header` value is assigned to the table's `options` attribute.
+You can assign `header` to a table's first row explicitly or implicitly.
+
+"#
+);
+
+#[test]
+fn header_row_ignores_styles() {
+    verifies!(
+        r#"
+TIP: The header row ignores any style operators assigned via column and cell specifiers.
+It also ignores alignment operators assigned to the table's column specifiers; however, any alignment operators assigned to a cell specifier in the header row are applied.
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("[%header,cols=\"1m,1\"]\n|===\n|*Column 1* ^e|Column 2\n\n|Cell 1 |Cell 2\n|===");
+
+    let table = only_table_from(&doc);
+
+    let header_cells = table.header_row().unwrap().cells();
+    assert_eq!(header_cells[0].style(), TableCellStyle::Default);
+    assert_eq!(header_cells[1].style(), TableCellStyle::Default);
+    assert_eq!(
+        header_cells[1].halign(),
+        crate::blocks::HorizontalAlignment::Center
+    );
+
+    let body_cells = table.body_rows()[0].cells();
+    assert_eq!(body_cells[0].style(), TableCellStyle::Monospace);
+}
+
+#[test]
+fn explicit_header_shorthand() {
+    verifies!(
+        r#"
+== Explicitly assign header to the first row
+
+The header row semantics and styles are explicitly assigned to the first row in a table by assigning `header` to the `options` attribute.
+The `options` attribute is set in the table's attribute list using the shorthand (`%value`) or formal syntax (`options="value"`).
+
+The `options` attribute is represented by the percent sign (`%`) when it's set using the shorthand syntax.
+In <<ex-short>>, `header` is assigned to using the shorthand syntax for `options`.
+
+.Table with `header` assigned using the shorthand syntax
+[source#ex-short]
+----
+[%header,cols="2,2,1"] <.>
+|===
+|Column 1, header row
+|Column 2, header row
+|Column 3, header row
+
+|Cell in column 1, row 2
+|Cell in column 2, row 2
+|Cell in column 3, row 2
+|===
+----
+<.> Values assigned using the shorthand syntax must be entered before the `cols` attribute (or any other named attributes) in a table's attribute list, otherwise the processor will ignore them.
+
+The table from <<ex-short>> is displayed below.
+
+.Result of <<ex-short>>
+[%header,cols="2,2,1"]
+|===
+|Column 1, header row
+|Column 2, header row
+|Column 3, header row
+
+|Cell in column 1, row 2
+|Cell in column 2, row 2
+|Cell in column 3, row 2
+|===
+
+"#
+    );
+
+    let doc = Parser::default().parse("[%header,cols=\"2,2,1\"]\n|===\n|Column 1, header row\n|Column 2, header row\n|Column 3, header row\n\n|Cell in column 1, row 2\n|Cell in column 2, row 2\n|Cell in column 3, row 2\n|===");
+
+    let table = only_table_from(&doc);
+
+    assert_eq!(
+        row_texts(table.header_row().unwrap()),
+        vec![
+            "Column 1, header row",
+            "Column 2, header row",
+            "Column 3, header row"
+        ]
+    );
+
+    assert_eq!(table.body_rows().len(), 1);
+
+    assert_eq!(
+        row_texts(&table.body_rows()[0]),
+        vec![
+            "Cell in column 1, row 2",
+            "Cell in column 2, row 2",
+            "Cell in column 3, row 2"
+        ]
+    );
+}
+
+#[test]
+fn explicit_header_formal() {
+    verifies!(
+        r#"
+In <<ex-formal>>, the `options` attribute is set and assigned the `header` value using the formal syntax.
+The `options` attribute accepts a comma-separated list of values.
+
+.Table with header assigned to the options attribute
+[source#ex-formal]
+----
+include::example$row.adoc[tag=opt-h]
+----
+
+The first row of the table in <<ex-formal>> is rendered using the corresponding header styles and semantics.
+
+.Result of <<ex-formal>>
+include::example$row.adoc[tag=opt-h]
+
+"#
+    );
+
+    let doc = Parser::default().parse("[cols=\"2*\",options=\"header\"]\n|===\n|Column 1, header row\n|Column 2, header row\n\n|Cell in column 1, row 2\n|Cell in column 2, row 2\n\n|Cell in column 1, row 3\n|Cell in column 2, row 3\n|===");
+
+    let table = only_table_from(&doc);
+    assert_eq!(table.columns().len(), 2);
+
+    assert_eq!(
+        row_texts(table.header_row().unwrap()),
+        vec!["Column 1, header row", "Column 2, header row"]
+    );
+
+    assert_eq!(table.body_rows().len(), 2);
+}
+
+#[test]
+fn implicit_header() {
+    verifies!(
+        r#"
+== Implicitly assign header to the first row
+
+You can implicitly define a header row based on how you layout the table.
+The following conventions determine when the first row automatically becomes the header row:
+
+. The first line of content inside the table delimiters is not empty.
+. The second line of content inside the table delimiters is empty.
+
+.First row is implicitly assigned header
+[source#ex-implicit]
+----
+include::example$row.adoc[tag=impl-h]
+----
+
+As seen in the result below, if all of these rules hold true, then the first row of the table is treated as a header row.
+
+.Result of <<ex-implicit>>
+include::example$row.adoc[tag=impl-h]
+
+"#
+    );
+
+    let doc = Parser::default().parse("|===\n|Column 1, header row |Column 2, header row\n\n|Cell in column 1, row 2\n|Cell in column 2, row 2\n\n|Cell in column 1, row 3\n|Cell in column 2, row 3\n|===");
+
+    let table = only_table_from(&doc);
+
+    assert_eq!(
+        row_texts(table.header_row().unwrap()),
+        vec!["Column 1, header row", "Column 2, header row"]
+    );
+
+    assert_eq!(table.body_rows().len(), 2);
+}
+
+#[test]
+fn noheader() {
+    verifies!(
+        r#"
+=== Deactivate the implicit assignment of header
+
+To suppress the implicit behavior of promoting the first row to a header row, assign the value `noheader` to the `options` attribute using the formal (`options=noheader`) or shorthand (`%noheader`) syntax.
+In <<ex-noheader>>, `noheader` is assigned using the shorthand syntax.
+
+.Deactivate implicit header row with noheader
+[source#ex-noheader]
+----
+[%noheader]
+|===
+|Cell in column 1, row 1 |Cell in column 2, row 1
+
+|Cell in column 1, row 2 |Cell in column 2, row 2
+|===
+----
+
+The table from <<ex-noheader>> is displayed below.
+
+.Result of <<ex-noheader>>
+[%noheader]
+|===
+|Cell in column 1, row 1 |Cell in column 2, row 1
+
+|Cell in column 1, row 2 |Cell in column 2, row 2
+|===
+
+//CAUTION: We're considering using a similar convention for enabling the footer in the future.
+//Thus, if you rely on this convention to enable the header row, it's advised that you not put all the cells in the last row on the same line unless you intend on making it the footer row.
+"#
+    );
+
+    let doc = Parser::default().parse("[%noheader]\n|===\n|Cell in column 1, row 1 |Cell in column 2, row 1\n\n|Cell in column 1, row 2 |Cell in column 2, row 2\n|===");
+
+    let table = only_table_from(&doc);
+    assert!(table.header_row().is_none());
+    assert!(!table.has_option("header"));
+
+    assert_eq!(table.body_rows().len(), 2);
+
+    assert_eq!(
+        row_texts(&table.body_rows()[0]),
+        vec!["Cell in column 1, row 1", "Cell in column 2, row 1"]
+    );
+}
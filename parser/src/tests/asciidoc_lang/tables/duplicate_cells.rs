@@ -0,0 +1,85 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{IsBlock, TableCellStyle},
+    tests::{
+        asciidoc_lang::tables::{only_table_from, row_texts},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/tables/pages/duplicate-cells.adoc");
+
+non_normative!(
+    r#"
+= Duplicate Cells
+// clone, copy, replicate, duplicate
+
+The contents of a cell can be duplicated in consecutive cells.
+
+== Duplication factor and operator
+
+The duplication factor and operator are applied to a xref:add-cells-and-rows.adoc#specifiers[cell's specifier] and allow you to clone a cell's content and properties across consecutive cells.
+A duplication is the first operator in a cell specifier.
+
+====
+<**duplication factor**><**duplication operator**><horizontal alignment operator><vertical alignment operator><style operator>|<cell's content>
+====
+
+The [.term]*duplication factor* is a single integer (`<n>`) that indicates how many times the cell's content should be duplicated.
+
+The [.term]*duplication operator* is an asterisk (`+*+`) placed directly after the duplication factor (`+<n>*+`).
+The duplication operator tells the converter to interpret the duplication factor as part of a duplication instead of a span.
+
+"#
+);
+
+#[test]
+fn duplicate_a_cell() {
+    verifies!(
+        r#"
+== Duplicate a cell and its properties
+
+To duplicate a cell, enter the duplication factor and duplication operator (`+<n>*+`) in the cell specifier.
+Don't insert any spaces between the duplication, any alignment or style operators (if present), and the xref:add-cells-and-rows.adoc#cell-separator[cell's separator] (`|`).
+
+.Duplicate the contents of two cells
+[source#ex-clone]
+----
+include::example$cell.adoc[tag=clone]
+----
+
+The table from <<ex-clone>> is displayed below.
+
+.Result of <<ex-clone>>
+include::example$cell.adoc[tag=clone]
+"#
+    );
+
+    let doc = Parser::default().parse("|===\n|Column 1, header row |Column 2, header row |Column 3, header row\n\n2*|This cell is duplicated in columns 1 and 2 because its specifier contains a duplication of `2*`\n|Cell in column 3, row 2\n\n|Cell in column 1, row 3\n|Cell in column 2, row 3\n3*e|This cell specifier contains the duplication `3*` and style operator `e`.\n\nThe cell's text is italicized and duplicated in column 3, row 3 and columns 1 and 2 on row 4.\n\n|Cell in column 3, row 4\n|===");
+
+    let table = only_table_from(&doc);
+    assert_eq!(table.columns().len(), 3);
+
+    let rows = table.body_rows();
+    assert_eq!(rows.len(), 3);
+
+    let duplicated = "This cell is duplicated in columns 1 and 2 because its specifier contains a duplication of <code>2*</code>";
+
+    assert_eq!(
+        row_texts(&rows[0]),
+        vec![duplicated, duplicated, "Cell in column 3, row 2"]
+    );
+
+    assert_eq!(rows[1].cells()[2].style(), TableCellStyle::Emphasis);
+    assert_eq!(rows[2].cells()[0].style(), TableCellStyle::Emphasis);
+    assert_eq!(rows[2].cells()[1].style(), TableCellStyle::Emphasis);
+
+    assert_eq!(
+        rows[1].cells()[2].rendered_content(),
+        rows[2].cells()[1].rendered_content()
+    );
+
+    assert_eq!(row_texts(&rows[2])[2], "Cell in column 3, row 4");
+}
@@ -0,0 +1,31 @@
+mod add_footer_row;
+mod add_header_row;
+mod build_a_basic_table;
+mod data_format;
+mod duplicate_cells;
+mod nested;
+mod span_cells;
+
+use crate::blocks::IsBlock;
+
+fn only_table_from<'src>(
+    doc: &'src crate::Document<'src>,
+) -> &'src crate::blocks::TableBlock<'src> {
+    let mut blocks = doc.nested_blocks();
+
+    let block = blocks.next().unwrap();
+    let crate::blocks::Block::Table(table) = block else {
+        panic!("Wrong block type: {block:#?}");
+    };
+
+    assert!(blocks.next().is_none());
+
+    table
+}
+
+fn row_texts<'src>(row: &'src crate::blocks::TableRow<'src>) -> Vec<&'src str> {
+    row.cells()
+        .iter()
+        .map(|cell| cell.rendered_content().unwrap())
+        .collect()
+}
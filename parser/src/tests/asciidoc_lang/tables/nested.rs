@@ -0,0 +1,119 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    blocks::{Block, IsBlock, TableCellStyle},
+    tests::{
+        asciidoc_lang::tables::{only_table_from, row_texts},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/tables/pages/nested.adoc");
+
+non_normative!(
+    r#"
+= Nesting Tables
+
+"#
+);
+
+#[test]
+fn nested_table_delimiter_and_separator() {
+    verifies!(
+        r#"
+Table cells marked with the AsciiDoc table style (`a`) support nested tables in addition to normal block content.
+To distinguish the inner table from the enclosing one, you need to use `!===` as the table delimiter and a cell separator that differs from that used for the enclosing table.
+The default cell separator for a nested table is `!`, though you can choose another character by defining the `separator` attribute on the table.
+
+"#
+    );
+
+    let doc = Parser::default()
+        .parse("[cols=\"1,1a\"]\n|===\n|Outer\n|\n[separator=;]\n!===\n;a!b ;c\n!===\n|===");
+
+    let table = only_table_from(&doc);
+
+    let cell = &table.body_rows()[0].cells()[1];
+    assert_eq!(cell.style(), TableCellStyle::AsciiDoc);
+
+    let mut blocks = cell.nested_blocks();
+
+    let Some(Block::Table(nested)) = blocks.next() else {
+        panic!("Expected a nested table");
+    };
+
+    assert!(blocks.next().is_none());
+    assert_eq!(row_texts(&nested.body_rows()[0]), vec!["a!b", "c"]);
+}
+
+non_normative!(
+    r#"
+NOTE: Although nested tables are not technically valid in DocBook 5.0, the DocBook toolchain processes them anyway.
+
+"#
+);
+
+#[test]
+fn nested_table_example() {
+    verifies!(
+        r#"
+The following example contains a nested table in the last cell.
+Notice the nested table has its own format, independent of that of the outer table:
+
+[source]
+----
+include::example$table.adoc[tag=nested]
+----
+
+.Result: A nested table
+include::example$table.adoc[tag=nested]
+
+"#
+    );
+
+    let doc = Parser::default().parse("[cols=\"1,2a\"]\n|===\n| Col 1 | Col 2\n\n| Cell 1.1\n| Cell 1.2\n\n| Cell 2.1\n| Cell 2.2\n\n[cols=\"2,1\"]\n!===\n! Col1 ! Col2\n\n! C11\n! C12\n\n!===\n\n|===");
+
+    let table = only_table_from(&doc);
+
+    assert_eq!(
+        row_texts(table.header_row().unwrap()),
+        vec!["Col 1", "Col 2"]
+    );
+
+    let rows = table.body_rows();
+    assert_eq!(rows.len(), 2);
+
+    assert_eq!(rows[0].cells()[0].rendered_content().unwrap(), "Cell 1.1");
+
+    let cell = &rows[1].cells()[1];
+    assert_eq!(cell.style(), TableCellStyle::AsciiDoc);
+
+    let mut blocks = cell.nested_blocks();
+
+    assert_eq!(
+        blocks.next().unwrap().rendered_content().unwrap(),
+        "Cell 2.2"
+    );
+
+    let Some(Block::Table(nested)) = blocks.next() else {
+        panic!("Expected a nested table");
+    };
+
+    assert!(blocks.next().is_none());
+
+    assert_eq!(
+        row_texts(nested.header_row().unwrap()),
+        vec!["Col1", "Col2"]
+    );
+
+    assert_eq!(nested.body_rows().len(), 1);
+    assert_eq!(row_texts(&nested.body_rows()[0]), vec!["C11", "C12"]);
+}
+
+non_normative!(
+    r#"
+We recommend using nested tables sparingly.
+There's usually a better way to present the information.
+"#
+);
@@ -0,0 +1,165 @@
+use pretty_assertions_sorted::assert_eq;
+
+use crate::{
+    Parser,
+    tests::{
+        asciidoc_lang::tables::{only_table_from, row_texts},
+        prelude::*,
+    },
+};
+
+track_file!("docs/modules/tables/pages/span-cells.adoc");
+
+non_normative!(
+    r#"
+= Span Columns and Rows
+
+A table cell can span more than one column and row.
+
+== Span factor and operator
+
+With a [.term]*span* a table cell can stretch across adjacent columns, rows, or a block of adjacent columns and rows.
+A span consists of a span factor and a span operator.
+
+The [.term]*span factor* indicates the number columns, rows, or columns and rows a cell should span.
+
+[[col-factor]]Column span factor:: A single integer (`<n>`) that represents the number of consecutive columns a cell should span.
+[[row-factor]]Row span factor:: A single integer prefixed with a dot (`.<n>`) that represents the number of consecutive rows a cell should span.
+[[block-factor]]Block span factor:: Two integers (`<n>.<n>`) that represent a block of adjacent columns and rows a cell should span.
+The first integer, `<n>`, is the column span factor.
+The second integer, which is prefixed with a dot, `.<n>`, is the row span factor.
+
+The [.term]*span operator* is a plus sign (`\+`) placed directly after the span factor (`<n>.<n>+`).
+The span operator tells the converter to interpret the span factor as part of a span instead of a duplication.
+
+A span is the first operator in a xref:add-cells-and-rows.adoc#specifiers[cell specifier].
+
+====
+<**span factor**><**span operator**><horizontal alignment operator><vertical alignment operator><style operator>|<cell's content>
+====
+
+"#
+);
+
+#[test]
+fn span_multiple_columns() {
+    verifies!(
+        r#"
+== Span multiple columns
+
+To have a cell span consecutive columns, enter the <<col-factor,column span factor>> and span operator (`<n>+`) in the cell specifier.
+Don't insert any spaces between the span, any alignment or style operators (if present), and the xref:add-cells-and-rows.adoc#cell-separator[cell's separator] (`|`).
+
+.Span three columns with a cell
+[source#ex-span-columns]
+----
+include::example$cell.adoc[tag=span-cols]
+----
+
+The table from <<ex-span-columns>> is displayed below.
+
+.Result of <<ex-span-columns>>
+include::example$cell.adoc[tag=span-cols]
+
+"#
+    );
+
+    let doc = Parser::default().parse("|===\n|Column 1, header row |Column 2, header row |Column 3, header row |Column 4, header row\n\n3+|This cell spans columns 1, 2, and 3 because its specifier contains a span of `3+`\n|Cell in column 4, row 2\n\n|Cell in column 1, row 3\n|Cell in column 2, row 3\n|Cell in column 3, row 3\n|Cell in column 4, row 3\n|===");
+
+    let table = only_table_from(&doc);
+    assert_eq!(table.columns().len(), 4);
+
+    let rows = table.body_rows();
+    assert_eq!(rows.len(), 2);
+
+    let cell = &rows[0].cells()[0];
+    assert_eq!(cell.colspan(), 3);
+    assert_eq!(cell.rowspan(), 1);
+
+    assert_eq!(row_texts(&rows[0])[1], "Cell in column 4, row 2");
+    assert_eq!(rows[1].cells().len(), 4);
+}
+
+#[test]
+fn span_multiple_rows() {
+    verifies!(
+        r#"
+== Span multiple rows
+
+To have a cell span consecutive rows, enter the <<row-factor,row span factor>> and span operator (`.<n>+`) in the cell specifier.
+Remember to prefix the span factor with a dot (`.`).
+Don't insert any spaces between the span, any alignment or style operators (if present), and the xref:add-cells-and-rows.adoc#cell-separator[cell's separator] (`|`).
+
+.Span two rows with a cell
+[source#ex-span-rows]
+----
+include::example$cell.adoc[tag=span-rows]
+----
+
+The table from <<ex-span-rows>> is displayed below.
+
+.Result of <<ex-span-rows>>
+include::example$cell.adoc[tag=span-rows]
+
+"#
+    );
+
+    let doc = Parser::default().parse("|===\n|Column 1, header row |Column 2, header row\n\n.2+|This cell spans rows 2 and 3 because its specifier contains a span of `.2+`\n|Cell in column 2, row 2\n\n|Cell in column 2, row 3\n\n|Cell in column 1, row 4\n|Cell in column 2, row 4\n|===");
+
+    let table = only_table_from(&doc);
+
+    let rows = table.body_rows();
+    assert_eq!(rows.len(), 3);
+
+    let cell = &rows[0].cells()[0];
+    assert_eq!(cell.colspan(), 1);
+    assert_eq!(cell.rowspan(), 2);
+
+    assert_eq!(row_texts(&rows[1]), vec!["Cell in column 2, row 3"]);
+    assert_eq!(rows[2].cells().len(), 2);
+}
+
+#[test]
+fn span_columns_and_rows() {
+    verifies!(
+        r#"
+== Span columns and rows
+
+A single cell can span a block of adjacent columns and rows.
+Enter the column span factor (`<n>`), followed by the row span factor (`.<n>`), and then the span operator (`+`).
+
+.Span two columns and three rows with a single cell
+[source#ex-block]
+----
+include::example$cell.adoc[tag=span-block]
+----
+
+The table from <<ex-block>> is displayed below.
+
+.Result of <<ex-block>>
+include::example$cell.adoc[tag=span-block]
+"#
+    );
+
+    let doc = Parser::default().parse("|===\n|Column 1, header row |Column 2, header row |Column 3, header row |Column 4, header row\n\n|Cell in column 1, row 2\n2.3+|This cell spans columns 2 and 3 and rows 2, 3, and 4 because its specifier contains a span of `2.3+`\n|Cell in column 4, row 2\n\n|Cell in column 1, row 3\n|Cell in column 4, row 3\n\n|Cell in column 1, row 4\n|Cell in column 4, row 4\n|===");
+
+    let table = only_table_from(&doc);
+
+    let rows = table.body_rows();
+    assert_eq!(rows.len(), 3);
+    assert!(table.header_row().is_some());
+
+    let cell = &rows[0].cells()[1];
+    assert_eq!(cell.colspan(), 2);
+    assert_eq!(cell.rowspan(), 3);
+
+    assert_eq!(
+        row_texts(&rows[1]),
+        vec!["Cell in column 1, row 3", "Cell in column 4, row 3"]
+    );
+
+    assert_eq!(
+        row_texts(&rows[2]),
+        vec!["Cell in column 1, row 4", "Cell in column 4, row 4"]
+    );
+}
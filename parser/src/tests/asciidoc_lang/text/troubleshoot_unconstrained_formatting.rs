@@ -101,9 +101,11 @@ To help you determine whether a particular syntax pattern requires an unconstrai
 "#
         );
 
-        let doc = Parser::default().parse(r#"** bold **"#);
+        // At the start of a line, `** ` would be read as a list item marker, so we
+        // verify this formatting pair in the middle of a paragraph.
+        let doc = Parser::default().parse(r#"Some ** bold **"#);
         let sb = super::first_simple_block(&doc);
-        assert_eq!(sb.content().rendered(), "<strong> bold </strong>");
+        assert_eq!(sb.content().rendered(), "Some <strong> bold </strong>");
     }
 
     #[test]
@@ -114,6 +114,8 @@ include::example$listing.adoc[tag=style]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -198,6 +200,7 @@ See xref:source-blocks.adoc[] to learn more about `source` and `source-highlight
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },),],
             source: Span {
                 data: "----\nThis is a _delimited listing block_.\n\nThe content inside is displayed as <pre> text.\n----",
@@ -207,6 +210,8 @@ See xref:source-blocks.adoc[] to learn more about `source` and `source-highlight
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -265,6 +270,7 @@ See xref:subs:apply-subs-to-blocks.adoc[] to learn more about the `subs` attribu
 
     let sg = vec![
         SubstitutionStep::SpecialCharacters,
+        SubstitutionStep::Callouts,
         SubstitutionStep::AttributeReferences,
     ];
 
@@ -305,6 +311,7 @@ See xref:subs:apply-subs-to-blocks.adoc[] to learn more about the `subs` attribu
             },
         }),
         substitution_group: SubstitutionGroup::Custom(sg),
+        stem_notation: None,
     });
 
     let doc = Parser::default().with_intrinsic_attribute(
@@ -101,6 +101,8 @@ include::example$literal.adoc[tag=indent]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -193,6 +195,8 @@ include::example$literal.adoc[tag=style]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -267,6 +271,7 @@ Notice in the output that the bold text formatting is not applied to the text no
                 anchor_reftext: None,
                 attrlist: None,
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },),],
             source: Span {
                 data: "....\nKismet: Where is the *defensive operations manual*?\n\nComputer: Calculating ...\nCan not locate object.\nYou are not authorized to know it exists.\n\nKismet: Did the werewolves tell you to say that?\n\nComputer: Calculating ...\n....",
@@ -276,6 +281,8 @@ Notice in the output that the bold text formatting is not applied to the text no
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -114,6 +114,7 @@ include::example$source.adoc[tag=src-base]
                     },
                 },),
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },),],
             source: Span {
                 data: "[source,ruby]\n----\nrequire 'sinatra'\n\nget '/hi' do\n  \"Hello World!\"\nend\n----",
@@ -123,6 +124,8 @@ include::example$source.adoc[tag=src-base]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -213,6 +216,7 @@ include::example$source.adoc[tag=src-implied]
                     },
                 },),
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },),],
             source: Span {
                 data: "[,ruby]\n----\nrequire 'sinatra'\n\nget '/hi' do\n  \"Hello World!\"\nend\n----",
@@ -222,6 +226,8 @@ include::example$source.adoc[tag=src-implied]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -318,6 +324,7 @@ include::example$source.adoc[tag=src-inc]
                     },
                 },),
                 substitution_group: SubstitutionGroup::Verbatim,
+                stem_notation: None,
             },),],
             source: Span {
                 data: "[,ruby]\n----\nUnresolved directive in (root file) - include::app.rb[]\n----",
@@ -327,6 +334,8 @@ include::example$source.adoc[tag=src-inc]
             },
             warnings: &[],
             source_map: SourceMap(&[]),
+            footnotes: &[],
+            index_terms: &[],
             catalog: Catalog {
                 refs: HashMap::from([]),
                 reftext_to_id: HashMap::from([]),
@@ -89,6 +89,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -179,6 +181,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -377,6 +381,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([
                         (
@@ -457,6 +463,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -517,6 +525,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -590,6 +600,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -664,6 +676,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -762,6 +776,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -861,6 +877,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -946,6 +964,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1020,6 +1040,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1101,6 +1123,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1174,6 +1198,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1247,6 +1273,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1367,6 +1395,8 @@ mod normal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1507,6 +1537,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1616,6 +1648,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1689,6 +1723,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1762,6 +1798,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1835,6 +1873,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1908,6 +1948,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1988,6 +2030,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -2086,6 +2130,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -2185,6 +2231,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -2270,6 +2318,8 @@ mod literal {
                 },
                 warnings: &[],
                 source_map: SourceMap(&[]),
+                footnotes: &[],
+                index_terms: &[],
                 catalog: Catalog {
                     refs: HashMap::from([]),
                     reftext_to_id: HashMap::from([]),
@@ -1552,8 +1552,12 @@ mod macros {
     use pretty_assertions_sorted::assert_eq;
 
     use crate::{
-        Parser, blocks::SimpleBlockStyle, content::SubstitutionStep, parser::ModificationContext,
-        strings::CowStr, tests::prelude::*,
+        Parser,
+        blocks::{IsBlock, SimpleBlockStyle},
+        content::SubstitutionStep,
+        parser::{ModificationContext, SafeMode},
+        strings::CowStr,
+        tests::prelude::*,
     };
 
     #[test]
@@ -2573,15 +2577,52 @@ mod macros {
             para = block_from_string 'image:circle.svg[Tiger,100,opts=inline]', safe: Asciidoctor::SafeMode::SERVER, attributes: { 'data-uri' => '', 'imagesdir' => 'fixtures', 'docdir' => testdir }
             assert_match(/<svg\s[^>]*width="100">/, para.sub_macros(para.source).gsub(/>\s+</, '><'))
         end
-
-        test 'an image macro with an SVG image should not use an object element when safe mode is secure' do
-            para = block_from_string 'image:tiger.svg[Tiger,opts=interactive]', attributes: { 'imagesdir' => 'images' }
-            assert_equal '<span class="image"><img src="images/tiger.svg" alt="Tiger"></span>', para.sub_macros(para.source).gsub(/>\s+</, '><')
-        end
         "###
         );
     }
 
+    #[test]
+    fn an_image_macro_with_an_svg_image_should_not_use_an_object_element_when_safe_mode_is_secure()
+    {
+        let mut p = Parser::default()
+            .with_safe_mode(SafeMode::Secure)
+            .with_intrinsic_attribute("imagesdir", "images", ModificationContext::Anywhere);
+
+        let maw = crate::blocks::Block::parse(
+            crate::Span::new("image:tiger.svg[Tiger,opts=interactive]"),
+            &mut p,
+        );
+
+        let block = maw.item.unwrap().item;
+
+        assert_eq!(
+            block,
+            Block::Simple(SimpleBlock {
+                content: Content {
+                    original: Span {
+                        data: "image:tiger.svg[Tiger,opts=interactive]",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                    rendered: r#"<span class="image"><img src="images/tiger.svg" alt="Tiger"></span>"#,
+                },
+                source: Span {
+                    data: "image:tiger.svg[Tiger,opts=interactive]",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+                style: SimpleBlockStyle::Paragraph,
+                title_source: None,
+                title: None,
+                anchor: None,
+                anchor_reftext: None,
+                attrlist: None,
+            },)
+        );
+    }
+
     #[test]
     fn a_single_line_image_macro_with_text_containing_escaped_square_bracket_should_be_interpreted_as_an_image_with_alt_text()
      {
@@ -3657,646 +3698,2017 @@ mod macros {
         );
     }
 
-    #[ignore]
     #[test]
-    fn todo_migrate_from_ruby_2() {
-        todo!(
-            "{}",
-            r###"
-        test 'a single-line footnote macro should be registered and output as a footnote' do
-            para = block_from_string 'Sentence text footnote:[An example footnote.].'
-            assert_equal %(Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 1, footnote.index
-            assert_nil footnote.id
-            assert_equal 'An example footnote.', footnote.text
-        end
-
-        test 'a multi-line footnote macro should be registered and output as a footnote without newline' do
-            para = block_from_string "Sentence text footnote:[An example footnote\nwith wrapped text.]."
-            assert_equal %(Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 1, footnote.index
-            assert_nil footnote.id
-            assert_equal 'An example footnote with wrapped text.', footnote.text
-        end
+    fn a_single_line_footnote_macro_should_be_registered_and_output_as_a_footnote() {
+        let doc = Parser::default().parse("Sentence text footnote:[An example footnote.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'an escaped closing square bracket in a footnote should be unescaped when converted' do
-            para = block_from_string %(footnote:[a #{BACKSLASH}] b].)
-            assert_equal %(<sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 'a ] b', footnote.text
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-        test 'a footnote macro can be directly adjacent to preceding word' do
-            para = block_from_string 'Sentence textfootnote:[An example footnote.].'
-            assert_equal 'Sentence text<sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.', para.sub_macros(para.source)
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: "An example footnote.",
+            }]
+        );
+    }
 
-        test 'a footnote macro may contain an escaped backslash' do
-            para = block_from_string "footnote:[\\]]\nfootnote:[a \\] b]\nfootnote:[a \\]\\] b]"
-            para.sub_macros para.source
-            assert_equal 3, para.document.catalog[:footnotes].size
-            footnote1 = para.document.catalog[:footnotes][0]
-            assert_equal ']', footnote1.text
-            footnote2 = para.document.catalog[:footnotes][1]
-            assert_equal 'a ] b', footnote2.text
-            footnote3 = para.document.catalog[:footnotes][2]
-            assert_equal 'a ]] b', footnote3.text
-        end
+    #[test]
+    fn a_multi_line_footnote_macro_should_be_registered_and_output_as_a_footnote_without_newline() {
+        let doc = Parser::default()
+            .parse("Sentence text footnote:[An example footnote\nwith wrapped text.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a footnote macro may contain a link macro' do
-            para = block_from_string 'Share your code. footnote:[https://github.com[GitHub]]'
-            assert_equal %(Share your code. <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote1 = para.document.catalog[:footnotes][0]
-            assert_equal '<a href="https://github.com">GitHub</a>', footnote1.text
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-        test 'a footnote macro may contain a plain URL' do
-            para = block_from_string %(the JLine footnote:[https://github.com/jline/jline2]\nlibrary.)
-            result = para.sub_macros para.source
-            assert_equal %(the JLine <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>\nlibrary.), result
-            assert_equal 1, para.document.catalog[:footnotes].size
-            fn1 = para.document.catalog[:footnotes].first
-            assert_equal '<a href="https://github.com/jline/jline2" class="bare">https://github.com/jline/jline2</a>', fn1.text
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: "An example footnote with wrapped text.",
+            }]
+        );
+    }
 
-        test 'a footnote macro followed by a semi-colon may contain a plain URL' do
-            para = block_from_string %(the JLine footnote:[https://github.com/jline/jline2];\nlibrary.)
-            result = para.sub_macros para.source
-            assert_equal %(the JLine <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>;\nlibrary.), result
-            assert_equal 1, para.document.catalog[:footnotes].size
-            fn1 = para.document.catalog[:footnotes].first
-            assert_equal '<a href="https://github.com/jline/jline2" class="bare">https://github.com/jline/jline2</a>', fn1.text
-        end
+    #[test]
+    fn an_escaped_closing_square_bracket_in_a_footnote_should_be_unescaped_when_converted() {
+        let doc = Parser::default().parse("footnote:[a \\] b].");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a footnote macro may contain text formatting' do
-            para = block_from_string 'You can download patches from the product page.footnote:[Only available with an _active_ subscription.]'
-            para.convert
-            footnotes = para.document.catalog[:footnotes]
-            assert_equal 1, footnotes.size
-            assert_equal 'Only available with an <em>active</em> subscription.', footnotes[0].text
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"<sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-        test 'an externalized footnote macro may contain text formatting' do
-            input = <<~'EOS'
-            :fn-disclaimer: pass:q[footnote:[Only available with an _active_ subscription.]]
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: "a ] b",
+            }]
+        );
+    }
 
-            You can download patches from the production page.{fn-disclaimer}
-            EOS
-            doc = document_from_string input
-            doc.convert
-            footnotes = doc.catalog[:footnotes]
-            assert_equal 1, footnotes.size
-            assert_equal 'Only available with an <em>active</em> subscription.', footnotes[0].text
-        end
+    #[test]
+    fn a_footnote_macro_can_be_directly_adjacent_to_preceding_word() {
+        let doc = Parser::default().parse("Sentence textfootnote:[An example footnote.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a footnote macro may contain a shorthand xref' do
-            # specialcharacters escaping is simulated
-            para = block_from_string 'text footnote:[&lt;&lt;_install,install&gt;&gt;]'
-            doc = para.document
-            doc.register :refs, ['_install', (Asciidoctor::Inline.new doc, :anchor, 'Install', type: :ref, target: '_install'), 'Install']
-            catalog = doc.catalog
-            assert_equal %(text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>), para.sub_macros(para.source)
-            assert_equal 1, catalog[:footnotes].size
-            footnote1 = catalog[:footnotes][0]
-            assert_equal '<a href="#_install">install</a>', footnote1.text
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text<sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
+    }
 
-        test 'a footnote macro may contain an xref macro' do
-            para = block_from_string 'text footnote:[xref:_install[install]]'
-            doc = para.document
-            doc.register :refs, ['_install', (Asciidoctor::Inline.new doc, :anchor, 'Install', type: :ref, target: '_install'), 'Install']
-            catalog = doc.catalog
-            assert_equal %(text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>), para.sub_macros(para.source)
-            assert_equal 1, catalog[:footnotes].size
-            footnote1 = catalog[:footnotes][0]
-            assert_equal '<a href="#_install">install</a>', footnote1.text
-        end
+    #[test]
+    fn a_footnote_macro_may_contain_an_escaped_backslash() {
+        let doc =
+            Parser::default().parse("footnote:[\\]]\nfootnote:[a \\] b]\nfootnote:[a \\]\\] b]");
 
-        test 'a footnote macro may contain an anchor macro' do
-            para = block_from_string 'text footnote:[a [[b]] [[c\]\] d]'
-            assert_equal %(text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote1 = para.document.catalog[:footnotes][0]
-            assert_equal 'a <a id="b"></a> [[c]] d', footnote1.text
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![
+                Footnote {
+                    index: 1,
+                    id: None,
+                    text: "]",
+                },
+                Footnote {
+                    index: 2,
+                    id: None,
+                    text: "a ] b",
+                },
+                Footnote {
+                    index: 3,
+                    id: None,
+                    text: "a ]] b",
+                },
+            ]
+        );
+    }
 
-        test 'subsequent footnote macros with escaped URLs should be restored in DocBook' do
-            input = 'foofootnote:[+http://example.com+]barfootnote:[+http://acme.com+]baz'
+    #[test]
+    fn a_footnote_macro_may_contain_a_link_macro() {
+        let doc = Parser::default().parse("Share your code. footnote:[https://github.com[GitHub]]");
+        let block = doc.nested_blocks().next().unwrap();
 
-            result = convert_string_to_embedded input, doctype: 'inline', backend: 'docbook'
-            assert_equal 'foo<footnote><simpara>http://example.com</simpara></footnote>bar<footnote><simpara>http://acme.com</simpara></footnote>baz', result
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Share your code. <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
 
-        test 'should increment index of subsequent footnote macros' do
-            para = block_from_string 'Sentence text footnote:[An example footnote.]. Sentence text footnote:[Another footnote.].'
-            assert_equal 'Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>. Sentence text <sup class="footnote">[<a id="_footnoteref_2" class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]</sup>.', para.sub_macros(para.source)
-            assert_equal 2, para.document.catalog[:footnotes].size
-            footnote1 = para.document.catalog[:footnotes][0]
-            assert_equal 1, footnote1.index
-            assert_nil footnote1.id
-            assert_equal 'An example footnote.', footnote1.text
-            footnote2 = para.document.catalog[:footnotes][1]
-            assert_equal 2, footnote2.index
-            assert_nil footnote2.id
-            assert_equal 'Another footnote.', footnote2.text
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r#"<a href="https://github.com">GitHub</a>"#,
+            }]
+        );
+    }
 
-        test 'a footnoteref macro with id and single-line text should be registered and output as a footnote' do
-            para = block_from_string 'Sentence text footnoteref:[ex1, An example footnote.].', attributes: { 'compat-mode' => '' }
-            assert_equal %(Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 1, footnote.index
-            assert_equal 'ex1', footnote.id
-            assert_equal 'An example footnote.', footnote.text
-        end
+    #[test]
+    fn a_footnote_macro_may_contain_a_plain_url() {
+        let doc = Parser::default()
+            .parse("the JLine footnote:[https://github.com/jline/jline2]\nlibrary.");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a footnoteref macro with id and multi-line text should be registered and output as a footnote without newlines' do
-            para = block_from_string "Sentence text footnoteref:[ex1, An example footnote\nwith wrapped text.].", attributes: { 'compat-mode' => '' }
-            assert_equal %(Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 1, footnote.index
-            assert_equal 'ex1', footnote.id
-            assert_equal 'An example footnote with wrapped text.', footnote.text
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "the JLine <sup class=\"footnote\">[<a id=\"_footnoteref_1\" class=\"footnote\" href=\"#_footnotedef_1\" title=\"View footnote.\">1</a>]</sup>\nlibrary."
+        );
 
-        test 'a footnoteref macro with id should refer to footnoteref with same id' do
-            para = block_from_string 'Sentence text footnoteref:[ex1, An example footnote.]. Sentence text footnoteref:[ex1].', attributes: { 'compat-mode' => '' }
-            assert_equal %(Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>. Sentence text <sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>.), para.sub_macros(para.source)
-            assert_equal 1, para.document.catalog[:footnotes].size
-            footnote = para.document.catalog[:footnotes].first
-            assert_equal 1, footnote.index
-            assert_equal 'ex1', footnote.id
-            assert_equal 'An example footnote.', footnote.text
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r#"<a href="https://github.com/jline/jline2" class="bare">https://github.com/jline/jline2</a>"#,
+            }]
+        );
+    }
 
-        test 'an unresolved footnote reference should produce a warning message and output fallback text in red' do
-            input = 'Sentence text.footnote:ex1[]'
-            using_memory_logger do |logger|
-            para = block_from_string input
-            output = para.sub_macros para.source
-            assert_equal 'Sentence text.<sup class="footnoteref red" title="Unresolved footnote reference.">[ex1]</sup>', output
-            assert_message logger, :WARN, 'invalid footnote reference: ex1'
-            end
-        end
+    #[test]
+    fn a_footnote_macro_followed_by_a_semi_colon_may_contain_a_plain_url() {
+        let doc = Parser::default()
+            .parse("the JLine footnote:[https://github.com/jline/jline2];\nlibrary.");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'using a footnoteref macro should generate a warning when compat mode is not enabled' do
-            input = 'Sentence text.footnoteref:[fn1,Commentary on this sentence.]'
-            using_memory_logger do |logger|
-            para = block_from_string input
-            para.sub_macros para.source
-            assert_message logger, :WARN, 'found deprecated footnoteref macro: footnoteref:[fn1,Commentary on this sentence.]; use footnote macro with target instead'
-            end
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "the JLine <sup class=\"footnote\">[<a id=\"_footnoteref_1\" class=\"footnote\" href=\"#_footnotedef_1\" title=\"View footnote.\">1</a>]</sup>;\nlibrary."
+        );
 
-        test 'inline footnote macro can be used to define and reference a footnote reference' do
-            input = <<~'EOS'
-            You can download the software from the product page.footnote:sub[Option only available if you have an active subscription.]
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r#"<a href="https://github.com/jline/jline2" class="bare">https://github.com/jline/jline2</a>"#,
+            }]
+        );
+    }
 
-            You can also file a support request.footnote:sub[]
+    #[test]
+    fn a_footnote_macro_may_contain_text_formatting() {
+        let doc = Parser::default().parse("You can download patches from the product page.footnote:[Only available with an _active_ subscription.]");
 
-            If all else fails, you can give us a call.footnoteref:[sub]
-            EOS
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: "Only available with an <em>active</em> subscription.",
+            }]
+        );
+    }
 
-            using_memory_logger do |logger|
-            output = convert_string_to_embedded input, attributes: { 'compat-mode' => '' }
-            assert_css '#_footnotedef_1', output, 1
-            assert_css 'p a[href="#_footnotedef_1"]', output, 3
-            assert_css '#footnotes .footnote', output, 1
-            assert_empty logger
-            end
-        end
+    #[test]
+    fn an_externalized_footnote_macro_may_contain_text_formatting() {
+        let doc = Parser::default().parse(":fn-disclaimer: pass:q[footnote:[Only available with an _active_ subscription.]]\n\nYou can download patches from the production page.{fn-disclaimer}");
 
-        test 'should parse multiple footnote references in a single line' do
-            input = 'notable text.footnote:id[about this [text\]], footnote:id[], footnote:id[]'
-            output = convert_string_to_embedded input
-            assert_xpath '(//p)[1]/sup[starts-with(@class,"footnote")]', output, 3
-            assert_xpath '(//p)[1]/sup[@class="footnote"]', output, 1
-            assert_xpath '(//p)[1]/sup[@class="footnoteref"]', output, 2
-            assert_xpath '(//p)[1]/sup[starts-with(@class,"footnote")]/a[@class="footnote"][text()="1"]', output, 3
-            assert_css '#footnotes .footnote', output, 1
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: "Only available with an <em>active</em> subscription.",
+            }]
+        );
+    }
 
-        test 'should not register footnote with id and text if id already registered' do
-            input = <<~'EOS'
-            :fn-notable-text: footnote:id[about this text]
+    #[test]
+    fn a_footnote_macro_may_contain_a_shorthand_xref() {
+        let doc = Parser::default()
+            .parse("[[_install]]\nInstall.\n\ntext footnote:[<<_install,install>>]");
+        let block = doc.nested_blocks().nth(1).unwrap();
 
-            notable text.{fn-notable-text}
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
 
-            more notable text.{fn-notable-text}
-            EOS
-            output = convert_string_to_embedded input
-            assert_xpath '(//p)[1]/sup[@class="footnote"]', output, 1
-            assert_xpath '(//p)[2]/sup[@class="footnoteref"]', output, 1
-            assert_css '#footnotes .footnote', output, 1
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r##"<a href="#_install">install</a>"##,
+            }]
+        );
+    }
 
-        test 'should not resolve an inline footnote macro missing both id and text' do
-            input = <<~'EOS'
-            The footnote:[] macro can be used for defining and referencing footnotes.
+    #[test]
+    fn a_footnote_macro_may_contain_an_xref_macro() {
+        let doc = Parser::default()
+            .parse("[[_install]]\nInstall.\n\ntext footnote:[xref:_install[install]]");
+        let block = doc.nested_blocks().nth(1).unwrap();
 
-            The footnoteref:[] macro is now deprecated.
-            EOS
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
 
-            output = convert_string_to_embedded input
-            assert_includes output, 'The footnote:[] macro'
-            assert_includes output, 'The footnoteref:[] macro'
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r##"<a href="#_install">install</a>"##,
+            }]
+        );
+    }
 
-        test 'inline footnote macro can define a numeric id without conflicting with auto-generated ID' do
-            input = 'You can download the software from the product page.footnote:1[Option only available if you have an active subscription.]'
+    #[test]
+    fn a_footnote_macro_may_contain_an_anchor_macro() {
+        let doc = Parser::default().parse("text footnote:[a [[b]] [[c\\]\\] d]");
+        let block = doc.nested_blocks().next().unwrap();
 
-            output = convert_string_to_embedded input
-            assert_css '#_footnote_1', output, 1
-            assert_css 'p sup#_footnote_1', output, 1
-            assert_css 'p a#_footnoteref_1', output, 1
-            assert_css 'p a[href="#_footnotedef_1"]', output, 1
-            assert_css '#footnotes #_footnotedef_1', output, 1
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
 
-        test 'inline footnote macro can define an id that uses any word characters in Unicode' do
-            input = <<~'EOS'
-            L'origine du mot forêt{blank}footnote:forêt[un massif forestier] est complexe.
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: None,
+                text: r#"a <a id="b"></a> [[c]] d"#,
+            }]
+        );
+    }
 
-            Qu'est-ce qu'une forêt ?{blank}footnote:forêt[]
-            EOS
-            output = convert_string_to_embedded input
-            assert_css '#_footnote_forêt', output, 1
-            assert_css '#_footnotedef_1', output, 1
-            assert_xpath '//a[@class="footnote"][text()="1"]', output, 2
-        end
+    #[test]
+    fn should_increment_index_of_subsequent_footnote_macros() {
+        let doc = Parser::default().parse("Sentence text footnote:[An example footnote.]. Sentence text footnote:[Another footnote.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'should be able to reference a bibliography entry in a footnote' do
-            input = <<~'EOS'
-            Choose a design pattern.footnote:[See <<gof>> to find a collection of design patterns.]
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>. Sentence text <sup class="footnote">[<a id="_footnoteref_2" class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]</sup>."##
+        );
 
-            [bibliography]
-            == Bibliography
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![
+                Footnote {
+                    index: 1,
+                    id: None,
+                    text: "An example footnote.",
+                },
+                Footnote {
+                    index: 2,
+                    id: None,
+                    text: "Another footnote.",
+                },
+            ]
+        );
+    }
 
-            * [[[gof]]] Erich Gamma, et al. _Design Patterns: Elements of Reusable Object-Oriented Software._ Addison-Wesley. 1994.
-            EOS
+    #[test]
+    fn a_footnoteref_macro_with_id_and_single_line_text_should_be_registered_and_output_as_a_footnote()
+     {
+        let doc = Parser::default().parse("Sentence text footnoteref:[ex1, An example footnote.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-            result = convert_string_to_embedded input
-            assert_include '<a href="#_footnoteref_1">1</a>. See <a href="#gof">[gof]</a> to find a collection of design patterns.', result
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-        test 'footnotes in headings are expected to be numbered out of sequence' do
-            input = <<~'EOS'
-            == Section 1
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: Some("ex1"),
+                text: "An example footnote.",
+            }]
+        );
+    }
 
-            para.footnote:[first footnote]
+    #[test]
+    fn a_footnoteref_macro_with_id_and_multi_line_text_should_be_registered_and_output_as_a_footnote_without_newlines()
+     {
+        let doc = Parser::default()
+            .parse("Sentence text footnoteref:[ex1, An example footnote\nwith wrapped text.].");
+        let block = doc.nested_blocks().next().unwrap();
 
-            == Section 2footnote:[second footnote]
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-            para.footnote:[third footnote]
-            EOS
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: Some("ex1"),
+                text: "An example footnote with wrapped text.",
+            }]
+        );
+    }
 
-            result = convert_string_to_embedded input
-            footnote_refs = xmlnodes_at_css 'a.footnote', result
-            footnote_defs = xmlnodes_at_css 'div.footnote', result
-            assert_equal 3, footnote_refs.length
-            assert_equal %w(1 1 2), footnote_refs.map(&:text)
-            assert_equal 3, footnote_defs.length
-            assert_equal ['1. second footnote', '1. first footnote', '2. third footnote'], footnote_defs.map(&:text).map(&:strip)
-        end
+    #[test]
+    fn a_footnoteref_macro_with_id_should_refer_to_footnoteref_with_same_id() {
+        let doc = Parser::default().parse(
+            "Sentence text footnoteref:[ex1, An example footnote.]. Sentence text footnoteref:[ex1].",
+        );
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a single-line index term macro with a primary term should be registered as an index reference' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macros = ['indexterm:[Tigers]', '(((Tigers)))']
-            macros.each do |macro|
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Tigers'], para.document.catalog[:indexterms].first
-            end
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"Sentence text <sup class="footnote" id="_footnote_ex1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>. Sentence text <sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>."##
+        );
 
-        test 'a single-line index term macro with primary and secondary terms should be registered as an index reference' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macros = ['indexterm:[Big cats, Tigers]', '(((Big cats, Tigers)))']
-            macros.each do |macro|
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Big cats', 'Tigers'], para.document.catalog[:indexterms].first
-            end
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: Some("ex1"),
+                text: "An example footnote.",
+            }]
+        );
+    }
 
-        test 'a single-line index term macro with primary, secondary and tertiary terms should be registered as an index reference' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macros = ['indexterm:[Big cats,Tigers , Panthera tigris]', '(((Big cats,Tigers , Panthera tigris)))']
-            macros.each do |macro|
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Big cats', 'Tigers', 'Panthera tigris'], para.document.catalog[:indexterms].first
-            end
-        end
+    #[test]
+    fn an_unresolved_footnote_reference_should_output_fallback_text_in_red() {
+        // NOTE: Asciidoctor also logs a warning (`invalid footnote reference:
+        // ex1`); we don't have a mechanism for reporting warnings from
+        // substitutions yet.
+        let doc = Parser::default().parse("Sentence text.footnote:ex1[]");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'a multi-line index term macro should be compacted and registered as an index reference' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macros = ["indexterm:[Panthera\ntigris]", "(((Panthera\ntigris)))"]
-            macros.each do |macro|
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Panthera tigris'], para.document.catalog[:indexterms].first
-            end
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r#"Sentence text.<sup class="footnoteref red" title="Unresolved footnote reference.">[ex1]</sup>"#
+        );
 
-        test 'should escape concealed index term if second bracket is preceded by a backslash' do
-            input = %[National Institute of Science and Technology (#{BACKSLASH}((NIST)))]
-            doc = document_from_string input, standalone: false
-            output = doc.convert
-            assert_xpath '//p[text()="National Institute of Science and Technology (((NIST)))"]', output, 1
-            #assert doc.catalog[:indexterms].empty?
-        end
+        assert_eq!(doc.footnotes().count(), 0);
+    }
 
-        test 'should only escape enclosing brackets if concealed index term is preceded by a backslash' do
-            input = %[National Institute of Science and Technology #{BACKSLASH}(((NIST)))]
-            doc = document_from_string input, standalone: false
-            output = doc.convert
-            assert_xpath '//p[text()="National Institute of Science and Technology (NIST)"]', output, 1
-            #term = doc.catalog[:indexterms].first
-            #assert_equal 1, term.size
-            #assert_equal 'NIST', term.first
-        end
+    #[test]
+    fn inline_footnote_macro_can_be_used_to_define_and_reference_a_footnote_reference() {
+        let doc = Parser::default().parse("You can download the software from the product page.footnote:sub[Option only available if you have an active subscription.]\n\nYou can also file a support request.footnote:sub[]\n\nIf all else fails, you can give us a call.footnoteref:[sub]");
 
-        test 'should not split index terms on commas inside of quoted terms' do
-            inputs = []
-            inputs.push <<~'EOS'
-            Tigers are big, scary cats.
-            indexterm:[Tigers, "[Big\],
-            scary cats"]
-            EOS
-            inputs.push <<~'EOS'
-            Tigers are big, scary cats.
-            (((Tigers, "[Big],
-            scary cats")))
-            EOS
+        let rendered: Vec<_> = doc
+            .nested_blocks()
+            .map(|block| block.rendered_content().unwrap())
+            .collect();
 
-            inputs.each do |input|
-            para = block_from_string input
-            output = para.sub_macros para.source
-            assert_equal input.lines.first, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #terms = para.document.catalog[:indexterms].first
-            #assert_equal 2, terms.size
-            #assert_equal 'Tigers', terms.first
-            #assert_equal '[Big], scary cats', terms.last
-            end
-        end
+        assert_eq!(
+            rendered,
+            vec![
+                r##"You can download the software from the product page.<sup class="footnote" id="_footnote_sub">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+                r##"You can also file a support request.<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+                r##"If all else fails, you can give us a call.<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+            ]
+        );
 
-        test 'normal substitutions are performed on an index term macro' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macros = ['indexterm:[*Tigers*]', '(((*Tigers*)))']
-            macros.each do |macro|
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.apply_subs para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['<strong>Tigers</strong>'], para.document.catalog[:indexterms].first
-            end
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: Some("sub"),
+                text: "Option only available if you have an active subscription.",
+            }]
+        );
+    }
 
-        test 'registers multiple index term macros' do
-            sentence = 'The tiger (Panthera tigris) is the largest cat species.'
-            macros = "(((Tigers)))\n(((Animals,Cats)))"
-            para = block_from_string "#{sentence}\n#{macros}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output.rstrip
-            #assert_equal 2, para.document.catalog[:indexterms].size
-            #assert_equal ['Tigers'], para.document.catalog[:indexterms][0]
-            #assert_equal ['Animals', 'Cats'], para.document.catalog[:indexterms][1]
-        end
+    #[test]
+    fn should_parse_multiple_footnote_references_in_a_single_line() {
+        let doc = Parser::default()
+            .parse("notable text.footnote:id[about this [text\\]], footnote:id[], footnote:id[]");
+        let block = doc.nested_blocks().next().unwrap();
 
-        test 'an index term macro with round bracket syntax may contain round brackets in term' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macro = '(((Tiger (Panthera tigris))))'
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Tiger (Panthera tigris)'], para.document.catalog[:indexterms].first
-        end
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"notable text.<sup class="footnote" id="_footnote_id">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>, <sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>, <sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
 
-        test 'visible shorthand index term macro should not consume trailing round bracket' do
-            input = '(text with ((index term)))'
-            expected = <<~'EOS'.chop
-            (text with <indexterm>
-            <primary>index term</primary>
-            </indexterm>index term)
-            EOS
-            #expected_term = ['index term']
-            para = block_from_string input, backend: :docbook
-            output = para.sub_macros para.source
-            assert_equal expected, output
-            #indexterms_table = para.document.catalog[:indexterms]
-            #assert_equal 1, indexterms_table.size
-            #assert_equal expected_term, indexterms_table[0]
-        end
+        assert_eq!(
+            doc.footnotes().collect::<Vec<_>>(),
+            vec![Footnote {
+                index: 1,
+                id: Some("id"),
+                text: "about this [text]",
+            }]
+        );
+    }
+
+    #[test]
+    fn should_not_register_footnote_with_id_and_text_if_id_already_registered() {
+        let doc = Parser::default().parse(":fn-notable-text: footnote:id[about this text]\n\nnotable text.{fn-notable-text}\n\nmore notable text.{fn-notable-text}");
+
+        let rendered: Vec<_> = doc
+            .nested_blocks()
+            .filter_map(|block| block.rendered_content())
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                r##"notable text.<sup class="footnote" id="_footnote_id">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+                r##"more notable text.<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+            ]
+        );
+
+        assert_eq!(doc.footnotes().count(), 1);
+    }
+
+    #[test]
+    fn should_not_resolve_an_inline_footnote_macro_missing_both_id_and_text() {
+        let doc = Parser::default().parse("The footnote:[] macro can be used for defining and referencing footnotes.\n\nThe footnoteref:[] macro is now deprecated.");
+
+        let rendered: Vec<_> = doc
+            .nested_blocks()
+            .map(|block| block.rendered_content().unwrap())
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                "The footnote:[] macro can be used for defining and referencing footnotes.",
+                "The footnoteref:[] macro is now deprecated.",
+            ]
+        );
+
+        assert_eq!(doc.footnotes().count(), 0);
+    }
+
+    #[test]
+    fn inline_footnote_macro_can_define_a_numeric_id_without_conflicting_with_auto_generated_id() {
+        let doc = Parser::default().parse("You can download the software from the product page.footnote:1[Option only available if you have an active subscription.]");
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            r##"You can download the software from the product page.<sup class="footnote" id="_footnote_1">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##
+        );
+    }
+
+    #[test]
+    fn inline_footnote_macro_can_define_an_id_that_uses_any_word_characters_in_unicode() {
+        // NOTE: The Asciidoctor test uses `{blank}`, which is an alias for
+        // `{empty}` that we don't define.
+        let doc = Parser::default().parse("L'origine du mot forêt{empty}footnote:forêt[un massif forestier] est complexe.\n\nQu'est-ce qu'une forêt ?{empty}footnote:forêt[]");
+
+        let rendered: Vec<_> = doc
+            .nested_blocks()
+            .map(|block| block.rendered_content().unwrap())
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                r##"L&#8217;origine du mot forêt<sup class="footnote" id="_footnote_forêt">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup> est complexe."##,
+                r##"Qu&#8217;est-ce qu&#8217;une forêt ?<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup>"##,
+            ]
+        );
+    }
+
+    #[test]
+    fn a_single_line_index_term_macro_with_a_primary_term_should_be_registered_as_an_index_reference()
+     {
+        let sentence = "The tiger (Panthera tigris) is the largest cat species.\n";
+
+        for macro_ in ["indexterm:[Tigers]", "(((Tigers)))"] {
+            let doc = Parser::default().parse(&format!("{sentence}{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), sentence);
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["Tigers"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 2,
+                        col: 1,
+                        offset: 56,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn a_single_line_index_term_macro_with_primary_and_secondary_terms_should_be_registered_as_an_index_reference()
+     {
+        let sentence = "The tiger (Panthera tigris) is the largest cat species.\n";
+
+        for macro_ in ["indexterm:[Big cats, Tigers]", "(((Big cats, Tigers)))"] {
+            let doc = Parser::default().parse(&format!("{sentence}{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), sentence);
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["Big cats", "Tigers"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 2,
+                        col: 1,
+                        offset: 56,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn a_single_line_index_term_macro_with_primary_secondary_and_tertiary_terms_should_be_registered_as_an_index_reference()
+     {
+        let sentence = "The tiger (Panthera tigris) is the largest cat species.\n";
+
+        for macro_ in [
+            "indexterm:[Big cats,Tigers , Panthera tigris]",
+            "(((Big cats,Tigers , Panthera tigris)))",
+        ] {
+            let doc = Parser::default().parse(&format!("{sentence}{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), sentence);
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["Big cats", "Tigers", "Panthera tigris"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 2,
+                        col: 1,
+                        offset: 56,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn a_multi_line_index_term_macro_should_be_compacted_and_registered_as_an_index_reference() {
+        let sentence = "The tiger (Panthera tigris) is the largest cat species.\n";
+
+        for macro_ in ["indexterm:[Panthera\ntigris]", "(((Panthera\ntigris)))"] {
+            let doc = Parser::default().parse(&format!("{sentence}{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), sentence);
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["Panthera tigris"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 2,
+                        col: 1,
+                        offset: 56,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn should_escape_concealed_index_term_if_second_bracket_is_preceded_by_a_backslash() {
+        let doc =
+            Parser::default().parse("National Institute of Science and Technology (\\((NIST)))");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "National Institute of Science and Technology (((NIST)))"
+        );
+
+        assert!(doc.index_terms().next().is_none());
+    }
+
+    #[test]
+    fn should_only_escape_enclosing_brackets_if_concealed_index_term_is_preceded_by_a_backslash() {
+        let doc =
+            Parser::default().parse("National Institute of Science and Technology \\(((NIST)))");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "National Institute of Science and Technology (NIST)"
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["NIST"],
+                see: None,
+                see_also: &[],
+                visible: true,
+                source: Span {
+                    data: "\\(((NIST)))",
+                    line: 1,
+                    col: 46,
+                    offset: 45,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn should_not_split_index_terms_on_commas_inside_of_quoted_terms() {
+        for (input, macro_) in [
+            (
+                "Tigers are big, scary cats.\nindexterm:[Tigers, \"[Big\\],\nscary cats\"]",
+                "indexterm:[Tigers, \"[Big\\],\nscary cats\"]",
+            ),
+            (
+                "Tigers are big, scary cats.\n(((Tigers, \"[Big],\nscary cats\")))",
+                "(((Tigers, \"[Big],\nscary cats\")))",
+            ),
+        ] {
+            let doc = Parser::default().parse(input);
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "Tigers are big, scary cats.\n"
+            );
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["Tigers", "[Big], scary cats"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 2,
+                        col: 1,
+                        offset: 28,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn normal_substitutions_are_performed_on_an_index_term_macro() {
+        let sentence = "The tiger (Panthera tigris) is the largest cat species.\n";
+
+        for (macro_, source) in [
+            (
+                "indexterm:[*Tigers*]",
+                "The tiger (Panthera tigris) is the largest cat species.\nindexterm:[*Tigers*]",
+            ),
+            (
+                "(((*Tigers*)))",
+                "The tiger (Panthera tigris) is the largest cat species.\n(((*Tigers*)))",
+            ),
+        ] {
+            let doc = Parser::default().parse(&format!("{sentence}{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), sentence);
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["<strong>Tigers</strong>"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: source,
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn registers_multiple_index_term_macros() {
+        let doc = Parser::default().parse(
+            "The tiger (Panthera tigris) is the largest cat species.\n(((Tigers)))\n(((Animals,Cats)))",
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap().trim_end(),
+            "The tiger (Panthera tigris) is the largest cat species."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["Tigers"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: "(((Tigers)))",
+                        line: 2,
+                        col: 1,
+                        offset: 56,
+                    },
+                },
+                IndexTerm {
+                    terms: &["Animals", "Cats"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: "(((Animals,Cats)))",
+                        line: 3,
+                        col: 1,
+                        offset: 69,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn an_index_term_macro_with_round_bracket_syntax_may_contain_round_brackets_in_term() {
+        let doc = Parser::default().parse(
+            "The tiger (Panthera tigris) is the largest cat species.\n(((Tiger (Panthera tigris))))",
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The tiger (Panthera tigris) is the largest cat species.\n"
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["Tiger (Panthera tigris)"],
+                see: None,
+                see_also: &[],
+                visible: false,
+                source: Span {
+                    data: "(((Tiger (Panthera tigris))))",
+                    line: 2,
+                    col: 1,
+                    offset: 56,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn visible_shorthand_index_term_macro_should_not_consume_trailing_round_bracket() {
+        let doc = Parser::default().parse("(text with ((index term)))");
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(block.rendered_content().unwrap(), "(text with index term)");
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["index term"],
+                see: None,
+                see_also: &[],
+                visible: true,
+                source: Span {
+                    data: "((index term)))",
+                    line: 1,
+                    col: 12,
+                    offset: 11,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn visible_shorthand_index_term_macro_should_not_consume_leading_round_bracket() {
+        let doc = Parser::default().parse("(((index term)) for text)");
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(block.rendered_content().unwrap(), "(index term for text)");
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["index term"],
+                see: None,
+                see_also: &[],
+                visible: true,
+                source: Span {
+                    data: "(((index term))",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn an_index_term_macro_with_square_bracket_syntax_may_contain_square_brackets_in_term() {
+        let doc = Parser::default().parse(
+            "The tiger (Panthera tigris) is the largest cat species.\nindexterm:[Tiger [Panthera tigris\\]]",
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The tiger (Panthera tigris) is the largest cat species.\n"
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["Tiger [Panthera tigris]"],
+                see: None,
+                see_also: &[],
+                visible: false,
+                source: Span {
+                    data: "indexterm:[Tiger [Panthera tigris\\]]",
+                    line: 2,
+                    col: 1,
+                    offset: 56,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn a_single_line_index_term_2_macro_should_be_registered_as_an_index_reference_and_retain_term_inline()
+     {
+        for (input, macro_) in [
+            (
+                "The indexterm2:[tiger] (Panthera tigris) is the largest cat species.",
+                "indexterm2:[tiger]",
+            ),
+            (
+                "The ((tiger)) (Panthera tigris) is the largest cat species.",
+                "((tiger))",
+            ),
+        ] {
+            let doc = Parser::default().parse(input);
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "The tiger (Panthera tigris) is the largest cat species."
+            );
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["tiger"],
+                    see: None,
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: macro_,
+                        line: 1,
+                        col: 5,
+                        offset: 4,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn a_multi_line_index_term_2_macro_should_be_compacted_and_registered_as_an_index_reference_and_retain_term_inline()
+     {
+        for (input, macro_) in [
+            (
+                "The indexterm2:[ panthera\ntigris ] is the largest cat species.",
+                "indexterm2:[ panthera\ntigris ]",
+            ),
+            (
+                "The (( panthera\ntigris )) is the largest cat species.",
+                "(( panthera\ntigris ))",
+            ),
+        ] {
+            let doc = Parser::default().parse(input);
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(
+                block.rendered_content().unwrap(),
+                "The panthera tigris is the largest cat species."
+            );
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["panthera tigris"],
+                    see: None,
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: macro_,
+                        line: 1,
+                        col: 5,
+                        offset: 4,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn registers_multiple_index_term_2_macros() {
+        let doc = Parser::default()
+            .parse("The ((tiger)) (Panthera tigris) is the largest ((cat)) species.");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The tiger (Panthera tigris) is the largest cat species."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["tiger"],
+                    see: None,
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: "((tiger))",
+                        line: 1,
+                        col: 5,
+                        offset: 4,
+                    },
+                },
+                IndexTerm {
+                    terms: &["cat"],
+                    see: None,
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: "((cat))",
+                        line: 1,
+                        col: 48,
+                        offset: 47,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_escape_visible_index_term_if_preceded_by_a_backslash() {
+        let doc = Parser::default()
+            .parse("The \\((tiger)) (Panthera tigris) is the largest \\((cat)) species.");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The ((tiger)) (Panthera tigris) is the largest ((cat)) species."
+        );
+
+        assert!(doc.index_terms().next().is_none());
+    }
+
+    #[test]
+    fn normal_substitutions_are_performed_on_an_index_term_2_macro() {
+        let doc = Parser::default()
+            .parse("The ((*tiger*)) (Panthera tigris) is the largest cat species.");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The <strong>tiger</strong> (Panthera tigris) is the largest cat species."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![IndexTerm {
+                terms: &["<strong>tiger</strong>"],
+                see: None,
+                see_also: &[],
+                visible: true,
+                source: Span {
+                    data: "The ((*tiger*)) (Panthera tigris) is the largest cat species.",
+                    line: 1,
+                    col: 1,
+                    offset: 0,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn index_term_2_macro_with_round_bracket_syntex_should_not_interfer_with_index_term_macro_with_round_bracket_syntax()
+     {
+        let doc = Parser::default()
+            .parse("The ((panthera tigris)) is the largest cat species.\n(((Big cats,Tigers)))");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "The panthera tigris is the largest cat species.\n"
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["panthera tigris"],
+                    see: None,
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: "((panthera tigris))",
+                        line: 1,
+                        col: 5,
+                        offset: 4,
+                    },
+                },
+                IndexTerm {
+                    terms: &["Big cats", "Tigers"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: "(((Big cats,Tigers)))",
+                        line: 2,
+                        col: 1,
+                        offset: 52,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_visible_shorthand_index_term_with_see_and_seealso() {
+        let doc = Parser::default()
+            .parse("((Flash >> HTML 5)) has been supplanted by ((HTML 5 &> CSS 3 &> SVG)).");
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "Flash has been supplanted by HTML 5."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["Flash"],
+                    see: Some("HTML 5"),
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: "((Flash >> HTML 5)) has been supplanted by ((HTML 5 &> CSS 3 &> SVG)).",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                },
+                IndexTerm {
+                    terms: &["HTML 5"],
+                    see: None,
+                    see_also: &["CSS 3", "SVG"],
+                    visible: true,
+                    source: Span {
+                        data: "((Flash >> HTML 5)) has been supplanted by ((HTML 5 &> CSS 3 &> SVG)).",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_concealed_shorthand_index_term_with_see_and_seealso() {
+        let doc = Parser::default().parse(
+            "Flash(((Flash >> HTML 5))) has been supplanted by HTML 5(((HTML 5 &> CSS 3 &> SVG))).",
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "Flash has been supplanted by HTML 5."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["Flash"],
+                    see: Some("HTML 5"),
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: "Flash(((Flash >> HTML 5))) has been supplanted by HTML 5(((HTML 5 &> CSS 3 &> SVG))).",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                },
+                IndexTerm {
+                    terms: &["HTML 5"],
+                    see: None,
+                    see_also: &["CSS 3", "SVG"],
+                    visible: false,
+                    source: Span {
+                        data: "Flash(((Flash >> HTML 5))) has been supplanted by HTML 5(((HTML 5 &> CSS 3 &> SVG))).",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_visible_index_term_macro_with_see_and_seealso() {
+        let doc = Parser::default().parse(
+            r#"indexterm2:[Flash,see=HTML 5] has been supplanted by indexterm2:[HTML 5,see-also="CSS 3, SVG"]."#,
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "Flash has been supplanted by HTML 5."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["Flash"],
+                    see: Some("HTML 5"),
+                    see_also: &[],
+                    visible: true,
+                    source: Span {
+                        data: "indexterm2:[Flash,see=HTML 5]",
+                        line: 1,
+                        col: 1,
+                        offset: 0,
+                    },
+                },
+                IndexTerm {
+                    terms: &["HTML 5"],
+                    see: None,
+                    see_also: &["CSS 3", "SVG"],
+                    visible: true,
+                    source: Span {
+                        data: r#"indexterm2:[HTML 5,see-also="CSS 3, SVG"]"#,
+                        line: 1,
+                        col: 54,
+                        offset: 53,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_concealed_index_term_macro_with_see_and_seealso() {
+        let doc = Parser::default().parse(
+            r#"Flashindexterm:[Flash,see=HTML 5] has been supplanted by HTML 5indexterm:[HTML 5,see-also="CSS 3, SVG"]."#,
+        );
+
+        let block = doc.nested_blocks().next().unwrap();
+
+        assert_eq!(
+            block.rendered_content().unwrap(),
+            "Flash has been supplanted by HTML 5."
+        );
+
+        assert_eq!(
+            doc.index_terms().collect::<Vec<_>>(),
+            vec![
+                IndexTerm {
+                    terms: &["Flash"],
+                    see: Some("HTML 5"),
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: "indexterm:[Flash,see=HTML 5]",
+                        line: 1,
+                        col: 6,
+                        offset: 5,
+                    },
+                },
+                IndexTerm {
+                    terms: &["HTML 5"],
+                    see: None,
+                    see_also: &["CSS 3", "SVG"],
+                    visible: false,
+                    source: Span {
+                        data: r#"indexterm:[HTML 5,see-also="CSS 3, SVG"]"#,
+                        line: 1,
+                        col: 64,
+                        offset: 63,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_honor_secondary_and_tertiary_index_terms_when_primary_index_term_is_quoted_and_contains_equals_sign()
+     {
+        for macro_ in [
+            r#"indexterm:["name=value",variable,assignment]"#,
+            "(((name=value,variable,assignment)))",
+        ] {
+            let doc = Parser::default().parse(&format!("Assigning variables.{macro_}"));
+            let block = doc.nested_blocks().next().unwrap();
+
+            assert_eq!(block.rendered_content().unwrap(), "Assigning variables.");
+
+            assert_eq!(
+                doc.index_terms().collect::<Vec<_>>(),
+                vec![IndexTerm {
+                    terms: &["name=value", "variable", "assignment"],
+                    see: None,
+                    see_also: &[],
+                    visible: false,
+                    source: Span {
+                        data: macro_,
+                        line: 1,
+                        col: 21,
+                        offset: 20,
+                    },
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn btn_macro() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"btn:[Save]"#));
+
+        let expected = r#"<b class="button">Save</b>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn btn_macro_that_spans_multiple_lines() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new("btn:[Rebase and\nmerge]"));
+
+        let expected = r#"<b class="button">Rebase and merge</b>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_single_key() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[F3]"#));
+
+        let expected = r#"<kbd>F3</kbd>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_single_backslash_key() {
+        let mut content = crate::content::Content::from(crate::Span::new("kbd:[\\ ]"));
+
+        let expected = "<kbd>\\</kbd>";
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl+Shift+T]"#));
+
+        let expected =
+            r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_that_spans_multiple_lines() {
+        let mut content = crate::content::Content::from(crate::Span::new("kbd:[Ctrl +\nT]"));
+
+        let expected = r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>T</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_delimited_by_pluses_with_spaces() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl + Shift + T]"#));
+
+        let expected =
+            r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_delimited_by_commas() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl,Shift,T]"#));
+
+        let expected =
+            r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_delimited_by_commas_with_spaces() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl, Shift, T]"#));
+
+        let expected =
+            r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_delimited_by_plus_containing_a_comma_key() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl+,]"#));
+
+        let expected = r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>,</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_delimited_by_commas_containing_a_plus_key() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl, +, Shift]"#));
+
+        let expected =
+            r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>+</kbd>+<kbd>Shift</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_where_last_key_matches_plus_delimiter() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl + +]"#));
+
+        let expected = r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>+</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_where_last_key_matches_comma_delimiter() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[Ctrl, ,]"#));
+
+        let expected = r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>,</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_containing_escaped_bracket() {
+        let mut content = crate::content::Content::from(crate::Span::new("kbd:[Ctrl + \\]]"));
+
+        let expected = r#"<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>]</kbd></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_with_key_combination_ending_in_backslash() {
+        let mut content = crate::content::Content::from(crate::Span::new("kbd:[Ctrl + \\ ]"));
+
+        let expected = "<span class=\"keyseq\"><kbd>Ctrl</kbd>+<kbd>\\</kbd></span>";
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_looks_for_delimiter_beyond_first_character() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[,te]"#));
+
+        let expected = r#"<kbd>,te</kbd>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn kbd_macro_restores_trailing_delimiter_as_key_value() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"kbd:[te,]"#));
+
+        let expected = r#"<kbd>te,</kbd>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_using_macro_sytnax() {
+        let mut content = crate::content::Content::from(crate::Span::new(r#"menu:File[]"#));
+
+        let expected = r#"<b class="menuref">File</b>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_multiple_menu_macros_in_same_line() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:File[] and menu:Edit[]"#));
+
+        let expected = r#"<b class="menuref">File</b> and <b class="menuref">Edit</b>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_using_macro_syntax() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:File[Save As&#8230;]"#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">File</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Save As&#8230;</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_macro_that_spans_multiple_lines() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new("menu:Preferences[Compile\non\nSave]"));
+
+        let expected = "<span class=\"menuseq\"><b class=\"menu\">Preferences</b>&#160;<b class=\"caret\">&#8250;</b> <b class=\"menuitem\">Compile\non\nSave</b></span>";
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_unescape_escaped_closing_bracket_in_menu_macro() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            "menu:Preferences[Compile [on\\] Save]",
+        ));
+
+        let expected = r#"<span class="menuseq"><b class="menu">Preferences</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Compile [on] Save</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_using_macro_syntax_when_fonts_icons_are_enabled() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            r#"menu:Tools[More Tools &gt; Extensions]"#,
+        ));
+
+        let expected = r#"<span class="menuseq"><b class="menu">Tools</b>&#160;<i class="fa fa-angle-right caret"></i> <b class="submenu">More Tools</b>&#160;<i class="fa fa-angle-right caret"></i> <b class="menuitem">Extensions</b></span>"#;
+
+        let p = Parser::default()
+            .with_intrinsic_attribute_bool("experimental", true, ModificationContext::ApiOnly)
+            .with_intrinsic_attribute("icons", "font", ModificationContext::ApiOnly);
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_in_submenu_using_macro_syntax() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:Tools[Project &gt; Build]"#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_in_submenu_using_macro_syntax_and_comma_delimiter() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:Tools[Project, Build]"#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_using_inline_syntax() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#""File &gt; Save As&#8230;""#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">File</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Save As&#8230;</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_with_menu_item_in_submenu_using_inline_syntax() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#""Tools &gt; Project &gt; Build""#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn inline_menu_syntax_should_not_match_closing_quote_of_xml_attribute() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            r#"<span class="xmltag">&lt;node&gt;</span><span class="classname">r</span>"#,
+        ));
+
+        let expected =
+            r#"<span class="xmltag">&lt;node&gt;</span><span class="classname">r</span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_menu_macro_with_items_containing_multibyte_characters() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:视图[放大, 重置]"#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">视图</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">放大</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">重置</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_inline_menu_with_items_containing_multibyte_characters() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#""视图 &gt; 放大 &gt; 重置""#));
+
+        let expected = r#"<span class="menuseq"><b class="menu">视图</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">放大</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">重置</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_process_a_menu_macro_with_a_target_that_begins_with_a_character_reference() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            r#"menu:&#8942;[More Tools, Extensions]"#,
+        ));
+
+        let expected = r#"<span class="menuseq"><b class="menu">&#8942;</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">More Tools</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Extensions</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
+
+    #[test]
+    fn should_not_process_a_menu_macro_with_a_target_that_ends_with_a_space() {
+        let mut content =
+            crate::content::Content::from(crate::Span::new(r#"menu:foo [bar] menu:File[Save]"#));
+
+        let expected = r#"menu:foo [bar] <span class="menuseq"><b class="menu">File</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Save</b></span>"#;
+
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
+
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
 
-        test 'visible shorthand index term macro should not consume leading round bracket' do
-            input = '(((index term)) for text)'
-            expected = <<~'EOS'.chop
-            (<indexterm>
-            <primary>index term</primary>
-            </indexterm>index term for text)
-            EOS
-            #expected_term = ['index term']
-            para = block_from_string input, backend: :docbook
-            output = para.sub_macros para.source
-            assert_equal expected, output
-            #indexterms_table = para.document.catalog[:indexterms]
-            #assert_equal 1, indexterms_table.size
-            #assert_equal expected_term, indexterms_table[0]
-        end
+    #[test]
+    fn should_process_an_inline_menu_that_begins_with_a_character_reference() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            r#""&#8942; &gt; More Tools &gt; Extensions""#,
+        ));
 
-        test 'an index term macro with square bracket syntax may contain square brackets in term' do
-            sentence = "The tiger (Panthera tigris) is the largest cat species.\n"
-            macro = 'indexterm:[Tiger [Panthera tigris\\]]'
-            para = block_from_string "#{sentence}#{macro}"
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['Tiger [Panthera tigris]'], para.document.catalog[:indexterms].first
-        end
+        let expected = r#"<span class="menuseq"><b class="menu">&#8942;</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">More Tools</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Extensions</b></span>"#;
 
-        test 'a single-line index term 2 macro should be registered as an index reference and retain term inline' do
-            sentence = 'The tiger (Panthera tigris) is the largest cat species.'
-            macros = ['The indexterm2:[tiger] (Panthera tigris) is the largest cat species.', 'The ((tiger)) (Panthera tigris) is the largest cat species.']
-            macros.each do |macro|
-            para = block_from_string macro
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['tiger'], para.document.catalog[:indexterms].first
-            end
-        end
+        let p = Parser::default().with_intrinsic_attribute_bool(
+            "experimental",
+            true,
+            ModificationContext::ApiOnly,
+        );
 
-        test 'a multi-line index term 2 macro should be compacted and registered as an index reference and retain term inline' do
-            sentence = 'The panthera tigris is the largest cat species.'
-            macros = ["The indexterm2:[ panthera\ntigris ] is the largest cat species.", "The (( panthera\ntigris )) is the largest cat species."]
-            macros.each do |macro|
-            para = block_from_string macro
-            output = para.sub_macros para.source
-            assert_equal sentence, output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['panthera tigris'], para.document.catalog[:indexterms].first
-            end
-        end
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
 
-        test 'registers multiple index term 2 macros' do
-            sentence = 'The ((tiger)) (Panthera tigris) is the largest ((cat)) species.'
-            para = block_from_string sentence
-            output = para.sub_macros para.source
-            assert_equal 'The tiger (Panthera tigris) is the largest cat species.', output
-            #assert_equal 2, para.document.catalog[:indexterms].size
-            #assert_equal ['tiger'], para.document.catalog[:indexterms][0]
-            #assert_equal ['cat'], para.document.catalog[:indexterms][1]
-        end
+    #[test]
+    fn ui_macros_require_experimental_attribute() {
+        let mut content = crate::content::Content::from(crate::Span::new(
+            r#"kbd:[F3] btn:[Save] menu:File[Save] "File &gt; Save""#,
+        ));
 
-        test 'should escape visible index term if preceded by a backslash' do
-            sentence = "The #{BACKSLASH}((tiger)) (Panthera tigris) is the largest #{BACKSLASH}((cat)) species."
-            para = block_from_string sentence
-            output = para.sub_macros para.source
-            assert_equal 'The ((tiger)) (Panthera tigris) is the largest ((cat)) species.', output
-            #assert para.document.catalog[:indexterms].empty?
-        end
+        let expected = r#"kbd:[F3] btn:[Save] menu:File[Save] "File &gt; Save""#;
 
-        test 'normal substitutions are performed on an index term 2 macro' do
-            sentence = 'The ((*tiger*)) (Panthera tigris) is the largest cat species.'
-            para = block_from_string sentence
-            output = para.apply_subs para.source
-            assert_equal 'The <strong>tiger</strong> (Panthera tigris) is the largest cat species.', output
-            #assert_equal 1, para.document.catalog[:indexterms].size
-            #assert_equal ['<strong>tiger</strong>'], para.document.catalog[:indexterms].first
-        end
+        let p = Parser::default();
 
-        test 'index term 2 macro with round bracket syntex should not interfer with index term macro with round bracket syntax' do
-            sentence = "The ((panthera tigris)) is the largest cat species.\n(((Big cats,Tigers)))"
-            para = block_from_string sentence
-            output = para.sub_macros para.source
-            assert_equal "The panthera tigris is the largest cat species.\n", output
-            #terms = para.document.catalog[:indexterms]
-            #assert_equal 2, terms.size
-            #assert_equal ['panthera tigris'], terms[0]
-            #assert_equal ['Big cats', 'Tigers'], terms[1]
-        end
+        SubstitutionStep::Macros.apply(&mut content, &p, None);
+        assert_eq!(
+            content.rendered,
+            CowStr::Boxed(expected.to_string().into_boxed_str())
+        );
+    }
 
-        test 'should parse visible shorthand index term with see and seealso' do
-            sentence = '((Flash >> HTML 5)) has been supplanted by ((HTML 5 &> CSS 3 &> SVG)).'
-            output = convert_string_to_embedded sentence, backend: 'docbook'
-            indexterm_flash = <<~'EOS'.chop
-            <indexterm>
-            <primary>Flash</primary>
-            <see>HTML 5</see>
-            </indexterm>
-            EOS
-            indexterm_html5 = <<~'EOS'.chop
-            <indexterm>
-            <primary>HTML 5</primary>
-            <seealso>CSS 3</seealso>
-            <seealso>SVG</seealso>
-            </indexterm>
-            EOS
-            assert_includes output, indexterm_flash
-            assert_includes output, indexterm_html5
+    #[ignore]
+    #[test]
+    fn todo_migrate_from_ruby_2() {
+        todo!(
+            "{}",
+            r###"
+        test 'subsequent footnote macros with escaped URLs should be restored in DocBook' do
+            input = 'foofootnote:[+http://example.com+]barfootnote:[+http://acme.com+]baz'
+
+            result = convert_string_to_embedded input, doctype: 'inline', backend: 'docbook'
+            assert_equal 'foo<footnote><simpara>http://example.com</simpara></footnote>bar<footnote><simpara>http://acme.com</simpara></footnote>baz', result
         end
 
-        test 'should parse concealed shorthand index term with see and seealso' do
-            sentence = 'Flash(((Flash >> HTML 5))) has been supplanted by HTML 5(((HTML 5 &> CSS 3 &> SVG))).'
-            output = convert_string_to_embedded sentence, backend: 'docbook'
-            indexterm_flash = <<~'EOS'.chop
-            <indexterm>
-            <primary>Flash</primary>
-            <see>HTML 5</see>
-            </indexterm>
-            EOS
-            indexterm_html5 = <<~'EOS'.chop
-            <indexterm>
-            <primary>HTML 5</primary>
-            <seealso>CSS 3</seealso>
-            <seealso>SVG</seealso>
-            </indexterm>
-            EOS
-            assert_includes output, indexterm_flash
-            assert_includes output, indexterm_html5
+        test 'using a footnoteref macro should generate a warning when compat mode is not enabled' do
+            input = 'Sentence text.footnoteref:[fn1,Commentary on this sentence.]'
+            using_memory_logger do |logger|
+            para = block_from_string input
+            para.sub_macros para.source
+            assert_message logger, :WARN, 'found deprecated footnoteref macro: footnoteref:[fn1,Commentary on this sentence.]; use footnote macro with target instead'
+            end
         end
 
-        test 'should parse visible index term macro with see and seealso' do
-            sentence = 'indexterm2:[Flash,see=HTML 5] has been supplanted by indexterm2:[HTML 5,see-also="CSS 3, SVG"].'
-            output = convert_string_to_embedded sentence, backend: 'docbook'
-            indexterm_flash = <<~'EOS'.chop
-            <indexterm>
-            <primary>Flash</primary>
-            <see>HTML 5</see>
-            </indexterm>
-            EOS
-            indexterm_html5 = <<~'EOS'.chop
-            <indexterm>
-            <primary>HTML 5</primary>
-            <seealso>CSS 3</seealso>
-            <seealso>SVG</seealso>
-            </indexterm>
+        test 'should be able to reference a bibliography entry in a footnote' do
+            input = <<~'EOS'
+            Choose a design pattern.footnote:[See <<gof>> to find a collection of design patterns.]
+
+            [bibliography]
+            == Bibliography
+
+            * [[[gof]]] Erich Gamma, et al. _Design Patterns: Elements of Reusable Object-Oriented Software._ Addison-Wesley. 1994.
             EOS
-            assert_includes output, indexterm_flash
-            assert_includes output, indexterm_html5
+
+            result = convert_string_to_embedded input
+            assert_include '<a href="#_footnoteref_1">1</a>. See <a href="#gof">[gof]</a> to find a collection of design patterns.', result
         end
 
-        test 'should parse concealed index term macro with see and seealso' do
-            sentence = 'Flashindexterm:[Flash,see=HTML 5] has been supplanted by HTML 5indexterm:[HTML 5,see-also="CSS 3, SVG"].'
-            output = convert_string_to_embedded sentence, backend: 'docbook'
-            indexterm_flash = <<~'EOS'.chop
-            <indexterm>
-            <primary>Flash</primary>
-            <see>HTML 5</see>
-            </indexterm>
-            EOS
-            indexterm_html5 = <<~'EOS'.chop
-            <indexterm>
-            <primary>HTML 5</primary>
-            <seealso>CSS 3</seealso>
-            <seealso>SVG</seealso>
-            </indexterm>
+        test 'footnotes in headings are expected to be numbered out of sequence' do
+            input = <<~'EOS'
+            == Section 1
+
+            para.footnote:[first footnote]
+
+            == Section 2footnote:[second footnote]
+
+            para.footnote:[third footnote]
             EOS
-            assert_includes output, indexterm_flash
-            assert_includes output, indexterm_html5
-        end
 
-        test 'should honor secondary and tertiary index terms when primary index term is quoted and contains equals sign' do
-            sentence = 'Assigning variables.'
-            expected = %(#{sentence}<indexterm><primary>name=value</primary><secondary>variable</secondary><tertiary>assignment</tertiary></indexterm>)
-            macros = ['indexterm:["name=value",variable,assignment]', '(((name=value,variable,assignment)))']
-            macros.each do |macro|
-            para = block_from_string %(#{sentence}#{macro}), backend: 'docbook'
-            output = (para.sub_macros para.source).tr ?\n, ''
-            assert_equal expected, output
-            end
+            result = convert_string_to_embedded input
+            footnote_refs = xmlnodes_at_css 'a.footnote', result
+            footnote_defs = xmlnodes_at_css 'div.footnote', result
+            assert_equal 3, footnote_refs.length
+            assert_equal %w(1 1 2), footnote_refs.map(&:text)
+            assert_equal 3, footnote_defs.length
+            assert_equal ['1. second footnote', '1. first footnote', '2. third footnote'], footnote_defs.map(&:text).map(&:strip)
         end
 
         context 'Button macro' do
-            test 'btn macro' do
-            para = block_from_string 'btn:[Save]', attributes: { 'experimental' => '' }
-            assert_equal '<b class="button">Save</b>', para.sub_macros(para.source)
-            end
-
-            test 'btn macro that spans multiple lines' do
-            para = block_from_string %(btn:[Rebase and\nmerge]), attributes: { 'experimental' => '' }
-            assert_equal '<b class="button">Rebase and merge</b>', para.sub_macros(para.source)
-            end
-
             test 'btn macro for docbook backend' do
             para = block_from_string 'btn:[Save]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<guibutton>Save</guibutton>', para.sub_macros(para.source)
@@ -4304,194 +5716,30 @@ mod macros {
         end
 
         context 'Keyboard macro' do
-            test 'kbd macro with single key' do
-            para = block_from_string 'kbd:[F3]', attributes: { 'experimental' => '' }
-            assert_equal '<kbd>F3</kbd>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with single backslash key' do
-            para = block_from_string "kbd:[#{BACKSLASH} ]", attributes: { 'experimental' => '' }
-            assert_equal '<kbd>\</kbd>', para.sub_macros(para.source)
-            end
-
             test 'kbd macro with single key, docbook backend' do
             para = block_from_string 'kbd:[F3]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<keycap>F3</keycap>', para.sub_macros(para.source)
             end
 
-            test 'kbd macro with key combination' do
-            para = block_from_string 'kbd:[Ctrl+Shift+T]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination that spans multiple lines' do
-            para = block_from_string %(kbd:[Ctrl +\nT]), attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>T</kbd></span>', para.sub_macros(para.source)
-            end
-
             test 'kbd macro with key combination, docbook backend' do
             para = block_from_string 'kbd:[Ctrl+Shift+T]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<keycombo><keycap>Ctrl</keycap><keycap>Shift</keycap><keycap>T</keycap></keycombo>', para.sub_macros(para.source)
             end
 
-            test 'kbd macro with key combination delimited by pluses with spaces' do
-            para = block_from_string 'kbd:[Ctrl + Shift + T]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination delimited by commas' do
-            para = block_from_string 'kbd:[Ctrl,Shift,T]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination delimited by commas with spaces' do
-            para = block_from_string 'kbd:[Ctrl, Shift, T]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>T</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination delimited by plus containing a comma key' do
-            para = block_from_string 'kbd:[Ctrl+,]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>,</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination delimited by commas containing a plus key' do
-            para = block_from_string 'kbd:[Ctrl, +, Shift]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>+</kbd>+<kbd>Shift</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination where last key matches plus delimiter' do
-            para = block_from_string 'kbd:[Ctrl + +]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>+</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination where last key matches comma delimiter' do
-            para = block_from_string 'kbd:[Ctrl, ,]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>,</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination containing escaped bracket' do
-            para = block_from_string 'kbd:[Ctrl + \]]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>]</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro with key combination ending in backslash' do
-            para = block_from_string "kbd:[Ctrl + #{BACKSLASH} ]", attributes: { 'experimental' => '' }
-            assert_equal '<span class="keyseq"><kbd>Ctrl</kbd>+<kbd>\\</kbd></span>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro looks for delimiter beyond first character' do
-            para = block_from_string 'kbd:[,te]', attributes: { 'experimental' => '' }
-            assert_equal '<kbd>,te</kbd>', para.sub_macros(para.source)
-            end
-
-            test 'kbd macro restores trailing delimiter as key value' do
-            para = block_from_string 'kbd:[te,]', attributes: { 'experimental' => '' }
-            assert_equal '<kbd>te,</kbd>', para.sub_macros(para.source)
-            end
-        end
-
-        context 'Menu macro' do
-            test 'should process menu using macro sytnax' do
-            para = block_from_string 'menu:File[]', attributes: { 'experimental' => '' }
-            assert_equal '<b class="menuref">File</b>', para.sub_macros(para.source)
-            end
-
             test 'should process menu for docbook backend' do
             para = block_from_string 'menu:File[]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<guimenu>File</guimenu>', para.sub_macros(para.source)
             end
 
-            test 'should process multiple menu macros in same line' do
-            para = block_from_string 'menu:File[] and menu:Edit[]', attributes: { 'experimental' => '' }
-            assert_equal '<b class="menuref">File</b> and <b class="menuref">Edit</b>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu with menu item using macro syntax' do
-            para = block_from_string 'menu:File[Save As&#8230;]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">File</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Save As&#8230;</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu macro that spans multiple lines' do
-            input = %(menu:Preferences[Compile\non\nSave])
-            para = block_from_string input, attributes: { 'experimental' => '' }
-            assert_equal %(<span class="menuseq"><b class="menu">Preferences</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Compile\non\nSave</b></span>), para.sub_macros(para.source)
-            end
-
-            test 'should unescape escaped closing bracket in menu macro' do
-            input = 'menu:Preferences[Compile [on\\] Save]'
-            para = block_from_string input, attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">Preferences</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Compile [on] Save</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu with menu item using macro syntax when fonts icons are enabled' do
-            para = block_from_string 'menu:Tools[More Tools &gt; Extensions]', attributes: { 'experimental' => '', 'icons' => 'font' }
-            assert_equal '<span class="menuseq"><b class="menu">Tools</b>&#160;<i class="fa fa-angle-right caret"></i> <b class="submenu">More Tools</b>&#160;<i class="fa fa-angle-right caret"></i> <b class="menuitem">Extensions</b></span>', para.sub_macros(para.source)
-            end
-
             test 'should process menu with menu item for docbook backend' do
             para = block_from_string 'menu:File[Save As&#8230;]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<menuchoice><guimenu>File</guimenu> <guimenuitem>Save As&#8230;</guimenuitem></menuchoice>', para.sub_macros(para.source)
             end
 
-            test 'should process menu with menu item in submenu using macro syntax' do
-            para = block_from_string 'menu:Tools[Project &gt; Build]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>', para.sub_macros(para.source)
-            end
-
             test 'should process menu with menu item in submenu for docbook backend' do
             para = block_from_string 'menu:Tools[Project &gt; Build]', backend: 'docbook', attributes: { 'experimental' => '' }
             assert_equal '<menuchoice><guimenu>Tools</guimenu> <guisubmenu>Project</guisubmenu> <guimenuitem>Build</guimenuitem></menuchoice>', para.sub_macros(para.source)
             end
-
-            test 'should process menu with menu item in submenu using macro syntax and comma delimiter' do
-            para = block_from_string 'menu:Tools[Project, Build]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu with menu item using inline syntax' do
-            para = block_from_string '"File &gt; Save As&#8230;"', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">File</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Save As&#8230;</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu with menu item in submenu using inline syntax' do
-            para = block_from_string '"Tools &gt; Project &gt; Build"', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">Tools</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">Project</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Build</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'inline menu syntax should not match closing quote of XML attribute' do
-            para = block_from_string '<span class="xmltag">&lt;node&gt;</span><span class="classname">r</span>', attributes: { 'experimental' => '' }
-            assert_equal '<span class="xmltag">&lt;node&gt;</span><span class="classname">r</span>', para.sub_macros(para.source)
-            end
-
-            test 'should process menu macro with items containing multibyte characters' do
-            para = block_from_string 'menu:视图[放大, 重置]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">视图</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">放大</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">重置</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process inline menu with items containing multibyte characters' do
-            para = block_from_string '"视图 &gt; 放大 &gt; 重置"', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">视图</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">放大</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">重置</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should process a menu macro with a target that begins with a character reference' do
-            para = block_from_string 'menu:&#8942;[More Tools, Extensions]', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">&#8942;</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">More Tools</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Extensions</b></span>', para.sub_macros(para.source)
-            end
-
-            test 'should not process a menu macro with a target that ends with a space' do
-            input = 'menu:foo [bar] menu:File[Save]'
-            para = block_from_string input, attributes: { 'experimental' => '' }
-            result = para.sub_macros para.source
-            assert_xpath '/span[@class="menuseq"]', result, 1
-            assert_xpath '//b[@class="menu"][text()="File"]', result, 1
-            end
-
-            test 'should process an inline menu that begins with a character reference' do
-            para = block_from_string '"&#8942; &gt; More Tools &gt; Extensions"', attributes: { 'experimental' => '' }
-            assert_equal '<span class="menuseq"><b class="menu">&#8942;</b>&#160;<b class="caret">&#8250;</b> <b class="submenu">More Tools</b>&#160;<b class="caret">&#8250;</b> <b class="menuitem">Extensions</b></span>', para.sub_macros(para.source)
-            end
-        end
-        end
         "###
         );
     }
@@ -4503,7 +5751,10 @@ mod passthroughs {
     use crate::{
         Parser,
         blocks::SimpleBlockStyle,
-        content::{Passthroughs, SubstitutionGroup, SubstitutionStep, passthroughs::Passthrough},
+        content::{
+            PassthroughKind, Passthroughs, SubstitutionGroup, SubstitutionStep,
+            passthroughs::Passthrough,
+        },
         parser::{ModificationContext, QuoteType},
         tests::prelude::*,
     };
@@ -4513,7 +5764,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("+++<code>inline code</code>+++"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4531,6 +5782,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::TriplePlus,
                 text: "<code>inline code</code>".to_owned(),
                 subs: SubstitutionGroup::None,
                 type_: None,
@@ -4545,7 +5797,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("[role]+++<code>inline code</code>+++"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4563,6 +5815,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::TriplePlus,
                 text: "<code>inline code</code>".to_owned(),
                 subs: SubstitutionGroup::None,
                 type_: Some(QuoteType::Unquoted,),
@@ -4576,7 +5829,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("+++<code>inline\ncode</code>+++"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4594,6 +5847,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::TriplePlus,
                 text: "<code>inline\ncode</code>".to_owned(),
                 subs: SubstitutionGroup::None,
                 type_: None,
@@ -4607,7 +5861,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("$$<code>{code}</code>$$"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4625,8 +5879,9 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::DoubleDollar,
                 text: "<code>{code}</code>".to_owned(),
-                subs: SubstitutionGroup::Verbatim,
+                subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
                 attrlist: None,
             },],)
@@ -4638,7 +5893,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("++<code>{code}</code>++"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4656,8 +5911,9 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::DoublePlus,
                 text: "<code>{code}</code>".to_owned(),
-                subs: SubstitutionGroup::Verbatim,
+                subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
                 attrlist: None,
             },],)
@@ -4816,7 +6072,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("$$<code>\n{code}\n</code>$$"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4834,8 +6090,9 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::DoubleDollar,
                 text: "<code>\n{code}\n</code>".to_owned(),
-                subs: SubstitutionGroup::Verbatim,
+                subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
                 attrlist: None,
             },],)
@@ -4848,7 +6105,7 @@ mod passthroughs {
             "pass:specialcharacters,quotes[<code>['code'\\]</code>]",
         ));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4866,6 +6123,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: "<code>['code']</code>".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
@@ -4883,7 +6141,7 @@ mod passthroughs {
             "pass:specialcharacters,quotes[<code>['more\ncode'\\]</code>]",
         ));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4901,6 +6159,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: "<code>['more\ncode']</code>".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
@@ -4960,7 +6219,7 @@ mod passthroughs {
         let mut content =
             crate::content::Content::from(crate::Span::new("pass:q,a[*<{backend}>*]"));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -4978,6 +6237,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: "*<{backend}>*".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::Quotes,
@@ -5017,7 +6277,7 @@ mod passthroughs {
     fn inline_pass_macro_supports_incremental_subs() {
         // TO DO: Restore this test once macro substitutions are implemented.
         let mut content = crate::content::Content::from(crate::Span::new("pass:n,-a[<{backend}>]"));
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5035,6 +6295,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: "<{backend}>".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![
                     SubstitutionStep::SpecialCharacters,
@@ -5075,7 +6336,7 @@ mod passthroughs {
     #[test]
     fn should_not_recognize_pass_macro_with_invalid_substitution_list_1() {
         let mut content = crate::content::Content::from(crate::Span::new("pass:,[foobar]"));
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5096,7 +6357,7 @@ mod passthroughs {
     #[test]
     fn should_not_recognize_pass_macro_with_invalid_substitution_list_2() {
         let mut content = crate::content::Content::from(crate::Span::new("pass:42[foobar]"));
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5117,7 +6378,7 @@ mod passthroughs {
     #[test]
     fn should_not_recognize_pass_macro_with_invalid_substitution_list_3() {
         let mut content = crate::content::Content::from(crate::Span::new("pass:a,[foobar]"));
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5178,6 +6439,7 @@ mod passthroughs {
             crate::content::Content::from(crate::Span::new("some \u{96}0\u{97} to study"));
 
         let pt = Passthroughs(vec![Passthrough {
+            kind: PassthroughKind::TriplePlus,
             text: "<code>inline code</code>".to_owned(),
             subs: SubstitutionGroup::None,
             type_: None,
@@ -5211,12 +6473,14 @@ mod passthroughs {
 
         let pt = Passthroughs(vec![
             Passthrough {
+                kind: PassthroughKind::DoubleDollar,
                 text: "<code>{code}</code>".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
                 attrlist: None,
             },
             Passthrough {
+                kind: PassthroughKind::DoubleDollar,
                 text: "{language}".to_owned(),
                 subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
@@ -5400,7 +6664,7 @@ mod passthroughs {
             "$$[(] <'basic form'> <'logical operator'> <'basic form'> [)]$$",
         ));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5418,8 +6682,9 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::DoubleDollar,
                 text: "[(] <'basic form'> <'logical operator'> <'basic form'> [)]".to_owned(),
-                subs: SubstitutionGroup::Verbatim,
+                subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters]),
                 type_: None,
                 attrlist: None,
             },],)
@@ -5432,7 +6697,7 @@ mod passthroughs {
             r#"pass:specialcharacters[[(\] <'basic form'> <'logical operator'> <'basic form'> [)\]]"#,
         ));
 
-        let pt = Passthroughs::extract_from(&mut content);
+        let pt = Passthroughs::extract_from(&mut content, &Parser::default());
 
         assert_eq!(
             content,
@@ -5450,6 +6715,7 @@ mod passthroughs {
         assert_eq!(
             pt,
             Passthroughs(vec![Passthrough {
+                kind: PassthroughKind::PassMacro,
                 text: r#"[(] <'basic form'> <'logical operator'> <'basic form'> [)]"#.to_owned(),
                 subs: SubstitutionGroup::Custom(vec![SubstitutionStep::SpecialCharacters,],),
                 type_: None,
@@ -5868,11 +7134,238 @@ mod passthroughs {
     }
 
     mod math_macros {
-        #[ignore]
+        use pretty_assertions_sorted::assert_eq;
+
+        use crate::{Parser, blocks::IsBlock, parser::ModificationContext};
+
+        fn rendered(parser: &mut Parser, source: &str) -> String {
+            let doc = parser.parse(source);
+            let block = doc.nested_blocks().next().unwrap();
+            block.rendered_content().unwrap().to_owned()
+        }
+
+        #[test]
+        fn should_passthrough_text_in_asciimath_macro_and_surround_with_asciimath_delimiters() {
+            let mut p = Parser::default().with_intrinsic_attribute(
+                "attribute-missing",
+                "warn",
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(
+                rendered(
+                    &mut p,
+                    "asciimath:[x/x={(1,if x!=0),(text{undefined},if x=0):}]"
+                ),
+                r#"\$x/x={(1,if x!=0),(text{undefined},if x=0):}\$"#
+            );
+        }
+
+        #[test]
+        fn should_not_recognize_asciimath_macro_with_no_content() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "asciimath:[]"),
+                "asciimath:[]"
+            );
+        }
+
+        #[test]
+        fn should_perform_specialcharacters_subs_on_asciimath_macro_content_in_html_backend_by_default()
+         {
+            assert_eq!(
+                rendered(&mut Parser::default(), "asciimath:[a < b]"),
+                r#"\$a &lt; b\$"#
+            );
+        }
+
+        #[test]
+        fn should_honor_explicit_subslist_on_asciimath_macro() {
+            let mut p = Parser::default().with_intrinsic_attribute(
+                "expr",
+                "x != 0",
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(
+                rendered(&mut p, "asciimath:attributes[{expr}]"),
+                r#"\$x != 0\$"#
+            );
+        }
+
+        #[test]
+        fn should_passthrough_text_in_latexmath_macro_and_surround_with_latex_math_delimiters() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    r#"latexmath:[C = \alpha + \beta Y^{\gamma} + \epsilon]"#
+                ),
+                r#"\(C = \alpha + \beta Y^{\gamma} + \epsilon\)"#
+            );
+        }
+
+        #[test]
+        fn should_strip_legacy_latex_math_delimiters_around_latexmath_content_if_present() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    r#"latexmath:[$C = \alpha + \beta Y^{\gamma} + \epsilon$]"#
+                ),
+                r#"\(C = \alpha + \beta Y^{\gamma} + \epsilon\)"#
+            );
+        }
+
+        #[test]
+        fn should_not_recognize_latexmath_macro_with_no_content() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "latexmath:[]"),
+                "latexmath:[]"
+            );
+        }
+
+        #[test]
+        fn should_unescape_escaped_square_bracket_in_equation() {
+            assert_eq!(
+                rendered(&mut Parser::default(), r#"latexmath:[\sqrt[3\]{x}]"#),
+                r#"\(\sqrt[3]{x}\)"#
+            );
+        }
+
+        #[test]
+        fn should_perform_specialcharacters_subs_on_latexmath_macro_in_html_backend_by_default() {
+            assert_eq!(
+                rendered(&mut Parser::default(), "latexmath:[a < b]"),
+                r#"\(a &lt; b\)"#
+            );
+        }
+
+        #[test]
+        fn should_honor_explicit_subslist_on_latexmath_macro() {
+            let mut p = Parser::default().with_intrinsic_attribute(
+                "expr",
+                r#"\sqrt{4} = 2"#,
+                ModificationContext::Anywhere,
+            );
+
+            assert_eq!(
+                rendered(&mut p, "latexmath:attributes[{expr}]"),
+                r#"\(\sqrt{4} = 2\)"#
+            );
+        }
+
+        #[test]
+        fn should_passthrough_math_macro_inside_another_passthrough() {
+            // NOTE: The first case in the Ruby test suite uses compat mode, which
+            // we don't support.
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "the text [x-]`asciimath:[x = y]` should be passed through as `literal` text"
+                ),
+                "the text <code>asciimath:[x = y]</code> should be passed through as <code>literal</code> text"
+            );
+
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    "the text `+asciimath:[x = y]+` should be passed through as `literal` text"
+                ),
+                "the text <code>asciimath:[x = y]</code> should be passed through as <code>literal</code> text"
+            );
+        }
+
+        #[test]
+        fn should_passthrough_text_in_stem_macro_and_surround_with_asciimath_delimiters_if_stem_attribute_is_asciimath_empty_or_not_set()
+         {
+            for stem in [None, Some(""), Some("asciimath"), Some("bogus")] {
+                let mut p = Parser::default().with_intrinsic_attribute(
+                    "attribute-missing",
+                    "warn",
+                    ModificationContext::Anywhere,
+                );
+
+                if let Some(stem) = stem {
+                    p = p.with_intrinsic_attribute("stem", stem, ModificationContext::Anywhere);
+                }
+
+                assert_eq!(
+                    rendered(&mut p, "stem:[x/x={(1,if x!=0),(text{undefined},if x=0):}]"),
+                    r#"\$x/x={(1,if x!=0),(text{undefined},if x=0):}\$"#
+                );
+            }
+        }
+
+        #[test]
+        fn should_passthrough_text_in_stem_macro_and_surround_with_latex_math_delimiters_if_stem_attribute_is_latexmath_latex_or_tex()
+         {
+            for stem in ["latexmath", "latex", "tex"] {
+                let mut p = Parser::default().with_intrinsic_attribute(
+                    "stem",
+                    stem,
+                    ModificationContext::Anywhere,
+                );
+
+                assert_eq!(
+                    rendered(&mut p, r#"stem:[C = \alpha + \beta Y^{\gamma} + \epsilon]"#),
+                    r#"\(C = \alpha + \beta Y^{\gamma} + \epsilon\)"#
+                );
+            }
+        }
+
+        #[test]
+        fn should_apply_substitutions_specified_on_stem_macro() {
+            for input in [
+                "stem:c,a[sqrt(x) <=> {solve-for-x}]",
+                "stem:n,-r[sqrt(x) <=> {solve-for-x}]",
+            ] {
+                let mut p = Parser::default()
+                    .with_intrinsic_attribute("stem", "asciimath", ModificationContext::Anywhere)
+                    .with_intrinsic_attribute("solve-for-x", "13", ModificationContext::Anywhere);
+
+                assert_eq!(rendered(&mut p, input), r#"\$sqrt(x) &lt;=&gt; 13\$"#);
+            }
+        }
+
+        #[test]
+        fn should_replace_passthroughs_inside_stem_expression() {
+            for (input, expected) in [
+                ("stem:[+1+]", r#"\$1\$"#),
+                (r#"stem:[+\infty-+]"#, r#"\$\infty-\$"#),
+                (r#"stem:[++\infty-++]"#, r#"\$\infty-\$"#),
+                (r#"stem:[+++\infty-+++]"#, r#"\$\infty-\$"#),
+                ("stem:[x+y]", r#"\$x+y\$"#),
+            ] {
+                let mut p = Parser::default().with_intrinsic_attribute(
+                    "stem",
+                    "",
+                    ModificationContext::Anywhere,
+                );
+
+                assert_eq!(rendered(&mut p, input), expected);
+            }
+        }
+
+        #[test]
+        fn should_not_recognize_stem_macro_with_no_content() {
+            assert_eq!(rendered(&mut Parser::default(), "stem:[]"), "stem:[]");
+        }
+
+        #[test]
+        fn should_unescape_escaped_square_bracket_in_stem_equation() {
+            assert_eq!(
+                rendered(&mut Parser::default(), r#"stem:[\sqrt[3\]{x}]"#),
+                r#"\$\sqrt[3]{x}\$"#
+            );
+        }
+
         #[test]
-        fn not_implemented() {
-            todo!("Review Ruby Asciidoctor test suite for `context 'Math macros'`");
-            // See https://github.com/scouten/asciidoc-parser/issues/261.
+        fn should_honor_escape_of_stem_macro() {
+            assert_eq!(
+                rendered(
+                    &mut Parser::default(),
+                    r#"\stem:[x = y] and \latexmath:[x = y]"#
+                ),
+                "stem:[x = y] and latexmath:[x = y]"
+            );
         }
     }
 }
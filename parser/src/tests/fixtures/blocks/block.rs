@@ -1,7 +1,7 @@
 use crate::tests::fixtures::{
     blocks::{
-        Break, CompoundDelimitedBlock, MediaBlock, Preamble, RawDelimitedBlock, SectionBlock,
-        SimpleBlock,
+        AdmonitionBlock, Break, CompoundDelimitedBlock, DescriptionListItem, ListBlock, ListItem,
+        MediaBlock, Preamble, RawDelimitedBlock, SectionBlock, SimpleBlock, TableBlock,
     },
     document::Attribute,
 };
@@ -13,6 +13,11 @@ pub(crate) enum Block {
     Section(SectionBlock),
     RawDelimited(RawDelimitedBlock),
     CompoundDelimited(CompoundDelimitedBlock),
+    Admonition(AdmonitionBlock),
+    Table(TableBlock),
+    List(ListBlock),
+    ListItem(ListItem),
+    DescriptionListItem(DescriptionListItem),
     Preamble(Preamble),
     Break(Break),
     DocumentAttribute(Attribute),
@@ -57,6 +62,35 @@ fn fixture_eq_observed(fixture: &Block, observed: &crate::blocks::Block) -> bool
             _ => false,
         },
 
+        Block::Admonition(admonition_fixture) => match observed {
+            crate::blocks::Block::Admonition(admonition_observed) => {
+                admonition_fixture == admonition_observed
+            }
+            _ => false,
+        },
+
+        Block::Table(table_fixture) => match observed {
+            crate::blocks::Block::Table(table_observed) => table_fixture == table_observed,
+            _ => false,
+        },
+
+        Block::List(list_fixture) => match observed {
+            crate::blocks::Block::List(list_observed) => list_fixture == list_observed,
+            _ => false,
+        },
+
+        Block::ListItem(item_fixture) => match observed {
+            crate::blocks::Block::ListItem(item_observed) => item_fixture == item_observed,
+            _ => false,
+        },
+
+        Block::DescriptionListItem(item_fixture) => match observed {
+            crate::blocks::Block::DescriptionListItem(item_observed) => {
+                item_fixture == item_observed
+            }
+            _ => false,
+        },
+
         Block::Preamble(preamble_fixture) => match observed {
             crate::blocks::Block::Preamble(preamble_observed) => {
                 preamble_fixture == preamble_observed
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::{
+    HasSpan,
+    blocks::{IsBlock, ListType},
+    tests::fixtures::{Span, attributes::Attrlist, blocks::Block, content::Content},
+};
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct ListBlock {
+    pub type_: ListType,
+    pub items: &'static [Block],
+    pub source: Span,
+    pub title_source: Option<Span>,
+    pub title: Option<&'static str>,
+    pub anchor: Option<Span>,
+    pub anchor_reftext: Option<Span>,
+    pub attrlist: Option<Attrlist>,
+}
+
+impl fmt::Debug for ListBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListBlock")
+            .field("type_", &self.type_)
+            .field("items", &self.items)
+            .field("source", &self.source)
+            .field("title_source", &self.title_source)
+            .field("title", &self.title)
+            .field("anchor", &self.anchor)
+            .field("anchor_reftext", &self.anchor_reftext)
+            .field("attrlist", &self.attrlist)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::ListBlock<'src>> for ListBlock {
+    fn eq(&self, other: &crate::blocks::ListBlock<'src>) -> bool {
+        fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<ListBlock> for crate::blocks::ListBlock<'_> {
+    fn eq(&self, other: &ListBlock) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+fn fixture_eq_observed(fixture: &ListBlock, observed: &crate::blocks::ListBlock) -> bool {
+    if fixture.type_ != observed.type_() {
+        return false;
+    }
+
+    if fixture.items.len() != observed.nested_blocks().len() {
+        return false;
+    }
+
+    for (fixture_item, observed_item) in fixture.items.iter().zip(observed.nested_blocks()) {
+        if fixture_item != observed_item {
+            return false;
+        }
+    }
+
+    if fixture.title_source.is_some() != observed.title_source().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_title_source) = fixture.title_source
+        && let Some(ref observed_title_source) = observed.title_source()
+        && fixture_title_source != observed_title_source
+    {
+        return false;
+    }
+
+    if fixture.title.is_some() != observed.title().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_title) = fixture.title
+        && let Some(ref observed_title) = observed.title()
+        && fixture_title != observed_title
+    {
+        return false;
+    }
+
+    if fixture.anchor.is_some() != observed.anchor().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_anchor) = fixture.anchor
+        && let Some(ref observed_anchor) = observed.anchor()
+        && fixture_anchor != observed_anchor
+    {
+        return false;
+    }
+
+    if fixture.anchor_reftext.is_some() != observed.anchor_reftext().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_anchor_reftext) = fixture.anchor_reftext
+        && let Some(ref observed_anchor_reftext) = observed.anchor_reftext()
+        && fixture_anchor_reftext != observed_anchor_reftext
+    {
+        return false;
+    }
+
+    if fixture.attrlist.is_some() != observed.attrlist().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_attrlist) = fixture.attrlist
+        && let Some(ref observed_attrlist) = observed.attrlist()
+        && &fixture_attrlist != observed_attrlist
+    {
+        return false;
+    }
+
+    fixture.source == observed.span()
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct ListItem {
+    pub marker: Span,
+    pub principal_text: Content,
+    pub checked: Option<bool>,
+    pub callout_ids: &'static [&'static str],
+    pub blocks: &'static [Block],
+    pub source: Span,
+}
+
+impl fmt::Debug for ListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListItem")
+            .field("marker", &self.marker)
+            .field("principal_text", &self.principal_text)
+            .field("checked", &self.checked)
+            .field("callout_ids", &self.callout_ids)
+            .field("blocks", &self.blocks)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::ListItem<'src>> for ListItem {
+    fn eq(&self, other: &crate::blocks::ListItem<'src>) -> bool {
+        list_item_fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<ListItem> for crate::blocks::ListItem<'_> {
+    fn eq(&self, other: &ListItem) -> bool {
+        list_item_fixture_eq_observed(other, self)
+    }
+}
+
+fn list_item_fixture_eq_observed(fixture: &ListItem, observed: &crate::blocks::ListItem) -> bool {
+    if fixture.blocks.len() != observed.nested_blocks().len() {
+        return false;
+    }
+
+    for (fixture_block, observed_block) in fixture.blocks.iter().zip(observed.nested_blocks()) {
+        if fixture_block != observed_block {
+            return false;
+        }
+    }
+
+    fixture.marker == observed.marker()
+        && &fixture.principal_text == observed.principal_text()
+        && fixture.checked == observed.checked()
+        && fixture.callout_ids == observed.callout_ids()
+        && fixture.source == observed.span()
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct DescriptionListItem {
+    pub terms: &'static [Content],
+    pub marker: Span,
+    pub principal_text: Option<Content>,
+    pub blocks: &'static [Block],
+    pub source: Span,
+}
+
+impl fmt::Debug for DescriptionListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DescriptionListItem")
+            .field("terms", &self.terms)
+            .field("marker", &self.marker)
+            .field("principal_text", &self.principal_text)
+            .field("blocks", &self.blocks)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::DescriptionListItem<'src>> for DescriptionListItem {
+    fn eq(&self, other: &crate::blocks::DescriptionListItem<'src>) -> bool {
+        description_list_item_fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<DescriptionListItem> for crate::blocks::DescriptionListItem<'_> {
+    fn eq(&self, other: &DescriptionListItem) -> bool {
+        description_list_item_fixture_eq_observed(other, self)
+    }
+}
+
+fn description_list_item_fixture_eq_observed(
+    fixture: &DescriptionListItem,
+    observed: &crate::blocks::DescriptionListItem,
+) -> bool {
+    if fixture.terms.len() != observed.terms().len() {
+        return false;
+    }
+
+    for (fixture_term, observed_term) in fixture.terms.iter().zip(observed.terms()) {
+        if fixture_term != observed_term {
+            return false;
+        }
+    }
+
+    if fixture.blocks.len() != observed.nested_blocks().len() {
+        return false;
+    }
+
+    for (fixture_block, observed_block) in fixture.blocks.iter().zip(observed.nested_blocks()) {
+        if fixture_block != observed_block {
+            return false;
+        }
+    }
+
+    let principal_text_eq = match (&fixture.principal_text, observed.principal_text()) {
+        (Some(fixture_text), Some(observed_text)) => fixture_text == observed_text,
+        (None, None) => true,
+        _ => false,
+    };
+
+    principal_text_eq && fixture.marker == observed.marker() && fixture.source == observed.span()
+}
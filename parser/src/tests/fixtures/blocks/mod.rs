@@ -1,3 +1,6 @@
+mod admonition;
+pub(crate) use admonition::AdmonitionBlock;
+
 mod block;
 pub(crate) use block::Block;
 
@@ -7,6 +10,9 @@ pub(crate) use r#break::Break;
 mod compound_delimited;
 pub(crate) use compound_delimited::CompoundDelimitedBlock;
 
+mod list;
+pub(crate) use list::{DescriptionListItem, ListBlock, ListItem};
+
 mod media;
 pub(crate) use media::MediaBlock;
 
@@ -24,3 +30,6 @@ pub(crate) use section_number::SectionNumber;
 
 mod simple;
 pub(crate) use simple::SimpleBlock;
+
+mod table;
+pub(crate) use table::{TableBlock, TableCell, TableRow};
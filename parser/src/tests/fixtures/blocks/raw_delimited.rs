@@ -3,7 +3,7 @@ use std::fmt;
 use crate::{
     HasSpan,
     blocks::{ContentModel, IsBlock},
-    content::SubstitutionGroup,
+    content::{StemNotation, SubstitutionGroup},
     tests::fixtures::{Span, attributes::Attrlist, content::Content},
 };
 
@@ -19,6 +19,7 @@ pub(crate) struct RawDelimitedBlock {
     pub anchor_reftext: Option<Span>,
     pub attrlist: Option<Attrlist>,
     pub substitution_group: SubstitutionGroup,
+    pub stem_notation: Option<StemNotation>,
 }
 
 impl fmt::Debug for RawDelimitedBlock {
@@ -34,6 +35,7 @@ impl fmt::Debug for RawDelimitedBlock {
             .field("anchor_reftext", &self.anchor_reftext)
             .field("attrlist", &self.attrlist)
             .field("substitution_group", &self.substitution_group)
+            .field("stem_notation", &self.stem_notation)
             .finish()
     }
 }
@@ -121,5 +123,7 @@ fn fixture_eq_observed(
         return false;
     }
 
-    fixture.source == observed.span() && fixture.substitution_group == observed.substitution_group()
+    fixture.source == observed.span()
+        && fixture.substitution_group == observed.substitution_group()
+        && fixture.stem_notation == observed.stem_notation()
 }
@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::{
+    HasSpan,
+    blocks::{
+        HorizontalAlignment, IsBlock, TableCellStyle, TableColumn, TableFormat, VerticalAlignment,
+    },
+    tests::fixtures::{Span, attributes::Attrlist, blocks::Block, content::Content},
+};
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct TableBlock {
+    pub columns: &'static [TableColumn],
+    pub format: TableFormat,
+    pub header_row: Option<TableRow>,
+    pub body_rows: &'static [TableRow],
+    pub footer_row: Option<TableRow>,
+    pub source: Span,
+    pub title_source: Option<Span>,
+    pub title: Option<&'static str>,
+    pub anchor: Option<Span>,
+    pub anchor_reftext: Option<Span>,
+    pub attrlist: Option<Attrlist>,
+}
+
+impl fmt::Debug for TableBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableBlock")
+            .field("columns", &self.columns)
+            .field("format", &self.format)
+            .field("header_row", &self.header_row)
+            .field("body_rows", &self.body_rows)
+            .field("footer_row", &self.footer_row)
+            .field("source", &self.source)
+            .field("title_source", &self.title_source)
+            .field("title", &self.title)
+            .field("anchor", &self.anchor)
+            .field("anchor_reftext", &self.anchor_reftext)
+            .field("attrlist", &self.attrlist)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::TableBlock<'src>> for TableBlock {
+    fn eq(&self, other: &crate::blocks::TableBlock<'src>) -> bool {
+        fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<TableBlock> for crate::blocks::TableBlock<'_> {
+    fn eq(&self, other: &TableBlock) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+fn fixture_eq_observed(fixture: &TableBlock, observed: &crate::blocks::TableBlock) -> bool {
+    if fixture.columns != observed.columns() {
+        return false;
+    }
+
+    if fixture.format != observed.format() {
+        return false;
+    }
+
+    if fixture.header_row.is_some() != observed.header_row().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_header_row) = fixture.header_row
+        && let Some(observed_header_row) = observed.header_row()
+        && fixture_header_row != observed_header_row
+    {
+        return false;
+    }
+
+    if fixture.body_rows.len() != observed.body_rows().len() {
+        return false;
+    }
+
+    for (fixture_row, observed_row) in fixture.body_rows.iter().zip(observed.body_rows()) {
+        if fixture_row != observed_row {
+            return false;
+        }
+    }
+
+    if fixture.footer_row.is_some() != observed.footer_row().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_footer_row) = fixture.footer_row
+        && let Some(observed_footer_row) = observed.footer_row()
+        && fixture_footer_row != observed_footer_row
+    {
+        return false;
+    }
+
+    if fixture.title_source.is_some() != observed.title_source().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_title_source) = fixture.title_source
+        && let Some(ref observed_title_source) = observed.title_source()
+        && fixture_title_source != observed_title_source
+    {
+        return false;
+    }
+
+    if fixture.title.is_some() != observed.title().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_title) = fixture.title
+        && let Some(ref observed_title) = observed.title()
+        && fixture_title != observed_title
+    {
+        return false;
+    }
+
+    if fixture.anchor.is_some() != observed.anchor().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_anchor) = fixture.anchor
+        && let Some(ref observed_anchor) = observed.anchor()
+        && fixture_anchor != observed_anchor
+    {
+        return false;
+    }
+
+    if fixture.anchor_reftext.is_some() != observed.anchor_reftext().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_anchor_reftext) = fixture.anchor_reftext
+        && let Some(ref observed_anchor_reftext) = observed.anchor_reftext()
+        && fixture_anchor_reftext != observed_anchor_reftext
+    {
+        return false;
+    }
+
+    if fixture.attrlist.is_some() != observed.attrlist().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_attrlist) = fixture.attrlist
+        && let Some(ref observed_attrlist) = observed.attrlist()
+        && &fixture_attrlist != observed_attrlist
+    {
+        return false;
+    }
+
+    fixture.source == observed.span()
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct TableRow {
+    pub cells: &'static [TableCell],
+}
+
+impl fmt::Debug for TableRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableRow")
+            .field("cells", &self.cells)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::TableRow<'src>> for TableRow {
+    fn eq(&self, other: &crate::blocks::TableRow<'src>) -> bool {
+        row_fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<TableRow> for crate::blocks::TableRow<'_> {
+    fn eq(&self, other: &TableRow) -> bool {
+        row_fixture_eq_observed(other, self)
+    }
+}
+
+fn row_fixture_eq_observed(fixture: &TableRow, observed: &crate::blocks::TableRow) -> bool {
+    if fixture.cells.len() != observed.cells().len() {
+        return false;
+    }
+
+    for (fixture_cell, observed_cell) in fixture.cells.iter().zip(observed.cells()) {
+        if fixture_cell != observed_cell {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct TableCell {
+    pub content: Option<Content>,
+    pub blocks: &'static [Block],
+    pub source: Span,
+    pub colspan: usize,
+    pub rowspan: usize,
+    pub halign: HorizontalAlignment,
+    pub valign: VerticalAlignment,
+    pub style: TableCellStyle,
+}
+
+impl fmt::Debug for TableCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableCell")
+            .field("content", &self.content)
+            .field("blocks", &self.blocks)
+            .field("source", &self.source)
+            .field("colspan", &self.colspan)
+            .field("rowspan", &self.rowspan)
+            .field("halign", &self.halign)
+            .field("valign", &self.valign)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::blocks::TableCell<'src>> for TableCell {
+    fn eq(&self, other: &crate::blocks::TableCell<'src>) -> bool {
+        cell_fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<TableCell> for crate::blocks::TableCell<'_> {
+    fn eq(&self, other: &TableCell) -> bool {
+        cell_fixture_eq_observed(other, self)
+    }
+}
+
+fn cell_fixture_eq_observed(fixture: &TableCell, observed: &crate::blocks::TableCell) -> bool {
+    if fixture.content.is_some() != observed.content().is_some() {
+        return false;
+    }
+
+    if let Some(ref fixture_content) = fixture.content
+        && let Some(observed_content) = observed.content()
+        && fixture_content != observed_content
+    {
+        return false;
+    }
+
+    if fixture.blocks.len() != observed.nested_blocks().len() {
+        return false;
+    }
+
+    for (fixture_block, observed_block) in fixture.blocks.iter().zip(observed.nested_blocks()) {
+        if fixture_block != observed_block {
+            return false;
+        }
+    }
+
+    fixture.colspan == observed.colspan()
+        && fixture.rowspan == observed.rowspan()
+        && fixture.halign == observed.halign()
+        && fixture.valign == observed.valign()
+        && fixture.style == observed.style()
+        && fixture.source == observed.span()
+}
@@ -5,7 +5,7 @@ use crate::{
     tests::fixtures::{
         Span,
         blocks::Block,
-        document::{Catalog, Header},
+        document::{Catalog, Footnote, Header, IndexTerm},
         parser::SourceMap,
         warnings::Warning,
     },
@@ -18,6 +18,8 @@ pub(crate) struct Document {
     pub source: Span,
     pub warnings: &'static [Warning],
     pub source_map: SourceMap,
+    pub footnotes: &'static [Footnote],
+    pub index_terms: &'static [IndexTerm],
     pub catalog: Catalog,
 }
 
@@ -29,6 +31,8 @@ impl fmt::Debug for Document {
             .field("source", &self.source)
             .field("warnings", &self.warnings)
             .field("source_map", &self.source_map)
+            .field("footnotes", &self.footnotes)
+            .field("index_terms", &self.index_terms)
             .field("catalog", &self.catalog)
             .finish()
     }
@@ -81,5 +85,26 @@ fn fixture_eq_observed(fixture: &Document, observed: &crate::Document) -> bool {
         }
     }
 
+    if fixture.footnotes.len() != observed.footnotes().len() {
+        return false;
+    }
+
+    for (fixture_footnote, observed_footnote) in fixture.footnotes.iter().zip(observed.footnotes())
+    {
+        if fixture_footnote != observed_footnote {
+            return false;
+        }
+    }
+
+    if fixture.index_terms.len() != observed.index_terms().len() {
+        return false;
+    }
+
+    for (fixture_term, observed_term) in fixture.index_terms.iter().zip(observed.index_terms()) {
+        if fixture_term != observed_term {
+            return false;
+        }
+    }
+
     &fixture.source_map == observed.source_map() && &fixture.catalog == observed.catalog()
 }
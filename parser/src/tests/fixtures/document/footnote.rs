@@ -0,0 +1,42 @@
+use std::{cmp::PartialEq, fmt};
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct Footnote {
+    pub(crate) index: usize,
+    pub(crate) id: Option<&'static str>,
+    pub(crate) text: &'static str,
+}
+
+impl fmt::Debug for Footnote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("crate::document::Footnote")
+            .field("index", &self.index)
+            .field("id", &self.id)
+            .field("text", &self.text)
+            .finish()
+    }
+}
+
+impl PartialEq<crate::document::Footnote> for Footnote {
+    fn eq(&self, other: &crate::document::Footnote) -> bool {
+        fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<Footnote> for crate::document::Footnote {
+    fn eq(&self, other: &Footnote) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+impl PartialEq<Footnote> for &crate::document::Footnote {
+    fn eq(&self, other: &Footnote) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+fn fixture_eq_observed(fixture: &Footnote, observed: &crate::document::Footnote) -> bool {
+    fixture.index == observed.index
+        && fixture.id == observed.id.as_deref()
+        && fixture.text == observed.text
+}
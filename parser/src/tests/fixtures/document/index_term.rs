@@ -0,0 +1,50 @@
+use std::{cmp::PartialEq, fmt};
+
+use crate::tests::fixtures::Span;
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct IndexTerm {
+    pub(crate) terms: &'static [&'static str],
+    pub(crate) see: Option<&'static str>,
+    pub(crate) see_also: &'static [&'static str],
+    pub(crate) visible: bool,
+    pub(crate) source: Span,
+}
+
+impl fmt::Debug for IndexTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("crate::document::IndexTerm")
+            .field("terms", &self.terms)
+            .field("see", &self.see)
+            .field("see_also", &self.see_also)
+            .field("visible", &self.visible)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<'src> PartialEq<crate::document::IndexTerm<'src>> for IndexTerm {
+    fn eq(&self, other: &crate::document::IndexTerm<'src>) -> bool {
+        fixture_eq_observed(self, other)
+    }
+}
+
+impl PartialEq<IndexTerm> for crate::document::IndexTerm<'_> {
+    fn eq(&self, other: &IndexTerm) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+impl PartialEq<IndexTerm> for &crate::document::IndexTerm<'_> {
+    fn eq(&self, other: &IndexTerm) -> bool {
+        fixture_eq_observed(other, self)
+    }
+}
+
+fn fixture_eq_observed(fixture: &IndexTerm, observed: &crate::document::IndexTerm) -> bool {
+    fixture.terms == observed.terms.as_slice()
+        && fixture.see == observed.see.as_deref()
+        && fixture.see_also == observed.see_also.as_slice()
+        && fixture.visible == observed.visible
+        && fixture.source == observed.source
+}
@@ -27,9 +27,15 @@ pub(crate) use catalog::Catalog;
 mod document;
 pub(crate) use document::Document;
 
+mod footnote;
+pub(crate) use footnote::Footnote;
+
 mod header;
 pub(crate) use header::Header;
 
+mod index_term;
+pub(crate) use index_term::IndexTerm;
+
 mod ref_entry;
 pub(crate) use ref_entry::RefEntry;
 
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::Span;
+use crate::{Span, parser::SourceLine};
 
 /// Describes a possible parse error (i.e. a "warning") and its location.
 ///
@@ -76,6 +76,63 @@ pub enum WarningType {
 
     #[error("Section heading level exceeds maximum (maximum 5, found {0})")]
     SectionHeadingLevelExceedsMaximum(usize),
+
+    #[error("List item index out of sequence (expected {0}, found {1})")]
+    ListItemOutOfSequence(String, String),
+
+    #[error("No callout found for <{0}>")]
+    NoCalloutFound(usize),
+
+    #[error("Table cell is missing its leading cell separator")]
+    TableMissingLeadingSeparator,
+
+    #[error("Table cell exceeds the number of columns in the table; dropping cell")]
+    TableCellExceedsColumns,
+
+    #[error("Table cell spans beyond the last column of the table")]
+    TableCellSpanExceedsColumns,
+
+    #[error("Table row is incomplete; dropping cells")]
+    TableIncompleteRow,
+
+    #[error("Table format {0:?} is not supported")]
+    TableInvalidFormat(String),
+
+    #[error("Table value has an unclosed quote")]
+    TableUnclosedQuote,
+
+    #[error("Table column, span, or repeat count exceeds the limit of {0}")]
+    TableCountExceedsLimit(usize),
+
+    #[error("Unmatched preprocessor directive: {0}")]
+    UnmatchedPreprocessorDirective(String),
+
+    #[error("Mismatched preprocessor directive: {0} (expected {1})")]
+    MismatchedPreprocessorDirective(String, String),
+
+    #[error("Malformed preprocessor directive: {0}")]
+    MalformedPreprocessorDirective(String),
+
+    #[error("Invalid expression in ifeval directive: {0}")]
+    InvalidIfevalExpression(String),
+
+    #[error("Tag '{0}' not found in include file {1}")]
+    IncludeTagNotFound(String, String),
+
+    #[error("Unclosed tag '{0}' in include file ({1})")]
+    UnclosedIncludeTag(String, SourceLine),
+
+    #[error("Mismatched end tag '{0}' (expected '{1}') in include file ({2})")]
+    MismatchedIncludeTag(String, String, SourceLine),
+
+    #[error("Unexpected end tag '{0}' in include file ({1})")]
+    UnexpectedIncludeTagEnd(String, SourceLine),
+
+    #[error("Maximum include depth of {0} exceeded (include chain: {chain})", chain = .1.join(" -> "))]
+    MaxIncludeDepthExceeded(usize, Vec<String>),
+
+    #[error("Include cycle detected (include chain: {chain})", chain = .0.join(" -> "))]
+    IncludeCycle(Vec<String>),
 }
 
 impl std::fmt::Debug for WarningType {
@@ -143,6 +200,104 @@ impl std::fmt::Debug for WarningType {
                 .debug_tuple("WarningType::SectionHeadingLevelExceedsMaximum")
                 .field(found)
                 .finish(),
+
+            WarningType::ListItemOutOfSequence(expected, found) => f
+                .debug_tuple("WarningType::ListItemOutOfSequence")
+                .field(expected)
+                .field(found)
+                .finish(),
+
+            WarningType::NoCalloutFound(number) => f
+                .debug_tuple("WarningType::NoCalloutFound")
+                .field(number)
+                .finish(),
+
+            WarningType::TableMissingLeadingSeparator => {
+                write!(f, "WarningType::TableMissingLeadingSeparator")
+            }
+
+            WarningType::TableCellExceedsColumns => {
+                write!(f, "WarningType::TableCellExceedsColumns")
+            }
+
+            WarningType::TableCellSpanExceedsColumns => {
+                write!(f, "WarningType::TableCellSpanExceedsColumns")
+            }
+
+            WarningType::TableIncompleteRow => {
+                write!(f, "WarningType::TableIncompleteRow")
+            }
+
+            WarningType::TableInvalidFormat(format) => f
+                .debug_tuple("WarningType::TableInvalidFormat")
+                .field(format)
+                .finish(),
+
+            WarningType::TableUnclosedQuote => {
+                write!(f, "WarningType::TableUnclosedQuote")
+            }
+
+            WarningType::TableCountExceedsLimit(limit) => f
+                .debug_tuple("WarningType::TableCountExceedsLimit")
+                .field(limit)
+                .finish(),
+
+            WarningType::UnmatchedPreprocessorDirective(directive) => f
+                .debug_tuple("WarningType::UnmatchedPreprocessorDirective")
+                .field(directive)
+                .finish(),
+
+            WarningType::MismatchedPreprocessorDirective(found, expected) => f
+                .debug_tuple("WarningType::MismatchedPreprocessorDirective")
+                .field(found)
+                .field(expected)
+                .finish(),
+
+            WarningType::MalformedPreprocessorDirective(directive) => f
+                .debug_tuple("WarningType::MalformedPreprocessorDirective")
+                .field(directive)
+                .finish(),
+
+            WarningType::InvalidIfevalExpression(directive) => f
+                .debug_tuple("WarningType::InvalidIfevalExpression")
+                .field(directive)
+                .finish(),
+
+            WarningType::IncludeTagNotFound(tag, file) => f
+                .debug_tuple("WarningType::IncludeTagNotFound")
+                .field(tag)
+                .field(file)
+                .finish(),
+
+            WarningType::UnclosedIncludeTag(tag, source_line) => f
+                .debug_tuple("WarningType::UnclosedIncludeTag")
+                .field(tag)
+                .field(source_line)
+                .finish(),
+
+            WarningType::MismatchedIncludeTag(found, expected, source_line) => f
+                .debug_tuple("WarningType::MismatchedIncludeTag")
+                .field(found)
+                .field(expected)
+                .field(source_line)
+                .finish(),
+
+            WarningType::UnexpectedIncludeTagEnd(tag, source_line) => f
+                .debug_tuple("WarningType::UnexpectedIncludeTagEnd")
+                .field(tag)
+                .field(source_line)
+                .finish(),
+
+            WarningType::MaxIncludeDepthExceeded(max_depth, chain) => f
+                .debug_tuple("WarningType::MaxIncludeDepthExceeded")
+                .field(max_depth)
+                .field(chain)
+                .finish(),
+
+            WarningType::IncludeCycle(chain) => f
+                .debug_tuple("WarningType::IncludeCycle")
+                .field(chain)
+                .finish(),
         }
     }
 }
@@ -201,7 +356,7 @@ mod tests {
         mod impl_debug {
             use pretty_assertions_sorted::assert_eq;
 
-            use crate::warnings::WarningType;
+            use crate::{parser::SourceLine, warnings::WarningType};
 
             #[test]
             fn attribute_value_missing_terminating_quote() {
@@ -388,6 +543,194 @@ mod tests {
                     "WarningType::SectionHeadingLevelExceedsMaximum(6)"
                 );
             }
+
+            #[test]
+            fn list_item_out_of_sequence() {
+                let warning = WarningType::ListItemOutOfSequence("b".to_owned(), "d".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::ListItemOutOfSequence(\"b\", \"d\")"
+                );
+            }
+
+            #[test]
+            fn no_callout_found() {
+                let warning = WarningType::NoCalloutFound(3);
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::NoCalloutFound(3)");
+            }
+
+            #[test]
+            fn table_missing_leading_separator() {
+                let warning = WarningType::TableMissingLeadingSeparator;
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableMissingLeadingSeparator");
+            }
+
+            #[test]
+            fn table_cell_exceeds_columns() {
+                let warning = WarningType::TableCellExceedsColumns;
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableCellExceedsColumns");
+            }
+
+            #[test]
+            fn table_cell_span_exceeds_columns() {
+                let warning = WarningType::TableCellSpanExceedsColumns;
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableCellSpanExceedsColumns");
+            }
+
+            #[test]
+            fn table_incomplete_row() {
+                let warning = WarningType::TableIncompleteRow;
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableIncompleteRow");
+            }
+
+            #[test]
+            fn table_invalid_format() {
+                let warning = WarningType::TableInvalidFormat("xsv".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableInvalidFormat(\"xsv\")");
+            }
+
+            #[test]
+            fn table_unclosed_quote() {
+                let warning = WarningType::TableUnclosedQuote;
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableUnclosedQuote");
+            }
+
+            #[test]
+            fn table_count_exceeds_limit() {
+                let warning = WarningType::TableCountExceedsLimit(1000);
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(debug_output, "WarningType::TableCountExceedsLimit(1000)");
+            }
+
+            #[test]
+            fn unmatched_preprocessor_directive() {
+                let warning =
+                    WarningType::UnmatchedPreprocessorDirective("endif::foo[]".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::UnmatchedPreprocessorDirective(\"endif::foo[]\")"
+                );
+            }
+
+            #[test]
+            fn mismatched_preprocessor_directive() {
+                let warning = WarningType::MismatchedPreprocessorDirective(
+                    "endif::foo[]".to_owned(),
+                    "endif::bar[]".to_owned(),
+                );
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::MismatchedPreprocessorDirective(\"endif::foo[]\", \"endif::bar[]\")"
+                );
+            }
+
+            #[test]
+            fn malformed_preprocessor_directive() {
+                let warning = WarningType::MalformedPreprocessorDirective("ifdef::[]".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::MalformedPreprocessorDirective(\"ifdef::[]\")"
+                );
+            }
+
+            #[test]
+            fn invalid_ifeval_expression() {
+                let warning = WarningType::InvalidIfevalExpression("ifeval::[foo]".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::InvalidIfevalExpression(\"ifeval::[foo]\")"
+                );
+            }
+
+            #[test]
+            fn include_tag_not_found() {
+                let warning =
+                    WarningType::IncludeTagNotFound("main".to_owned(), "hello.rs".to_owned());
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::IncludeTagNotFound(\"main\", \"hello.rs\")"
+                );
+            }
+
+            #[test]
+            fn unclosed_include_tag() {
+                let warning = WarningType::UnclosedIncludeTag(
+                    "main".to_owned(),
+                    SourceLine(Some("hello.rs".to_owned()), 3),
+                );
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::UnclosedIncludeTag(\"main\", SourceLine(Some(\"hello.rs\"), 3))"
+                );
+            }
+
+            #[test]
+            fn mismatched_include_tag() {
+                let warning = WarningType::MismatchedIncludeTag(
+                    "a".to_owned(),
+                    "b".to_owned(),
+                    SourceLine(Some("hello.rs".to_owned()), 7),
+                );
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::MismatchedIncludeTag(\"a\", \"b\", SourceLine(Some(\"hello.rs\"), 7))"
+                );
+            }
+
+            #[test]
+            fn unexpected_include_tag_end() {
+                let warning = WarningType::UnexpectedIncludeTagEnd(
+                    "main".to_owned(),
+                    SourceLine(Some("hello.rs".to_owned()), 9),
+                );
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::UnexpectedIncludeTagEnd(\"main\", SourceLine(Some(\"hello.rs\"), 9))"
+                );
+            }
+
+            #[test]
+            fn max_include_depth_exceeded() {
+                let warning = WarningType::MaxIncludeDepthExceeded(
+                    64,
+                    vec!["main.adoc".to_owned(), "main.adoc".to_owned()],
+                );
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::MaxIncludeDepthExceeded(64, [\"main.adoc\", \"main.adoc\"])"
+                );
+            }
+
+            #[test]
+            fn include_cycle() {
+                let warning = WarningType::IncludeCycle(vec![
+                    "main.adoc".to_owned(),
+                    "a.adoc".to_owned(),
+                    "a.adoc".to_owned(),
+                ]);
+                let debug_output = format!("{:?}", warning);
+                assert_eq!(
+                    debug_output,
+                    "WarningType::IncludeCycle([\"main.adoc\", \"a.adoc\", \"a.adoc\"])"
+                );
+            }
         }
     }
 